@@ -158,3 +158,202 @@ paper_tutorial_body_up_down = 1. Open Settings → Page Turn Mode → Up/Down. 2
 paper_tutorial_body_scroll = 1. Open Settings → Page Turn Mode → Scroll. 2. Scroll the page to read the story.
 paper_tutorial_skip = Skip
 paper_tutorial_got_it = Got it
+submit_queued_offline = You're offline. The change was saved and will be sent when the connection returns.
+pending_changes = { $count } change(s) waiting to sync
+pending_changes_sync_now = Sync now
+pending_changes_synced = Offline changes synced.
+pending_changes_rejected = Queued changes rejected by the server
+login_error_invalid = Incorrect email or password.
+register_error_rejected = Registration was rejected. The email may already be in use.
+auth_error_network = Could not reach the server. Check your connection and try again.
+auth_error_unexpected = Something went wrong
+logout = Log out
+logout_success = You have been logged out.
+invite_request_error_rejected = We couldn't accept this request. Please check your email address.
+invite_code_error_unknown = This invite code doesn't exist.
+invite_code_error_not_approved = This invite request hasn't been approved.
+invite_code_error_expired = This invite code has expired. Please request a new one.
+invite_code_error_used = This invite code has already been used.
+invite_code_error_email_mismatch = This invite code was issued for a different email.
+invite_status_pending = Pending
+invite_status_approved = Approved
+invite_status_rejected = Rejected
+invite_status_redeemed = Redeemed
+invite_status_expired = Expired
+invite_review_title = Invite requests
+invite_review_empty = No invite requests yet.
+invite_review_approve = Approve
+invite_review_reissue = Issue new code
+invite_review_reject = Reject
+invite_review_expires = expires { $date }
+invite_review_updated = Invite updated
+invite_review_failed = Could not update invite
+dashboard_language_not_assigned = You are not assigned to translate this language.
+graph_view_show = Story map
+graph_view_hide = Hide story map
+graph_empty = This chapter has no paragraphs yet.
+graph_edge_normal = Choice
+graph_edge_random = Random target
+graph_edge_timeout = Timeout
+graph_edge_same_page = Same page
+graph_zoom_in = Zoom in
+graph_zoom_out = Zoom out
+graph_zoom_reset = Reset view
+graph_external_chapter = other chapter
+graph_retarget_success = Choice target updated
+graph_retarget_failed = Could not update the choice target
+chapter_manager_toggle = Manage chapters
+chapter_manager_title = Chapters
+chapter_manager_untitled = (untitled)
+chapter_manager_new_placeholder = New chapter title
+chapter_manager_add = Add chapter
+chapter_manager_rename = Rename
+chapter_manager_save = Save
+chapter_manager_cancel = Cancel
+chapter_manager_move_up = Move up
+chapter_manager_move_down = Move down
+chapter_manager_delete = Delete
+chapter_manager_delete_confirm = Delete the chapter "{ $title }"?
+chapter_manager_delete_paragraphs = { $count } paragraph(s) in this chapter will be deleted as well.
+chapter_manager_delete_inbound = { $count } choice(s) in other chapters point into this chapter:
+chapter_manager_saved = Chapters updated
+chapter_manager_failed = Could not update chapters
+paragraph_delete_action = Delete paragraph
+paragraph_delete_cancel = Cancel
+paragraph_delete_confirm = Delete paragraph { $id }?
+paragraph_delete_no_references = No choices point at this paragraph.
+paragraph_delete_inbound = { $count } choice(s) point at this paragraph:
+paragraph_delete_retarget = Point them at another paragraph
+paragraph_delete_pick_target = Choose a paragraph
+paragraph_delete_remove_choices = Remove those choices (timeouts only lose their countdown)
+paragraph_delete_done = Paragraph deleted
+paragraph_delete_failed = Could not delete the paragraph; nothing was changed
+paragraph_clone_duplicate = Duplicate paragraph
+paragraph_clone_depth = Depth
+paragraph_clone_subtree = Clone branch ({ $count } paragraphs)
+paragraph_clone_done = Copy created
+paragraph_clone_failed = Could not create the copy
+revision_show_history = Show history
+revision_hide_history = Hide history
+revision_history_title = Revision history
+revision_loading = Loading revisions…
+revision_empty = No revisions yet. One is recorded every time the paragraph is saved.
+revision_unknown_author = Unknown author
+revision_no_changes = This revision matches the current paragraph.
+revision_captions = Choice captions
+revision_choices = Choices
+revision_impacts = { $count } impacts
+revision_restore = Restore this revision
+revision_restored = Revision restored
+revision_restore_failed = Could not restore the revision
+search_toggle = Search story
+search_placeholder = Search text and choices in every language
+search_all_chapters = All chapters
+search_any_translation = Any translation state
+search_missing_translation = Missing { $lang }
+search_has_impacts = Has impacts
+search_has_timer = Has timer
+search_result_count = { $count } paragraphs found
+search_no_results = No paragraphs match
+search_field_body = Text
+search_field_caption = Choice { $number }
+find_replace_toggle = Find and replace
+find_replace_find = Find
+find_replace_replace = Replace with
+find_replace_regex = Regular expression
+find_replace_case_sensitive = Match case
+find_replace_all_languages = All languages
+find_replace_invalid_pattern = Invalid pattern: { $error }
+find_replace_match_count = { $count } matches
+find_replace_apply = Replace { $count } matches
+find_replace_done = Paragraphs updated
+find_replace_failed = Replace stopped after some paragraphs
+bundle_toggle = Import / export
+bundle_export = Export story
+bundle_export_failed = Export failed
+bundle_import_title = Import a story bundle
+bundle_paste_placeholder = Choose a bundle file or paste its JSON here
+bundle_remap = Import as a copy with new ids
+bundle_dry_run = Dry run
+bundle_invalid = Cannot import this bundle
+bundle_collection = Collection
+bundle_create = Create
+bundle_update = Update
+bundle_apply = Import { $count } records
+bundle_imported = Story imported
+bundle_import_failed = Import stopped
+bundle_chapters = Chapters
+bundle_paragraphs = Paragraphs
+bundle_characters = Characters
+bundle_relationships = Relationships
+bundle_attributes = Attributes
+bundle_settings = Settings
+bundle_twee = Twee 3 source (text in { $lang })
+bundle_twee_warnings = { $count } items were not carried over
+bundle_export_ink = Export as Ink
+bundle_export_choicescript = Export as ChoiceScript
+bundle_export_issues = { $count } features did not carry over
+markup_hint = *emphasis*  **strong**  @Name: dialogue  * * * scene break  [^1] footnote
+markup_preview_show = Preview
+markup_preview_hide = Hide preview
+interpolation_unknown_key = { $key } is never set by this story
+interpolation_syntax_error = Cannot read {"{"}{ $expression }{"}"}: { $message }
+input_prompt_action = Reader input
+prompt_kind = Answer type
+prompt_kind_text = Text
+prompt_kind_number = Number
+prompt_kind_select = Pick one
+prompt_required = Answer required
+prompt_min_length = Minimum length
+prompt_max_length = Maximum length
+prompt_pattern = Pattern (regular expression)
+prompt_min = Minimum
+prompt_max = Maximum
+prompt_integer = Whole numbers only
+prompt_options = Options (comma separated)
+prompt_config_missing_key = Set an action key to store the answer under.
+prompt_config_invalid_pattern = The pattern is not a valid regular expression: { $message }
+prompt_config_empty_range = The minimum is larger than the maximum.
+prompt_config_no_options = Add at least one option.
+prompt_error_required = Please enter an answer.
+prompt_error_too_short = Use at least { $min } characters.
+prompt_error_too_long = Use at most { $max } characters.
+prompt_error_pattern = That answer is not in the expected format.
+prompt_error_number = Please enter a number.
+prompt_error_integer = Please enter a whole number.
+prompt_error_min = The answer must be at least { $min }.
+prompt_error_max = The answer must be at most { $max }.
+prompt_error_option = Please pick one of the options.
+narration = Narration
+narration_listen = 🔊 Listen
+narration_stop = ⏹ Stop narration
+narration_rate = Speed
+narration_voice = Voice
+narration_voice_default = System default
+audio_volume = Sound volume
+audio_mute = Mute sound
+illustration_alt = Illustration alt text
+illustration_alt_placeholder = Describe the illustration for screen readers
+bookmarks = Bookmarks
+bookmark_add = Bookmark
+bookmark_note_placeholder = Note (optional)
+bookmark_remove = Remove bookmark
+bookmarks_empty = No bookmarks in this chapter yet.
+bookmark_not_on_page = This passage is not on the current page.
+story_map = Map
+story_map_summary = { $visited } of { $total } paragraphs seen · { $endings_reached } of { $endings } endings reached
+story_map_empty = Nothing discovered in this chapter yet.
+achievements = Achievements
+achievement_unlocked = Achievement unlocked: { $title }
+achievement_unlocked_on = Unlocked { $date }
+achievement_locked = Locked
+achievements_unlocked_count = { $unlocked } / { $total } unlocked
+achievements_empty = This story has no achievements yet.
+relaxed_timers = Timed choices
+relaxed_timers_mandatory = The author requires timers in this story.
+prompt_config_reserved_key = "{ $key }" is used by the reader's settings; pick another action key.
+illustration = Illustration
+illustration_remove = Remove illustration
+illustration_upload_failed = Could not save the illustration
+paragraph_clone_no_illustrations = Illustrations are not copied.
+paragraph_clone_left_behind = These copies could not be removed again, delete them by hand:
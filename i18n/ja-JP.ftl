@@ -158,3 +158,202 @@ paper_tutorial_body_up_down = 1. Open Settings → Page Turn Mode → Up/Down. 2
 paper_tutorial_body_scroll = 1. Open Settings → Page Turn Mode → Scroll. 2. Scroll the page to read the story.
 paper_tutorial_skip = Skip
 paper_tutorial_got_it = Got it
+submit_queued_offline = オフラインです。変更は保存され、接続が戻ると送信されます。
+pending_changes = { $count } 件の変更が同期待ちです
+pending_changes_sync_now = 今すぐ同期
+pending_changes_synced = オフラインの変更を同期しました。
+pending_changes_rejected = サーバーに拒否された保留中の変更
+login_error_invalid = メールアドレスまたはパスワードが正しくありません。
+register_error_rejected = 登録が拒否されました。このメールアドレスは既に使用されている可能性があります。
+auth_error_network = サーバーに接続できません。接続を確認して再試行してください。
+auth_error_unexpected = 予期しないエラーが発生しました
+logout = ログアウト
+logout_success = ログアウトしました。
+invite_request_error_rejected = この申請を受け付けられませんでした。メールアドレスをご確認ください。
+invite_code_error_unknown = この招待コードは存在しません。
+invite_code_error_not_approved = この招待申請はまだ承認されていません。
+invite_code_error_expired = 招待コードの有効期限が切れています。再度申請してください。
+invite_code_error_used = この招待コードは使用済みです。
+invite_code_error_email_mismatch = この招待コードは別のメールアドレス宛てに発行されています。
+invite_status_pending = 審査待ち
+invite_status_approved = 承認済み
+invite_status_rejected = 却下
+invite_status_redeemed = 使用済み
+invite_status_expired = 期限切れ
+invite_review_title = 招待申請
+invite_review_empty = 招待申請はまだありません。
+invite_review_approve = 承認
+invite_review_reissue = コードを再発行
+invite_review_reject = 却下
+invite_review_expires = 有効期限 { $date }
+invite_review_updated = 招待を更新しました
+invite_review_failed = 招待を更新できませんでした
+dashboard_language_not_assigned = この言語の翻訳は割り当てられていません。
+graph_view_show = ストーリーマップ
+graph_view_hide = ストーリーマップを隠す
+graph_empty = この章にはまだ段落がありません。
+graph_edge_normal = 選択肢
+graph_edge_random = ランダム遷移
+graph_edge_timeout = タイムアウト
+graph_edge_same_page = 同じページ
+graph_zoom_in = 拡大
+graph_zoom_out = 縮小
+graph_zoom_reset = 表示をリセット
+graph_external_chapter = 別の章
+graph_retarget_success = 選択肢の遷移先を更新しました
+graph_retarget_failed = 選択肢の遷移先を更新できませんでした
+chapter_manager_toggle = 章を管理
+chapter_manager_title = 章
+chapter_manager_untitled = （無題）
+chapter_manager_new_placeholder = 新しい章のタイトル
+chapter_manager_add = 章を追加
+chapter_manager_rename = 名前を変更
+chapter_manager_save = 保存
+chapter_manager_cancel = キャンセル
+chapter_manager_move_up = 上へ移動
+chapter_manager_move_down = 下へ移動
+chapter_manager_delete = 削除
+chapter_manager_delete_confirm = 章「{ $title }」を削除しますか？
+chapter_manager_delete_paragraphs = この章の { $count } 件の段落も削除されます。
+chapter_manager_delete_inbound = 他の章の { $count } 件の選択肢がこの章を指しています：
+chapter_manager_saved = 章を更新しました
+chapter_manager_failed = 章を更新できませんでした
+paragraph_delete_action = 段落を削除
+paragraph_delete_cancel = キャンセル
+paragraph_delete_confirm = 段落 { $id } を削除しますか？
+paragraph_delete_no_references = この段落を指す選択肢はありません。
+paragraph_delete_inbound = { $count } 件の選択肢がこの段落を指しています：
+paragraph_delete_retarget = 別の段落を指すように変更
+paragraph_delete_pick_target = 段落を選択
+paragraph_delete_remove_choices = これらの選択肢を削除（タイムアウトはカウントダウンのみ解除）
+paragraph_delete_done = 段落を削除しました
+paragraph_delete_failed = 段落を削除できませんでした。変更はありません
+paragraph_clone_duplicate = 段落を複製
+paragraph_clone_depth = 深さ
+paragraph_clone_subtree = 分岐を複製（{ $count } 段落）
+paragraph_clone_done = コピーを作成しました
+paragraph_clone_failed = コピーを作成できませんでした
+revision_show_history = 履歴を表示
+revision_hide_history = 履歴を隠す
+revision_history_title = 改訂履歴
+revision_loading = 履歴を読み込み中…
+revision_empty = 履歴はまだありません。段落を保存するたびに記録されます。
+revision_unknown_author = 不明な作成者
+revision_no_changes = この改訂は現在の段落と同じです。
+revision_captions = 選択肢の文言
+revision_choices = 選択肢
+revision_impacts = 影響 { $count } 件
+revision_restore = この改訂に戻す
+revision_restored = 改訂を復元しました
+revision_restore_failed = 改訂を復元できませんでした
+search_toggle = ストーリーを検索
+search_placeholder = すべての言語の本文と選択肢を検索
+search_all_chapters = すべての章
+search_any_translation = 翻訳状況を問わない
+search_missing_translation = { $lang }が未翻訳
+search_has_impacts = 影響あり
+search_has_timer = 制限時間あり
+search_result_count = { $count } 件の段落が見つかりました
+search_no_results = 一致する段落はありません
+search_field_body = 本文
+search_field_caption = 選択肢 { $number }
+find_replace_toggle = 検索と置換
+find_replace_find = 検索する文字列
+find_replace_replace = 置換後の文字列
+find_replace_regex = 正規表現
+find_replace_case_sensitive = 大文字と小文字を区別
+find_replace_all_languages = すべての言語
+find_replace_invalid_pattern = 無効なパターン：{ $error }
+find_replace_match_count = { $count } 件一致
+find_replace_apply = { $count } 件を置換
+find_replace_done = 段落を更新しました
+find_replace_failed = 一部の段落の後で置換が中断されました
+bundle_toggle = インポート／エクスポート
+bundle_export = ストーリーをエクスポート
+bundle_export_failed = エクスポートに失敗しました
+bundle_import_title = ストーリーバンドルをインポート
+bundle_paste_placeholder = バンドルファイルを選ぶか、JSON をここに貼り付けてください
+bundle_remap = 新しい ID でコピーとしてインポート
+bundle_dry_run = ドライラン
+bundle_invalid = このバンドルはインポートできません
+bundle_collection = コレクション
+bundle_create = 作成
+bundle_update = 更新
+bundle_apply = { $count } 件のレコードをインポート
+bundle_imported = ストーリーをインポートしました
+bundle_import_failed = インポートが中断されました
+bundle_chapters = 章
+bundle_paragraphs = 段落
+bundle_characters = キャラクター
+bundle_relationships = 関係
+bundle_attributes = 属性
+bundle_settings = 設定
+bundle_twee = Twee 3 ソース（本文は { $lang }）
+bundle_twee_warnings = { $count } 件の項目は取り込まれませんでした
+bundle_export_ink = Ink 形式でエクスポート
+bundle_export_choicescript = ChoiceScript 形式でエクスポート
+bundle_export_issues = { $count } 件の機能はエクスポートされませんでした
+markup_hint = *強調*  **太字**  @名前: 台詞  * * * 場面転換  [^1] 脚注
+markup_preview_show = プレビュー
+markup_preview_hide = プレビューを隠す
+interpolation_unknown_key = { $key } はこのストーリーで設定されていません
+interpolation_syntax_error = {"{"}{ $expression }{"}"} を解釈できません：{ $message }
+input_prompt_action = 読者の入力
+prompt_kind = 回答の種類
+prompt_kind_text = テキスト
+prompt_kind_number = 数値
+prompt_kind_select = 選択式
+prompt_required = 回答必須
+prompt_min_length = 最小文字数
+prompt_max_length = 最大文字数
+prompt_pattern = パターン（正規表現）
+prompt_min = 最小値
+prompt_max = 最大値
+prompt_integer = 整数のみ
+prompt_options = 選択肢（カンマ区切り）
+prompt_config_missing_key = 回答を保存するアクションキーを設定してください。
+prompt_config_invalid_pattern = パターンが正しい正規表現ではありません：{ $message }
+prompt_config_empty_range = 最小値が最大値より大きくなっています。
+prompt_config_no_options = 選択肢を一つ以上追加してください。
+prompt_error_required = 回答を入力してください。
+prompt_error_too_short = { $min } 文字以上で入力してください。
+prompt_error_too_long = { $max } 文字以内で入力してください。
+prompt_error_pattern = 回答の形式が正しくありません。
+prompt_error_number = 数値を入力してください。
+prompt_error_integer = 整数を入力してください。
+prompt_error_min = { $min } 以上を入力してください。
+prompt_error_max = { $max } 以下を入力してください。
+prompt_error_option = 選択肢から一つ選んでください。
+narration = 読み上げ
+narration_listen = 🔊 読み上げ
+narration_stop = ⏹ 読み上げを停止
+narration_rate = 速度
+narration_voice = 音声
+narration_voice_default = システムの既定
+audio_volume = 音量
+audio_mute = ミュート
+illustration_alt = 挿絵の代替テキスト
+illustration_alt_placeholder = スクリーンリーダー向けに挿絵を説明してください
+bookmarks = しおり
+bookmark_add = しおりを挟む
+bookmark_note_placeholder = メモ（任意）
+bookmark_remove = しおりを削除
+bookmarks_empty = この章にはまだしおりがありません。
+bookmark_not_on_page = この箇所は現在のページにありません。
+story_map = マップ
+story_map_summary = { $total } 段落中 { $visited } 段落既読 · エンディング { $endings_reached } / { $endings }
+story_map_empty = この章で見つけた段落はまだありません。
+achievements = 実績
+achievement_unlocked = 実績解除：{ $title }
+achievement_unlocked_on = { $date } に解除
+achievement_locked = 未解除
+achievements_unlocked_count = { $unlocked } / { $total } 解除
+achievements_empty = この物語にはまだ実績がありません。
+relaxed_timers = 制限時間付きの選択肢
+relaxed_timers_mandatory = この物語では作者が制限時間を必須にしています。
+prompt_config_reserved_key = 「{ $key }」はリーダーの設定で使われています。別のアクションキーを指定してください。
+illustration = 挿絵
+illustration_remove = 挿絵を削除
+illustration_upload_failed = 挿絵を保存できませんでした
+paragraph_clone_no_illustrations = 挿絵はコピーされません。
+paragraph_clone_left_behind = 次のコピーを取り消せませんでした。手動で削除してください：
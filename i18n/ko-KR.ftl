@@ -158,3 +158,202 @@ paper_tutorial_body_up_down = 1. Open Settings → Page Turn Mode → Up/Down. 2
 paper_tutorial_body_scroll = 1. Open Settings → Page Turn Mode → Scroll. 2. Scroll the page to read the story.
 paper_tutorial_skip = Skip
 paper_tutorial_got_it = Got it
+submit_queued_offline = 오프라인 상태입니다. 변경 사항이 저장되었으며 연결이 복구되면 전송됩니다.
+pending_changes = 동기화 대기 중인 변경 { $count }건
+pending_changes_sync_now = 지금 동기화
+pending_changes_synced = 오프라인 변경 사항이 동기화되었습니다.
+pending_changes_rejected = 서버에서 거부된 대기 변경
+login_error_invalid = 이메일 또는 비밀번호가 올바르지 않습니다.
+register_error_rejected = 가입이 거부되었습니다. 이미 사용 중인 이메일일 수 있습니다.
+auth_error_network = 서버에 연결할 수 없습니다. 연결을 확인한 후 다시 시도하세요.
+auth_error_unexpected = 예기치 않은 오류가 발생했습니다
+logout = 로그아웃
+logout_success = 로그아웃되었습니다.
+invite_request_error_rejected = 요청을 접수할 수 없습니다. 이메일 주소를 확인해 주세요.
+invite_code_error_unknown = 존재하지 않는 초대 코드입니다.
+invite_code_error_not_approved = 아직 승인되지 않은 초대 요청입니다.
+invite_code_error_expired = 초대 코드가 만료되었습니다. 다시 요청해 주세요.
+invite_code_error_used = 이미 사용된 초대 코드입니다.
+invite_code_error_email_mismatch = 다른 이메일로 발급된 초대 코드입니다.
+invite_status_pending = 대기 중
+invite_status_approved = 승인됨
+invite_status_rejected = 거절됨
+invite_status_redeemed = 사용됨
+invite_status_expired = 만료됨
+invite_review_title = 초대 요청
+invite_review_empty = 아직 초대 요청이 없습니다.
+invite_review_approve = 승인
+invite_review_reissue = 코드 재발급
+invite_review_reject = 거절
+invite_review_expires = 만료 { $date }
+invite_review_updated = 초대가 업데이트되었습니다
+invite_review_failed = 초대를 업데이트할 수 없습니다
+dashboard_language_not_assigned = 이 언어의 번역 권한이 없습니다.
+graph_view_show = 스토리 맵
+graph_view_hide = 스토리 맵 숨기기
+graph_empty = 이 장에는 아직 단락이 없습니다.
+graph_edge_normal = 선택지
+graph_edge_random = 무작위 대상
+graph_edge_timeout = 시간 초과
+graph_edge_same_page = 같은 페이지
+graph_zoom_in = 확대
+graph_zoom_out = 축소
+graph_zoom_reset = 보기 초기화
+graph_external_chapter = 다른 장
+graph_retarget_success = 선택지 대상을 변경했습니다
+graph_retarget_failed = 선택지 대상을 변경하지 못했습니다
+chapter_manager_toggle = 챕터 관리
+chapter_manager_title = 챕터
+chapter_manager_untitled = (제목 없음)
+chapter_manager_new_placeholder = 새 챕터 제목
+chapter_manager_add = 챕터 추가
+chapter_manager_rename = 이름 변경
+chapter_manager_save = 저장
+chapter_manager_cancel = 취소
+chapter_manager_move_up = 위로 이동
+chapter_manager_move_down = 아래로 이동
+chapter_manager_delete = 삭제
+chapter_manager_delete_confirm = 챕터 "{ $title }"을(를) 삭제할까요?
+chapter_manager_delete_paragraphs = 이 챕터의 단락 { $count }개도 함께 삭제됩니다.
+chapter_manager_delete_inbound = 다른 챕터의 선택지 { $count }개가 이 챕터를 가리킵니다:
+chapter_manager_saved = 챕터가 업데이트되었습니다
+chapter_manager_failed = 챕터를 업데이트할 수 없습니다
+paragraph_delete_action = 단락 삭제
+paragraph_delete_cancel = 취소
+paragraph_delete_confirm = 단락 { $id }을(를) 삭제할까요?
+paragraph_delete_no_references = 이 단락을 가리키는 선택지가 없습니다.
+paragraph_delete_inbound = 선택지 { $count }개가 이 단락을 가리킵니다:
+paragraph_delete_retarget = 다른 단락을 가리키도록 변경
+paragraph_delete_pick_target = 단락 선택
+paragraph_delete_remove_choices = 해당 선택지 삭제 (시간 초과는 카운트다운만 해제)
+paragraph_delete_done = 단락이 삭제되었습니다
+paragraph_delete_failed = 단락을 삭제할 수 없습니다. 변경된 내용이 없습니다
+paragraph_clone_duplicate = 단락 복제
+paragraph_clone_depth = 깊이
+paragraph_clone_subtree = 분기 복제 (단락 { $count }개)
+paragraph_clone_done = 사본이 생성되었습니다
+paragraph_clone_failed = 사본을 만들 수 없습니다
+revision_show_history = 기록 보기
+revision_hide_history = 기록 숨기기
+revision_history_title = 수정 기록
+revision_loading = 기록을 불러오는 중…
+revision_empty = 아직 기록이 없습니다. 단락을 저장할 때마다 기록됩니다.
+revision_unknown_author = 알 수 없는 작성자
+revision_no_changes = 이 수정본은 현재 단락과 같습니다.
+revision_captions = 선택지 문구
+revision_choices = 선택지
+revision_impacts = 영향 { $count }개
+revision_restore = 이 수정본으로 복원
+revision_restored = 수정본을 복원했습니다
+revision_restore_failed = 수정본을 복원할 수 없습니다
+search_toggle = 스토리 검색
+search_placeholder = 모든 언어의 본문과 선택지 검색
+search_all_chapters = 모든 챕터
+search_any_translation = 번역 상태 무관
+search_missing_translation = { $lang } 번역 없음
+search_has_impacts = 영향 있음
+search_has_timer = 타이머 있음
+search_result_count = 단락 { $count }개를 찾았습니다
+search_no_results = 일치하는 단락이 없습니다
+search_field_body = 본문
+search_field_caption = 선택지 { $number }
+find_replace_toggle = 찾기 및 바꾸기
+find_replace_find = 찾을 내용
+find_replace_replace = 바꿀 내용
+find_replace_regex = 정규식
+find_replace_case_sensitive = 대소문자 구분
+find_replace_all_languages = 모든 언어
+find_replace_invalid_pattern = 잘못된 패턴: { $error }
+find_replace_match_count = { $count }개 일치
+find_replace_apply = { $count }개 바꾸기
+find_replace_done = 단락을 업데이트했습니다
+find_replace_failed = 일부 단락 이후 바꾸기가 중단되었습니다
+bundle_toggle = 가져오기/내보내기
+bundle_export = 스토리 내보내기
+bundle_export_failed = 내보내기에 실패했습니다
+bundle_import_title = 스토리 번들 가져오기
+bundle_paste_placeholder = 번들 파일을 선택하거나 JSON을 여기에 붙여 넣으세요
+bundle_remap = 새 ID로 사본으로 가져오기
+bundle_dry_run = 시험 실행
+bundle_invalid = 이 번들을 가져올 수 없습니다
+bundle_collection = 컬렉션
+bundle_create = 생성
+bundle_update = 업데이트
+bundle_apply = 레코드 { $count }개 가져오기
+bundle_imported = 스토리를 가져왔습니다
+bundle_import_failed = 가져오기가 중단되었습니다
+bundle_chapters = 챕터
+bundle_paragraphs = 단락
+bundle_characters = 캐릭터
+bundle_relationships = 관계
+bundle_attributes = 속성
+bundle_settings = 설정
+bundle_twee = Twee 3 소스 (텍스트 언어 { $lang })
+bundle_twee_warnings = { $count }개 항목을 가져오지 못했습니다
+bundle_export_ink = Ink로 내보내기
+bundle_export_choicescript = ChoiceScript로 내보내기
+bundle_export_issues = { $count }개 기능을 내보내지 못했습니다
+markup_hint = *강조*  **굵게**  @이름: 대사  * * * 장면 구분  [^1] 각주
+markup_preview_show = 미리 보기
+markup_preview_hide = 미리 보기 숨기기
+interpolation_unknown_key = { $key }은(는) 이 스토리에서 설정되지 않습니다
+interpolation_syntax_error = {"{"}{ $expression }{"}"}을(를) 읽을 수 없습니다: { $message }
+input_prompt_action = 독자 입력
+prompt_kind = 답변 유형
+prompt_kind_text = 텍스트
+prompt_kind_number = 숫자
+prompt_kind_select = 하나 선택
+prompt_required = 답변 필수
+prompt_min_length = 최소 길이
+prompt_max_length = 최대 길이
+prompt_pattern = 패턴 (정규식)
+prompt_min = 최솟값
+prompt_max = 최댓값
+prompt_integer = 정수만
+prompt_options = 선택지 (쉼표로 구분)
+prompt_config_missing_key = 답변을 저장할 액션 키를 설정하세요.
+prompt_config_invalid_pattern = 패턴이 올바른 정규식이 아닙니다: { $message }
+prompt_config_empty_range = 최솟값이 최댓값보다 큽니다.
+prompt_config_no_options = 선택지를 하나 이상 추가하세요.
+prompt_error_required = 답변을 입력하세요.
+prompt_error_too_short = { $min }자 이상 입력하세요.
+prompt_error_too_long = { $max }자 이하로 입력하세요.
+prompt_error_pattern = 답변 형식이 올바르지 않습니다.
+prompt_error_number = 숫자를 입력하세요.
+prompt_error_integer = 정수를 입력하세요.
+prompt_error_min = { $min } 이상이어야 합니다.
+prompt_error_max = { $max } 이하여야 합니다.
+prompt_error_option = 선택지 중 하나를 고르세요.
+narration = 낭독
+narration_listen = 🔊 듣기
+narration_stop = ⏹ 낭독 중지
+narration_rate = 속도
+narration_voice = 음성
+narration_voice_default = 시스템 기본값
+audio_volume = 음량
+audio_mute = 음소거
+illustration_alt = 삽화 대체 텍스트
+illustration_alt_placeholder = 화면 낭독기를 위해 삽화를 설명하세요
+bookmarks = 책갈피
+bookmark_add = 책갈피 추가
+bookmark_note_placeholder = 메모 (선택)
+bookmark_remove = 책갈피 삭제
+bookmarks_empty = 이 장에는 아직 책갈피가 없습니다.
+bookmark_not_on_page = 이 구절은 현재 페이지에 없습니다.
+story_map = 지도
+story_map_summary = 문단 { $total }개 중 { $visited }개 읽음 · 엔딩 { $endings }개 중 { $endings_reached }개 도달
+story_map_empty = 이 장에서 아직 발견한 문단이 없습니다.
+achievements = 업적
+achievement_unlocked = 업적 달성: { $title }
+achievement_unlocked_on = { $date } 달성
+achievement_locked = 잠김
+achievements_unlocked_count = { $total }개 중 { $unlocked }개 달성
+achievements_empty = 이 이야기에는 아직 업적이 없습니다.
+relaxed_timers = 제한 시간 선택지
+relaxed_timers_mandatory = 이 이야기에서는 작가가 제한 시간을 필수로 지정했습니다.
+prompt_config_reserved_key = "{ $key }"은(는) 리더 설정에서 사용하는 이름입니다. 다른 액션 키를 지정하세요.
+illustration = 삽화
+illustration_remove = 삽화 삭제
+illustration_upload_failed = 삽화를 저장할 수 없습니다
+paragraph_clone_no_illustrations = 삽화는 복사되지 않습니다.
+paragraph_clone_left_behind = 다음 사본을 되돌리지 못했습니다. 직접 삭제해 주세요:
@@ -157,3 +157,202 @@ paper_tutorial_body_up_down = 1. 打開設定，選擇翻頁模式 → 上下翻
 paper_tutorial_body_scroll = 1. 打開設定，選擇翻頁模式 → 捲動。2. 使用捲動閱讀內容。
 paper_tutorial_skip = 跳過
 paper_tutorial_got_it = 知道了
+submit_queued_offline = 目前離線，變更已暫存，連線恢復後會自動送出。
+pending_changes = { $count } 筆變更等待同步
+pending_changes_sync_now = 立即同步
+pending_changes_synced = 離線變更已同步。
+pending_changes_rejected = 伺服器拒絕的暫存變更
+login_error_invalid = 電子郵件或密碼不正確。
+register_error_rejected = 註冊遭拒，此電子郵件可能已被使用。
+auth_error_network = 無法連線到伺服器，請檢查網路後再試一次。
+auth_error_unexpected = 發生未預期的錯誤
+logout = 登出
+logout_success = 已登出。
+invite_request_error_rejected = 無法受理此申請，請確認電子郵件地址。
+invite_code_error_unknown = 邀請碼不存在。
+invite_code_error_not_approved = 此邀請申請尚未核准。
+invite_code_error_expired = 邀請碼已過期，請重新申請。
+invite_code_error_used = 邀請碼已被使用。
+invite_code_error_email_mismatch = 此邀請碼對應的電子郵件不同。
+invite_status_pending = 待審核
+invite_status_approved = 已核准
+invite_status_rejected = 已拒絕
+invite_status_redeemed = 已使用
+invite_status_expired = 已過期
+invite_review_title = 邀請申請
+invite_review_empty = 目前沒有邀請申請。
+invite_review_approve = 核准
+invite_review_reissue = 重新發放邀請碼
+invite_review_reject = 拒絕
+invite_review_expires = 到期時間 { $date }
+invite_review_updated = 邀請已更新
+invite_review_failed = 無法更新邀請
+dashboard_language_not_assigned = 你未被指派翻譯此語言。
+graph_view_show = 故事地圖
+graph_view_hide = 隱藏故事地圖
+graph_empty = 此章節尚無段落。
+graph_edge_normal = 選項
+graph_edge_random = 隨機目標
+graph_edge_timeout = 逾時
+graph_edge_same_page = 同頁
+graph_zoom_in = 放大
+graph_zoom_out = 縮小
+graph_zoom_reset = 重設檢視
+graph_external_chapter = 其他章節
+graph_retarget_success = 已更新選項目標
+graph_retarget_failed = 無法更新選項目標
+chapter_manager_toggle = 管理章節
+chapter_manager_title = 章節
+chapter_manager_untitled = （未命名）
+chapter_manager_new_placeholder = 新章節標題
+chapter_manager_add = 新增章節
+chapter_manager_rename = 重新命名
+chapter_manager_save = 儲存
+chapter_manager_cancel = 取消
+chapter_manager_move_up = 上移
+chapter_manager_move_down = 下移
+chapter_manager_delete = 刪除
+chapter_manager_delete_confirm = 確定要刪除章節「{ $title }」嗎？
+chapter_manager_delete_paragraphs = 此章節中的 { $count } 個段落也會一併刪除。
+chapter_manager_delete_inbound = 其他章節中有 { $count } 個選項指向此章節：
+chapter_manager_saved = 章節已更新
+chapter_manager_failed = 無法更新章節
+paragraph_delete_action = 刪除段落
+paragraph_delete_cancel = 取消
+paragraph_delete_confirm = 確定要刪除段落 { $id } 嗎？
+paragraph_delete_no_references = 沒有任何選項指向此段落。
+paragraph_delete_inbound = 有 { $count } 個選項指向此段落：
+paragraph_delete_retarget = 改為指向其他段落
+paragraph_delete_pick_target = 選擇段落
+paragraph_delete_remove_choices = 移除這些選項（倒數逾時只會取消倒數）
+paragraph_delete_done = 段落已刪除
+paragraph_delete_failed = 無法刪除段落，未做任何變更
+paragraph_clone_duplicate = 複製段落
+paragraph_clone_depth = 深度
+paragraph_clone_subtree = 複製分支（{ $count } 個段落）
+paragraph_clone_done = 已建立副本
+paragraph_clone_failed = 無法建立副本
+revision_show_history = 顯示修訂紀錄
+revision_hide_history = 隱藏修訂紀錄
+revision_history_title = 修訂紀錄
+revision_loading = 正在載入修訂紀錄…
+revision_empty = 尚無修訂紀錄。每次儲存段落都會新增一筆。
+revision_unknown_author = 未知作者
+revision_no_changes = 此修訂與目前段落相同。
+revision_captions = 選項文字
+revision_choices = 選項
+revision_impacts = { $count } 項影響
+revision_restore = 還原此修訂
+revision_restored = 已還原修訂
+revision_restore_failed = 無法還原修訂
+search_toggle = 搜尋故事
+search_placeholder = 搜尋所有語言的內文與選項
+search_all_chapters = 所有章節
+search_any_translation = 不限翻譯狀態
+search_missing_translation = 缺少{ $lang }
+search_has_impacts = 含影響
+search_has_timer = 含倒數
+search_result_count = 找到 { $count } 個段落
+search_no_results = 沒有符合的段落
+search_field_body = 內文
+search_field_caption = 選項 { $number }
+find_replace_toggle = 尋找與取代
+find_replace_find = 尋找
+find_replace_replace = 取代為
+find_replace_regex = 正規表示式
+find_replace_case_sensitive = 區分大小寫
+find_replace_all_languages = 所有語言
+find_replace_invalid_pattern = 無效的樣式：{ $error }
+find_replace_match_count = { $count } 處符合
+find_replace_apply = 取代 { $count } 處
+find_replace_done = 段落已更新
+find_replace_failed = 取代在部分段落後中止
+bundle_toggle = 匯入／匯出
+bundle_export = 匯出故事
+bundle_export_failed = 匯出失敗
+bundle_import_title = 匯入故事套件
+bundle_paste_placeholder = 選擇套件檔案，或在此貼上其 JSON
+bundle_remap = 以新 ID 匯入為副本
+bundle_dry_run = 試執行
+bundle_invalid = 無法匯入此套件
+bundle_collection = 資料集
+bundle_create = 新增
+bundle_update = 更新
+bundle_apply = 匯入 { $count } 筆紀錄
+bundle_imported = 故事已匯入
+bundle_import_failed = 匯入已中止
+bundle_chapters = 章節
+bundle_paragraphs = 段落
+bundle_characters = 角色
+bundle_relationships = 關係
+bundle_attributes = 屬性
+bundle_settings = 設定
+bundle_twee = Twee 3 原始檔（文字為 { $lang }）
+bundle_twee_warnings = 有 { $count } 項內容未能轉入
+bundle_export_ink = 匯出為 Ink
+bundle_export_choicescript = 匯出為 ChoiceScript
+bundle_export_issues = 有 { $count } 項功能未能匯出
+markup_hint = *強調*  **粗體**  @角色: 對白  * * * 場景分隔  [^1] 註腳
+markup_preview_show = 預覽
+markup_preview_hide = 隱藏預覽
+interpolation_unknown_key = 故事中沒有設定 { $key }
+interpolation_syntax_error = 無法解析 {"{"}{ $expression }{"}"}：{ $message }
+input_prompt_action = 讀者輸入
+prompt_kind = 回答類型
+prompt_kind_text = 文字
+prompt_kind_number = 數字
+prompt_kind_select = 單選
+prompt_required = 必須回答
+prompt_min_length = 最短長度
+prompt_max_length = 最長長度
+prompt_pattern = 格式（正規表示式）
+prompt_min = 最小值
+prompt_max = 最大值
+prompt_integer = 僅限整數
+prompt_options = 選項（以逗號分隔）
+prompt_config_missing_key = 請設定用來儲存回答的動作鍵。
+prompt_config_invalid_pattern = 格式不是有效的正規表示式：{ $message }
+prompt_config_empty_range = 最小值大於最大值。
+prompt_config_no_options = 請至少新增一個選項。
+prompt_error_required = 請輸入回答。
+prompt_error_too_short = 至少需要 { $min } 個字。
+prompt_error_too_long = 最多 { $max } 個字。
+prompt_error_pattern = 回答格式不符。
+prompt_error_number = 請輸入數字。
+prompt_error_integer = 請輸入整數。
+prompt_error_min = 回答不得小於 { $min }。
+prompt_error_max = 回答不得大於 { $max }。
+prompt_error_option = 請選擇其中一個選項。
+narration = 朗讀
+narration_listen = 🔊 朗讀
+narration_stop = ⏹ 停止朗讀
+narration_rate = 語速
+narration_voice = 語音
+narration_voice_default = 系統預設
+audio_volume = 音量
+audio_mute = 靜音
+illustration_alt = 插圖替代文字
+illustration_alt_placeholder = 為螢幕閱讀器描述這張插圖
+bookmarks = 書籤
+bookmark_add = 加入書籤
+bookmark_note_placeholder = 備註（選填）
+bookmark_remove = 移除書籤
+bookmarks_empty = 這個章節還沒有書籤。
+bookmark_not_on_page = 這段文字不在目前的頁面上。
+story_map = 地圖
+story_map_summary = 已讀 { $visited } / { $total } 個段落 · 已抵達 { $endings_reached } / { $endings } 個結局
+story_map_empty = 這個章節還沒有探索過的段落。
+achievements = 成就
+achievement_unlocked = 解鎖成就：{ $title }
+achievement_unlocked_on = 於 { $date } 解鎖
+achievement_locked = 尚未解鎖
+achievements_unlocked_count = 已解鎖 { $unlocked } / { $total }
+achievements_empty = 這個故事還沒有成就。
+relaxed_timers = 限時選項
+relaxed_timers_mandatory = 作者要求本故事使用計時。
+prompt_config_reserved_key = 「{ $key }」是閱讀器設定使用的名稱，請換一個動作鍵。
+illustration = 插圖
+illustration_remove = 移除插圖
+illustration_upload_failed = 無法儲存插圖
+paragraph_clone_no_illustrations = 插圖不會被複製。
+paragraph_clone_left_behind = 以下副本無法移除，請手動刪除：
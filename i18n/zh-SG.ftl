@@ -157,3 +157,202 @@ paper_tutorial_body_up_down = 1. 打开设置，选择翻页模式 → 上下翻
 paper_tutorial_body_scroll = 1. 打开设置，选择翻页模式 → 滚动。2. 使用滚动阅读内容。
 paper_tutorial_skip = 跳过
 paper_tutorial_got_it = 知道了
+submit_queued_offline = 当前离线，更改已暂存，连接恢复后会自动发送。
+pending_changes = { $count } 条更改等待同步
+pending_changes_sync_now = 立即同步
+pending_changes_synced = 离线更改已同步。
+pending_changes_rejected = 服务器拒绝的暂存更改
+login_error_invalid = 电子邮件或密码不正确。
+register_error_rejected = 注册被拒绝，该电子邮件可能已被使用。
+auth_error_network = 无法连接到服务器，请检查网络后重试。
+auth_error_unexpected = 发生意外错误
+logout = 退出登录
+logout_success = 已退出登录。
+invite_request_error_rejected = 无法受理此申请，请确认电子邮件地址。
+invite_code_error_unknown = 邀请码不存在。
+invite_code_error_not_approved = 此邀请申请尚未批准。
+invite_code_error_expired = 邀请码已过期，请重新申请。
+invite_code_error_used = 邀请码已被使用。
+invite_code_error_email_mismatch = 此邀请码对应的电子邮件不同。
+invite_status_pending = 待审核
+invite_status_approved = 已批准
+invite_status_rejected = 已拒绝
+invite_status_redeemed = 已使用
+invite_status_expired = 已过期
+invite_review_title = 邀请申请
+invite_review_empty = 目前没有邀请申请。
+invite_review_approve = 批准
+invite_review_reissue = 重新发放邀请码
+invite_review_reject = 拒绝
+invite_review_expires = 到期时间 { $date }
+invite_review_updated = 邀请已更新
+invite_review_failed = 无法更新邀请
+dashboard_language_not_assigned = 你未被指派翻译此语言。
+graph_view_show = 故事地图
+graph_view_hide = 隐藏故事地图
+graph_empty = 此章节尚无段落。
+graph_edge_normal = 选项
+graph_edge_random = 随机目标
+graph_edge_timeout = 超时
+graph_edge_same_page = 同页
+graph_zoom_in = 放大
+graph_zoom_out = 缩小
+graph_zoom_reset = 重置视图
+graph_external_chapter = 其他章节
+graph_retarget_success = 已更新选项目标
+graph_retarget_failed = 无法更新选项目标
+chapter_manager_toggle = 管理章节
+chapter_manager_title = 章节
+chapter_manager_untitled = （未命名）
+chapter_manager_new_placeholder = 新章节标题
+chapter_manager_add = 新增章节
+chapter_manager_rename = 重命名
+chapter_manager_save = 保存
+chapter_manager_cancel = 取消
+chapter_manager_move_up = 上移
+chapter_manager_move_down = 下移
+chapter_manager_delete = 删除
+chapter_manager_delete_confirm = 确定要删除章节「{ $title }」吗？
+chapter_manager_delete_paragraphs = 此章节中的 { $count } 个段落也会一并删除。
+chapter_manager_delete_inbound = 其他章节中有 { $count } 个选项指向此章节：
+chapter_manager_saved = 章节已更新
+chapter_manager_failed = 无法更新章节
+paragraph_delete_action = 删除段落
+paragraph_delete_cancel = 取消
+paragraph_delete_confirm = 确定要删除段落 { $id } 吗？
+paragraph_delete_no_references = 没有任何选项指向此段落。
+paragraph_delete_inbound = 有 { $count } 个选项指向此段落：
+paragraph_delete_retarget = 改为指向其他段落
+paragraph_delete_pick_target = 选择段落
+paragraph_delete_remove_choices = 移除这些选项（倒计时超时只会取消倒计时）
+paragraph_delete_done = 段落已删除
+paragraph_delete_failed = 无法删除段落，未做任何更改
+paragraph_clone_duplicate = 复制段落
+paragraph_clone_depth = 深度
+paragraph_clone_subtree = 复制分支（{ $count } 个段落）
+paragraph_clone_done = 已创建副本
+paragraph_clone_failed = 无法创建副本
+revision_show_history = 显示修订记录
+revision_hide_history = 隐藏修订记录
+revision_history_title = 修订记录
+revision_loading = 正在加载修订记录…
+revision_empty = 尚无修订记录。每次保存段落都会新增一条。
+revision_unknown_author = 未知作者
+revision_no_changes = 此修订与当前段落相同。
+revision_captions = 选项文字
+revision_choices = 选项
+revision_impacts = { $count } 项影响
+revision_restore = 还原此修订
+revision_restored = 已还原修订
+revision_restore_failed = 无法还原修订
+search_toggle = 搜索故事
+search_placeholder = 搜索所有语言的正文与选项
+search_all_chapters = 所有章节
+search_any_translation = 不限翻译状态
+search_missing_translation = 缺少{ $lang }
+search_has_impacts = 含影响
+search_has_timer = 含倒计时
+search_result_count = 找到 { $count } 个段落
+search_no_results = 没有符合的段落
+search_field_body = 正文
+search_field_caption = 选项 { $number }
+find_replace_toggle = 查找与替换
+find_replace_find = 查找
+find_replace_replace = 替换为
+find_replace_regex = 正则表达式
+find_replace_case_sensitive = 区分大小写
+find_replace_all_languages = 所有语言
+find_replace_invalid_pattern = 无效的模式：{ $error }
+find_replace_match_count = { $count } 处匹配
+find_replace_apply = 替换 { $count } 处
+find_replace_done = 段落已更新
+find_replace_failed = 替换在部分段落后中止
+bundle_toggle = 导入／导出
+bundle_export = 导出故事
+bundle_export_failed = 导出失败
+bundle_import_title = 导入故事包
+bundle_paste_placeholder = 选择包文件，或在此粘贴其 JSON
+bundle_remap = 以新 ID 导入为副本
+bundle_dry_run = 试运行
+bundle_invalid = 无法导入此包
+bundle_collection = 数据集
+bundle_create = 新建
+bundle_update = 更新
+bundle_apply = 导入 { $count } 条记录
+bundle_imported = 故事已导入
+bundle_import_failed = 导入已中止
+bundle_chapters = 章节
+bundle_paragraphs = 段落
+bundle_characters = 角色
+bundle_relationships = 关系
+bundle_attributes = 属性
+bundle_settings = 设置
+bundle_twee = Twee 3 源文件（文本为 { $lang }）
+bundle_twee_warnings = 有 { $count } 项内容未能转入
+bundle_export_ink = 导出为 Ink
+bundle_export_choicescript = 导出为 ChoiceScript
+bundle_export_issues = 有 { $count } 项功能未能导出
+markup_hint = *强调*  **粗体**  @角色: 对白  * * * 场景分隔  [^1] 脚注
+markup_preview_show = 预览
+markup_preview_hide = 隐藏预览
+interpolation_unknown_key = 故事中没有设置 { $key }
+interpolation_syntax_error = 无法解析 {"{"}{ $expression }{"}"}：{ $message }
+input_prompt_action = 读者输入
+prompt_kind = 回答类型
+prompt_kind_text = 文字
+prompt_kind_number = 数字
+prompt_kind_select = 单选
+prompt_required = 必须回答
+prompt_min_length = 最短长度
+prompt_max_length = 最长长度
+prompt_pattern = 格式（正则表达式）
+prompt_min = 最小值
+prompt_max = 最大值
+prompt_integer = 仅限整数
+prompt_options = 选项（以逗号分隔）
+prompt_config_missing_key = 请设置用来保存回答的动作键。
+prompt_config_invalid_pattern = 格式不是有效的正则表达式：{ $message }
+prompt_config_empty_range = 最小值大于最大值。
+prompt_config_no_options = 请至少添加一个选项。
+prompt_error_required = 请输入回答。
+prompt_error_too_short = 至少需要 { $min } 个字。
+prompt_error_too_long = 最多 { $max } 个字。
+prompt_error_pattern = 回答格式不符。
+prompt_error_number = 请输入数字。
+prompt_error_integer = 请输入整数。
+prompt_error_min = 回答不得小于 { $min }。
+prompt_error_max = 回答不得大于 { $max }。
+prompt_error_option = 请选择其中一个选项。
+narration = 朗读
+narration_listen = 🔊 朗读
+narration_stop = ⏹ 停止朗读
+narration_rate = 语速
+narration_voice = 语音
+narration_voice_default = 系统默认
+audio_volume = 音量
+audio_mute = 静音
+illustration_alt = 插图替代文字
+illustration_alt_placeholder = 为屏幕阅读器描述这张插图
+bookmarks = 书签
+bookmark_add = 加入书签
+bookmark_note_placeholder = 备注（选填）
+bookmark_remove = 移除书签
+bookmarks_empty = 这个章节还没有书签。
+bookmark_not_on_page = 这段文字不在当前的页面上。
+story_map = 地图
+story_map_summary = 已读 { $visited } / { $total } 个段落 · 已抵达 { $endings_reached } / { $endings } 个结局
+story_map_empty = 这个章节还没有探索过的段落。
+achievements = 成就
+achievement_unlocked = 解锁成就：{ $title }
+achievement_unlocked_on = 于 { $date } 解锁
+achievement_locked = 尚未解锁
+achievements_unlocked_count = 已解锁 { $unlocked } / { $total }
+achievements_empty = 这个故事还没有成就。
+relaxed_timers = 限时选项
+relaxed_timers_mandatory = 作者要求本故事使用计时。
+prompt_config_reserved_key = “{ $key }”是阅读器设置使用的名称，请换一个动作键。
+illustration = 插图
+illustration_remove = 移除插图
+illustration_upload_failed = 无法保存插图
+paragraph_clone_no_illustrations = 插图不会被复制。
+paragraph_clone_left_behind = 以下副本无法移除，请手动删除：
@@ -0,0 +1,48 @@
+// Invite checks shared by invites.pb.js. Hook handlers run in isolated
+// contexts, so they load this module with `require` instead of sharing
+// top-level functions.
+//
+// Keep `problem` in sync with `Invite::check_redeemable` in
+// src/services/invite.rs; the names match `InviteCodeError` in snake_case.
+
+/// Uppercases the code and strips everything but letters, digits and dashes.
+function normalizeCode(code) {
+    return String(code || "").toUpperCase().replace(/[^A-Z0-9-]/g, "");
+}
+
+/// The invite issued with `code`, or null.
+function findInvite(app, code) {
+    if (!code) {
+        return null;
+    }
+    try {
+        return app.findFirstRecordByData("invites", "code", code);
+    } catch (_) {
+        return null;
+    }
+}
+
+/// "" when `invite` can be redeemed by `email` at `now`, otherwise why not.
+function problem(invite, email, now) {
+    if (!invite) {
+        return "unknown";
+    }
+    const status = invite.getString("status");
+    if (status === "redeemed") {
+        return "already_used";
+    }
+    if (status !== "approved") {
+        return "not_approved";
+    }
+    const expires = invite.getDateTime("expires");
+    if (!expires.isZero() && expires.unix() <= now.unix()) {
+        return "expired";
+    }
+    const expected = invite.getString("email").trim().toLowerCase();
+    if (expected !== String(email || "").trim().toLowerCase()) {
+        return "email_mismatch";
+    }
+    return "";
+}
+
+module.exports = { normalizeCode, findInvite, problem };
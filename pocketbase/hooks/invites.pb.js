@@ -0,0 +1,47 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// Invite-only sign-up.
+//
+// The reader app checks a code before submitting so it can explain what is
+// wrong with it, but the rules are enforced here: the `users` create endpoint
+// is public, and the `invites` collection is only readable by admins (see
+// migrations/1729000000_invite_rules.js).
+//
+// Set IFECARO_OPEN_SIGNUP=true to accept sign-ups without a code, e.g. for
+// tests/pocketbase_auth_tests.rs against a local container.
+
+// Tells the sign-up form whether `code` can be redeemed by `email` without
+// exposing the invite itself.
+routerAdd("POST", "/api/invites/check", (e) => {
+    const invites = require(`${__hooks}/invites.js`);
+    const body = e.requestInfo().body;
+    const invite = invites.findInvite(e.app, invites.normalizeCode(body.code));
+    const problem = invites.problem(invite, body.email, new DateTime());
+    return e.json(200, { problem: problem || null });
+});
+
+// Creates the account and redeems the invite in one transaction, so a code
+// can never be used twice.
+onRecordCreateRequest((e) => {
+    if (e.hasSuperuserAuth() || $os.getenv("IFECARO_OPEN_SIGNUP") === "true") {
+        return e.next();
+    }
+
+    const invites = require(`${__hooks}/invites.js`);
+    const code = invites.normalizeCode(e.requestInfo().body.invite_code);
+
+    e.app.runInTransaction((txApp) => {
+        const invite = invites.findInvite(txApp, code);
+        const problem = invites.problem(invite, e.record.getString("email"), new DateTime());
+        if (problem) {
+            throw new BadRequestError("Invalid invite code: " + problem);
+        }
+
+        e.app = txApp;
+        e.next();
+
+        invite.set("status", "redeemed");
+        invite.set("redeemed_by", e.record.id);
+        txApp.save(invite);
+    });
+}, "users");
@@ -0,0 +1,36 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// Translators may only change the `texts` entries of the languages assigned
+// to them (`languages` on their user record, a multiple select). Everything
+// else on a paragraph stays as it was. The role rules themselves live in
+// migrations/1729000100_role_rules.js.
+onRecordUpdateRequest((e) => {
+    if (e.hasSuperuserAuth() || !e.auth || e.auth.getString("role") !== "translator") {
+        return e.next();
+    }
+
+    const before = e.record.original();
+    for (const field of ["chapter_id", "choices", "illustration"]) {
+        if (before.getString(field) !== e.record.getString(field)) {
+            throw new ForbiddenError("Translators can only edit texts.");
+        }
+    }
+
+    const byLang = (record) => {
+        const texts = {};
+        for (const text of JSON.parse(record.getString("texts") || "[]")) {
+            texts[text.lang] = JSON.stringify(text);
+        }
+        return texts;
+    };
+    const assigned = e.auth.getStringSlice("languages");
+    const old = byLang(before);
+    const updated = byLang(e.record);
+    for (const lang of new Set([...Object.keys(old), ...Object.keys(updated)])) {
+        if (!assigned.includes(lang) && old[lang] !== updated[lang]) {
+            throw new ForbiddenError(`Not assigned to ${lang}.`);
+        }
+    }
+
+    return e.next();
+}, "paragraphs");
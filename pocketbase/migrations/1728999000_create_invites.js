@@ -0,0 +1,47 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// The `invites` collection behind `Invite` in src/services/invite.rs. Its
+// access rules are set by 1729000000_invite_rules.js; instances where the
+// collection was made by hand keep their fields.
+migrate((app) => {
+    try {
+        app.findCollectionByNameOrId("invites");
+        return;
+    } catch (_) {
+        // Not created yet.
+    }
+
+    const users = app.findCollectionByNameOrId("users");
+    const invites = new Collection({
+        type: "base",
+        name: "invites",
+        fields: [
+            { name: "name", type: "text", required: true, max: 200 },
+            { name: "email", type: "email", required: true },
+            { name: "reason", type: "text", max: 2000 },
+            {
+                name: "status",
+                type: "select",
+                maxSelect: 1,
+                values: ["pending", "approved", "rejected", "redeemed"],
+            },
+            { name: "code", type: "text", max: 64 },
+            { name: "expires", type: "date" },
+            {
+                name: "redeemed_by",
+                type: "relation",
+                collectionId: users.id,
+                maxSelect: 1,
+            },
+            { name: "created", type: "autodate", onCreate: true },
+            { name: "updated", type: "autodate", onCreate: true, onUpdate: true },
+        ],
+        indexes: [
+            "CREATE UNIQUE INDEX idx_invites_code ON invites (code) WHERE code != ''",
+        ],
+    });
+
+    app.save(invites);
+}, (app) => {
+    app.delete(app.findCollectionByNameOrId("invites"));
+});
@@ -0,0 +1,11 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// Paragraph and chapter writes go through /api/batch (`ApiClient::batch`),
+// which PocketBase turns off by default. `BATCH_LIMIT` in
+// src/services/api.rs matches `maxRequests`.
+migrate((app) => {
+    const settings = app.settings();
+    settings.batch.enabled = true;
+    settings.batch.maxRequests = Math.max(settings.batch.maxRequests, 50);
+    app.save(settings);
+});
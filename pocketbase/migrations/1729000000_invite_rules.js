@@ -0,0 +1,22 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// Only admins can list, review or delete invites. Anyone may request one, but
+// only as a pending request without a code; codes are redeemed by the `users`
+// create hook in hooks/invites.pb.js.
+migrate((app) => {
+    const admin = '@request.auth.role = "admin"';
+    const invites = app.findCollectionByNameOrId("invites");
+
+    invites.listRule = admin;
+    invites.viewRule = admin;
+    invites.updateRule = admin;
+    invites.deleteRule = admin;
+    invites.createRule = [
+        '(@request.body.status:isset = false || @request.body.status = "pending")',
+        "@request.body.code:isset = false",
+        "@request.body.expires:isset = false",
+        "@request.body.redeemed_by:isset = false",
+    ].join(" && ");
+
+    app.save(invites);
+});
@@ -0,0 +1,49 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// The `paragraph_revisions` collection behind `ParagraphRevision` in
+// src/services/revisions.rs. Every paragraph write creates one in the same
+// batch, so saving fails until it exists. A paragraph's revisions are deleted
+// with it. Access rules are set by 1729000100_role_rules.js.
+migrate((app) => {
+    try {
+        app.findCollectionByNameOrId("paragraph_revisions");
+        return;
+    } catch (_) {
+        // Not created yet.
+    }
+
+    const paragraphs = app.findCollectionByNameOrId("paragraphs");
+    const users = app.findCollectionByNameOrId("users");
+    const revisions = new Collection({
+        type: "base",
+        name: "paragraph_revisions",
+        fields: [
+            {
+                name: "paragraph",
+                type: "relation",
+                required: true,
+                collectionId: paragraphs.id,
+                cascadeDelete: true,
+                maxSelect: 1,
+            },
+            {
+                name: "author",
+                type: "relation",
+                collectionId: users.id,
+                maxSelect: 1,
+            },
+            { name: "author_name", type: "text", max: 200 },
+            { name: "texts", type: "json" },
+            { name: "choices", type: "json" },
+            { name: "created", type: "autodate", onCreate: true },
+            { name: "updated", type: "autodate", onCreate: true, onUpdate: true },
+        ],
+        indexes: [
+            "CREATE INDEX idx_paragraph_revisions_paragraph ON paragraph_revisions (paragraph, created)",
+        ],
+    });
+
+    app.save(revisions);
+}, (app) => {
+    app.delete(app.findCollectionByNameOrId("paragraph_revisions"));
+});
@@ -0,0 +1,24 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// The `illustration` file field behind `Paragraph::illustration` in
+// src/models/paragraph.rs, which the dashboard's illustration upload writes.
+// Each paragraph keeps at most one image; alt text lives in its `texts`.
+migrate((app) => {
+    const paragraphs = app.findCollectionByNameOrId("paragraphs");
+    if (paragraphs.fields.getByName("illustration")) {
+        return;
+    }
+
+    paragraphs.fields.add(new FileField({
+        name: "illustration",
+        maxSelect: 1,
+        maxSize: 5 * 1024 * 1024,
+        mimeTypes: ["image/jpeg", "image/png", "image/webp", "image/gif", "image/avif"],
+        thumbs: ["480x0", "960x0"],
+    }));
+    app.save(paragraphs);
+}, (app) => {
+    const paragraphs = app.findCollectionByNameOrId("paragraphs");
+    paragraphs.fields.removeByName("illustration");
+    app.save(paragraphs);
+});
@@ -0,0 +1,74 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// Server side of `Capabilities` in src/models/role.rs:
+// * readers can only read the story,
+// * translators can edit the texts of their assigned languages (checked by
+//   hooks/roles.pb.js, since rules cannot compare JSON entries),
+// * editors can change paragraphs and chapters,
+// * only admins (or superusers) can assign roles and languages.
+//
+// Every collection must exist by now (paragraph_revisions is created by
+// 1729000050_create_paragraph_revisions.js): one that is missing stops the
+// migration instead of being left locked to superusers.
+migrate((app) => {
+    const translator = '(@request.auth.role = "translator" || @request.auth.role = "editor" || @request.auth.role = "admin")';
+    const editor = '(@request.auth.role = "editor" || @request.auth.role = "admin")';
+    const admin = '@request.auth.role = "admin"';
+    const ownRoleUntouched = "@request.body.role:isset = false && @request.body.languages:isset = false";
+
+    const rules = {
+        users: {
+            createRule: ownRoleUntouched,
+            updateRule: `(id = @request.auth.id && ${ownRoleUntouched}) || ${admin}`,
+        },
+        paragraphs: {
+            createRule: editor,
+            updateRule: translator,
+            deleteRule: editor,
+        },
+        chapters: {
+            createRule: editor,
+            updateRule: editor,
+            deleteRule: editor,
+        },
+        paragraph_revisions: {
+            listRule: translator,
+            viewRule: translator,
+            createRule: translator,
+            updateRule: null,
+            deleteRule: admin,
+        },
+    };
+
+    // The fields the rules and hooks/roles.pb.js read; `Role` and
+    // `LANGUAGES` in src/models/role.rs and src/constants/config.rs list the
+    // same values.
+    const users = app.findCollectionByNameOrId("users");
+    if (!users.fields.getByName("role")) {
+        users.fields.add(new SelectField({
+            name: "role",
+            maxSelect: 1,
+            values: ["reader", "translator", "editor", "admin"],
+        }));
+    }
+    if (!users.fields.getByName("languages")) {
+        const languages = ["en-US", "en-GB", "es-ES", "es-CL", "fr-FR", "zh-TW", "zh-CN"];
+        users.fields.add(new SelectField({
+            name: "languages",
+            maxSelect: languages.length,
+            values: languages,
+        }));
+    }
+    app.save(users);
+
+    for (const [name, collectionRules] of Object.entries(rules)) {
+        let collection;
+        try {
+            collection = app.findCollectionByNameOrId(name);
+        } catch (err) {
+            throw new Error(`Cannot set the role rules of missing collection "${name}": ${err}`);
+        }
+        Object.assign(collection, collectionRules);
+        app.save(collection);
+    }
+});
@@ -0,0 +1,56 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// The `audio_cues` collection behind `AudioCue` in src/models/audio.rs.
+// Readers load every cue; editors author them, e.g. from the PocketBase
+// dashboard, by uploading the sound and naming the chapter, paragraph or
+// outcome tier it belongs to.
+migrate((app) => {
+    try {
+        app.findCollectionByNameOrId("audio_cues");
+        return;
+    } catch (_) {
+        // Not created yet.
+    }
+
+    const editor = '(@request.auth.role = "editor" || @request.auth.role = "admin")';
+    const cues = new Collection({
+        type: "base",
+        name: "audio_cues",
+        listRule: "",
+        viewRule: "",
+        createRule: editor,
+        updateRule: editor,
+        deleteRule: editor,
+        fields: [
+            {
+                name: "kind",
+                type: "select",
+                required: true,
+                maxSelect: 1,
+                values: ["ambience", "effect"],
+            },
+            { name: "chapter_id", type: "text", max: 64 },
+            { name: "paragraph_id", type: "text", max: 64 },
+            {
+                name: "tier",
+                type: "select",
+                maxSelect: 1,
+                values: ["great_success", "success", "mixed", "failure", "disaster"],
+            },
+            {
+                name: "file",
+                type: "file",
+                required: true,
+                maxSelect: 1,
+                maxSize: 20 * 1024 * 1024,
+                mimeTypes: ["audio/mpeg", "audio/ogg", "audio/wav", "audio/webm", "audio/aac", "audio/mp4"],
+            },
+            { name: "created", type: "autodate", onCreate: true },
+            { name: "updated", type: "autodate", onCreate: true, onUpdate: true },
+        ],
+    });
+
+    app.save(cues);
+}, (app) => {
+    app.delete(app.findCollectionByNameOrId("audio_cues"));
+});
@@ -0,0 +1,36 @@
+/// <reference path="../pb_data/types.d.ts" />
+
+// The `achievements` collection behind `Achievement` in
+// src/models/achievements.rs: per-language `texts` ({ lang, title,
+// description }), the `trigger` expression and the gallery `order`. Readers
+// load them all; editors author them from the PocketBase dashboard.
+migrate((app) => {
+    try {
+        app.findCollectionByNameOrId("achievements");
+        return;
+    } catch (_) {
+        // Not created yet.
+    }
+
+    const editor = '(@request.auth.role = "editor" || @request.auth.role = "admin")';
+    const achievements = new Collection({
+        type: "base",
+        name: "achievements",
+        listRule: "",
+        viewRule: "",
+        createRule: editor,
+        updateRule: editor,
+        deleteRule: editor,
+        fields: [
+            { name: "texts", type: "json" },
+            { name: "trigger", type: "text", required: true, max: 2000 },
+            { name: "order", type: "number", onlyInt: true },
+            { name: "created", type: "autodate", onCreate: true },
+            { name: "updated", type: "autodate", onCreate: true, onUpdate: true },
+        ],
+    });
+
+    app.save(achievements);
+}, (app) => {
+    app.delete(app.findCollectionByNameOrId("achievements"));
+});
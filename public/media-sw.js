@@ -0,0 +1,74 @@
+// Media-only service worker: keeps offline copies of uploaded story media.
+// Every other request, including the app shell and authenticated API calls,
+// is left to the network.
+//
+// Uploaded media never changes under the same URL, so the cache has no
+// version and is read before the network.
+const MEDIA_CACHE_NAME = 'ifecaro-media';
+const MEDIA_PATH = '/api/files/';
+
+self.addEventListener('install', () => self.skipWaiting());
+
+// Caches from the old app-wide worker may hold API responses from a signed-in
+// session, so they are dropped when this worker takes over
+self.addEventListener('activate', event => {
+    event.waitUntil(
+        caches.keys()
+            .then(cacheNames => Promise.all(
+                cacheNames
+                    .filter(cacheName => cacheName.startsWith('ifecaro-cache-'))
+                    .map(cacheName => caches.delete(cacheName))
+            ))
+            .then(() => self.clients.claim())
+            .catch(error => console.error('Media worker activation failed:', error))
+    );
+});
+
+function isMediaRequest(url) {
+    return url.pathname.includes(MEDIA_PATH);
+}
+
+async function cacheFirstMedia(request) {
+    const cache = await caches.open(MEDIA_CACHE_NAME);
+    const cached = await cache.match(request, { ignoreVary: true });
+    if (cached) return cached;
+
+    const response = await fetch(request);
+    // Range requests (audio seeking) answer 206, which cannot be cached
+    if (response.ok && response.status === 200) {
+        cache.put(request, response.clone())
+            .catch(error => console.error('Media cache update failed:', error));
+    }
+    return response;
+}
+
+// The app asks for media it will need soon: { type: 'CACHE_MEDIA', urls: [...] }
+self.addEventListener('message', event => {
+    const data = event.data || {};
+    if (data.type !== 'CACHE_MEDIA' || !Array.isArray(data.urls)) return;
+
+    event.waitUntil(
+        caches.open(MEDIA_CACHE_NAME).then(cache =>
+            Promise.all(data.urls.map(async mediaUrl => {
+                if (await cache.match(mediaUrl)) return;
+                try {
+                    const response = await fetch(mediaUrl);
+                    if (response.ok) await cache.put(mediaUrl, response);
+                } catch (error) {
+                    console.error('Media precache failed:', mediaUrl, error);
+                }
+            }))
+        )
+    );
+});
+
+self.addEventListener('fetch', event => {
+    const url = new URL(event.request.url);
+    if (event.request.method !== 'GET' || !isMediaRequest(url)) return;
+
+    event.respondWith(
+        cacheFirstMedia(event.request).catch(() =>
+            new Response('Media unavailable offline', { status: 503 })
+        )
+    );
+});
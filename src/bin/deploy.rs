@@ -681,6 +681,7 @@ fn copy_pwa_resources() -> Result<()> {
         &format!("{}/manifest.json", public_dir),
     );
     copy_if_exists("public/sw.js", &format!("{}/sw.js", public_dir));
+    copy_if_exists("public/media-sw.js", &format!("{}/media-sw.js", public_dir));
     copy_if_exists(
         "public/img/icons/favicon.ico",
         &format!("{}/favicon.ico", public_dir),
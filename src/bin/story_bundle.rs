@@ -0,0 +1,247 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod cli {
+    use anyhow::{anyhow, Context, Result};
+    use clap::{Parser, Subcommand};
+    use colored::*;
+    use ifecaro::models::bundle::{BundleCollection, IdStrategy, RecordAction, StoryBundle};
+    use ifecaro::models::story_export::{export_choicescript, export_ink, StoryExport};
+    use ifecaro::models::twee::twee_to_bundle;
+    use ifecaro::services::api::HttpApiClient;
+    use ifecaro::services::auth::login;
+    use ifecaro::services::bundle::{apply_import, export_bundle, plan_bundle_import};
+    use ifecaro::services::paragraphs::new_record_id;
+    use ifecaro::services::retry::{RetryPolicy, RetryingApiClient};
+    use std::path::{Path, PathBuf};
+
+    #[derive(Parser)]
+    #[command(name = "story-bundle")]
+    #[command(about = "Export, import and convert whole Ifecaro stories", long_about = None)]
+    struct Cli {
+        /// PocketBase API root, e.g. http://localhost:8090/api; needed by
+        /// every command except `convert`
+        #[arg(long, env = "IFECARO_API_URL")]
+        url: Option<String>,
+        /// Account to sign in with; collections that need an editor are
+        /// read and written anonymously otherwise
+        #[arg(long, env = "IFECARO_EMAIL")]
+        email: Option<String>,
+        #[arg(long, env = "IFECARO_PASSWORD", hide_env_values = true)]
+        password: Option<String>,
+        #[command(subcommand)]
+        command: Commands,
+    }
+
+    #[derive(Subcommand)]
+    enum Commands {
+        /// Write every chapter, paragraph, character, relationship, attribute
+        /// and setting to a bundle file, or the story to Ink or ChoiceScript
+        Export {
+            #[arg(long, value_enum, default_value_t = ExportFormat::Bundle)]
+            format: ExportFormat,
+            /// Language of the text in Ink and ChoiceScript exports
+            #[arg(long, default_value = "en-US")]
+            lang: String,
+            /// Output file, or directory for ChoiceScript; stdout when omitted
+            #[arg(long)]
+            out: Option<PathBuf>,
+        },
+        /// Load a bundle, or a Twine story with `--twee`, into the story
+        Import {
+            #[arg(long)]
+            file: PathBuf,
+            /// Read `file` as Twee 3 source instead of a bundle
+            #[arg(long)]
+            twee: bool,
+            /// Language of the Twee text
+            #[arg(long, default_value = "en-US")]
+            lang: String,
+            /// Give every record a new id instead of overwriting records
+            /// with the same id
+            #[arg(long)]
+            remap: bool,
+            /// Only report what would be created and updated
+            #[arg(long)]
+            dry_run: bool,
+        },
+        /// Turn Twee 3 source into a bundle file without contacting a server
+        Convert {
+            #[arg(long)]
+            file: PathBuf,
+            /// Language of the Twee text
+            #[arg(long, default_value = "en-US")]
+            lang: String,
+            /// Output file; stdout when omitted
+            #[arg(long)]
+            out: Option<PathBuf>,
+        },
+    }
+
+    #[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+    enum ExportFormat {
+        /// Versioned JSON bundle that can be imported again
+        Bundle,
+        /// One `.ink` file
+        Ink,
+        /// `startup.txt` plus one scene per chapter
+        Choicescript,
+    }
+
+    /// Writes Ink or ChoiceScript files and lists what did not carry over.
+    fn write_story_export(export: &StoryExport, out: Option<&PathBuf>) -> Result<()> {
+        for issue in &export.issues {
+            eprintln!("{} {}", "⚠".yellow(), issue);
+        }
+        match (out, export.files.as_slice()) {
+            (None, [file]) => write_output(None, &file.content),
+            (None, _) => Err(anyhow!("--out <directory> is needed for several files")),
+            (Some(path), [file]) if !path.is_dir() => write_output(Some(path), &file.content),
+            (Some(dir), files) => {
+                std::fs::create_dir_all(dir)
+                    .with_context(|| format!("Cannot create {}", dir.display()))?;
+                for file in files {
+                    write_output(Some(&dir.join(&file.name)), &file.content)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn write_output(out: Option<&PathBuf>, json: &str) -> Result<()> {
+        match out {
+            Some(path) => std::fs::write(path, json)
+                .with_context(|| format!("Cannot write {}", path.display())),
+            None => {
+                println!("{}", json);
+                Ok(())
+            }
+        }
+    }
+
+    /// Converts Twee source, printing what was not carried over.
+    fn convert_twee(file: &Path, lang: &str) -> Result<StoryBundle> {
+        let source = std::fs::read_to_string(file)
+            .with_context(|| format!("Cannot read {}", file.display()))?;
+        let exported_at = chrono::Utc::now().to_rfc3339();
+        let import = twee_to_bundle(&source, lang, &exported_at, new_record_id)?;
+        for warning in &import.warnings {
+            eprintln!("{} {}", "⚠".yellow(), warning);
+        }
+        Ok(import.bundle)
+    }
+
+    pub async fn run() -> Result<()> {
+        let cli = Cli::parse();
+        if let Commands::Convert { file, lang, out } = &cli.command {
+            let bundle = convert_twee(file, lang)?;
+            return write_output(out.as_ref(), &serde_json::to_string_pretty(&bundle)?);
+        }
+        let url = cli
+            .url
+            .as_deref()
+            .ok_or_else(|| anyhow!("--url (or IFECARO_API_URL) is required"))?;
+        let client =
+            RetryingApiClient::new(HttpApiClient::with_base_url(url), RetryPolicy::default());
+        match (&cli.email, &cli.password) {
+            (Some(email), Some(password)) => {
+                login(&client, email, password)
+                    .await
+                    .map_err(|e| anyhow!("Sign-in failed: {}", e))?;
+            }
+            (None, None) => {}
+            _ => return Err(anyhow!("--email and --password must be given together")),
+        }
+
+        match cli.command {
+            Commands::Export { format, lang, out } => {
+                let exported_at = chrono::Utc::now().to_rfc3339();
+                let bundle = export_bundle(&client, &exported_at)
+                    .await
+                    .map_err(|e| anyhow!("Export failed: {}", e))?;
+                match format {
+                    ExportFormat::Ink => {
+                        return write_story_export(&export_ink(&bundle, &lang), out.as_ref())
+                    }
+                    ExportFormat::Choicescript => {
+                        return write_story_export(
+                            &export_choicescript(&bundle, &lang),
+                            out.as_ref(),
+                        )
+                    }
+                    ExportFormat::Bundle => {}
+                }
+                let json = serde_json::to_string_pretty(&bundle)?;
+                write_output(out.as_ref(), &json)?;
+                if let Some(path) = out {
+                    eprintln!(
+                        "{} {} chapters, {} paragraphs → {}",
+                        "✅ Exported".green().bold(),
+                        bundle.chapters.len(),
+                        bundle.paragraphs.len(),
+                        path.display()
+                    );
+                }
+            }
+            Commands::Import {
+                file,
+                twee,
+                lang,
+                remap,
+                dry_run,
+            } => {
+                let bundle = if twee {
+                    convert_twee(&file, &lang)?
+                } else {
+                    let text = std::fs::read_to_string(&file)
+                        .with_context(|| format!("Cannot read {}", file.display()))?;
+                    StoryBundle::parse(&text)?
+                };
+                let strategy = if remap {
+                    IdStrategy::Remap
+                } else {
+                    IdStrategy::Keep
+                };
+                let plan = plan_bundle_import(&client, &bundle, strategy)
+                    .await
+                    .map_err(|e| anyhow!("Cannot read the current story: {}", e))?;
+
+                println!("{}", "Collection        Create  Update".bold());
+                for collection in BundleCollection::ALL {
+                    println!(
+                        "{:<16} {:>7} {:>7}",
+                        collection.name(),
+                        plan.count(collection, RecordAction::Create),
+                        plan.count(collection, RecordAction::Update)
+                    );
+                }
+                if dry_run {
+                    println!("{}", "Dry run: nothing was written".yellow());
+                    return Ok(());
+                }
+                if let Err(failure) = apply_import(&client, &plan).await {
+                    return Err(anyhow!(
+                        "Import stopped after {} of {} records: {}",
+                        failure.applied,
+                        plan.records.len(),
+                        failure.error
+                    ));
+                }
+                println!(
+                    "{} {} records",
+                    "✅ Imported".green().bold(),
+                    plan.records.len()
+                );
+            }
+            Commands::Convert { .. } => unreachable!("handled before connecting"),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    cli::run().await
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -0,0 +1,60 @@
+use crate::contexts::settings_context::use_settings_context;
+use crate::contexts::story_context::use_story_context;
+use crate::models::audio::Volume;
+use crate::services::audio;
+use dioxus::prelude::*;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AudioDirectorProps {
+    /// Paragraph the reader is on; its chapter picks the ambience.
+    pub paragraph_id: Signal<String>,
+    /// Base URL for cue files, see [`crate::models::audio::AudioCue::file_url`].
+    pub files_base: String,
+}
+
+/// Plays the story's audio cues as the reader moves through it. Renders
+/// nothing.
+#[component]
+pub fn AudioDirector(props: AudioDirectorProps) -> Element {
+    let story_ctx = use_story_context();
+    let settings_ctx = use_settings_context();
+    let paragraph_id = props.paragraph_id;
+    // Effects play once per arrival, not again when the cues finish loading
+    let mut effects_played_for = use_signal(String::new);
+
+    use_effect(move || {
+        let volume = Volume::from_settings(&settings_ctx.read().settings);
+        audio::set_gain(volume.gain());
+    });
+
+    let files_base = props.files_base.clone();
+    use_effect(move || {
+        let paragraph_id = paragraph_id.read().clone();
+        let cues_signal = story_ctx.read().audio_cues;
+        let cues = cues_signal.read();
+        let chapter_id = story_ctx
+            .read()
+            .paragraphs
+            .read()
+            .iter()
+            .find(|p| p.id == paragraph_id)
+            .map(|p| p.chapter_id.clone())
+            .unwrap_or_default();
+
+        let ambience = cues
+            .ambience_for(&chapter_id)
+            .map(|cue| cue.file_url(&files_base));
+        audio::play_ambience(ambience.as_deref());
+
+        if *effects_played_for.peek() != paragraph_id && !cues.is_empty() {
+            for cue in cues.effects_for(&paragraph_id) {
+                audio::play_effect(&cue.file_url(&files_base));
+            }
+            effects_played_for.set(paragraph_id);
+        }
+    });
+
+    use_drop(audio::stop_all);
+
+    rsx! {}
+}
@@ -0,0 +1,365 @@
+use crate::components::chapter_selector::ChapterSelector;
+use crate::contexts::settings_context::use_settings_context;
+use crate::contexts::story_context::{use_story_context, StoryContext};
+use crate::models::bookmarks::{
+    excerpt, page_turn_mode, Bookmark, Bookmarks, CurrentPage, PagePosition, ReadingPosition,
+    EXCERPT_CHARS,
+};
+use crate::services::bookmarks::{
+    load_bookmarks, load_reading_position, save_bookmarks, save_reading_position,
+};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use gloo_timers::callback::Timeout;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Scrolling has to settle this long before the position is saved.
+const SAVE_DELAY_MS: u32 = 400;
+/// Time for the restored page to lay out before scrolling into it.
+const RESTORE_DELAY_MS: u32 = 150;
+
+struct PageMetrics {
+    scroll_top: f64,
+    /// What one page turn scrolls: the viewport below the navbar.
+    page_height: f64,
+    max_scroll_top: f64,
+}
+
+fn page_metrics() -> Option<PageMetrics> {
+    let document = web_sys::window()?.document()?;
+    let root = document.document_element()?;
+    let client_height = root.client_height() as f64;
+    let navbar_height = document
+        .query_selector("#app-navbar")
+        .ok()
+        .flatten()
+        .map(|navbar| navbar.get_bounding_client_rect().height())
+        .unwrap_or(0.0);
+    Some(PageMetrics {
+        scroll_top: root.scroll_top() as f64,
+        page_height: (client_height - navbar_height).max(0.0),
+        max_scroll_top: (root.scroll_height() as f64 - client_height).max(0.0),
+    })
+}
+
+fn scroll_to(position: &PagePosition) {
+    let (Some(window), Some(metrics)) = (web_sys::window(), page_metrics()) else {
+        return;
+    };
+    let top = position.scroll_top(metrics.page_height, metrics.max_scroll_top);
+    window.scroll_to_with_x_and_y(0.0, top);
+}
+
+/// Opening words of the first story block still visible below the navbar.
+fn visible_excerpt() -> Option<String> {
+    let document = web_sys::window()?.document()?;
+    let navbar_bottom = document
+        .query_selector("#app-navbar")
+        .ok()
+        .flatten()
+        .map(|navbar| navbar.get_bounding_client_rect().bottom())
+        .unwrap_or(0.0);
+    let blocks = document.query_selector_all(".story-text-filter p").ok()?;
+    (0..blocks.length())
+        .filter_map(|index| blocks.item(index)?.dyn_into::<web_sys::Element>().ok())
+        .find(|block| block.get_bounding_client_rect().bottom() > navbar_bottom)
+        .and_then(|block| block.text_content())
+        .map(|text| excerpt(&text, EXCERPT_CHARS))
+}
+
+fn is_reader_mode(settings: &HashMap<String, String>) -> bool {
+    settings
+        .get("reader_mode")
+        .map(|v| v == "true")
+        .unwrap_or(false)
+}
+
+fn chapter_of(story: &StoryContext, paragraph_id: &str) -> Option<String> {
+    story
+        .paragraphs
+        .peek()
+        .iter()
+        .find(|p| p.id == paragraph_id)
+        .map(|p| p.chapter_id.clone())
+}
+
+/// Saves the reader's place in the chapter while they scroll and brings it
+/// back when the same page is shown again, e.g. after a reload. Renders
+/// nothing.
+#[component]
+pub fn PositionKeeper(paragraph_id: Signal<String>) -> Element {
+    let story_ctx = use_story_context();
+    let settings_ctx = use_settings_context();
+    let mut restored_for = use_signal(String::new);
+
+    use_effect(move || {
+        let current = paragraph_id.read().clone();
+        if current.is_empty() || *restored_for.peek() == current {
+            return;
+        }
+        restored_for.set(current.clone());
+        let Some(chapter_id) = chapter_of(&story_ctx.peek(), &current) else {
+            return;
+        };
+        let reader_mode = is_reader_mode(&settings_ctx.peek().settings);
+        spawn(async move {
+            let Some(saved) = load_reading_position(&chapter_id).await else {
+                return;
+            };
+            let page = CurrentPage {
+                paragraph_id: &current,
+                reader_mode,
+                path: &[],
+            };
+            if saved.is_current(&page) {
+                Timeout::new(RESTORE_DELAY_MS, move || scroll_to(&saved.position)).forget();
+            }
+        });
+    });
+
+    let listener = use_hook(move || {
+        if !cfg!(target_arch = "wasm32") {
+            return None;
+        }
+        let pending: Rc<RefCell<Option<Timeout>>> = Rc::default();
+        let on_scroll = Closure::<dyn FnMut()>::new(move || {
+            // Replacing the timeout cancels the previous one
+            *pending.borrow_mut() = Some(Timeout::new(SAVE_DELAY_MS, move || {
+                let current = paragraph_id.peek().clone();
+                let Some(chapter_id) = chapter_of(&story_ctx.peek(), &current) else {
+                    return;
+                };
+                let Some(metrics) = page_metrics() else {
+                    return;
+                };
+                let settings = settings_ctx.peek().settings.clone();
+                let page = CurrentPage {
+                    paragraph_id: &current,
+                    reader_mode: is_reader_mode(&settings),
+                    path: &[],
+                };
+                let position = ReadingPosition::new(
+                    &page,
+                    PagePosition::capture(
+                        &page_turn_mode(&settings),
+                        metrics.scroll_top,
+                        metrics.page_height,
+                    ),
+                );
+                wasm_bindgen_futures::spawn_local(async move {
+                    save_reading_position(&chapter_id, &position).await;
+                });
+            }));
+        });
+        let window = web_sys::window()?;
+        let _ =
+            window.add_event_listener_with_callback("scroll", on_scroll.as_ref().unchecked_ref());
+        Some(Rc::new(on_scroll))
+    });
+
+    use_drop(move || {
+        if let (Some(on_scroll), Some(window)) = (listener.as_ref(), web_sys::window()) {
+            let _ = window.remove_event_listener_with_callback(
+                "scroll",
+                on_scroll.as_ref().as_ref().unchecked_ref(),
+            );
+        }
+    });
+
+    rsx! {}
+}
+
+/// Button to bookmark the passage on screen, and the reader's bookmarks
+/// listed by chapter.
+#[component]
+pub fn BookmarkPanel(paragraph_id: Signal<String>) -> Element {
+    let story_ctx = use_story_context();
+    let settings_ctx = use_settings_context();
+    let mut bookmarks = use_signal(Bookmarks::default);
+    let mut open = use_signal(|| false);
+    let mut note = use_signal(String::new);
+    // Chapter whose bookmarks are listed; empty for the current one
+    let mut listed_chapter = use_signal(String::new);
+    let mut selector_open = use_signal(|| false);
+    let mut search_query = use_signal(String::new);
+    let mut not_on_page = use_signal(|| false);
+    // Stays off until the stored bookmarks are read, so they are never
+    // replaced by the ones added this session alone
+    let mut can_save = use_signal(|| false);
+
+    use_hook(move || {
+        spawn(async move {
+            if let Ok(stored) = load_bookmarks().await {
+                bookmarks.set(stored);
+                can_save.set(true);
+            }
+        })
+    });
+
+    let current = paragraph_id.read().clone();
+    let current_chapter = chapter_of(&story_ctx.read(), &current).unwrap_or_default();
+    let shown_chapter = if listed_chapter.read().is_empty() {
+        current_chapter.clone()
+    } else {
+        listed_chapter.read().clone()
+    };
+    // Only chapters the reader has reached, so the list gives nothing away
+    let chapters = {
+        let story = story_ctx.read();
+        let paragraphs = story.paragraphs.read();
+        let path = story.choice_ids.read();
+        let mut reached: Vec<String> = paragraphs
+            .iter()
+            .filter(|p| p.id == current || path.contains(&p.id))
+            .map(|p| p.chapter_id.clone())
+            .collect();
+        reached.extend(bookmarks.read().items.iter().map(|b| b.chapter_id.clone()));
+        let mut chapters: Vec<_> = story
+            .chapters
+            .read()
+            .iter()
+            .filter(|c| c.id != "settingschapter" && reached.contains(&c.id))
+            .cloned()
+            .collect();
+        chapters.sort_by_key(|c| c.order);
+        chapters
+    };
+    let listed = bookmarks.read().in_chapter(&shown_chapter);
+    let percentages = {
+        let story = story_ctx.read();
+        let paragraphs = story.paragraphs.read();
+        let percentages = story.progress.read().percentages(&paragraphs);
+        percentages
+    };
+
+    let add_bookmark = move |_: MouseEvent| {
+        let current = paragraph_id.peek().clone();
+        let Some(chapter_id) = chapter_of(&story_ctx.peek(), &current) else {
+            return;
+        };
+        let Some(metrics) = page_metrics() else {
+            return;
+        };
+        let settings = settings_ctx.peek().settings.clone();
+        let page = CurrentPage {
+            paragraph_id: &current,
+            reader_mode: is_reader_mode(&settings),
+            path: &[],
+        };
+        let bookmark = Bookmark {
+            id: format!("{}", js_sys::Date::now() as u64),
+            chapter_id,
+            place: ReadingPosition::new(
+                &page,
+                PagePosition::capture(
+                    &page_turn_mode(&settings),
+                    metrics.scroll_top,
+                    metrics.page_height,
+                ),
+            ),
+            excerpt: visible_excerpt().unwrap_or_default(),
+            note: note.peek().trim().to_string(),
+        };
+        bookmarks.write().add(bookmark);
+        note.set(String::new());
+        open.set(true);
+        listed_chapter.set(String::new());
+        if *can_save.peek() {
+            let snapshot = bookmarks.peek().clone();
+            spawn(async move { save_bookmarks(&snapshot).await });
+        }
+    };
+
+    let go_to = move |bookmark: Bookmark| {
+        let story = story_ctx.peek();
+        let path = story.choice_ids.peek().clone();
+        let current = paragraph_id.peek().clone();
+        let page = CurrentPage {
+            paragraph_id: &current,
+            reader_mode: is_reader_mode(&settings_ctx.peek().settings),
+            path: &path,
+        };
+        if bookmark.place.is_on(&page) {
+            not_on_page.set(false);
+            open.set(false);
+            scroll_to(&bookmark.place.position);
+        } else {
+            not_on_page.set(true);
+        }
+    };
+
+    let remove = move |id: String| {
+        bookmarks.write().remove(&id);
+        if *can_save.peek() {
+            let snapshot = bookmarks.peek().clone();
+            spawn(async move { save_bookmarks(&snapshot).await });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "relative",
+            button {
+                class: "text-sm px-3 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#d4c29a] text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                aria_expanded: if open() { "true" } else { "false" },
+                onclick: move |evt| {
+                    evt.stop_propagation();
+                    not_on_page.set(false);
+                    open.toggle();
+                },
+                {t!("bookmarks")}
+            }
+            if open() {
+                div {
+                    class: "page-turn-block absolute right-0 z-20 mt-2 w-80 max-w-[90vw] p-4 space-y-3 rounded-lg shadow-lg bg-white dark:bg-gray-800 paper:bg-[#fef8e7] border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2]",
+                    onclick: move |evt| evt.stop_propagation(),
+                    // Typing a note must not pick choices or turn pages
+                    onkeydown: move |evt| evt.stop_propagation(),
+                    div {
+                        class: "flex gap-2",
+                        input {
+                            class: "flex-1 min-w-0 px-3 py-1.5 text-sm rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#d4c29a] bg-white dark:bg-gray-700 paper:bg-transparent",
+                            r#type: "text",
+                            placeholder: t!("bookmark_note_placeholder"),
+                            value: "{note}",
+                            oninput: move |evt| note.set(evt.value()),
+                        }
+                        button {
+                            class: "text-sm px-3 py-1.5 rounded-md bg-gray-900 text-white dark:bg-gray-100 dark:text-gray-900 paper:bg-[#374151] paper:text-[#fef8e7]",
+                            onclick: add_bookmark,
+                            {t!("bookmark_add")}
+                        }
+                    }
+                    ChapterSelector {
+                        label: t!("bookmarks"),
+                        value: shown_chapter,
+                        chapters,
+                        is_open: selector_open(),
+                        search_query: search_query.read().clone(),
+                        on_toggle: move |_| selector_open.toggle(),
+                        on_search: move |query| search_query.set(query),
+                        on_select: move |chapter: crate::models::chapter::Chapter| {
+                            listed_chapter.set(chapter.id);
+                            selector_open.set(false);
+                            not_on_page.set(false);
+                        },
+                        bookmarks: listed.clone(),
+                        on_bookmark_select: go_to,
+                        on_bookmark_remove: remove,
+                        progress: percentages,
+                    }
+                    if listed.is_empty() {
+                        p { class: "text-sm text-gray-500 dark:text-gray-400 paper:text-[#6b7280]", {t!("bookmarks_empty")} }
+                    }
+                    if not_on_page() {
+                        p { class: "text-sm text-amber-700 dark:text-amber-400", {t!("bookmark_not_on_page")} }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use crate::models::story_graph::{EdgeKind, GraphEdge, StoryGraph, NODE_HEIGHT, NODE_WIDTH};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+const MIN_SCALE: f64 = 0.3;
+const MAX_SCALE: f64 = 2.5;
+const ZOOM_STEP: f64 = 1.2;
+const CLICK_SLOP: f64 = 4.0;
+
+#[derive(Clone, PartialEq)]
+enum Drag {
+    Pan {
+        start: (f64, f64),
+        origin: (f64, f64),
+        moved: bool,
+    },
+    EdgeEnd {
+        edge: GraphEdge,
+        start: (f64, f64),
+        origin: (f64, f64),
+        current: (f64, f64),
+    },
+}
+
+fn edge_style(kind: EdgeKind) -> (&'static str, &'static str) {
+    match kind {
+        EdgeKind::Normal => ("#6b7280", ""),
+        EdgeKind::Random => ("#8b5cf6", "6 4"),
+        EdgeKind::Timeout => ("#f59e0b", "2 4"),
+        EdgeKind::SamePage => ("#10b981", ""),
+    }
+}
+
+fn edge_path(from: (f64, f64), to: (f64, f64)) -> String {
+    let bend = ((to.0 - from.0).abs() / 2.0).max(40.0);
+    format!(
+        "M {} {} C {} {}, {} {}, {} {}",
+        from.0,
+        from.1,
+        from.0 + bend,
+        from.1,
+        to.0 - bend,
+        to.1,
+        to.0,
+        to.1
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct BranchGraphProps {
+    pub graph: StoryGraph,
+    #[props(default)]
+    pub selected_id: Option<String>,
+    /// Lets the user drag edge ends onto another node.
+    #[props(default = true)]
+    pub editable: bool,
+    pub on_select: EventHandler<String>,
+    pub on_retarget: EventHandler<(GraphEdge, String)>,
+}
+
+/// Pan/zoom view of a chapter's paragraph graph.
+///
+/// Clicking a node selects it; dragging the handle at the end of an edge and
+/// dropping it on another node retargets that choice.
+#[component]
+pub fn BranchGraph(props: BranchGraphProps) -> Element {
+    let mut offset = use_signal(|| (24.0_f64, 24.0_f64));
+    let mut scale = use_signal(|| 1.0_f64);
+    let mut drag = use_signal(|| None::<Drag>);
+
+    let graph = props.graph.clone();
+    let (width, height) = graph.bounds();
+    let (offset_x, offset_y) = *offset.read();
+    let zoom = *scale.read();
+    let dragging_edge = match drag.read().as_ref() {
+        Some(Drag::EdgeEnd { edge, current, .. }) => Some((edge.clone(), *current)),
+        _ => None,
+    };
+
+    let mut zoom_by = move |factor: f64| {
+        let next = (*scale.peek() * factor).clamp(MIN_SCALE, MAX_SCALE);
+        scale.set(next);
+    };
+
+    let on_pointer_up = {
+        let graph = graph.clone();
+        move |_: Event<PointerData>| {
+            let finished = drag.peek().clone();
+            drag.set(None);
+            if let Some(Drag::EdgeEnd { edge, current, .. }) = finished {
+                if let Some(node) = graph.node_at(current.0, current.1) {
+                    if node.id != edge.to && !node.external {
+                        props.on_retarget.call((edge, node.id.clone()));
+                    }
+                }
+            }
+        }
+    };
+
+    if graph.nodes.is_empty() {
+        return rsx! {
+            p { class: "text-sm text-gray-500 dark:text-gray-400 paper:text-[#4b5563]", {t!("graph_empty")} }
+        };
+    }
+
+    rsx! {
+        div {
+            class: "space-y-2",
+            div {
+                class: "flex flex-wrap items-center justify-between gap-2 text-xs",
+                div {
+                    class: "flex flex-wrap gap-3",
+                    for (kind, label) in [
+                        (EdgeKind::Normal, t!("graph_edge_normal")),
+                        (EdgeKind::Random, t!("graph_edge_random")),
+                        (EdgeKind::Timeout, t!("graph_edge_timeout")),
+                        (EdgeKind::SamePage, t!("graph_edge_same_page")),
+                    ] {
+                        span {
+                            class: "inline-flex items-center gap-1",
+                            svg {
+                                width: "24",
+                                height: "8",
+                                line {
+                                    x1: "0",
+                                    y1: "4",
+                                    x2: "24",
+                                    y2: "4",
+                                    stroke: edge_style(kind).0,
+                                    stroke_width: "2",
+                                    stroke_dasharray: edge_style(kind).1,
+                                }
+                            }
+                            "{label}"
+                        }
+                    }
+                }
+                div {
+                    class: "flex gap-1",
+                    button {
+                        class: "px-2 py-1 rounded border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2]",
+                        title: t!("graph_zoom_out"),
+                        onclick: move |_| zoom_by(1.0 / ZOOM_STEP),
+                        "−"
+                    }
+                    button {
+                        class: "px-2 py-1 rounded border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2]",
+                        onclick: move |_| {
+                            scale.set(1.0);
+                            offset.set((24.0, 24.0));
+                        },
+                        {t!("graph_zoom_reset")}
+                    }
+                    button {
+                        class: "px-2 py-1 rounded border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2]",
+                        title: t!("graph_zoom_in"),
+                        onclick: move |_| zoom_by(ZOOM_STEP),
+                        "+"
+                    }
+                }
+            }
+            svg {
+                class: "w-full h-[28rem] rounded-lg border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] bg-gray-50 dark:bg-gray-900 paper:bg-[#fbf3df] touch-none select-none",
+                style: if drag.read().is_some() { "cursor: grabbing;" } else { "cursor: grab;" },
+                onwheel: move |event: Event<WheelData>| {
+                    event.prevent_default();
+                    let delta = event.data.delta().strip_units().y;
+                    zoom_by(if delta < 0.0 { ZOOM_STEP } else { 1.0 / ZOOM_STEP });
+                },
+                onpointerdown: move |event: Event<PointerData>| {
+                    if !event.data.is_primary() {
+                        return;
+                    }
+                    let coords = event.data.client_coordinates();
+                    drag.set(Some(Drag::Pan {
+                        start: (coords.x, coords.y),
+                        origin: *offset.peek(),
+                        moved: false,
+                    }));
+                },
+                onpointermove: move |event: Event<PointerData>| {
+                    if drag.peek().is_none() {
+                        return;
+                    }
+                    let coords = event.data.client_coordinates();
+                    let zoom = *scale.peek();
+                    let mut state = drag.write();
+                    match state.as_mut() {
+                        Some(Drag::Pan { start, origin, moved }) => {
+                            let (dx, dy) = (coords.x - start.0, coords.y - start.1);
+                            if dx.abs() > CLICK_SLOP || dy.abs() > CLICK_SLOP {
+                                *moved = true;
+                            }
+                            offset.set((origin.0 + dx, origin.1 + dy));
+                        }
+                        Some(Drag::EdgeEnd { start, origin, current, .. }) => {
+                            *current = (
+                                origin.0 + (coords.x - start.0) / zoom,
+                                origin.1 + (coords.y - start.1) / zoom,
+                            );
+                        }
+                        None => {}
+                    }
+                },
+                onpointerup: on_pointer_up.clone(),
+                onpointerleave: move |_| drag.set(None),
+                g {
+                    transform: "translate({offset_x} {offset_y}) scale({zoom})",
+                    rect {
+                        x: "-2000",
+                        y: "-2000",
+                        width: "{width + 4000.0}",
+                        height: "{height + 4000.0}",
+                        fill: "transparent",
+                    }
+                    for edge in graph.edges.iter().cloned() {
+                        if let (Some(from), Some(to)) = (graph.node(&edge.from), graph.node(&edge.to)) {
+                            {
+                                let start = (from.x + NODE_WIDTH, from.y + NODE_HEIGHT / 2.0);
+                                let is_dragged = dragging_edge.as_ref().map(|(dragged, _)| *dragged == edge).unwrap_or(false);
+                                let end = match &dragging_edge {
+                                    Some((_, current)) if is_dragged => *current,
+                                    _ => (to.x, to.y + NODE_HEIGHT / 2.0),
+                                };
+                                let (stroke, dash) = edge_style(edge.kind);
+                                let editable = props.editable;
+                                let edge_key = format!("{}-{}-{:?}", edge.from, edge.choice_index, edge.slot);
+                                rsx! {
+                                    g {
+                                        key: "{edge_key}",
+                                        path {
+                                            d: edge_path(start, end),
+                                            fill: "none",
+                                            stroke,
+                                            stroke_width: "2",
+                                            stroke_dasharray: dash,
+                                        }
+                                        circle {
+                                            cx: "{end.0}",
+                                            cy: "{end.1}",
+                                            r: "6",
+                                            fill: stroke,
+                                            class: if editable { "cursor-crosshair" } else { "" },
+                                            onpointerdown: move |event: Event<PointerData>| {
+                                                event.stop_propagation();
+                                                if !editable {
+                                                    return;
+                                                }
+                                                let coords = event.data.client_coordinates();
+                                                drag.set(Some(Drag::EdgeEnd {
+                                                    edge: edge.clone(),
+                                                    start: (coords.x, coords.y),
+                                                    origin: end,
+                                                    current: end,
+                                                }));
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    for node in graph.nodes.iter().cloned() {
+                        {
+                            let is_selected = props.selected_id.as_deref() == Some(node.id.as_str());
+                            let (fill, stroke) = match (node.external, is_selected) {
+                                (true, _) => ("#f3f4f6", "#9ca3af"),
+                                (false, true) => ("#dbeafe", "#2563eb"),
+                                (false, false) => ("#ffffff", "#d1d5db"),
+                            };
+                            let node_id = node.id.clone();
+                            let external = node.external;
+                            let title = if node.external {
+                                format!("{} ({})", node.id, t!("graph_external_chapter"))
+                            } else {
+                                node.id.clone()
+                            };
+                            rsx! {
+                                g {
+                                    key: "{node.id}",
+                                    class: if node.external { "" } else { "cursor-pointer" },
+                                    onpointerup: move |event: Event<PointerData>| {
+                                        if matches!(*drag.peek(), Some(Drag::Pan { moved: false, .. })) && !external {
+                                            event.stop_propagation();
+                                            drag.set(None);
+                                            props.on_select.call(node_id.clone());
+                                        }
+                                    },
+                                    title { "{title}" }
+                                    rect {
+                                        x: "{node.x}",
+                                        y: "{node.y}",
+                                        width: "{NODE_WIDTH}",
+                                        height: "{NODE_HEIGHT}",
+                                        rx: "8",
+                                        fill,
+                                        stroke,
+                                        stroke_width: if is_selected { "3" } else { "1.5" },
+                                        stroke_dasharray: if node.external { "4 3" } else { "" },
+                                    }
+                                    text {
+                                        x: "{node.x + 10.0}",
+                                        y: "{node.y + 22.0}",
+                                        font_size: "12",
+                                        font_weight: "600",
+                                        fill: "#111827",
+                                        "{node.id}"
+                                    }
+                                    text {
+                                        x: "{node.x + 10.0}",
+                                        y: "{node.y + 42.0}",
+                                        font_size: "11",
+                                        fill: "#4b5563",
+                                        "{node.label}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
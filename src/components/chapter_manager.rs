@@ -0,0 +1,360 @@
+use crate::components::paragraph_delete::{retarget_options, RepairMode, RepairPicker};
+use crate::contexts::auth_context::use_auth_context;
+use crate::contexts::chapter_context::{Chapter, ChapterState};
+use crate::contexts::paragraph_context::ParagraphState;
+use crate::models::story_graph::EdgeSlot;
+use crate::services::api::{ApiClient, HttpApiClient};
+use crate::services::chapters::{
+    create_chapter, delete_chapter, move_chapter, plan_chapter_delete, rename_chapter,
+    ChapterDeletePlan,
+};
+use crate::services::retry::{RetryPolicy, RetryingApiClient};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
+use std::time::Duration;
+use wasm_bindgen_futures::spawn_local;
+
+fn client() -> RetryingApiClient<HttpApiClient> {
+    RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default())
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ChapterManagerProps {
+    /// Language whose titles are shown and renamed.
+    pub lang: String,
+    /// Called with the id of a chapter after it has been deleted.
+    #[props(default)]
+    pub on_deleted: EventHandler<String>,
+}
+
+/// Chapter list for structure editors: create, rename, reorder and delete.
+///
+/// Deleting asks for confirmation and lists what goes away with the chapter,
+/// including choices in other chapters that point into it; those are
+/// retargeted or removed in the same batch that deletes the chapter.
+#[component]
+pub fn ChapterManager(props: ChapterManagerProps) -> Element {
+    let toast = use_toast();
+    let auth = use_auth_context();
+    let mut chapter_state = use_context::<Signal<ChapterState>>();
+    let mut paragraph_state = use_context::<Signal<ParagraphState>>();
+    let mut new_title = use_signal(String::new);
+    let mut renaming = use_signal(|| None::<(String, String)>);
+    let mut pending_delete = use_signal(|| None::<ChapterDeletePlan>);
+    let repair_mode = use_signal(|| RepairMode::Retarget);
+    let mut retarget_to = use_signal(String::new);
+    let mut busy = use_signal(|| false);
+
+    let saved_text = t!("chapter_manager_saved");
+    let failed_text = t!("chapter_manager_failed");
+    let notify = move |result: Result<(), String>| {
+        let request = match result {
+            Ok(()) => ToastRequest::new(ToastKind::Success, saved_text.clone())
+                .with_timeout(Duration::from_millis(3000)),
+            Err(error) => {
+                ToastRequest::new(ToastKind::Error, format!("{} ({})", failed_text, error))
+                    .with_timeout(Duration::from_millis(5000))
+            }
+        };
+        toast.push(request);
+    };
+
+    let handle_create = {
+        let lang = props.lang.clone();
+        let notify = notify.clone();
+        move |_| {
+            let title = new_title.peek().trim().to_string();
+            if title.is_empty() || *busy.peek() {
+                return;
+            }
+            busy.set(true);
+            let lang = lang.clone();
+            let notify = notify.clone();
+            spawn_local(async move {
+                let chapters = chapter_state.peek().chapters.clone();
+                match create_chapter(&client(), &chapters, &lang, &title).await {
+                    Ok(chapter) => {
+                        chapter_state.write().upsert(chapter);
+                        new_title.set(String::new());
+                        notify(Ok(()));
+                    }
+                    Err(error) => notify(Err(error.to_string())),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let handle_rename = {
+        let lang = props.lang.clone();
+        let notify = notify.clone();
+        move |_| {
+            let Some((chapter_id, title)) = renaming.peek().clone() else {
+                return;
+            };
+            let Some(chapter) = chapter_state
+                .peek()
+                .chapters
+                .iter()
+                .find(|c| c.id == chapter_id)
+                .cloned()
+            else {
+                return;
+            };
+            if *busy.peek() {
+                return;
+            }
+            busy.set(true);
+            let lang = lang.clone();
+            let notify = notify.clone();
+            spawn_local(async move {
+                match rename_chapter(&client(), &chapter, &lang, &title).await {
+                    Ok(saved) => {
+                        chapter_state.write().upsert(saved);
+                        renaming.set(None);
+                        notify(Ok(()));
+                    }
+                    Err(error) => notify(Err(error.to_string())),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let handle_move = {
+        let notify = notify.clone();
+        move |from: usize, to: usize| {
+            if *busy.peek() {
+                return;
+            }
+            busy.set(true);
+            let notify = notify.clone();
+            spawn_local(async move {
+                let chapters = chapter_state.peek().chapters.clone();
+                match move_chapter(&client(), &chapters, from, to).await {
+                    Ok(saved) => {
+                        let mut state = chapter_state.write();
+                        for chapter in saved {
+                            state.upsert(chapter);
+                        }
+                        notify(Ok(()));
+                    }
+                    Err(error) => notify(Err(error.to_string())),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let handle_delete = {
+        let notify = notify.clone();
+        let on_deleted = props.on_deleted;
+        move |_| {
+            let Some(plan) = pending_delete.peek().clone() else {
+                return;
+            };
+            if *busy.peek() {
+                return;
+            }
+            let repair = repair_mode.peek().repair(&retarget_to.peek());
+            let author = auth.peek().user().cloned();
+            busy.set(true);
+            let notify = notify.clone();
+            spawn_local(async move {
+                let client = client();
+                let paragraphs = paragraph_state.peek().paragraphs.clone();
+                match delete_chapter(&client, &plan, &paragraphs, &repair, author.as_ref()).await {
+                    Ok(repaired) => {
+                        let mut state = paragraph_state.write();
+                        for paragraph in repaired {
+                            state.replace(paragraph);
+                        }
+                        state.remove(&plan.paragraphs);
+                        drop(state);
+                        chapter_state.write().remove(&plan.chapter_id);
+                        pending_delete.set(None);
+                        on_deleted.call(plan.chapter_id.clone());
+                        notify(Ok(()));
+                    }
+                    Err(error) => {
+                        // A chapter split across several batches may be half
+                        // deleted; reload so the next plan starts from the server.
+                        if let Ok(data) = client.get_paragraphs().await {
+                            paragraph_state.write().set_paragraphs(data.items);
+                        }
+                        notify(Err(error.to_string()));
+                    }
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let chapters: Vec<Chapter> = chapter_state.read().chapters.clone();
+    let count = chapters.len();
+    let lang = props.lang.clone();
+    let untitled = t!("chapter_manager_untitled");
+    let title_of = move |chapter: &Chapter| {
+        chapter
+            .title_for(&lang)
+            .or_else(|| chapter.titles.first().map(|t| t.title.as_str()))
+            .map(str::to_string)
+            .unwrap_or_else(|| untitled.clone())
+    };
+    let timeout_label = t!("graph_edge_timeout");
+    let is_busy = *busy.read();
+
+    rsx! {
+        section {
+            class: "mb-6 space-y-4",
+            h3 {
+                class: "text-lg font-semibold text-gray-900 dark:text-gray-100",
+                {t!("chapter_manager_title")}
+            }
+            ul {
+                class: "divide-y divide-gray-200 dark:divide-gray-700 paper:divide-[#e4d5b2] rounded-lg border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2]",
+                for (index, chapter) in chapters.iter().cloned().enumerate() {
+                    li {
+                        key: "{chapter.id}",
+                        class: "px-3 py-2 flex flex-wrap items-center gap-2",
+                        span {
+                            class: "w-8 text-xs text-gray-500 dark:text-gray-400 paper:text-[#4b5563]",
+                            "{chapter.order}"
+                        }
+                        if renaming.read().as_ref().map(|(id, _)| id == &chapter.id).unwrap_or(false) {
+                            input {
+                                class: "flex-1 min-w-0 h-9 px-2 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                                value: renaming.read().as_ref().map(|(_, title)| title.clone()).unwrap_or_default(),
+                                oninput: {
+                                    let id = chapter.id.clone();
+                                    move |event: Event<FormData>| renaming.set(Some((id.clone(), event.value())))
+                                },
+                            }
+                            button {
+                                class: "px-3 py-1 rounded-md bg-blue-600 text-white hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                                disabled: is_busy,
+                                onclick: handle_rename.clone(),
+                                {t!("chapter_manager_save")}
+                            }
+                            button {
+                                class: "px-3 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] text-sm",
+                                onclick: move |_| renaming.set(None),
+                                {t!("chapter_manager_cancel")}
+                            }
+                        } else {
+                            span { class: "flex-1 min-w-0 truncate", {title_of(&chapter)} }
+                            button {
+                                class: "px-2 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                                title: t!("chapter_manager_move_up"),
+                                disabled: is_busy || index == 0,
+                                onclick: {
+                                    let mut handle_move = handle_move.clone();
+                                    move |_| handle_move(index, index - 1)
+                                },
+                                "↑"
+                            }
+                            button {
+                                class: "px-2 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                                title: t!("chapter_manager_move_down"),
+                                disabled: is_busy || index + 1 == count,
+                                onclick: {
+                                    let mut handle_move = handle_move.clone();
+                                    move |_| handle_move(index, index + 1)
+                                },
+                                "↓"
+                            }
+                            button {
+                                class: "px-3 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] text-sm",
+                                onclick: {
+                                    let id = chapter.id.clone();
+                                    let title = chapter.title_for(&props.lang).unwrap_or_default().to_string();
+                                    move |_| renaming.set(Some((id.clone(), title.clone())))
+                                },
+                                {t!("chapter_manager_rename")}
+                            }
+                            button {
+                                class: "px-3 py-1 rounded-md border border-red-500 text-red-600 hover:bg-red-50 dark:hover:bg-red-900/30 paper:hover:bg-[#f0e6cf] disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                                disabled: is_busy,
+                                onclick: {
+                                    let id = chapter.id.clone();
+                                    move |_| {
+                                        let plan = plan_chapter_delete(&id, &paragraph_state.peek().paragraphs);
+                                        retarget_to.set(String::new());
+                                        pending_delete.set(Some(plan));
+                                    }
+                                },
+                                {t!("chapter_manager_delete")}
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(plan) = pending_delete.read().clone() {
+                div {
+                    class: "rounded-lg border border-red-300 dark:border-red-700 bg-red-50 dark:bg-red-900/20 paper:bg-[#fbeee0] p-4 space-y-2 text-sm",
+                    p {
+                        class: "font-medium",
+                        {t!("chapter_manager_delete_confirm", title: chapters.iter().find(|c| c.id == plan.chapter_id).map(&title_of).unwrap_or_default())}
+                    }
+                    if !plan.paragraphs.is_empty() {
+                        p { {t!("chapter_manager_delete_paragraphs", count: plan.paragraphs.len().to_string())} }
+                    }
+                    if !plan.inbound.is_empty() {
+                        p { {t!("chapter_manager_delete_inbound", count: plan.inbound.len().to_string())} }
+                        ul {
+                            class: "list-disc pl-5 text-xs text-gray-600 dark:text-gray-300 paper:text-[#374151]",
+                            for reference in plan.inbound.iter() {
+                                li {
+                                    key: "{reference.paragraph_id}-{reference.choice_index}-{reference.slot:?}",
+                                    if matches!(reference.slot, EdgeSlot::Timeout(_)) {
+                                        "{reference.paragraph_id} #{reference.choice_index + 1} ({timeout_label}) → {reference.target}"
+                                    } else {
+                                        "{reference.paragraph_id} #{reference.choice_index + 1} → {reference.target}"
+                                    }
+                                }
+                            }
+                        }
+                        RepairPicker {
+                            name: "chapter-delete-repair",
+                            mode: repair_mode,
+                            retarget_to,
+                            options: retarget_options(&paragraph_state.read().paragraphs, &plan.paragraphs, &props.lang),
+                        }
+                    }
+                    div {
+                        class: "flex gap-2 pt-2",
+                        button {
+                            class: "px-3 py-1 rounded-md bg-red-600 text-white hover:bg-red-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                            disabled: is_busy || (!plan.inbound.is_empty() && *repair_mode.read() == RepairMode::Retarget && retarget_to.read().is_empty()),
+                            onclick: handle_delete.clone(),
+                            {t!("chapter_manager_delete")}
+                        }
+                        button {
+                            class: "px-3 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2]",
+                            onclick: move |_| pending_delete.set(None),
+                            {t!("chapter_manager_cancel")}
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "flex gap-2",
+                input {
+                    class: "flex-1 min-w-0 h-10 px-3 rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                    placeholder: t!("chapter_manager_new_placeholder"),
+                    value: "{new_title}",
+                    oninput: move |event: Event<FormData>| new_title.set(event.value()),
+                }
+                button {
+                    class: "h-10 px-4 rounded-lg text-white bg-blue-600 hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_busy || new_title.read().trim().is_empty(),
+                    onclick: handle_create,
+                    {t!("chapter_manager_add")}
+                }
+            }
+        }
+    }
+}
@@ -1,15 +1,51 @@
 use crate::components::dropdown::Dropdown;
 use crate::contexts::chapter_context::Chapter;
 use crate::contexts::language_context::LanguageState;
+use crate::models::bookmarks::Bookmark;
 use dioxus::hooks::use_context;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
-use std::cell::RefCell;
-use std::thread_local;
+use std::collections::HashMap;
 
-thread_local! {
-    static SELECTED_LANGUAGE: RefCell<String> = RefCell::new(String::new());
-    static INTERFACE_LANGUAGE: RefCell<String> = RefCell::new(String::new());
+/// A chapter with the text the dropdown shows for it.
+#[derive(Debug, Clone, PartialEq)]
+struct DisplayChapter {
+    chapter: Chapter,
+    display_text: String,
+}
+
+/// The chapter's title in `selected_lang`, or a marked fallback, followed by
+/// the reader's progress when one is known.
+fn chapter_title(
+    chapter: &Chapter,
+    selected_lang: &str,
+    interface_lang: &str,
+    progress: &HashMap<String, u8>,
+) -> String {
+    // First look for translation in current language
+    let title = if let Some(title) = chapter.titles.iter().find(|t| t.lang == selected_lang) {
+        title.title.clone()
+    } else {
+        // If current language translation not found, prioritize interface language, then English or first available translation, and add untranslated marker
+        let fallback_title = chapter
+            .titles
+            .iter()
+            .find(|t| t.lang == interface_lang)
+            .or_else(|| {
+                chapter
+                    .titles
+                    .iter()
+                    .find(|t| t.lang == "en-US" || t.lang == "en-GB")
+            })
+            .or_else(|| chapter.titles.first())
+            .map(|t| t.title.clone())
+            .unwrap_or_default();
+        format!("（{}）{}", t!("untranslated"), fallback_title)
+    };
+    match progress.get(&chapter.id) {
+        Some(percent) => format!("{title} · {percent}%"),
+        None => title,
+    }
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -28,6 +64,16 @@ pub struct ChapterSelectorProps {
     pub class: String,
     #[props(default = String::new())]
     pub selected_language: String,
+    /// Bookmarks of the selected chapter, listed below the dropdown.
+    #[props(default)]
+    pub bookmarks: Vec<Bookmark>,
+    #[props(default)]
+    pub on_bookmark_select: Option<EventHandler<Bookmark>>,
+    #[props(default)]
+    pub on_bookmark_remove: Option<EventHandler<String>>,
+    /// Percentage of each chapter the reader has seen, by chapter id.
+    #[props(default)]
+    pub progress: HashMap<String, u8>,
 }
 
 #[component]
@@ -44,16 +90,6 @@ pub fn ChapterSelector(props: ChapterSelectorProps) -> Element {
     // Get interface language as fallback option
     let interface_lang = language_state.read().current_language.clone();
 
-    // Update thread_local variable
-    SELECTED_LANGUAGE.with(|lang| {
-        *lang.borrow_mut() = selected_lang.clone();
-    });
-
-    // Update interface language to thread_local
-    INTERFACE_LANGUAGE.with(|lang| {
-        *lang.borrow_mut() = interface_lang.clone();
-    });
-
     // Filter chapters
     let filtered_chapters = props
         .chapters
@@ -83,67 +119,19 @@ pub fn ChapterSelector(props: ChapterSelectorProps) -> Element {
                 false
             }
         })
-        .cloned()
+        .map(|chapter| DisplayChapter {
+            display_text: chapter_title(chapter, &selected_lang, &interface_lang, &props.progress),
+            chapter: chapter.clone(),
+        })
         .collect::<Vec<_>>();
 
     // Find currently selected chapter
-    let selected_chapter_title = if props.value.is_empty() {
-        props.label.clone()
-    } else {
-        props
-            .chapters
-            .iter()
-            .find(|c| c.id == props.value)
-            .map(|c| {
-                // First look for translation in current language
-                if let Some(title) = c.titles.iter().find(|t| t.lang == selected_lang) {
-                    title.title.clone()
-                } else {
-                    // If current language translation not found, prioritize interface language, then English or first available translation, and add untranslated marker
-                    let fallback_title = c
-                        .titles
-                        .iter()
-                        .find(|t| t.lang == interface_lang)
-                        .or_else(|| {
-                            c.titles
-                                .iter()
-                                .find(|t| t.lang == "en-US" || t.lang == "en-GB")
-                        })
-                        .or_else(|| c.titles.first())
-                        .map(|t| t.title.clone())
-                        .unwrap_or_default();
-                    format!("（{}）{}", t!("untranslated"), fallback_title)
-                }
-            })
-            .unwrap_or_else(|| props.label.clone())
-    };
-
-    // Define display function, use thread_local variable to get current selected language
-    fn display_chapter_title(chapter: &Chapter) -> String {
-        let selected_lang = SELECTED_LANGUAGE.with(|lang| lang.borrow().clone());
-        let interface_lang = INTERFACE_LANGUAGE.with(|lang| lang.borrow().clone());
-
-        // First look for translation in current language
-        if let Some(title) = chapter.titles.iter().find(|t| t.lang == selected_lang) {
-            title.title.clone()
-        } else {
-            // If current language translation not found, prioritize interface language, then English or first available translation, and add untranslated marker
-            let fallback_title = chapter
-                .titles
-                .iter()
-                .find(|t| t.lang == interface_lang)
-                .or_else(|| {
-                    chapter
-                        .titles
-                        .iter()
-                        .find(|t| t.lang == "en-US" || t.lang == "en-GB")
-                })
-                .or_else(|| chapter.titles.first())
-                .map(|t| t.title.clone())
-                .unwrap_or_default();
-            format!("（{}）{}", dioxus_i18n::t!("untranslated"), fallback_title)
-        }
-    }
+    let selected_chapter_title = props
+        .chapters
+        .iter()
+        .find(|c| !props.value.is_empty() && c.id == props.value)
+        .map(|c| chapter_title(c, &selected_lang, &interface_lang, &props.progress))
+        .unwrap_or_else(|| props.label.clone());
 
     rsx! {
         Dropdown {
@@ -154,8 +142,8 @@ pub fn ChapterSelector(props: ChapterSelectorProps) -> Element {
             search_query: props.search_query,
             on_toggle: props.on_toggle,
             on_search: props.on_search,
-            on_select: props.on_select,
-            display_fn: display_chapter_title,
+            on_select: move |option: DisplayChapter| props.on_select.call(option.chapter),
+            display_fn: |option: &DisplayChapter| option.display_text.clone(),
             has_error: props.has_error,
             class: props.class,
             search_placeholder: t!("search_chapter"),
@@ -165,5 +153,45 @@ pub fn ChapterSelector(props: ChapterSelectorProps) -> Element {
             search_input_class: "",
             option_class: "",
         }
+        if !props.bookmarks.is_empty() {
+            ul {
+                class: "mt-3 space-y-1",
+                for bookmark in props.bookmarks.iter().cloned() {
+                    li {
+                        key: "{bookmark.id}",
+                        class: "flex items-start gap-2",
+                        button {
+                            class: "flex-1 min-w-0 text-left px-3 py-2 rounded-md hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                            onclick: {
+                                let bookmark = bookmark.clone();
+                                move |_| {
+                                    if let Some(handler) = props.on_bookmark_select {
+                                        handler.call(bookmark.clone());
+                                    }
+                                }
+                            },
+                            span { class: "block text-sm truncate", "{bookmark.excerpt}" }
+                            if !bookmark.note.is_empty() {
+                                span {
+                                    class: "block text-xs text-gray-500 dark:text-gray-400 paper:text-[#6b7280] whitespace-pre-wrap",
+                                    "{bookmark.note}"
+                                }
+                            }
+                        }
+                        if let Some(on_remove) = props.on_bookmark_remove {
+                            button {
+                                class: "px-2 py-2 text-sm text-gray-500 hover:text-red-600",
+                                aria_label: t!("bookmark_remove"),
+                                onclick: {
+                                    let id = bookmark.id.clone();
+                                    move |_| on_remove.call(id.clone())
+                                },
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,284 @@
+use crate::contexts::auth_context::use_auth_context;
+use crate::contexts::chapter_context::ChapterState;
+use crate::contexts::paragraph_context::ParagraphState;
+use crate::models::replace::{apply_matches, find_matches, FindOptions};
+use crate::models::search::MatchField;
+use crate::services::api::HttpApiClient;
+use crate::services::paragraphs::save_paragraph_texts;
+use crate::services::retry::{RetryPolicy, RetryingApiClient};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
+use std::collections::{BTreeSet, HashSet};
+use std::time::Duration;
+use wasm_bindgen_futures::spawn_local;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FindReplaceProps {
+    /// Language used for chapter titles.
+    pub lang: String,
+    /// Called with the ids of the paragraphs that were rewritten.
+    pub on_applied: EventHandler<Vec<String>>,
+}
+
+/// Story-wide find and replace over body texts and choice captions.
+///
+/// Every match is previewed in context and can be unticked before the
+/// replacement is saved.
+#[component]
+pub fn FindReplace(props: FindReplaceProps) -> Element {
+    let toast = use_toast();
+    let auth = use_auth_context();
+    let mut paragraph_state = use_context::<Signal<ParagraphState>>();
+    let chapter_state = use_context::<Signal<ChapterState>>();
+    let mut pattern = use_signal(String::new);
+    let mut replacement = use_signal(String::new);
+    let mut use_regex = use_signal(|| false);
+    let mut case_sensitive = use_signal(|| false);
+    let mut lang = use_signal(String::new);
+    let mut chapter_id = use_signal(String::new);
+    let mut excluded = use_signal(HashSet::<String>::new);
+    let mut busy = use_signal(|| false);
+
+    let found = use_memo(move || {
+        let non_empty = |value: &String| (!value.is_empty()).then(|| value.clone());
+        let options = FindOptions {
+            pattern: pattern.read().clone(),
+            regex: *use_regex.read(),
+            case_sensitive: *case_sensitive.read(),
+            lang: non_empty(&lang.read()),
+            chapter_id: non_empty(&chapter_id.read()),
+        };
+        find_matches(
+            &paragraph_state.read().paragraphs,
+            &options,
+            &replacement.read(),
+        )
+        .map_err(|error| error.to_string())
+    });
+    // A different search makes earlier opt-outs meaningless
+    use_effect(move || {
+        pattern.read();
+        use_regex.read();
+        case_sensitive.read();
+        lang.read();
+        chapter_id.read();
+        excluded.write().clear();
+    });
+
+    let done_text = t!("find_replace_done");
+    let failed_text = t!("find_replace_failed");
+    let handle_apply = {
+        let on_applied = props.on_applied;
+        move |_| {
+            if *busy.peek() {
+                return;
+            }
+            let Ok(matches) = found.peek().clone() else {
+                return;
+            };
+            let edited = apply_matches(
+                &paragraph_state.peek().paragraphs,
+                &matches,
+                &excluded.peek(),
+            );
+            if edited.is_empty() {
+                return;
+            }
+            busy.set(true);
+            let author = auth.peek().user().cloned();
+            let toast = toast.clone();
+            let done_text = done_text.clone();
+            let failed_text = failed_text.clone();
+            spawn_local(async move {
+                let client = RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default());
+                let result = save_paragraph_texts(&client, &edited, author.as_ref()).await;
+                let saved = match &result {
+                    Ok(()) => edited.len(),
+                    Err(partial) => partial.saved,
+                };
+                let ids: Vec<String> = edited[..saved].iter().map(|p| p.id.clone()).collect();
+                {
+                    let mut state = paragraph_state.write();
+                    for paragraph in edited.into_iter().take(saved) {
+                        state.replace(paragraph);
+                    }
+                }
+                match result {
+                    Ok(()) => {
+                        toast.push(
+                            ToastRequest::new(
+                                ToastKind::Success,
+                                format!("{} ({})", done_text, saved),
+                            )
+                            .with_timeout(Duration::from_millis(3000)),
+                        );
+                    }
+                    Err(partial) => {
+                        toast.push(
+                            ToastRequest::new(
+                                ToastKind::Error,
+                                format!("{} ({}, {})", failed_text, partial.saved, partial.error),
+                            )
+                            .with_timeout(Duration::from_millis(5000)),
+                        );
+                    }
+                }
+                if !ids.is_empty() {
+                    on_applied.call(ids);
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let languages: BTreeSet<String> = paragraph_state
+        .read()
+        .paragraphs
+        .iter()
+        .flat_map(|p| p.texts.iter().map(|t| t.lang.clone()))
+        .collect();
+    let chapter_options: Vec<(String, String)> = chapter_state
+        .read()
+        .chapters
+        .iter()
+        .map(|c| {
+            let title = c
+                .title_for(&props.lang)
+                .or_else(|| c.titles.first().map(|t| t.title.as_str()))
+                .unwrap_or(c.id.as_str())
+                .to_string();
+            (c.id.clone(), title)
+        })
+        .collect();
+    let field_label = |field: &MatchField| match field {
+        MatchField::Body => t!("search_field_body"),
+        MatchField::Caption(index) => {
+            t!("search_field_caption", number: (index + 1).to_string())
+        }
+    };
+    let (matches, pattern_error) = match found.read().clone() {
+        Ok(matches) => (matches, None),
+        Err(error) => (Vec::new(), Some(error)),
+    };
+    let selected_count = {
+        let excluded = excluded.read();
+        matches
+            .iter()
+            .filter(|m| !excluded.contains(&m.key()))
+            .count()
+    };
+
+    rsx! {
+        section {
+            class: "mb-6 rounded-lg border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] p-4 space-y-3 text-sm",
+            div {
+                class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+                input {
+                    class: "h-10 px-3 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                    placeholder: t!("find_replace_find"),
+                    value: "{pattern}",
+                    oninput: move |event: FormEvent| pattern.set(event.value()),
+                }
+                input {
+                    class: "h-10 px-3 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                    placeholder: t!("find_replace_replace"),
+                    value: "{replacement}",
+                    oninput: move |event: FormEvent| replacement.set(event.value()),
+                }
+            }
+            div {
+                class: "flex flex-wrap items-center gap-3",
+                label {
+                    class: "flex items-center gap-2",
+                    input {
+                        r#type: "checkbox",
+                        checked: *use_regex.read(),
+                        onchange: move |event: FormEvent| use_regex.set(event.checked()),
+                    }
+                    {t!("find_replace_regex")}
+                }
+                label {
+                    class: "flex items-center gap-2",
+                    input {
+                        r#type: "checkbox",
+                        checked: *case_sensitive.read(),
+                        onchange: move |event: FormEvent| case_sensitive.set(event.checked()),
+                    }
+                    {t!("find_replace_case_sensitive")}
+                }
+                select {
+                    class: "h-9 px-2 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                    value: lang.read().clone(),
+                    oninput: move |event: FormEvent| lang.set(event.value()),
+                    option { value: "", {t!("find_replace_all_languages")} }
+                    for code in languages.iter() {
+                        option { key: "{code}", value: code.clone(), "{code}" }
+                    }
+                }
+                select {
+                    class: "h-9 px-2 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                    value: chapter_id.read().clone(),
+                    oninput: move |event: FormEvent| chapter_id.set(event.value()),
+                    option { value: "", {t!("search_all_chapters")} }
+                    for (id, title) in chapter_options.iter() {
+                        option { key: "{id}", value: id.clone(), "{title}" }
+                    }
+                }
+            }
+            if let Some(error) = pattern_error {
+                p { class: "text-red-600 dark:text-red-400", {t!("find_replace_invalid_pattern", error: error)} }
+            } else if !pattern.read().is_empty() {
+                p {
+                    class: "text-gray-500 dark:text-gray-400 paper:text-[#4b5563]",
+                    {t!("find_replace_match_count", count: matches.len().to_string())}
+                }
+            }
+            ul {
+                class: "max-h-96 overflow-y-auto divide-y divide-gray-200 dark:divide-gray-700 paper:divide-[#e4d5b2]",
+                for found in matches.iter() {
+                    li {
+                        key: "{found.key()}",
+                        class: "py-2 flex items-start gap-2",
+                        input {
+                            class: "mt-1",
+                            r#type: "checkbox",
+                            checked: !excluded.read().contains(&found.key()),
+                            onchange: {
+                                let key = found.key();
+                                move |event: FormEvent| {
+                                    if event.checked() {
+                                        excluded.write().remove(&key);
+                                    } else {
+                                        excluded.write().insert(key.clone());
+                                    }
+                                }
+                            },
+                        }
+                        div {
+                            class: "min-w-0",
+                            p {
+                                class: "text-xs font-mono text-gray-500 dark:text-gray-400 paper:text-[#4b5563]",
+                                "{found.paragraph_id} · {found.lang} · "
+                                {field_label(&found.field)}
+                            }
+                            p {
+                                class: "break-words",
+                                "{found.before}"
+                                del { class: "bg-red-100 dark:bg-red-900/40", "{found.matched}" }
+                                ins { class: "bg-green-100 dark:bg-green-900/40 no-underline", "{found.replacement}" }
+                                "{found.after}"
+                            }
+                        }
+                    }
+                }
+            }
+            button {
+                class: "px-4 py-2 rounded-lg bg-blue-600 text-white hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                disabled: *busy.read() || selected_count == 0,
+                onclick: handle_apply,
+                {t!("find_replace_apply", count: selected_count.to_string())}
+            }
+        }
+    }
+}
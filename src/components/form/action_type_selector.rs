@@ -1,4 +1,5 @@
 use crate::components::dropdown::Dropdown;
+use crate::models::input_prompt::INPUT_ACTION;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
 
@@ -40,6 +41,10 @@ pub fn ActionTypeSelector(props: ActionTypeSelectorProps) -> Element {
             value: "setting".to_string(),
             label: t!("setting"),
         },
+        ActionType {
+            value: INPUT_ACTION.to_string(),
+            label: t!("input_prompt_action"),
+        },
     ];
 
     // Find currently selected action type
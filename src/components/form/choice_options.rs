@@ -1,29 +1,19 @@
 use crate::{
     components::choice_impacts_editor::{CharacterOption, ChoiceImpactsEditor, RelationshipOption},
-    components::form::{ActionTypeSelector, InputField},
+    components::form::{ActionTypeSelector, InputField, PromptRulesEditor},
     components::paragraph_list::{MultiSelectParagraphList, Paragraph},
     contexts::chapter_context::Chapter,
+    hooks::choices_reducer::Choice,
     models::impacts::{Impact, ImpactList},
+    models::input_prompt::{InputPrompt, INPUT_ACTION},
+    models::paragraph::ParagraphChoice,
 };
 use dioxus::prelude::*;
 use dioxus_i18n::t;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ChoiceOptionsProps {
-    // (caption, goto, action_type, action_key, action_value, target_chapter, same_page, time_limit, timeout_to, timeout_target_chapter, impacts)
-    pub choices: Vec<(
-        String,
-        Vec<String>,
-        String,
-        Option<String>,
-        Option<serde_json::Value>,
-        String,
-        bool,
-        Option<u32>,
-        Option<String>,
-        String,
-        Vec<Impact>,
-    )>,
+    pub choices: Vec<Choice>,
     pub on_choice_change: EventHandler<(usize, String, String)>,
     pub on_choice_add_paragraph: EventHandler<(usize, String)>,
     pub on_choice_remove_paragraph: EventHandler<(usize, String)>,
@@ -55,6 +45,10 @@ pub struct ChoiceOptionsProps {
     pub characters: Vec<CharacterOption>,
     pub relationships: Vec<RelationshipOption>,
     pub on_impacts_change: EventHandler<(usize, Vec<Impact>)>,
+    /// When false only captions are editable (translator view); targets,
+    /// actions, timeouts and impacts are hidden and options cannot be added or removed.
+    #[props(default = true)]
+    pub structure_editable: bool,
 }
 
 #[component]
@@ -68,28 +62,43 @@ pub fn ChoiceOptions(props: ChoiceOptionsProps) -> Element {
                 {t!("options")}
             }
             // Add option button (desktop: on the right side of title, mobile: hidden)
-            button {
-                class: "hidden lg:inline-flex items-center px-4 py-2 text-sm font-medium text-white bg-blue-600 hover:bg-blue-700 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2 transition-colors duration-200",
-                onclick: move |_| props.on_add_choice.call(()),
-                svg {
-                    xmlns: "http://www.w3.org/2000/svg",
-                    class: "w-4 h-4 mr-2",
-                    fill: "none",
-                    view_box: "0 0 24 24",
-                    stroke: "currentColor",
-                    stroke_width: "2",
-                    path {
-                        stroke_linecap: "round",
-                        stroke_linejoin: "round",
-                        d: "M12 4v16m8-8H4"
+            if props.structure_editable {
+                button {
+                    class: "hidden lg:inline-flex items-center px-4 py-2 text-sm font-medium text-white bg-blue-600 hover:bg-blue-700 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2 transition-colors duration-200",
+                    onclick: move |_| props.on_add_choice.call(()),
+                    svg {
+                        xmlns: "http://www.w3.org/2000/svg",
+                        class: "w-4 h-4 mr-2",
+                        fill: "none",
+                        view_box: "0 0 24 24",
+                        stroke: "currentColor",
+                        stroke_width: "2",
+                        path {
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round",
+                            d: "M12 4v16m8-8H4"
+                        }
                     }
+                    {t!("add_option")}
                 }
-                {t!("add_option")}
             }
         }
 
         // Render all options
-        {props.choices.iter().enumerate().map(|(index, (caption, goto_list, action_type, action_key, action_value, target_chapter, same_page, time_limit, timeout_to, timeout_target_chapter, impacts))| {
+        {props.choices.iter().enumerate().map(|(index, choice)| {
+            let Choice { caption, stored, target_chapter, timeout_target_chapter } = choice;
+            let ParagraphChoice {
+                to: goto_list,
+                type_: action_type,
+                key: action_key,
+                value: action_value,
+                time_limit,
+                timeout_to,
+                ..
+            } = stored;
+            let same_page = choice.same_page();
+            let impacts = choice.impacts().to_vec();
+
             // Check if action type is empty (None)
             let is_action_disabled = action_type.is_empty();
 
@@ -105,6 +114,30 @@ pub fn ChoiceOptions(props: ChoiceOptionsProps) -> Element {
             let timeout_ids_for_add = timeout_selected_ids.clone();
             let timeout_ids_for_remove = timeout_selected_ids.clone();
 
+            if !props.structure_editable {
+                return rsx! {
+                    div {
+                        key: "{index}",
+                        class: "border-2 border-gray-200 dark:border-gray-600 rounded-lg mb-4 p-4 space-y-2",
+                        div {
+                            class: "text-sm font-medium text-gray-700 dark:text-gray-300",
+                            {format!("{} {}", t!("option"), index + 1)}
+                        }
+                        InputField {
+                            label: t!("caption"),
+                            value: caption.clone(),
+                            on_input: move |value| {
+                                props.on_choice_change.call((index, "caption".to_string(), value));
+                            },
+                            placeholder: t!("caption"),
+                            has_error: false,
+                            required: true,
+                            on_blur: move |_| {},
+                        }
+                    }
+                };
+            }
+
             rsx! {
                 div {
                     key: "{index}",
@@ -237,34 +270,45 @@ pub fn ChoiceOptions(props: ChoiceOptionsProps) -> Element {
                                         }
                                     }
 
-                                    div {
-                                        class: if is_action_disabled { "opacity-50" } else { "" },
-                                        InputField {
-                                            label: t!("action_value"),
-                                            placeholder: t!("action_value"),
-                                            value: action_value.clone().map(|v| match v {
-                                                serde_json::Value::String(s) => s.clone(),
-                                                _ => v.to_string()
-                                            }).unwrap_or_default(),
-                                            required: false,
-                                            has_error: false,
-                                            disabled: is_action_disabled,
-                                            on_input: move |value: String| {
-                                                if !is_action_disabled {
-                                                    props.on_choice_change.call((index, "action_value".to_string(), value));
-                                                }
-                                            },
-                                            on_blur: move |_| {}
+                                    if action_type.as_str() != INPUT_ACTION {
+                                        div {
+                                            class: if is_action_disabled { "opacity-50" } else { "" },
+                                            InputField {
+                                                label: t!("action_value"),
+                                                placeholder: t!("action_value"),
+                                                value: action_value.clone().map(|v| match v {
+                                                    serde_json::Value::String(s) => s.clone(),
+                                                    _ => v.to_string()
+                                                }).unwrap_or_default(),
+                                                required: false,
+                                                has_error: false,
+                                                disabled: is_action_disabled,
+                                                on_input: move |value: String| {
+                                                    if !is_action_disabled {
+                                                        props.on_choice_change.call((index, "action_value".to_string(), value));
+                                                    }
+                                                },
+                                                on_blur: move |_| {}
+                                            }
                                         }
                                     }
                                 }
+                                if action_type.as_str() == INPUT_ACTION {
+                                    PromptRulesEditor {
+                                        prompt_key: action_key.clone(),
+                                        value: action_value.clone(),
+                                        on_change: move |prompt: InputPrompt| {
+                                            props.on_choice_change.call((index, "action_prompt".to_string(), prompt.to_value().to_string()));
+                                        },
+                                    }
+                                }
                             }
                             // same_page checkbox
                             div {
                                 class: "flex items-center mt-2",
                                 input {
                                     r#type: "checkbox",
-                                    checked: *same_page,
+                                    checked: same_page,
                                     onchange: move |evt| {
                                         let checked = evt.value() == "true";
                                         props.on_choice_change.call((index, "same_page".to_string(), checked.to_string()));
@@ -377,10 +421,12 @@ pub fn ChoiceOptions(props: ChoiceOptionsProps) -> Element {
         })}
 
         // Add option button (mobile: shown at the bottom, desktop: hidden)
-        button {
-            class: "lg:hidden w-full px-4 py-3 border-2 border-dashed border-gray-300 dark:border-gray-600 rounded-lg text-gray-600 dark:text-gray-400 hover:border-gray-400 dark:hover:border-gray-500 hover:text-gray-700 dark:hover:text-gray-300 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500 transition-colors duration-200",
-            onclick: move |_| props.on_add_choice.call(()),
-            {t!("add_option")}
+        if props.structure_editable {
+            button {
+                class: "lg:hidden w-full px-4 py-3 border-2 border-dashed border-gray-300 dark:border-gray-600 rounded-lg text-gray-600 dark:text-gray-400 hover:border-gray-400 dark:hover:border-gray-500 hover:text-gray-700 dark:hover:text-gray-300 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500 transition-colors duration-200",
+                onclick: move |_| props.on_add_choice.call(()),
+                {t!("add_option")}
+            }
         }
     }
 }
@@ -1,9 +1,11 @@
 pub mod action_type_selector;
 pub mod choice_options;
 pub mod input_field;
+pub mod prompt_rules_editor;
 pub mod textarea_field;
 
 pub use action_type_selector::ActionTypeSelector;
 pub use choice_options::ChoiceOptions;
 pub use input_field::InputField;
+pub use prompt_rules_editor::PromptRulesEditor;
 pub use textarea_field::TextareaField;
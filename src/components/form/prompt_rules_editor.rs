@@ -0,0 +1,269 @@
+use crate::components::form::InputField;
+use crate::models::input_prompt::{InputPrompt, PromptConfigError, PromptKind};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+fn parse_optional<T: std::str::FromStr>(value: &str) -> Option<T> {
+    value.trim().parse().ok()
+}
+
+fn show_optional<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+fn config_error_text(error: &PromptConfigError) -> String {
+    match error {
+        PromptConfigError::MissingKey => t!("prompt_config_missing_key"),
+        PromptConfigError::ReservedKey(key) => t!("prompt_config_reserved_key", key: key.clone()),
+        PromptConfigError::InvalidPattern(message) => {
+            t!("prompt_config_invalid_pattern", message: message.clone())
+        }
+        PromptConfigError::EmptyRange => t!("prompt_config_empty_range"),
+        PromptConfigError::NoOptions => t!("prompt_config_no_options"),
+    }
+}
+
+/// Text field that keeps what is typed and commits it on blur, so partial
+/// input such as `-` or `1.` is not parsed away while typing.
+#[component]
+fn DraftField(
+    label: String,
+    value: String,
+    has_error: bool,
+    on_commit: EventHandler<String>,
+) -> Element {
+    let mut draft = use_signal(|| value.clone());
+    rsx! {
+        InputField {
+            label: label.clone(),
+            placeholder: label,
+            value: draft(),
+            required: false,
+            has_error,
+            on_input: move |value: String| draft.set(value),
+            on_blur: move |_| on_commit.call(draft()),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PromptRulesEditorProps {
+    /// Setting key the answer is stored under.
+    pub prompt_key: Option<String>,
+    pub value: Option<serde_json::Value>,
+    pub on_change: EventHandler<InputPrompt>,
+}
+
+/// Authoring form for the rules of an `input` choice.
+#[component]
+pub fn PromptRulesEditor(props: PromptRulesEditorProps) -> Element {
+    let prompt = props
+        .value
+        .as_ref()
+        .and_then(InputPrompt::from_value)
+        .unwrap_or_default();
+    let errors = prompt.check(props.prompt_key.as_deref());
+    let kind_value = match &prompt.kind {
+        PromptKind::Text { .. } => "text",
+        PromptKind::Number { .. } => "number",
+        PromptKind::Select { .. } => "select",
+    };
+    let kinds = [
+        ("text", t!("prompt_kind_text")),
+        ("number", t!("prompt_kind_number")),
+        ("select", t!("prompt_kind_select")),
+    ];
+    let on_change = props.on_change;
+    // Each field edits a copy of the current prompt and hands the result back
+    let edit = {
+        let prompt = prompt.clone();
+        move |update: &dyn Fn(&mut InputPrompt)| {
+            let mut next = prompt.clone();
+            update(&mut next);
+            on_change.call(next);
+        }
+    };
+    let label_class = "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2";
+    let select_class = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md bg-white dark:bg-gray-700 text-gray-900 dark:text-white";
+
+    rsx! {
+        div {
+            class: "mt-4 space-y-4",
+            div {
+                class: "grid grid-cols-1 lg:grid-cols-3 gap-4",
+                div {
+                    label { class: label_class, {t!("prompt_kind")} }
+                    select {
+                        class: select_class,
+                        onchange: {
+                            let edit = edit.clone();
+                            move |evt: FormEvent| {
+                                let kind = match evt.value().as_str() {
+                                    "number" => PromptKind::Number { min: None, max: None, integer: false },
+                                    "select" => PromptKind::Select { options: Vec::new() },
+                                    _ => InputPrompt::default().kind,
+                                };
+                                edit(&move |p: &mut InputPrompt| p.kind = kind.clone());
+                            }
+                        },
+                        for (value, label) in kinds.iter() {
+                            option {
+                                key: "{value}",
+                                value: "{value}",
+                                selected: *value == kind_value,
+                                "{label}"
+                            }
+                        }
+                    }
+                }
+                div {
+                    class: "flex items-center lg:mt-7",
+                    input {
+                        r#type: "checkbox",
+                        class: "form-checkbox h-4 w-4 text-blue-600",
+                        checked: prompt.required,
+                        onchange: {
+                            let edit = edit.clone();
+                            move |evt: FormEvent| {
+                                let required = evt.value() == "true";
+                                edit(&move |p: &mut InputPrompt| p.required = required);
+                            }
+                        },
+                    }
+                    label { class: "ml-2 text-sm text-gray-700 dark:text-gray-300", {t!("prompt_required")} }
+                }
+            }
+            if let PromptKind::Text { min_length, max_length, pattern } = &prompt.kind {
+                div {
+                    class: "grid grid-cols-1 lg:grid-cols-3 gap-4",
+                    InputField {
+                        label: t!("prompt_min_length"),
+                        placeholder: t!("prompt_min_length"),
+                        value: show_optional(min_length),
+                        required: false,
+                        has_error: false,
+                        on_input: {
+                            let edit = edit.clone();
+                            move |value: String| edit(&move |p: &mut InputPrompt| {
+                                if let PromptKind::Text { min_length, .. } = &mut p.kind {
+                                    *min_length = parse_optional(&value);
+                                }
+                            })
+                        },
+                        on_blur: move |_| {}
+                    }
+                    InputField {
+                        label: t!("prompt_max_length"),
+                        placeholder: t!("prompt_max_length"),
+                        value: show_optional(max_length),
+                        required: false,
+                        has_error: false,
+                        on_input: {
+                            let edit = edit.clone();
+                            move |value: String| edit(&move |p: &mut InputPrompt| {
+                                if let PromptKind::Text { max_length, .. } = &mut p.kind {
+                                    *max_length = parse_optional(&value);
+                                }
+                            })
+                        },
+                        on_blur: move |_| {}
+                    }
+                    InputField {
+                        label: t!("prompt_pattern"),
+                        placeholder: "^[A-Za-z ]+$".to_string(),
+                        value: pattern.clone().unwrap_or_default(),
+                        required: false,
+                        has_error: false,
+                        on_input: {
+                            let edit = edit.clone();
+                            move |value: String| edit(&move |p: &mut InputPrompt| {
+                                if let PromptKind::Text { pattern, .. } = &mut p.kind {
+                                    *pattern = (!value.is_empty()).then(|| value.clone());
+                                }
+                            })
+                        },
+                        on_blur: move |_| {}
+                    }
+                }
+            }
+            if let PromptKind::Number { min, max, integer } = &prompt.kind {
+                div {
+                    class: "grid grid-cols-1 lg:grid-cols-3 gap-4",
+                    DraftField {
+                        label: t!("prompt_min"),
+                        value: show_optional(min),
+                        has_error: false,
+                        on_commit: {
+                            let edit = edit.clone();
+                            move |value: String| edit(&move |p: &mut InputPrompt| {
+                                if let PromptKind::Number { min, .. } = &mut p.kind {
+                                    *min = parse_optional(&value);
+                                }
+                            })
+                        },
+                    }
+                    DraftField {
+                        label: t!("prompt_max"),
+                        value: show_optional(max),
+                        has_error: false,
+                        on_commit: {
+                            let edit = edit.clone();
+                            move |value: String| edit(&move |p: &mut InputPrompt| {
+                                if let PromptKind::Number { max, .. } = &mut p.kind {
+                                    *max = parse_optional(&value);
+                                }
+                            })
+                        },
+                    }
+                    div {
+                        class: "flex items-center lg:mt-7",
+                        input {
+                            r#type: "checkbox",
+                            class: "form-checkbox h-4 w-4 text-blue-600",
+                            checked: *integer,
+                            onchange: {
+                                let edit = edit.clone();
+                                move |evt: FormEvent| {
+                                    let checked = evt.value() == "true";
+                                    edit(&move |p: &mut InputPrompt| {
+                                        if let PromptKind::Number { integer, .. } = &mut p.kind {
+                                            *integer = checked;
+                                        }
+                                    });
+                                }
+                            },
+                        }
+                        label { class: "ml-2 text-sm text-gray-700 dark:text-gray-300", {t!("prompt_integer")} }
+                    }
+                }
+            }
+            if let PromptKind::Select { options } = &prompt.kind {
+                DraftField {
+                    label: t!("prompt_options"),
+                    value: options.join(", "),
+                    has_error: errors.contains(&PromptConfigError::NoOptions),
+                    on_commit: {
+                        let edit = edit.clone();
+                        move |value: String| edit(&move |p: &mut InputPrompt| {
+                            if let PromptKind::Select { options } = &mut p.kind {
+                                *options = value
+                                    .split(',')
+                                    .map(|option| option.trim().to_string())
+                                    .filter(|option| !option.is_empty())
+                                    .collect();
+                            }
+                        })
+                    },
+                }
+            }
+            if !errors.is_empty() {
+                ul {
+                    class: "text-sm text-red-600 dark:text-red-400 list-disc pl-5",
+                    for error in errors.iter() {
+                        li { {config_error_text(error)} }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::constants::config::PARAGRAPHS;
+use crate::contexts::paragraph_context::{Paragraph, ParagraphState};
+use crate::services::api::HttpApiClient;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
+use std::time::Duration;
+
+/// File field of a paragraph record holding its image.
+const ILLUSTRATION_FIELD: &str = "illustration";
+
+#[derive(Props, Clone, PartialEq)]
+pub struct IllustrationUploadProps {
+    pub paragraph_id: String,
+}
+
+/// Uploads, replaces or removes the image of a saved paragraph. The file is
+/// sent on its own, so edits to the text stay unsaved in the form.
+#[component]
+pub fn IllustrationUpload(props: IllustrationUploadProps) -> Element {
+    let toast = use_toast();
+    let mut paragraph_state = use_context::<Signal<ParagraphState>>();
+    let mut busy = use_signal(|| false);
+
+    let current = paragraph_state
+        .read()
+        .get_by_id(&props.paragraph_id)
+        .map(|p| p.illustration)
+        .unwrap_or_default();
+
+    let failed_text = t!("illustration_upload_failed");
+    let handle_upload = {
+        let paragraph_id = props.paragraph_id.clone();
+        let toast = toast.clone();
+        let failed_text = failed_text.clone();
+        move |event: FormEvent| {
+            let Some(file) = event.files().into_iter().next() else {
+                return;
+            };
+            if *busy.peek() {
+                return;
+            }
+            busy.set(true);
+            let paragraph_id = paragraph_id.clone();
+            let toast = toast.clone();
+            let failed_text = failed_text.clone();
+            spawn(async move {
+                let uploaded = match file.read_bytes().await {
+                    Ok(bytes) => HttpApiClient::new()
+                        .upload_file::<Paragraph>(
+                            PARAGRAPHS,
+                            &paragraph_id,
+                            ILLUSTRATION_FIELD,
+                            &file.name(),
+                            bytes.to_vec(),
+                        )
+                        .await
+                        .map_err(|error| error.to_string()),
+                    Err(error) => {
+                        tracing::error!(error = %error, "Failed to read illustration file");
+                        Err(error.to_string())
+                    }
+                };
+                match uploaded {
+                    Ok(record) => {
+                        set_illustration(paragraph_state, &paragraph_id, record.illustration)
+                    }
+                    Err(error) => {
+                        toast.push(
+                            ToastRequest::new(
+                                ToastKind::Error,
+                                format!("{} ({})", failed_text, error),
+                            )
+                            .with_timeout(Duration::from_millis(5000)),
+                        );
+                    }
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let handle_remove = {
+        let paragraph_id = props.paragraph_id.clone();
+        move |_| {
+            if *busy.peek() {
+                return;
+            }
+            busy.set(true);
+            let paragraph_id = paragraph_id.clone();
+            let toast = toast.clone();
+            let failed_text = failed_text.clone();
+            spawn(async move {
+                match HttpApiClient::new()
+                    .clear_file(PARAGRAPHS, &paragraph_id, ILLUSTRATION_FIELD)
+                    .await
+                {
+                    Ok(()) => set_illustration(paragraph_state, &paragraph_id, String::new()),
+                    Err(error) => {
+                        toast.push(
+                            ToastRequest::new(
+                                ToastKind::Error,
+                                format!("{} ({})", failed_text, error),
+                            )
+                            .with_timeout(Duration::from_millis(5000)),
+                        );
+                    }
+                }
+                busy.set(false);
+            });
+        }
+    };
+    let is_busy = *busy.read();
+
+    rsx! {
+        div {
+            class: "mt-4 flex flex-wrap items-center gap-2 text-sm",
+            label {
+                class: "text-gray-600 dark:text-gray-300 paper:text-[#374151]",
+                {t!("illustration")}
+            }
+            input {
+                class: "block text-sm disabled:opacity-50",
+                r#type: "file",
+                accept: "image/*",
+                disabled: is_busy,
+                onchange: handle_upload,
+            }
+            if !current.is_empty() {
+                span { class: "text-gray-500 dark:text-gray-400 paper:text-[#6b7280]", "{current}" }
+                button {
+                    class: "px-3 py-1 rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_busy,
+                    onclick: handle_remove,
+                    {t!("illustration_remove")}
+                }
+            }
+        }
+    }
+}
+
+/// Records the stored file name locally; the rest of the paragraph is left as
+/// loaded so the form is not reset.
+fn set_illustration(mut paragraph_state: Signal<ParagraphState>, id: &str, file: String) {
+    let Some(mut paragraph) = paragraph_state.peek().get_by_id(id) else {
+        return;
+    };
+    paragraph.illustration = file;
+    paragraph_state.write().replace(paragraph);
+}
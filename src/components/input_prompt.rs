@@ -0,0 +1,98 @@
+use crate::models::input_prompt::{InputPrompt, PromptError, PromptKind};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+fn error_text(error: &PromptError) -> String {
+    match error {
+        PromptError::Required => t!("prompt_error_required"),
+        PromptError::TooShort(min) => t!("prompt_error_too_short", min: min.to_string()),
+        PromptError::TooLong(max) => t!("prompt_error_too_long", max: max.to_string()),
+        PromptError::PatternMismatch => t!("prompt_error_pattern"),
+        PromptError::NotANumber => t!("prompt_error_number"),
+        PromptError::NotAnInteger => t!("prompt_error_integer"),
+        PromptError::BelowMin(min) => t!("prompt_error_min", min: min.to_string()),
+        PromptError::AboveMax(max) => t!("prompt_error_max", max: max.to_string()),
+        PromptError::NotAnOption => t!("prompt_error_option"),
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PromptFieldProps {
+    pub prompt: InputPrompt,
+    /// Label of the submit button, taken from the choice caption.
+    pub caption: String,
+    pub enabled: bool,
+    /// Checked answer, ready to store.
+    pub on_submit: EventHandler<String>,
+}
+
+/// Reader-facing field for an `input` choice.
+#[component]
+pub fn PromptField(props: PromptFieldProps) -> Element {
+    let mut value = use_signal(String::new);
+    let mut error = use_signal(|| None::<PromptError>);
+    let prompt = props.prompt.clone();
+    let mut submit = move || {
+        if !props.enabled {
+            return;
+        }
+        match prompt.validate(&value.read()) {
+            Ok(answer) => {
+                error.set(None);
+                props.on_submit.call(answer);
+            }
+            Err(e) => error.set(Some(e)),
+        }
+    };
+    let field_class = "w-full md:w-72 px-3 py-2 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#d4c29a] bg-white dark:bg-gray-800 paper:bg-transparent text-gray-900 dark:text-white paper:text-[#1f2937] focus:outline-none focus:ring-2 focus:ring-gray-400";
+
+    rsx! {
+        form {
+            class: "flex flex-col md:flex-row md:items-center gap-3",
+            onclick: move |evt| evt.stop_propagation(),
+            // Digits and Enter belong to the field, not the choice shortcuts
+            onkeydown: move |evt| evt.stop_propagation(),
+            onsubmit: move |evt: FormEvent| {
+                evt.prevent_default();
+                submit();
+            },
+            if let PromptKind::Select { options } = &props.prompt.kind {
+                select {
+                    class: field_class,
+                    disabled: !props.enabled,
+                    onchange: move |evt| value.set(evt.value()),
+                    option { value: "", selected: value.read().is_empty(), "—" }
+                    for option_value in options.iter() {
+                        option {
+                            key: "{option_value}",
+                            value: "{option_value}",
+                            selected: *value.read() == *option_value,
+                            "{option_value}"
+                        }
+                    }
+                }
+            } else {
+                input {
+                    class: field_class,
+                    r#type: if matches!(props.prompt.kind, PromptKind::Number { .. }) { "number" } else { "text" },
+                    inputmode: if matches!(props.prompt.kind, PromptKind::Number { .. }) { "decimal" } else { "text" },
+                    disabled: !props.enabled,
+                    value: "{value}",
+                    oninput: move |evt| {
+                        value.set(evt.value());
+                        error.set(None);
+                    },
+                }
+            }
+            button {
+                r#type: "submit",
+                class: "px-4 py-2 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#d4c29a] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] disabled:opacity-50 disabled:cursor-not-allowed",
+                disabled: !props.enabled,
+                "{props.caption}"
+            }
+        }
+        if let Some(e) = error.read().as_ref() {
+            p { class: "mt-2 text-sm text-red-600 dark:text-red-400", role: "alert", {error_text(e)} }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::services::api::{ApiClient, ApiError, HttpApiClient};
+use crate::services::invite::{approve_invite, reject_invite, Invite, InviteStatus};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
+use std::time::Duration;
+use wasm_bindgen_futures::spawn_local;
+
+#[derive(Clone, Copy, PartialEq)]
+enum ReviewAction {
+    Approve,
+    Reject,
+}
+
+fn status_label(invite: &Invite, now: chrono::DateTime<chrono::Utc>) -> String {
+    if invite.is_expired(now) {
+        return t!("invite_status_expired");
+    }
+    match invite.status {
+        InviteStatus::Pending => t!("invite_status_pending"),
+        InviteStatus::Approved => t!("invite_status_approved"),
+        InviteStatus::Rejected => t!("invite_status_rejected"),
+        InviteStatus::Redeemed => t!("invite_status_redeemed"),
+    }
+}
+
+/// Admin list of invite requests with approve / reject actions.
+///
+/// PocketBase only lets admins list the `invites` collection, so the panel
+/// hides itself when the list request is refused.
+#[component]
+pub fn InviteReview() -> Element {
+    let toast = use_toast();
+    let mut invites = use_signal(Vec::<Invite>::new);
+    let mut is_visible = use_signal(|| false);
+    let mut busy_id = use_signal(|| None::<String>);
+
+    use_effect(move || {
+        spawn_local(async move {
+            match HttpApiClient::new().list_invites().await {
+                Ok(data) => {
+                    invites.set(data.items);
+                    is_visible.set(true);
+                }
+                Err(ApiError::ServerError(401 | 403)) => is_visible.set(false),
+                Err(error) => {
+                    tracing::error!(error = %error, "Failed to load invite requests");
+                    is_visible.set(false);
+                }
+            }
+        });
+    });
+
+    let updated_text = t!("invite_review_updated");
+    let failed_text = t!("invite_review_failed");
+    let handle_action = move |invite_id: String, action: ReviewAction| {
+        if busy_id.peek().is_some() {
+            return;
+        }
+        busy_id.set(Some(invite_id.clone()));
+        let toast = toast.clone();
+        let updated_text = updated_text.clone();
+        let failed_text = failed_text.clone();
+        spawn_local(async move {
+            let client = HttpApiClient::new();
+            let result = match action {
+                ReviewAction::Approve => {
+                    approve_invite(&client, &invite_id, chrono::Utc::now()).await
+                }
+                ReviewAction::Reject => reject_invite(&client, &invite_id).await,
+            };
+            match result {
+                Ok(updated) => {
+                    if let Some(slot) = invites.write().iter_mut().find(|i| i.id == updated.id) {
+                        *slot = updated;
+                    }
+                    toast.push(
+                        ToastRequest::new(ToastKind::Success, updated_text)
+                            .with_timeout(Duration::from_millis(3000)),
+                    );
+                }
+                Err(error) => {
+                    toast.push(
+                        ToastRequest::new(ToastKind::Error, format!("{} ({})", failed_text, error))
+                            .with_timeout(Duration::from_millis(5000)),
+                    );
+                }
+            }
+            busy_id.set(None);
+        });
+    };
+
+    if !*is_visible.read() {
+        return rsx! {};
+    }
+
+    let now = chrono::Utc::now();
+    rsx! {
+        section {
+            class: "mt-6 bg-white dark:bg-gray-800 paper:bg-[#fef8e7] paper:text-[#1f2937] rounded-lg shadow-sm border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] p-4 sm:p-6 lg:p-8",
+            h2 { class: "text-lg font-semibold mb-4", {t!("invite_review_title")} }
+            if invites.read().is_empty() {
+                p { class: "text-sm text-gray-500 dark:text-gray-400 paper:text-[#4b5563]", {t!("invite_review_empty")} }
+            }
+            ul {
+                class: "divide-y divide-gray-200 dark:divide-gray-700 paper:divide-[#e4d5b2]",
+                for invite in invites.read().iter().cloned() {
+                    li {
+                        key: "{invite.id}",
+                        class: "py-3 flex flex-col sm:flex-row sm:items-start gap-3",
+                        div {
+                            class: "flex-1 min-w-0 space-y-1",
+                            p { class: "font-medium", "{invite.name} <{invite.email}>" }
+                            if !invite.reason.is_empty() {
+                                p { class: "text-sm text-gray-600 dark:text-gray-300 paper:text-[#374151] whitespace-pre-line", "{invite.reason}" }
+                            }
+                            p {
+                                class: "text-xs text-gray-500 dark:text-gray-400 paper:text-[#4b5563]",
+                                {status_label(&invite, now)}
+                                if invite.status == InviteStatus::Approved {
+                                    " · {invite.code} · "
+                                    {t!("invite_review_expires", date: invite.expires.clone())}
+                                }
+                            }
+                        }
+                        if matches!(invite.status, InviteStatus::Pending | InviteStatus::Approved) {
+                            div {
+                                class: "flex gap-2 shrink-0",
+                                button {
+                                    class: "px-3 py-1 rounded-md bg-green-600 text-white hover:bg-green-700 disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                                    disabled: busy_id.read().is_some(),
+                                    onclick: {
+                                        let id = invite.id.clone();
+                                        let mut handle_action = handle_action.clone();
+                                        move |_| handle_action(id.clone(), ReviewAction::Approve)
+                                    },
+                                    if invite.status == InviteStatus::Approved {
+                                        {t!("invite_review_reissue")}
+                                    } else {
+                                        {t!("invite_review_approve")}
+                                    }
+                                }
+                                button {
+                                    class: "px-3 py-1 rounded-md border border-red-500 text-red-600 hover:bg-red-50 dark:hover:bg-red-900/30 paper:hover:bg-[#f0e6cf] disabled:opacity-50 disabled:cursor-not-allowed text-sm",
+                                    disabled: busy_id.read().is_some(),
+                                    onclick: {
+                                        let id = invite.id.clone();
+                                        let mut handle_action = handle_action.clone();
+                                        move |_| handle_action(id.clone(), ReviewAction::Reject)
+                                    },
+                                    {t!("invite_review_reject")}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::models::markup::{parse, Block, Inline};
+use crate::models::narration::Sentence;
+use dioxus::prelude::*;
+use std::ops::Range;
+
+const HIGHLIGHT_CLASS: &str =
+    "bg-yellow-100 dark:bg-yellow-900/40 paper:bg-[#f3e3b5] rounded transition-colors duration-200";
+
+fn render_inlines(content: &[Inline]) -> Element {
+    rsx! {
+        for inline in content.iter() {
+            {render_inline(inline)}
+        }
+    }
+}
+
+fn render_inline(inline: &Inline) -> Element {
+    match inline {
+        Inline::Text(text) => rsx! { "{text}" },
+        Inline::Emphasis(children) => rsx! { em { {render_inlines(children)} } },
+        Inline::Strong(children) => rsx! { strong { {render_inlines(children)} } },
+        Inline::FootnoteRef(number) => rsx! {
+            sup { class: "ml-0.5 text-xs text-gray-500 dark:text-gray-400 paper:text-[#6b7280]", "{number}" }
+        },
+    }
+}
+
+/// Renders `content`, marking the characters of its plain text that fall in
+/// `range`; `offset` is where `content` starts in that text.
+fn render_marked(content: &[Inline], range: &Range<usize>, offset: &mut usize) -> Element {
+    let children: Vec<Element> = content
+        .iter()
+        .map(|inline| render_marked_inline(inline, range, offset))
+        .collect();
+    rsx! {
+        for child in children {
+            {child}
+        }
+    }
+}
+
+fn render_marked_inline(inline: &Inline, range: &Range<usize>, offset: &mut usize) -> Element {
+    match inline {
+        Inline::Text(text) => {
+            let start = *offset;
+            let end = start + text.chars().count();
+            *offset = end;
+            let from = range.start.clamp(start, end) - start;
+            let to = range.end.clamp(start, end) - start;
+            if from >= to {
+                return render_inline(inline);
+            }
+            let before: String = text.chars().take(from).collect();
+            let marked: String = text.chars().skip(from).take(to - from).collect();
+            let after: String = text.chars().skip(to).collect();
+            rsx! {
+                "{before}"
+                span { class: HIGHLIGHT_CLASS, aria_current: "true", "{marked}" }
+                "{after}"
+            }
+        }
+        Inline::Emphasis(children) => {
+            let inner = render_marked(children, range, offset);
+            rsx! { em { {inner} } }
+        }
+        Inline::Strong(children) => {
+            let inner = render_marked(children, range, offset);
+            rsx! { strong { {inner} } }
+        }
+        Inline::FootnoteRef(_) => render_inline(inline),
+    }
+}
+
+/// `content` with the part of `highlight` that lies in it marked, when the
+/// sentence belongs to text block `block`. `skip` is the length of the plain
+/// text before `content` in the block, such as a speaker's `Name: `.
+fn render_block_inlines(
+    content: &[Inline],
+    block: Option<usize>,
+    highlight: Option<&Sentence>,
+    skip: usize,
+) -> Element {
+    match highlight.filter(|sentence| Some(sentence.block) == block) {
+        Some(sentence) => {
+            let range = sentence.start.saturating_sub(skip)..sentence.end.saturating_sub(skip);
+            render_marked(content, &range, &mut 0)
+        }
+        None => render_inlines(content),
+    }
+}
+
+/// Paragraph text rendered from its markup; see [`crate::models::markup`].
+/// Blocks are siblings so the caller's spacing classes apply to each.
+/// `highlight` marks a sentence from
+/// [`sentences_for_paragraph`](crate::models::narration::sentences_for_paragraph)
+/// of the same text.
+#[component]
+pub fn MarkupText(text: String, #[props(default)] highlight: Option<Sentence>) -> Element {
+    let document = parse(&text);
+    // Sentences count only the blocks that have text
+    let mut text_blocks = 0;
+    let block_numbers: Vec<Option<usize>> = document
+        .blocks
+        .iter()
+        .map(|block| {
+            block.plain_text().map(|_| {
+                text_blocks += 1;
+                text_blocks - 1
+            })
+        })
+        .collect();
+    rsx! {
+        for (block, number) in document.blocks.iter().zip(block_numbers) {
+            if let Block::Speaker { name, content } = block {
+                p {
+                    class: "tracking-wide leading-relaxed text-justify paper:text-[#1f2937]",
+                    span { class: "font-semibold mr-2", "{name}" }
+                    {render_block_inlines(content, number, highlight.as_ref(), name.chars().count() + 2)}
+                }
+            } else if let Block::Paragraph(content) = block {
+                p {
+                    class: "indent-10 tracking-wide leading-relaxed text-justify paper:text-[#1f2937]",
+                    {render_block_inlines(content, number, highlight.as_ref(), 0)}
+                }
+            } else if let Block::Illustration { src, alt } = block {
+                // The fixed box keeps page turns in paper mode from shifting
+                // while the image loads
+                figure {
+                    class: "not-prose mx-auto w-full max-w-2xl aspect-[4/3] max-h-[60vh] break-inside-avoid",
+                    img {
+                        class: "h-full w-full object-contain",
+                        src: "{src}",
+                        alt: "{alt}",
+                        loading: "lazy",
+                        decoding: "async",
+                    }
+                }
+            } else {
+                p {
+                    class: "text-center tracking-[1em] select-none text-gray-500 dark:text-gray-400 paper:text-[#6b7280]",
+                    role: "separator",
+                    "* * *"
+                }
+            }
+        }
+        if !document.footnotes.is_empty() {
+            ol {
+                class: "list-decimal pl-6 pt-4 border-t border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] text-sm space-y-1",
+                for footnote in document.footnotes.iter() {
+                    li { key: "{footnote.label}", {render_inlines(&footnote.content)} }
+                }
+            }
+        }
+    }
+}
@@ -1,13 +1,29 @@
+pub mod audio_director;
+pub mod bookmarks;
+pub mod branch_graph;
+pub mod chapter_manager;
 pub mod chapter_selector;
 pub mod choice_impacts_editor;
 pub mod dropdown;
+pub mod find_replace;
 pub mod form;
+pub mod illustration_upload;
+pub mod input_prompt;
+pub mod invite_review;
 pub mod language_selector;
+pub mod markup_text;
+pub mod narration;
 pub mod navbar;
+pub mod paragraph_clone;
+pub mod paragraph_delete;
 pub mod paragraph_form;
 pub mod paragraph_list;
+pub mod revision_history;
 pub mod settings;
+pub mod story_bundle;
 pub mod story_content;
+pub mod story_map;
+pub mod story_search;
 pub mod title;
 pub mod translation_form;
 
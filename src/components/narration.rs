@@ -0,0 +1,71 @@
+use crate::components::markup_text::MarkupText;
+use crate::contexts::settings_context::use_settings_context;
+use crate::models::narration::{
+    parse_rate, resume_index, sentences_for_paragraph, NARRATION_RATE_SETTING,
+    NARRATION_VOICE_SETTING,
+};
+use crate::services::speech;
+use dioxus::prelude::*;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NarratedTextProps {
+    pub paragraph: Signal<String>,
+    /// Language of the story text, passed on to the speech engine.
+    pub lang: String,
+}
+
+/// Reads the paragraph aloud sentence by sentence, highlighting the one being
+/// spoken in the rendered markup. After the last sentence it stops and waits at the choices; text a
+/// choice adds to the page is read from where it left off.
+#[component]
+pub fn NarratedText(props: NarratedTextProps) -> Element {
+    let paragraph = props.paragraph;
+    let settings_ctx = use_settings_context();
+    let sentences = use_memo(move || sentences_for_paragraph(&paragraph.read()));
+    let mut current = use_signal(|| None::<usize>);
+    // Bumped whenever speech is cancelled so late callbacks are ignored
+    let mut generation = use_signal(|| 0u64);
+    let mut previous = use_signal(Vec::new);
+
+    use_effect(move || {
+        let list = sentences();
+        let start = resume_index(&previous.peek(), &list);
+        previous.set(list);
+        speech::cancel();
+        *generation.write() += 1;
+        current.set(Some(start));
+    });
+
+    let lang = props.lang.clone();
+    use_effect(move || {
+        let Some(index) = current() else {
+            return;
+        };
+        let Some(sentence) = sentences.peek().get(index).cloned() else {
+            current.set(None);
+            return;
+        };
+        let settings = settings_ctx.peek().settings.clone();
+        let rate = parse_rate(settings.get(NARRATION_RATE_SETTING).map(String::as_str));
+        let voice = settings.get(NARRATION_VOICE_SETTING).cloned();
+        let expected = *generation.peek();
+        let spoken = speech::speak(&sentence.text, &lang, voice.as_deref(), rate, move || {
+            if generation.try_peek().is_ok_and(|g| *g == expected) {
+                current.set(Some(index + 1));
+            }
+        });
+        if !spoken {
+            current.set(None);
+        }
+    });
+
+    use_drop(move || {
+        speech::cancel();
+    });
+
+    let highlight = current().and_then(|index| sentences.read().get(index).cloned());
+
+    rsx! {
+        MarkupText { text: paragraph.read().clone(), highlight }
+    }
+}
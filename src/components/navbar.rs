@@ -325,6 +325,9 @@ pub fn Navbar(closure_signal: Signal<Option<Closure<dyn FnMut(Event)>>>) -> Elem
                                 Route::Story { .. } => {
                                     let _ = navigator.push(Route::Story { lang: lang_code.clone() });
                                 }
+                                Route::Achievements { .. } => {
+                                    let _ = navigator.push(Route::Achievements { lang: lang_code.clone() });
+                                }
                                 Route::Dashboard { .. } => {
                                     let _ = navigator.push(Route::Dashboard { lang: lang_code.clone() });
                                 }
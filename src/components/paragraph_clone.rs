@@ -0,0 +1,150 @@
+use crate::contexts::auth_context::use_auth_context;
+use crate::contexts::paragraph_context::ParagraphState;
+use crate::models::subtree::subtree_ids;
+use crate::services::api::HttpApiClient;
+use crate::services::paragraphs::clone_subtree;
+use crate::services::retry::{RetryPolicy, RetryingApiClient};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
+use std::time::Duration;
+use wasm_bindgen_futures::spawn_local;
+
+const MAX_DEPTH: usize = 10;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ParagraphCloneControlsProps {
+    pub paragraph_id: String,
+    /// Called with the id of the new copy of `paragraph_id`.
+    pub on_cloned: EventHandler<String>,
+}
+
+/// "Duplicate" and "clone subtree" actions for the paragraph being edited.
+#[component]
+pub fn ParagraphCloneControls(props: ParagraphCloneControlsProps) -> Element {
+    let toast = use_toast();
+    let auth = use_auth_context();
+    let mut paragraph_state = use_context::<Signal<ParagraphState>>();
+    let mut depth = use_signal(|| 1_usize);
+    let mut busy = use_signal(|| false);
+
+    let done_text = t!("paragraph_clone_done");
+    let failed_text = t!("paragraph_clone_failed");
+    let left_behind_text = t!("paragraph_clone_left_behind");
+    let handle_clone = {
+        let paragraph_id = props.paragraph_id.clone();
+        let on_cloned = props.on_cloned;
+        move |depth: usize| {
+            if *busy.peek() {
+                return;
+            }
+            busy.set(true);
+            let author = auth.peek().user().cloned();
+            let paragraph_id = paragraph_id.clone();
+            let toast = toast.clone();
+            let done_text = done_text.clone();
+            let failed_text = failed_text.clone();
+            let left_behind_text = left_behind_text.clone();
+            spawn_local(async move {
+                let client = RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default());
+                let paragraphs = paragraph_state.peek().paragraphs.clone();
+                match clone_subtree(&client, &paragraphs, &paragraph_id, depth, author.as_ref())
+                    .await
+                {
+                    Ok(copies) => {
+                        let root_id = copies[0].id.clone();
+                        paragraph_state.write().extend(copies);
+                        toast.push(
+                            ToastRequest::new(ToastKind::Success, done_text)
+                                .with_timeout(Duration::from_millis(3000)),
+                        );
+                        on_cloned.call(root_id);
+                    }
+                    Err(failure) => {
+                        toast.push(
+                            ToastRequest::new(
+                                ToastKind::Error,
+                                format!("{} ({})", failed_text, failure.error),
+                            )
+                            .with_timeout(Duration::from_millis(5000)),
+                        );
+                        if !failure.left_behind.is_empty() {
+                            toast.push(
+                                ToastRequest::new(
+                                    ToastKind::Error,
+                                    format!(
+                                        "{} {}",
+                                        left_behind_text,
+                                        failure.left_behind.join(", ")
+                                    ),
+                                )
+                                .with_timeout(Duration::from_millis(15000)),
+                            );
+                        }
+                    }
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let (subtree_size, has_illustrations) = {
+        let state = paragraph_state.read();
+        let ids = subtree_ids(&state.paragraphs, &props.paragraph_id, *depth.read());
+        let has_illustrations = state
+            .paragraphs
+            .iter()
+            .any(|p| !p.illustration.is_empty() && ids.contains(&p.id));
+        (ids.len(), has_illustrations)
+    };
+    let is_busy = *busy.read();
+
+    rsx! {
+        div {
+            class: "mt-3 flex flex-wrap items-center gap-2 text-sm",
+            button {
+                class: "px-3 py-2 rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] disabled:opacity-50 disabled:cursor-not-allowed",
+                disabled: is_busy,
+                onclick: {
+                    let mut handle_clone = handle_clone.clone();
+                    move |_| handle_clone(0)
+                },
+                {t!("paragraph_clone_duplicate")}
+            }
+            div {
+                class: "flex items-center gap-2",
+                label {
+                    class: "text-gray-600 dark:text-gray-300 paper:text-[#374151]",
+                    {t!("paragraph_clone_depth")}
+                }
+                input {
+                    class: "w-16 h-9 px-2 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                    r#type: "number",
+                    min: "1",
+                    max: "{MAX_DEPTH}",
+                    value: "{depth}",
+                    oninput: move |event: FormEvent| {
+                        if let Ok(value) = event.value().parse::<usize>() {
+                            depth.set(value.clamp(1, MAX_DEPTH));
+                        }
+                    },
+                }
+                button {
+                    class: "px-3 py-2 rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_busy,
+                    onclick: {
+                        let mut handle_clone = handle_clone.clone();
+                        move |_| handle_clone(*depth.peek())
+                    },
+                    {t!("paragraph_clone_subtree", count: subtree_size.to_string())}
+                }
+            }
+            if has_illustrations {
+                p {
+                    class: "w-full text-xs text-amber-700 dark:text-amber-400",
+                    {t!("paragraph_clone_no_illustrations")}
+                }
+            }
+        }
+    }
+}
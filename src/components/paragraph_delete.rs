@@ -0,0 +1,248 @@
+use crate::contexts::auth_context::use_auth_context;
+use crate::contexts::paragraph_context::{Paragraph, ParagraphState};
+use crate::models::references::ReferenceRepair;
+use crate::models::story_graph::EdgeSlot;
+use crate::services::api::HttpApiClient;
+use crate::services::paragraphs::{delete_paragraph_with_repair, plan_paragraph_delete};
+use crate::services::retry::{RetryPolicy, RetryingApiClient};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
+use std::time::Duration;
+use wasm_bindgen_futures::spawn_local;
+
+const LABEL_CHARS: usize = 40;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum RepairMode {
+    Retarget,
+    Remove,
+}
+
+impl RepairMode {
+    pub fn repair(self, retarget_to: &str) -> ReferenceRepair {
+        match self {
+            RepairMode::Retarget => ReferenceRepair::Retarget(retarget_to.to_string()),
+            RepairMode::Remove => ReferenceRepair::RemoveChoices,
+        }
+    }
+}
+
+/// `(id, label)` for every paragraph outside `deleted`, labelled with the
+/// first line of its `lang` text.
+pub fn retarget_options(
+    paragraphs: &[Paragraph],
+    deleted: &[String],
+    lang: &str,
+) -> Vec<(String, String)> {
+    paragraphs
+        .iter()
+        .filter(|p| !deleted.contains(&p.id))
+        .map(|p| {
+            let first_line = p
+                .text_for(lang)
+                .or_else(|| p.texts.first())
+                .and_then(|text| text.paragraphs.lines().next())
+                .unwrap_or("")
+                .trim();
+            let label: String = first_line.chars().take(LABEL_CHARS).collect();
+            (p.id.clone(), format!("{} — {}", p.id, label))
+        })
+        .collect()
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RepairPickerProps {
+    /// Name of the radio group; unique per dialog.
+    pub name: String,
+    pub mode: Signal<RepairMode>,
+    pub retarget_to: Signal<String>,
+    /// From [`retarget_options`].
+    pub options: Vec<(String, String)>,
+}
+
+/// Radio choice between retargeting dangling choices and removing them.
+#[component]
+pub fn RepairPicker(props: RepairPickerProps) -> Element {
+    let mut mode = props.mode;
+    let mut retarget_to = props.retarget_to;
+    rsx! {
+        div {
+            class: "space-y-2",
+            label {
+                class: "flex items-center gap-2",
+                input {
+                    r#type: "radio",
+                    name: props.name.clone(),
+                    checked: *mode.read() == RepairMode::Retarget,
+                    onchange: move |_| mode.set(RepairMode::Retarget),
+                }
+                {t!("paragraph_delete_retarget")}
+            }
+            if *mode.read() == RepairMode::Retarget {
+                select {
+                    class: "w-full h-9 px-2 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                    value: retarget_to.read().clone(),
+                    oninput: move |event: FormEvent| retarget_to.set(event.value()),
+                    option { value: "", disabled: true, {t!("paragraph_delete_pick_target")} }
+                    for (id, label) in props.options.iter() {
+                        option { key: "{id}", value: id.clone(), "{label}" }
+                    }
+                }
+            }
+            label {
+                class: "flex items-center gap-2",
+                input {
+                    r#type: "radio",
+                    name: props.name.clone(),
+                    checked: *mode.read() == RepairMode::Remove,
+                    onchange: move |_| mode.set(RepairMode::Remove),
+                }
+                {t!("paragraph_delete_remove_choices")}
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ParagraphDeleteDialogProps {
+    pub paragraph_id: String,
+    /// Language used for the retarget option labels.
+    pub lang: String,
+    pub on_cancel: EventHandler<()>,
+    /// Called with the deleted id once the server has applied the batch.
+    pub on_deleted: EventHandler<String>,
+}
+
+/// Confirmation for deleting a paragraph.
+///
+/// Lists every choice that points at the paragraph and lets the editor
+/// retarget those choices or remove them; the repair and the delete are sent
+/// as one batch so a failure leaves the story untouched.
+#[component]
+pub fn ParagraphDeleteDialog(props: ParagraphDeleteDialogProps) -> Element {
+    let toast = use_toast();
+    let auth = use_auth_context();
+    let mut paragraph_state = use_context::<Signal<ParagraphState>>();
+    let mode = use_signal(|| RepairMode::Retarget);
+    let retarget_to = use_signal(String::new);
+    let mut busy = use_signal(|| false);
+
+    let plan = plan_paragraph_delete(&props.paragraph_id, &paragraph_state.read().paragraphs);
+
+    let deleted_text = t!("paragraph_delete_done");
+    let failed_text = t!("paragraph_delete_failed");
+    let handle_confirm = {
+        let on_deleted = props.on_deleted;
+        let plan = plan.clone();
+        move |_| {
+            if *busy.peek() {
+                return;
+            }
+            let repair = mode.peek().repair(&retarget_to.peek());
+            let author = auth.peek().user().cloned();
+            busy.set(true);
+            let plan = plan.clone();
+            let toast = toast.clone();
+            let deleted_text = deleted_text.clone();
+            let failed_text = failed_text.clone();
+            spawn_local(async move {
+                let client = RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default());
+                let paragraphs = paragraph_state.peek().paragraphs.clone();
+                match delete_paragraph_with_repair(
+                    &client,
+                    &plan,
+                    &paragraphs,
+                    &repair,
+                    author.as_ref(),
+                )
+                .await
+                {
+                    Ok(repaired) => {
+                        let mut state = paragraph_state.write();
+                        for paragraph in repaired {
+                            state.replace(paragraph);
+                        }
+                        state.remove(std::slice::from_ref(&plan.paragraph_id));
+                        drop(state);
+                        toast.push(
+                            ToastRequest::new(ToastKind::Success, deleted_text)
+                                .with_timeout(Duration::from_millis(3000)),
+                        );
+                        on_deleted.call(plan.paragraph_id.clone());
+                    }
+                    Err(error) => {
+                        toast.push(
+                            ToastRequest::new(
+                                ToastKind::Error,
+                                format!("{} ({})", failed_text, error),
+                            )
+                            .with_timeout(Duration::from_millis(5000)),
+                        );
+                    }
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let has_references = !plan.inbound.is_empty();
+    let needs_target = has_references && *mode.read() == RepairMode::Retarget;
+    let options = retarget_options(
+        &paragraph_state.read().paragraphs,
+        std::slice::from_ref(&plan.paragraph_id),
+        &props.lang,
+    );
+    let timeout_label = t!("graph_edge_timeout");
+
+    rsx! {
+        div {
+            class: "mt-4 rounded-lg border border-red-300 dark:border-red-700 bg-red-50 dark:bg-red-900/20 paper:bg-[#fbeee0] p-4 space-y-3 text-sm text-left",
+            p {
+                class: "font-medium",
+                {t!("paragraph_delete_confirm", id: plan.paragraph_id.clone())}
+            }
+            if has_references {
+                p { {t!("paragraph_delete_inbound", count: plan.inbound.len().to_string())} }
+                ul {
+                    class: "list-disc pl-5 text-xs text-gray-600 dark:text-gray-300 paper:text-[#374151]",
+                    for reference in plan.inbound.iter() {
+                        li {
+                            key: "{reference.paragraph_id}-{reference.choice_index}-{reference.slot:?}",
+                            if matches!(reference.slot, EdgeSlot::Timeout(_)) {
+                                "{reference.paragraph_id} #{reference.choice_index + 1} ({timeout_label})"
+                            } else {
+                                "{reference.paragraph_id} #{reference.choice_index + 1}"
+                            }
+                        }
+                    }
+                }
+                RepairPicker {
+                    name: "paragraph-delete-repair",
+                    mode,
+                    retarget_to,
+                    options,
+                }
+            } else {
+                p {
+                    class: "text-gray-600 dark:text-gray-300 paper:text-[#374151]",
+                    {t!("paragraph_delete_no_references")}
+                }
+            }
+            div {
+                class: "flex gap-2 pt-1",
+                button {
+                    class: "px-3 py-1 rounded-md bg-red-600 text-white hover:bg-red-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: *busy.read() || (needs_target && retarget_to.read().is_empty()),
+                    onclick: handle_confirm,
+                    {t!("paragraph_delete_action")}
+                }
+                button {
+                    class: "px-3 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2]",
+                    onclick: move |_| props.on_cancel.call(()),
+                    {t!("paragraph_delete_cancel")}
+                }
+            }
+        }
+    }
+}
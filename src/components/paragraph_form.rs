@@ -1,6 +1,7 @@
 use crate::components::form::{ChoiceOptions, TextareaField};
 use crate::components::paragraph_list::Paragraph;
 use crate::contexts::chapter_context::Chapter;
+use crate::hooks::choices_reducer::Choice;
 use crate::models::impacts::Impact;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
@@ -26,19 +27,7 @@ pub struct ParagraphFormProps {
     on_add_choice: EventHandler<()>,
     on_remove_choice: EventHandler<usize>,
     on_submit: EventHandler<()>,
-    choices: Vec<(
-        String,
-        Vec<String>,
-        String,
-        Option<String>,
-        Option<serde_json::Value>,
-        String,
-        bool,
-        Option<u32>,
-        Option<String>,
-        String,
-        Vec<Impact>,
-    )>,
+    choices: Vec<Choice>,
     available_chapters: Vec<Chapter>,
     selected_language: String,
     choice_paragraphs: Vec<Paragraph>,
@@ -56,21 +45,7 @@ pub struct ParagraphFormProps {
 
 #[component]
 pub fn ParagraphForm(props: ParagraphFormProps) -> Element {
-    let mut choices = use_signal(|| {
-        Vec::<(
-            String,
-            Vec<String>,
-            String,
-            Option<String>,
-            Option<serde_json::Value>,
-            String,
-            bool,
-            Option<u32>,
-            Option<String>,
-            String,
-            Vec<Impact>,
-        )>::new()
-    });
+    let mut choices = use_signal(|| Vec::<Choice>::new());
     let available_chapters = use_signal(|| Vec::<Chapter>::new());
     let selected_language = use_signal(|| String::new());
     let choice_paragraphs = use_signal(|| Vec::<Paragraph>::new());
@@ -119,49 +94,26 @@ pub fn ParagraphForm(props: ParagraphFormProps) -> Element {
             ChoiceOptions {
                 choices: choices.read().clone(),
                 on_choice_change: move |(index, field, value): (usize, String, String)| {
-                    let mut choices_write = choices.write();
-                    match field.as_str() {
-                        "caption" => choices_write[index].0 = value,
-                        "goto" => choices_write[index].1 = value.split(',').map(|s| s.trim().to_string()).collect(),
-                        "action_type" => choices_write[index].2 = value,
-                        "action_key" => choices_write[index].3 = Some(value),
-                        "action_value" => choices_write[index].4 = Some(serde_json::Value::String(value)),
-                        "target_chapter" => choices_write[index].5 = value,
-                        "same_page" => choices_write[index].6 = value == "true",
-                        "time_limit" => choices_write[index].7 = value.parse::<u32>().ok(),
-                        "timeout_to" => choices_write[index].8 = if value.trim().is_empty() { None } else { Some(value) },
-                        "timeout_target_chapter" => choices_write[index].9 = value,
-                        _ => {}
+                    if let Some(choice) = choices.write().get_mut(index) {
+                        choice.set_field(&field, value);
                     }
                 },
                 on_choice_add_paragraph: move |(index, paragraph_id): (usize, String)| {
                     let mut choices_write = choices.write();
                     if let Some(choice) = choices_write.get_mut(index) {
-                        if !choice.1.contains(&paragraph_id) {
-                            choice.1.push(paragraph_id);
+                        if !choice.stored.to.contains(&paragraph_id) {
+                            choice.stored.to.push(paragraph_id);
                         }
                     }
                 },
                 on_choice_remove_paragraph: move |(index, paragraph_id): (usize, String)| {
                     let mut choices_write = choices.write();
                     if let Some(choice) = choices_write.get_mut(index) {
-                        choice.1.retain(|id| id != &paragraph_id);
+                        choice.stored.to.retain(|id| id != &paragraph_id);
                     }
                 },
                 on_add_choice: move |_| {
-                    choices.write().push((
-                        String::new(),
-                        Vec::<String>::new(),
-                        String::new(),
-                        None,
-                        None,
-                        String::new(),
-                        false,
-                        None,
-                        None,
-                        String::new(),
-                        Vec::new(),
-                    ));
+                    choices.write().push(Choice::default());
                 },
                 on_remove_choice: move |index| {
                     choices.write().remove(index);
@@ -170,7 +122,7 @@ pub fn ParagraphForm(props: ParagraphFormProps) -> Element {
                 relationships: Vec::new(),
                 on_impacts_change: move |(index, impacts): (usize, Vec<Impact>)| {
                     if let Some(choice) = choices.write().get_mut(index) {
-                        choice.10 = impacts.clone();
+                        choice.set_impacts(impacts.clone());
                     }
                 },
                 available_chapters: available_chapters.read().clone(),
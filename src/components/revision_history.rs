@@ -0,0 +1,236 @@
+use crate::contexts::auth_context::use_auth_context;
+use crate::models::diff::{describe_choice, diff_choices, diff_texts, DiffLine};
+use crate::models::paragraph::{Paragraph, ParagraphChoice};
+use crate::services::api::{ApiClient, HttpApiClient};
+use crate::services::retry::{RetryPolicy, RetryingApiClient};
+use crate::services::revisions::{restore_revision, ParagraphRevision};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
+use std::time::Duration;
+use wasm_bindgen_futures::spawn_local;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RevisionHistoryProps {
+    /// The paragraph as currently saved; revisions are diffed against it.
+    pub paragraph: Paragraph,
+    /// Language listed first in the text diff.
+    pub lang: String,
+    /// Called with the paragraph once a revision has been restored.
+    pub on_restored: EventHandler<Paragraph>,
+}
+
+const SAME_CLASS: &str = "text-gray-600 dark:text-gray-300 paper:text-[#374151]";
+const ADDED_CLASS: &str = "bg-green-50 dark:bg-green-900/30 text-green-800 dark:text-green-300";
+const REMOVED_CLASS: &str =
+    "bg-red-50 dark:bg-red-900/30 text-red-800 dark:text-red-300 line-through";
+
+fn diff_line_class(line: &DiffLine) -> &'static str {
+    match line {
+        DiffLine::Same(_) => SAME_CLASS,
+        DiffLine::Added(_) => ADDED_CLASS,
+        DiffLine::Removed(_) => REMOVED_CLASS,
+    }
+}
+
+fn diff_line_text(line: &DiffLine) -> String {
+    match line {
+        DiffLine::Same(text) => format!("  {}", text),
+        DiffLine::Added(text) => format!("+ {}", text),
+        DiffLine::Removed(text) => format!("- {}", text),
+    }
+}
+
+fn choice_summary(choice: Option<&ParagraphChoice>) -> String {
+    let Some(choice) = choice else {
+        return "—".to_string();
+    };
+    let mut summary = describe_choice(choice);
+    if choice.same_page.unwrap_or(false) {
+        summary.push_str(&format!(" · {}", t!("graph_edge_same_page")));
+    }
+    let impacts = choice.impacts.as_ref().map(Vec::len).unwrap_or(0);
+    if impacts > 0 {
+        summary.push_str(&format!(
+            " · {}",
+            t!("revision_impacts", count: impacts.to_string())
+        ));
+    }
+    summary
+}
+
+/// Saved revisions of a paragraph, newest first.
+///
+/// Selecting a revision shows what restoring it would change compared to the
+/// current paragraph: a line diff per language and the choices that differ.
+#[component]
+pub fn RevisionHistory(props: RevisionHistoryProps) -> Element {
+    let toast = use_toast();
+    let auth = use_auth_context();
+    let mut revisions = use_signal(Vec::<ParagraphRevision>::new);
+    let mut selected = use_signal(|| None::<String>);
+    let mut is_loading = use_signal(|| true);
+    let mut busy = use_signal(|| false);
+
+    let paragraph_id = props.paragraph.id.clone();
+    use_effect(move || {
+        let paragraph_id = paragraph_id.clone();
+        spawn_local(async move {
+            let client = RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default());
+            match client.list_revisions(&paragraph_id).await {
+                Ok(data) => revisions.set(data.items),
+                Err(error) => {
+                    tracing::error!(error = %error, "Failed to load paragraph revisions");
+                }
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let restored_text = t!("revision_restored");
+    let failed_text = t!("revision_restore_failed");
+    let handle_restore = {
+        let current = props.paragraph.clone();
+        let on_restored = props.on_restored;
+        move |revision: ParagraphRevision| {
+            if *busy.peek() {
+                return;
+            }
+            busy.set(true);
+            let current = current.clone();
+            let author = auth.peek().user().cloned();
+            let toast = toast.clone();
+            let restored_text = restored_text.clone();
+            let failed_text = failed_text.clone();
+            spawn_local(async move {
+                let client = RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default());
+                match restore_revision(&client, &current, &revision, author.as_ref()).await {
+                    Ok(restored) => {
+                        toast.push(
+                            ToastRequest::new(ToastKind::Success, restored_text)
+                                .with_timeout(Duration::from_millis(3000)),
+                        );
+                        on_restored.call(restored);
+                    }
+                    Err(error) => {
+                        toast.push(
+                            ToastRequest::new(
+                                ToastKind::Error,
+                                format!("{} ({})", failed_text, error),
+                            )
+                            .with_timeout(Duration::from_millis(5000)),
+                        );
+                    }
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let selected_revision = selected
+        .read()
+        .as_ref()
+        .and_then(|id| revisions.read().iter().find(|r| r.id == *id).cloned());
+    let mut text_diffs = selected_revision
+        .as_ref()
+        .map(|revision| diff_texts(&props.paragraph.texts, &revision.texts))
+        .unwrap_or_default();
+    text_diffs.sort_by_key(|diff| diff.lang != props.lang);
+    let choice_changes = selected_revision
+        .as_ref()
+        .map(|revision| diff_choices(&props.paragraph.choices, &revision.choices))
+        .unwrap_or_default();
+    let unknown_author = t!("revision_unknown_author");
+
+    rsx! {
+        div {
+            class: "mt-4 rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] p-4 space-y-3 text-sm text-left",
+            h3 { class: "font-medium", {t!("revision_history_title")} }
+            if *is_loading.read() {
+                p { class: "text-gray-500 dark:text-gray-400 paper:text-[#4b5563]", {t!("revision_loading")} }
+            } else if revisions.read().is_empty() {
+                p { class: "text-gray-500 dark:text-gray-400 paper:text-[#4b5563]", {t!("revision_empty")} }
+            }
+            ul {
+                class: "max-h-48 overflow-y-auto divide-y divide-gray-200 dark:divide-gray-700 paper:divide-[#e4d5b2]",
+                for revision in revisions.read().iter().cloned() {
+                    li {
+                        key: "{revision.id}",
+                        class: if selected.read().as_deref() == Some(revision.id.as_str()) {
+                            "py-2 px-2 flex items-center justify-between gap-2 bg-gray-100 dark:bg-gray-700 paper:bg-[#f0e6cf] cursor-pointer"
+                        } else {
+                            "py-2 px-2 flex items-center justify-between gap-2 hover:bg-gray-50 dark:hover:bg-gray-700/50 paper:hover:bg-[#f6edda] cursor-pointer"
+                        },
+                        onclick: {
+                            let id = revision.id.clone();
+                            move |_| selected.set(Some(id.clone()))
+                        },
+                        span { class: "font-mono text-xs", "{revision.created}" }
+                        span {
+                            class: "truncate",
+                            if revision.author_name.is_empty() {
+                                "{unknown_author}"
+                            } else {
+                                "{revision.author_name}"
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(revision) = selected_revision {
+                div {
+                    class: "space-y-3",
+                    if text_diffs.is_empty() && choice_changes.is_empty() {
+                        p { class: "text-gray-500 dark:text-gray-400 paper:text-[#4b5563]", {t!("revision_no_changes")} }
+                    }
+                    for diff in text_diffs.iter() {
+                        div {
+                            key: "{diff.lang}",
+                            p { class: "font-medium", "{diff.lang}" }
+                            pre {
+                                class: "mt-1 text-xs whitespace-pre-wrap font-mono rounded-md overflow-x-auto",
+                                for (index, line) in diff.body.iter().enumerate() {
+                                    div { key: "body-{index}", class: diff_line_class(line), {diff_line_text(line)} }
+                                }
+                            }
+                            if diff.captions.iter().any(|line| !matches!(line, DiffLine::Same(_))) {
+                                p { class: "mt-1 text-xs font-medium", {t!("revision_captions")} }
+                                pre {
+                                    class: "text-xs whitespace-pre-wrap font-mono rounded-md",
+                                    for (index, line) in diff.captions.iter().enumerate() {
+                                        div { key: "caption-{index}", class: diff_line_class(line), {diff_line_text(line)} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if !choice_changes.is_empty() {
+                        div {
+                            p { class: "font-medium", {t!("revision_choices")} }
+                            ul {
+                                class: "mt-1 space-y-1 text-xs font-mono",
+                                for change in choice_changes.iter() {
+                                    li {
+                                        key: "choice-{change.index}",
+                                        div { "#{change.index + 1}" }
+                                        div { class: REMOVED_CLASS, "- {choice_summary(change.before.as_ref())}" }
+                                        div { class: ADDED_CLASS, "+ {choice_summary(change.after.as_ref())}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    button {
+                        class: "px-3 py-1 rounded-md bg-green-600 text-white hover:bg-green-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                        disabled: *busy.read() || (text_diffs.is_empty() && choice_changes.is_empty()),
+                        onclick: {
+                            let mut handle_restore = handle_restore.clone();
+                            move |_| handle_restore(revision.clone())
+                        },
+                        {t!("revision_restore")}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,21 @@
+use crate::contexts::auth_context::use_auth_context;
 use crate::contexts::language_context::LanguageState;
 use crate::contexts::settings_context::use_settings_context;
+use crate::contexts::story_context::use_story_context;
 use crate::enums::route::Route;
 use crate::enums::style::NavbarStyle;
+use crate::models::audio::{Volume, AUDIO_MUTED_SETTING, AUDIO_VOLUME_SETTING};
+use crate::models::countdown::{parse_factor, RELAXED_STEPS, RELAXED_TIMERS_SETTING, TIMERS_OFF};
+use crate::models::narration::{
+    parse_rate, NARRATION_RATE_SETTING, NARRATION_VOICE_SETTING, RATE_STEPS,
+};
 #[cfg(target_arch = "wasm32")]
 use crate::services::indexeddb::clear_all_disabled_choices_from_indexeddb;
+use crate::services::auth::logout;
 use crate::services::indexeddb::clear_choices_and_random_choices;
 #[cfg(target_arch = "wasm32")]
 use crate::services::indexeddb::set_setting_to_indexeddb;
+use crate::services::speech;
 use crate::utils::theme::{apply_theme_class, ThemeMode};
 use dioxus::prelude::*;
 use dioxus_i18n::t;
@@ -28,6 +37,9 @@ pub fn Settings(props: SettingsProps) -> Element {
     let language_state = use_context::<Signal<LanguageState>>();
     let current_lang = language_state.read().current_language.clone();
     let settings_context = use_settings_context();
+    let mut auth_context = use_auth_context();
+    let signed_in_email = auth_context.read().user().map(|user| user.email.clone());
+    let logout_toast = toast.clone();
     let reader_mode = settings_context
         .read()
         .settings
@@ -53,6 +65,38 @@ pub fn Settings(props: SettingsProps) -> Element {
             }
         });
 
+    let volume = Volume::from_settings(&settings_context.read().settings);
+    let volume_percent = (volume.level * 100.0).round() as i32;
+    let mute_status = if volume.muted { t!("on") } else { t!("off") };
+    let narration_rate = parse_rate(
+        settings_context
+            .read()
+            .settings
+            .get(NARRATION_RATE_SETTING)
+            .map(String::as_str),
+    );
+    let narration_voice = settings_context
+        .read()
+        .settings
+        .get(NARRATION_VOICE_SETTING)
+        .cloned()
+        .unwrap_or_default();
+    let timer_factor = parse_factor(
+        settings_context
+            .read()
+            .settings
+            .get(RELAXED_TIMERS_SETTING)
+            .map(String::as_str),
+    );
+    let timers_mandatory = *use_story_context().read().timers_mandatory.read();
+    let narration_supported = speech::is_supported();
+    // Voices load lazily, so the list is read again each time the menu renders
+    let narration_voices = if *is_open.read() {
+        speech::voice_names_for(&current_lang)
+    } else {
+        Vec::new()
+    };
+
     let animation_class = if *is_open.read() {
         "translate-y-0 opacity-100"
     } else {
@@ -98,6 +142,16 @@ pub fn Settings(props: SettingsProps) -> Element {
         "grid grid-cols-1 gap-2"
     };
 
+    // Countdowns hold while the menu is open
+    {
+        let settings_context = settings_context.clone();
+        use_effect(move || {
+            let open = *is_open.read();
+            let mut panel_open = settings_context.peek().panel_open;
+            panel_open.set(open);
+        });
+    }
+
     {
         let mut settings_context = settings_context.clone();
         let theme_mode = theme_mode.clone();
@@ -139,13 +193,30 @@ pub fn Settings(props: SettingsProps) -> Element {
                     class: format!("{position_class} w-full sm:min-w-[16rem] sm:max-w-[60vw] shadow-lg bg-white dark:bg-gray-800 paper:bg-[#fef8e7] paper:text-[#1f2937] ring-1 ring-black ring-opacity-5 paper:ring-[#d4c29a] paper:ring-opacity-60 paper-surface z-[1000] transition duration-200 ease-in-out transform {animation_class} will-change-transform will-change-opacity"),
                 div {
                     class: "py-1",
-                        button {
-                            class: "w-full text-left px-4 py-2 text-sm text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] pen-texture-text",
-                            onclick: move |_| {
-                                let _ = navigator.push(Route::Login { lang: current_lang.clone() });
-                                is_open.set(false);
-                            },
-                            "{t!(\"login\")}" 
+                        if let Some(email) = signed_in_email {
+                            button {
+                                class: "w-full text-left px-4 py-2 text-sm text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] pen-texture-text",
+                                onclick: move |_| {
+                                    logout();
+                                    auth_context.write().session = None;
+                                    logout_toast.success(t!("logout_success"));
+                                    is_open.set(false);
+                                },
+                                div {
+                                    class: "flex items-center justify-between w-full",
+                                    span { class: "font-medium", "{t!(\"logout\")}" }
+                                    span { class: "text-xs text-gray-500 dark:text-gray-400 ml-2 truncate", "{email}" }
+                                }
+                            }
+                        } else {
+                            button {
+                                class: "w-full text-left px-4 py-2 text-sm text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] pen-texture-text",
+                                onclick: move |_| {
+                                    let _ = navigator.push(Route::Login { lang: current_lang.clone() });
+                                    is_open.set(false);
+                                },
+                                "{t!(\"login\")}"
+                            }
                         }
                         button {
                             class: "w-full text-left px-4 py-2 text-sm text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] pen-texture-text",
@@ -274,6 +345,181 @@ pub fn Settings(props: SettingsProps) -> Element {
                             })}
                         }
                     }
+                    div { class: "border-t border-gray-200 dark:border-gray-700 my-1" }
+                    div {
+                        class: "px-4 py-2",
+                        div {
+                            class: "flex items-center justify-between mb-2",
+                            label {
+                                r#for: "settings-audio-volume",
+                                class: "text-sm font-medium text-gray-800 dark:text-gray-100 pen-texture-text",
+                                "{t!(\"audio_volume\")}"
+                            }
+                            span { class: "text-xs text-gray-500 dark:text-gray-400", "{volume_percent}%" }
+                        }
+                        input {
+                            id: "settings-audio-volume",
+                            r#type: "range",
+                            min: "0",
+                            max: "100",
+                            step: "5",
+                            value: "{volume_percent}",
+                            disabled: volume.muted,
+                            class: "w-full accent-blue-600 disabled:opacity-50",
+                            oninput: move |evt| {
+                                let mut settings_context = settings_context.clone();
+                                let Ok(percent) = evt.value().parse::<f32>() else {
+                                    return;
+                                };
+                                let level = (percent / 100.0).clamp(0.0, 1.0).to_string();
+                                settings_context
+                                    .write()
+                                    .settings
+                                    .insert(AUDIO_VOLUME_SETTING.to_string(), level.clone());
+                                #[cfg(target_arch = "wasm32")]
+                                {
+                                    set_setting_to_indexeddb(AUDIO_VOLUME_SETTING, &level);
+                                }
+                            },
+                        }
+                        button {
+                            class: "w-full text-left mt-2 text-sm text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] rounded-md px-2 py-1 pen-texture-text",
+                            aria_pressed: if volume.muted { "true" } else { "false" },
+                            onclick: move |_| {
+                                let mut settings_context = settings_context.clone();
+                                let muted = (!volume.muted).to_string();
+                                settings_context
+                                    .write()
+                                    .settings
+                                    .insert(AUDIO_MUTED_SETTING.to_string(), muted.clone());
+                                #[cfg(target_arch = "wasm32")]
+                                {
+                                    set_setting_to_indexeddb(AUDIO_MUTED_SETTING, &muted);
+                                }
+                            },
+                            div {
+                                class: "flex items-center justify-between w-full",
+                                span { "{t!(\"audio_mute\")}" }
+                                span { class: "text-xs text-gray-500 dark:text-gray-400 ml-2", "{mute_status}" }
+                            }
+                        }
+                    }
+                    div { class: "border-t border-gray-200 dark:border-gray-700 my-1" }
+                    div {
+                        class: "px-4 py-2",
+                        div { class: "text-sm font-medium text-gray-800 dark:text-gray-100 mb-2 pen-texture-text", "{t!(\"relaxed_timers\")}" }
+                        if timers_mandatory {
+                            div { class: "text-xs text-gray-500 dark:text-gray-400 mb-1", "{t!(\"relaxed_timers_mandatory\")}" }
+                        }
+                        div {
+                            class: "grid grid-cols-5 gap-2",
+                            {RELAXED_STEPS.iter().map(|step| Some(*step)).chain([None]).map(|step| {
+                                let value = step.map(|f| f.to_string()).unwrap_or_else(|| TIMERS_OFF.to_string());
+                                let stored = value.clone();
+                                let label = match step {
+                                    Some(factor) => format!("{factor}×"),
+                                    None => t!("off"),
+                                };
+                                let selected = match (step, timer_factor) {
+                                    (Some(a), Some(b)) => (a - b).abs() < f32::EPSILON,
+                                    (None, None) => true,
+                                    _ => false,
+                                };
+                                rsx! {
+                                    button {
+                                        key: "{value}",
+                                        disabled: timers_mandatory,
+                                        aria_pressed: if selected { "true" } else { "false" },
+                                        class: format!(
+                                            "w-full px-2 py-1 text-sm rounded-md border transition-colors duration-150 pen-texture-text disabled:opacity-50 disabled:cursor-not-allowed {}",
+                                            if selected {
+                                                "border-blue-500 text-blue-700 dark:text-blue-300 paper:text-[#1f2937] bg-blue-50 dark:bg-blue-900/30 paper:bg-[#eae0c9] paper:border-[#c6b17e]"
+                                            } else {
+                                                "border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]"
+                                            }
+                                        ),
+                                        onclick: move |_| {
+                                            let mut settings_context = settings_context.clone();
+                                            settings_context
+                                                .write()
+                                                .settings
+                                                .insert(RELAXED_TIMERS_SETTING.to_string(), stored.clone());
+                                            #[cfg(target_arch = "wasm32")]
+                                            {
+                                                set_setting_to_indexeddb(RELAXED_TIMERS_SETTING, &stored);
+                                            }
+                                        },
+                                        "{label}"
+                                    }
+                                }
+                            })}
+                        }
+                    }
+                    if narration_supported {
+                        div { class: "border-t border-gray-200 dark:border-gray-700 my-1" }
+                        div {
+                            class: "px-4 py-2",
+                            div { class: "text-sm font-medium text-gray-800 dark:text-gray-100 mb-2 pen-texture-text", "{t!(\"narration\")}" }
+                            div { class: "text-xs text-gray-500 dark:text-gray-400 mb-1", "{t!(\"narration_rate\")}" }
+                            div {
+                                class: "grid grid-cols-4 gap-2 mb-3",
+                                for rate in RATE_STEPS {
+                                    button {
+                                        key: "{rate}",
+                                        class: format!(
+                                            "w-full px-2 py-1 text-sm rounded-md border transition-colors duration-150 pen-texture-text {}",
+                                            if (narration_rate - rate).abs() < f32::EPSILON {
+                                                "border-blue-500 text-blue-700 dark:text-blue-300 paper:text-[#1f2937] bg-blue-50 dark:bg-blue-900/30 paper:bg-[#eae0c9] paper:border-[#c6b17e]"
+                                            } else {
+                                                "border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]"
+                                            }
+                                        ),
+                                        onclick: move |_| {
+                                            let mut settings_context = settings_context.clone();
+                                            settings_context
+                                                .write()
+                                                .settings
+                                                .insert(NARRATION_RATE_SETTING.to_string(), rate.to_string());
+                                            #[cfg(target_arch = "wasm32")]
+                                            {
+                                                set_setting_to_indexeddb(NARRATION_RATE_SETTING, &rate.to_string());
+                                            }
+                                        },
+                                        "{rate}×"
+                                    }
+                                }
+                            }
+                            div { class: "text-xs text-gray-500 dark:text-gray-400 mb-1", "{t!(\"narration_voice\")}" }
+                            select {
+                                class: "w-full px-2 py-1 text-sm rounded-md border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] bg-white dark:bg-gray-800 paper:bg-transparent text-gray-700 dark:text-gray-300 paper:text-[#374151]",
+                                onchange: move |evt| {
+                                    let mut settings_context = settings_context.clone();
+                                    let voice = evt.value();
+                                    if voice.is_empty() {
+                                        settings_context.write().settings.remove(NARRATION_VOICE_SETTING);
+                                    } else {
+                                        settings_context
+                                            .write()
+                                            .settings
+                                            .insert(NARRATION_VOICE_SETTING.to_string(), voice.clone());
+                                    }
+                                    #[cfg(target_arch = "wasm32")]
+                                    {
+                                        set_setting_to_indexeddb(NARRATION_VOICE_SETTING, &voice);
+                                    }
+                                },
+                                option { value: "", selected: narration_voice.is_empty(), "{t!(\"narration_voice_default\")}" }
+                                for name in narration_voices.iter() {
+                                    option {
+                                        key: "{name}",
+                                        value: "{name}",
+                                        selected: *name == narration_voice,
+                                        "{name}"
+                                    }
+                                }
+                            }
+                        }
+                    }
                     if show_clear {
                         div {
                             class: "border-t border-gray-200 dark:border-gray-700 my-1",
@@ -0,0 +1,413 @@
+use crate::contexts::chapter_context::ChapterState;
+use crate::contexts::paragraph_context::ParagraphState;
+use crate::models::bundle::{BundleCollection, IdStrategy, ImportPlan, RecordAction, StoryBundle};
+use crate::models::story_export::{
+    export_choicescript, export_ink, illustration_issues, ExportFile, ExportIssue, StoryExport,
+};
+use crate::models::twee::{twee_to_bundle, TweeWarning};
+use crate::services::api::HttpApiClient;
+use crate::services::bundle::{apply_import, export_bundle, plan_bundle_import};
+use crate::services::paragraphs::new_record_id;
+use crate::services::retry::{RetryPolicy, RetryingApiClient};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+
+fn collection_label(collection: BundleCollection) -> String {
+    match collection {
+        BundleCollection::Chapters => t!("bundle_chapters"),
+        BundleCollection::Characters => t!("bundle_characters"),
+        BundleCollection::Attributes => t!("bundle_attributes"),
+        BundleCollection::Relationships => t!("bundle_relationships"),
+        BundleCollection::Paragraphs => t!("bundle_paragraphs"),
+        BundleCollection::Settings => t!("bundle_settings"),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Bundle,
+    Ink,
+    ChoiceScript,
+}
+
+/// Hands `content` to the browser as a file download. The content goes
+/// through a `Blob` URL, since `data:` URLs are capped well below the size of
+/// a large story.
+fn download_file(file_name: &str, mime: &str, content: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(&format!("{};charset=utf-8", mime));
+    let parts = js_sys::Array::of1(&content.into());
+    let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options) else {
+        return;
+    };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+        return;
+    };
+    if let Ok(link) = document.create_element("a") {
+        let _ = link.set_attribute("href", &url);
+        let _ = link.set_attribute("download", file_name);
+        if let Ok(link) = link.dyn_into::<web_sys::HtmlElement>() {
+            link.click();
+        }
+    }
+    // Revoked once the download has had time to start
+    gloo_timers::callback::Timeout::new(1_000, move || {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    })
+    .forget();
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StoryBundlePanelProps {
+    /// Language of imported Twee passages and of Ink and ChoiceScript exports.
+    pub lang: String,
+}
+
+/// Whole-story export to a JSON bundle, Ink or ChoiceScript, and import from
+/// a bundle or Twee 3 source.
+///
+/// An import is always planned first; the counts of records to create and
+/// update are shown before anything is written.
+#[component]
+pub fn StoryBundlePanel(props: StoryBundlePanelProps) -> Element {
+    let toast = use_toast();
+    let mut paragraph_state = use_context::<Signal<ParagraphState>>();
+    let mut chapter_state = use_context::<Signal<ChapterState>>();
+    let mut source = use_signal(String::new);
+    let mut remap = use_signal(|| false);
+    let mut is_twee = use_signal(|| false);
+    let mut warnings = use_signal(Vec::<TweeWarning>::new);
+    let mut export_issues = use_signal(Vec::<ExportIssue>::new);
+    let mut plan = use_signal(|| None::<ImportPlan>);
+    let mut busy = use_signal(|| false);
+
+    let export_failed_text = t!("bundle_export_failed");
+    let handle_export = {
+        let toast = toast.clone();
+        let lang = props.lang.clone();
+        move |format: ExportFormat| {
+            if *busy.peek() {
+                return;
+            }
+            busy.set(true);
+            let toast = toast.clone();
+            let lang = lang.clone();
+            let export_failed_text = export_failed_text.clone();
+            spawn_local(async move {
+                let client = RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default());
+                let now = chrono::Utc::now();
+                let stamp = now.format("%Y%m%d-%H%M");
+                match export_bundle(&client, &now.to_rfc3339()).await {
+                    Ok(bundle) => {
+                        let export = match format {
+                            ExportFormat::Bundle => StoryExport {
+                                files: vec![ExportFile {
+                                    name: "story.json".to_string(),
+                                    content: serde_json::to_string_pretty(&bundle)
+                                        .unwrap_or_default(),
+                                }],
+                                issues: illustration_issues(&bundle.paragraphs),
+                            },
+                            ExportFormat::Ink => export_ink(&bundle, &lang),
+                            ExportFormat::ChoiceScript => export_choicescript(&bundle, &lang),
+                        };
+                        let mime = if format == ExportFormat::Bundle {
+                            "application/json"
+                        } else {
+                            "text/plain"
+                        };
+                        for file in &export.files {
+                            download_file(&format!("{}-{}", stamp, file.name), mime, &file.content);
+                        }
+                        export_issues.set(export.issues);
+                    }
+                    Err(error) => {
+                        toast.push(
+                            ToastRequest::new(
+                                ToastKind::Error,
+                                format!("{} ({})", export_failed_text, error),
+                            )
+                            .with_timeout(Duration::from_millis(5000)),
+                        );
+                    }
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let invalid_text = t!("bundle_invalid");
+    let handle_preview = {
+        let toast = toast.clone();
+        let lang = props.lang.clone();
+        move |_| {
+            if *busy.peek() {
+                return;
+            }
+            let parsed = if *is_twee.peek() {
+                twee_to_bundle(
+                    &source.peek(),
+                    &lang,
+                    &chrono::Utc::now().to_rfc3339(),
+                    new_record_id,
+                )
+                .map(|import| {
+                    warnings.set(import.warnings);
+                    import.bundle
+                })
+                .map_err(|error| error.to_string())
+            } else {
+                warnings.set(Vec::new());
+                StoryBundle::parse(&source.peek()).map_err(|error| error.to_string())
+            };
+            let bundle = match parsed {
+                Ok(bundle) => bundle,
+                Err(error) => {
+                    toast.push(
+                        ToastRequest::new(
+                            ToastKind::Error,
+                            format!("{} ({})", invalid_text, error),
+                        )
+                        .with_timeout(Duration::from_millis(5000)),
+                    );
+                    return;
+                }
+            };
+            let strategy = if *remap.peek() {
+                IdStrategy::Remap
+            } else {
+                IdStrategy::Keep
+            };
+            busy.set(true);
+            let toast = toast.clone();
+            let invalid_text = invalid_text.clone();
+            spawn_local(async move {
+                let client = RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default());
+                match plan_bundle_import(&client, &bundle, strategy).await {
+                    Ok(planned) => plan.set(Some(planned)),
+                    Err(error) => {
+                        toast.push(
+                            ToastRequest::new(
+                                ToastKind::Error,
+                                format!("{} ({})", invalid_text, error),
+                            )
+                            .with_timeout(Duration::from_millis(5000)),
+                        );
+                    }
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let imported_text = t!("bundle_imported");
+    let import_failed_text = t!("bundle_import_failed");
+    let handle_import = move |_| {
+        if *busy.peek() {
+            return;
+        }
+        let Some(planned) = plan.peek().clone() else {
+            return;
+        };
+        busy.set(true);
+        let toast = toast.clone();
+        let imported_text = imported_text.clone();
+        let import_failed_text = import_failed_text.clone();
+        spawn_local(async move {
+            let client = RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default());
+            match apply_import(&client, &planned).await {
+                Ok(()) => {
+                    toast.push(
+                        ToastRequest::new(ToastKind::Success, imported_text)
+                            .with_timeout(Duration::from_millis(3000)),
+                    );
+                    plan.set(None);
+                    warnings.set(Vec::new());
+                    source.set(String::new());
+                }
+                Err(failure) => {
+                    toast.push(
+                        ToastRequest::new(
+                            ToastKind::Error,
+                            format!(
+                                "{} ({}/{}, {})",
+                                import_failed_text,
+                                failure.applied,
+                                planned.records.len(),
+                                failure.error
+                            ),
+                        )
+                        .with_timeout(Duration::from_millis(5000)),
+                    );
+                }
+            }
+            // Partial imports are reloaded too, so the editor matches the server
+            if let Ok(story) = export_bundle(&client, "").await {
+                chapter_state.write().set_chapters(story.chapters);
+                paragraph_state.write().set_paragraphs(story.paragraphs);
+            }
+            busy.set(false);
+        });
+    };
+
+    let rows: Vec<(String, usize, usize)> = plan
+        .read()
+        .as_ref()
+        .map(|planned| {
+            BundleCollection::ALL
+                .iter()
+                .map(|&collection| {
+                    (
+                        collection_label(collection),
+                        planned.count(collection, RecordAction::Create),
+                        planned.count(collection, RecordAction::Update),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let planned_total = plan.read().as_ref().map(|p| p.records.len()).unwrap_or(0);
+
+    rsx! {
+        section {
+            class: "mb-6 rounded-lg border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] p-4 space-y-3 text-sm",
+            div {
+                class: "flex flex-wrap gap-2",
+                for (format, label) in [
+                    (ExportFormat::Bundle, t!("bundle_export")),
+                    (ExportFormat::Ink, t!("bundle_export_ink")),
+                    (ExportFormat::ChoiceScript, t!("bundle_export_choicescript")),
+                ] {
+                    button {
+                        key: "{format:?}",
+                        class: "px-4 py-2 rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] disabled:opacity-50 disabled:cursor-not-allowed",
+                        disabled: *busy.read(),
+                        onclick: {
+                            let mut handle_export = handle_export.clone();
+                            move |_| handle_export(format)
+                        },
+                        "{label}"
+                    }
+                }
+            }
+            if !export_issues.read().is_empty() {
+                div {
+                    p { class: "font-medium text-amber-700 dark:text-amber-400", {t!("bundle_export_issues", count: export_issues.read().len().to_string())} }
+                    ul {
+                        class: "mt-1 max-h-40 overflow-y-auto list-disc pl-5 text-xs text-gray-600 dark:text-gray-300 paper:text-[#374151]",
+                        for (index, issue) in export_issues.read().iter().enumerate() {
+                            li { key: "{index}", "{issue}" }
+                        }
+                    }
+                }
+            }
+            h3 { class: "font-medium", {t!("bundle_import_title")} }
+            input {
+                class: "block w-full text-sm",
+                r#type: "file",
+                accept: ".json,.twee,.tw,application/json,text/plain",
+                onchange: move |event: FormEvent| {
+                    let Some(file) = event.files().into_iter().next() else {
+                        return;
+                    };
+                    spawn(async move {
+                        match file.read_string().await {
+                            Ok(text) => {
+                                source.set(text);
+                                plan.set(None);
+                            }
+                            Err(error) => {
+                                tracing::error!(error = %error, "Failed to read bundle file");
+                            }
+                        }
+                    });
+                },
+            }
+            textarea {
+                class: "w-full h-32 px-3 py-2 font-mono text-xs rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                placeholder: t!("bundle_paste_placeholder"),
+                value: "{source}",
+                oninput: move |event: FormEvent| {
+                    source.set(event.value());
+                    plan.set(None);
+                },
+            }
+            label {
+                class: "flex items-center gap-2",
+                input {
+                    r#type: "checkbox",
+                    checked: *remap.read(),
+                    onchange: move |event: FormEvent| {
+                        remap.set(event.checked());
+                        plan.set(None);
+                    },
+                }
+                {t!("bundle_remap")}
+            }
+            label {
+                class: "flex items-center gap-2",
+                input {
+                    r#type: "checkbox",
+                    checked: *is_twee.read(),
+                    onchange: move |event: FormEvent| {
+                        is_twee.set(event.checked());
+                        plan.set(None);
+                    },
+                }
+                {t!("bundle_twee", lang: props.lang.clone())}
+            }
+            button {
+                class: "px-4 py-2 rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf] disabled:opacity-50 disabled:cursor-not-allowed",
+                disabled: *busy.read() || source.read().trim().is_empty(),
+                onclick: handle_preview,
+                {t!("bundle_dry_run")}
+            }
+            if !warnings.read().is_empty() {
+                div {
+                    p { class: "font-medium text-amber-700 dark:text-amber-400", {t!("bundle_twee_warnings", count: warnings.read().len().to_string())} }
+                    ul {
+                        class: "mt-1 max-h-40 overflow-y-auto list-disc pl-5 text-xs text-gray-600 dark:text-gray-300 paper:text-[#374151]",
+                        for (index, warning) in warnings.read().iter().enumerate() {
+                            li { key: "{index}", "{warning}" }
+                        }
+                    }
+                }
+            }
+            if plan.read().is_some() {
+                table {
+                    class: "w-full text-left",
+                    thead {
+                        tr {
+                            th { class: "py-1", {t!("bundle_collection")} }
+                            th { class: "py-1 text-right", {t!("bundle_create")} }
+                            th { class: "py-1 text-right", {t!("bundle_update")} }
+                        }
+                    }
+                    tbody {
+                        for (label, created, updated) in rows.iter() {
+                            tr {
+                                key: "{label}",
+                                td { class: "py-1", "{label}" }
+                                td { class: "py-1 text-right font-mono", "{created}" }
+                                td { class: "py-1 text-right font-mono", "{updated}" }
+                            }
+                        }
+                    }
+                }
+                button {
+                    class: "px-4 py-2 rounded-lg bg-blue-600 text-white hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: *busy.read() || planned_total == 0,
+                    onclick: handle_import,
+                    {t!("bundle_apply", count: planned_total.to_string())}
+                }
+            }
+        }
+    }
+}
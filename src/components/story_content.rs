@@ -1,14 +1,24 @@
 #![allow(unused_mut)]
+use crate::components::bookmarks::{BookmarkPanel, PositionKeeper};
+use crate::components::input_prompt::PromptField;
+use crate::components::markup_text::MarkupText;
+use crate::components::narration::NarratedText;
+use crate::components::story_map::{ProgressRecorder, StoryMap};
 use crate::contexts::language_context::LanguageState;
 use crate::contexts::settings_context::use_settings_context;
 use crate::contexts::story_context::use_story_context;
+use crate::enums::route::Route;
 use crate::layout::KeyboardState;
+use crate::models::bookmarks::page_turn_mode;
+use crate::models::countdown::should_pause;
+use crate::models::input_prompt::{InputPrompt, INPUT_ACTION};
 use crate::pages::story::paragraph_has_translation;
 use crate::services::indexeddb::{
     get_disabled_choices_from_indexeddb, set_disabled_choice_to_indexeddb,
 };
 #[cfg(target_arch = "wasm32")]
 use crate::services::indexeddb::set_setting_to_indexeddb;
+use crate::services::speech;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
 use serde::{Deserialize, Serialize};
@@ -50,6 +60,8 @@ pub struct StoryContentProps {
     pub reader_mode: bool,
     pub chapter_title: String,
     pub current_paragraph_id: Signal<String>,
+    /// Answer to an `input` choice, set just before `on_choice_click` fires.
+    pub prompt_answer: Signal<Option<String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -69,29 +81,15 @@ pub struct Action {
     pub to: Cow<'static, str>,
 }
 
-impl From<crate::components::translation_form::ParagraphChoice> for Choice {
-    fn from(choice: crate::components::translation_form::ParagraphChoice) -> Self {
+impl From<crate::models::paragraph::ParagraphChoice> for Choice {
+    fn from(choice: crate::models::paragraph::ParagraphChoice) -> Self {
         Self {
-            caption: String::new().into(), // caption is now generated by frontend
+            caption: String::new().into(), // caption comes from the `Text` entry of the current language
             action: Action {
-                type_: choice.get_type().into(),
-                key: choice.get_key(),
-                value: choice.get_value(),
-                to: choice.get_to().into(),
-            },
-        }
-    }
-}
-
-impl From<crate::models::story::Choice> for Choice {
-    fn from(choice: crate::models::story::Choice) -> Self {
-        Self {
-            caption: choice.caption.into(),
-            action: Action {
-                type_: choice.action.type_.into(),
-                key: choice.action.key,
-                value: choice.action.value,
-                to: choice.action.to.first().cloned().unwrap_or_default().into(),
+                type_: choice.type_.into(),
+                key: choice.key,
+                value: choice.value,
+                to: choice.to.first().cloned().unwrap_or_default().into(),
             },
         }
     }
@@ -141,15 +139,7 @@ pub fn StoryContentUI(props: StoryContentUIProps) -> Element {
             class: "prose-sm dark:prose-invert lg:prose-base mx-auto max-w-3xl p-8 text-gray-900 dark:text-white paper:text-[#1f2937] paper:prose paper:prose-base paper:bg-transparent bg-white dark:bg-transparent",
             div {
                 class: "whitespace-pre-wrap space-y-8 story-text-filter",
-                {props.paragraph.split('\n')
-                    .filter(|p| !p.trim().is_empty())
-                    .map(|p| rsx! {
-                        p {
-                            class: "indent-10 tracking-wide leading-relaxed text-justify paper:text-[#1f2937]",
-                            dangerous_inner_html: escape_html(p),
-                        }
-                    })
-                }
+                MarkupText { text: props.paragraph.clone() }
             }
             ol {
                 class: "mt-10 w-full md:w-fit list-decimal space-y-3",
@@ -183,6 +173,8 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
     let story_ctx = use_story_context();
     let settings_ctx = use_settings_context();
     let mut show_paper_tutorial = use_signal(|| false);
+    let mut narrating = use_signal(|| false);
+    let narration_supported = use_hook(speech::is_supported);
     let mut pointer_start = use_signal(|| None::<(f64, f64)>);
     let mut countdowns = props.countdowns.clone();
     let mut max_times = props.max_times.clone();
@@ -199,24 +191,7 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
     let paragraphs_vec = story_ctx.read().paragraphs.read().clone();
     let paragraphs_vec_keydown = paragraphs_vec.clone();
     let current_language_keydown = current_language.clone();
-    let theme_mode = settings_ctx
-        .read()
-        .settings
-        .get("theme_mode")
-        .cloned()
-        .unwrap_or_else(|| "auto".to_string());
-    let page_turn_mode = settings_ctx
-        .read()
-        .settings
-        .get("page_turn_mode")
-        .cloned()
-        .unwrap_or_else(|| {
-            if theme_mode == "paper" {
-                "horizontal".to_string()
-            } else {
-                "scroll".to_string()
-            }
-        });
+    let page_turn_mode = page_turn_mode(&settings_ctx.read().settings);
     let tutorial_message = match page_turn_mode.as_str() {
         "horizontal" => t!("paper_tutorial_body_left_right"),
         "vertical" => t!("paper_tutorial_body_up_down"),
@@ -299,6 +274,17 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
         });
     }
 
+    // Running countdowns follow a changed limit at their new speed
+    {
+        let story_ctx = story_ctx.clone();
+        use_effect(move || {
+            let rates = story_ctx.read().countdown_rates.read().clone();
+            for (index, rate) in rates.into_iter().enumerate() {
+                set_countdown_rate(index, rate);
+            }
+        });
+    }
+
     // Load disabled state from IndexedDB
     {
         let mut disabled_by_countdown = disabled_by_countdown.clone();
@@ -450,6 +436,41 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
         });
     }
 
+    // Track page visibility so countdowns hold while the reader is away
+    let page_hidden = use_signal(|| false);
+    let visibility_listener = {
+        let page_hidden = page_hidden.clone();
+        use_hook(move || {
+            let (_, document) = get_window_document()?;
+            let mut page_hidden = page_hidden.clone();
+            let doc = document.clone();
+            let handler = wasm_bindgen::closure::Closure::<dyn FnMut()>::new(move || {
+                if let Ok(mut guard) = page_hidden.try_write() {
+                    *guard = doc.hidden();
+                }
+            });
+            document
+                .add_event_listener_with_callback(
+                    "visibilitychange",
+                    handler.as_ref().unchecked_ref(),
+                )
+                .is_ok()
+                .then(|| std::rc::Rc::new(handler))
+        })
+    };
+    // The listener writes this component's signals, so it must not outlive it
+    use_drop(move || {
+        let (Some(handler), Some((_, document))) =
+            (visibility_listener.as_ref(), get_window_document())
+        else {
+            return;
+        };
+        let _ = document.remove_event_listener_with_callback(
+            "visibilitychange",
+            handler.as_ref().as_ref().unchecked_ref(),
+        );
+    });
+
     let is_settings_chapter = story_ctx.read().is_settings_chapter();
 
     let has_countdown = use_memo(move || countdowns.read().iter().any(|&c| c > 0));
@@ -716,7 +737,12 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
     };
 
     // Move is_countdown_paused reading outside the render loop to avoid scope issues
-    let countdown_paused = *is_countdown_paused.read();
+    let settings_open = *settings_ctx.read().panel_open.read();
+    let countdown_paused = should_pause(
+        *is_countdown_paused.read(),
+        *page_hidden.read(),
+        settings_open,
+    );
 
     // Check time_left setting
     let time_left_enabled = use_memo(move || {
@@ -1071,16 +1097,38 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
             }
             article {
                 class: "prose-sm dark:prose-invert lg:prose-base mx-auto max-w-3xl p-8 text-gray-900 dark:text-white paper:text-[#1f2937] paper:prose paper:prose-base paper:bg-transparent bg-white dark:bg-transparent",
+                if !is_settings_chapter {
+                    div {
+                        class: "flex justify-end gap-2 mb-4 not-prose",
+                        Link {
+                            to: Route::Achievements { lang: current_language.clone() },
+                            class: "text-sm px-3 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#d4c29a] text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                            onclick: move |evt: MouseEvent| evt.stop_propagation(),
+                            {t!("achievements")}
+                        }
+                        StoryMap { paragraph_id: current_paragraph_id_signal }
+                        BookmarkPanel { paragraph_id: current_paragraph_id_signal }
+                        if narration_supported {
+                            button {
+                                class: "text-sm px-3 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#d4c29a] text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                                aria_pressed: if narrating() { "true" } else { "false" },
+                                onclick: move |evt| {
+                                    evt.stop_propagation();
+                                    narrating.toggle();
+                                },
+                                if narrating() { {t!("narration_stop")} } else { {t!("narration_listen")} }
+                            }
+                        }
+                    }
+                    PositionKeeper { paragraph_id: current_paragraph_id_signal }
+                    ProgressRecorder { paragraph_id: current_paragraph_id_signal }
+                }
                 div {
                     class: "whitespace-pre-wrap space-y-8 story-text-filter",
-                    {props.paragraph.read().split('\n')
-                        .filter(|p| !p.trim().is_empty())
-                        .map(|p| rsx! {
-                            p {
-                                class: "indent-10 tracking-wide leading-relaxed text-justify",
-                                {p}
-                            }
-                        })
+                    if narrating() && !is_settings_chapter {
+                        NarratedText { paragraph: props.paragraph, lang: current_language.clone() }
+                    } else {
+                        MarkupText { text: props.paragraph.read().clone() }
                     }
                 }
                 if is_settings_chapter || !props.reader_mode {
@@ -1102,13 +1150,18 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
                                     .unwrap_or(false)
                                 && has_translation;
                             let is_selected = keyboard_state.read().selected_index == index as i32;
+                            let prompt = (choice.action.type_ == INPUT_ACTION)
+                                .then(|| choice.action.value.as_ref().and_then(InputPrompt::from_value))
+                                .flatten();
+                            let is_prompt = prompt.is_some();
                             let on_click = {
                                 let goto = goto.clone();
                                 let on_choice_click = on_choice_click.clone();
                                 let mut keyboard_state = keyboard_state.clone();
                                 move |evt: Event<MouseData>| {
                                     evt.stop_propagation();
-                                    if is_enabled {
+                                    // Input choices continue only through their field's submit
+                                    if is_enabled && !is_prompt {
                                         keyboard_state.write().selected_index = index as i32;
                                         on_choice_click.call((goto.to_string(), index));
                                     }
@@ -1116,6 +1169,7 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
                             };
                             let countdown = countdowns.read().get(index).copied().unwrap_or(0);
                             let max_time = max_times.read().get(index).copied().unwrap_or(0);
+                            let countdown_rate = story_ctx.read().countdown_rates.read().get(index).copied().unwrap_or(1.0);
                             // Use paragraph content hash to ensure each paragraph's animation is independent
                             let mut hasher = DefaultHasher::new();
                             props.paragraph.read().hash(&mut hasher);
@@ -1144,7 +1198,26 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
                                         )
                                     }},
                                     onclick: on_click,
-                                    span { class: "mr-2", {caption.as_ref()} }
+                                    if let Some(prompt) = prompt {
+                                        PromptField {
+                                            prompt,
+                                            caption: caption.to_string(),
+                                            enabled: is_enabled,
+                                            on_submit: {
+                                                let goto = goto.clone();
+                                                let on_choice_click = on_choice_click.clone();
+                                                let mut prompt_answer = props.prompt_answer;
+                                                let mut keyboard_state = keyboard_state.clone();
+                                                move |answer: String| {
+                                                    keyboard_state.write().selected_index = index as i32;
+                                                    prompt_answer.set(Some(answer));
+                                                    on_choice_click.call((goto.to_string(), index));
+                                                }
+                                            },
+                                        }
+                                    } else {
+                                        span { class: "mr-2", {caption.as_ref()} }
+                                    }
                                     { ( *disabled_state_loaded.read() && countdown > 0 && !disabled_by_countdown.try_read()
                                         .map(|guard| guard.get(index).copied().unwrap_or(false))
                                         .unwrap_or(false)
@@ -1158,6 +1231,8 @@ pub fn StoryContent(props: StoryContentProps) -> Element {
                                                 "animation: {} linear {} forwards;animation-play-state:{};animation-delay:0.2s;",
                                                 animation_name, duration, animation_play_state
                                             ),
+                                            "data-countdown": "{index}",
+                                            onanimationstart: move |_| set_countdown_rate(index, countdown_rate),
                                             onanimationend: move |_| {
                                                 // 只有在 animation 正常運行 (非 paused) 狀態下，才在結束時停用選項
                                                 if !countdown_paused {
@@ -1279,3 +1354,30 @@ pub fn is_scrolled_to_top(scroll_top: i32, is_mobile: bool) -> bool {
   .opacity-100 { opacity: 1; }
 }
 */
+
+/// Sets the speed of choice `index`'s countdown bar; the bar keeps the
+/// progress it made.
+fn set_countdown_rate(index: usize, rate: f64) {
+    let Some(bar) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| {
+            document
+                .query_selector(&format!("[data-countdown=\"{index}\"]"))
+                .ok()
+                .flatten()
+        })
+    else {
+        return;
+    };
+    let Some(get_animations) = js_sys::Reflect::get(&bar, &"getAnimations".into())
+        .ok()
+        .and_then(|value| value.dyn_into::<js_sys::Function>().ok())
+    else {
+        return;
+    };
+    if let Ok(animations) = get_animations.call0(&bar) {
+        for animation in js_sys::Array::from(&animations).iter() {
+            let _ = js_sys::Reflect::set(&animation, &"playbackRate".into(), &rate.into());
+        }
+    }
+}
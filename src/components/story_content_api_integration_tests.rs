@@ -87,6 +87,7 @@ mod integration_tests {
                         "Continue forward".to_string(),
                         "Return to exit".to_string(),
                     ],
+                    ..Default::default()
                 },
                 Text {
                     lang: "en".to_string(),
@@ -96,10 +97,11 @@ mod integration_tests {
                         "Continue forward".to_string(),
                         "Return to exit".to_string(),
                     ],
+                    ..Default::default()
                 },
             ],
             choices: vec![
-                ParagraphChoice::Complex {
+                ParagraphChoice {
                     to: vec!["investigate_scene".to_string()],
                     type_: "goto".to_string(),
                     key: None,
@@ -109,8 +111,8 @@ mod integration_tests {
                     timeout_to: None,
                     impacts: None,
                 },
-                ParagraphChoice::Simple(vec!["corridor_ahead".to_string()]),
-                ParagraphChoice::Complex {
+                ParagraphChoice::goto(vec!["corridor_ahead".to_string()]),
+                ParagraphChoice {
                     to: vec!["castle_entrance".to_string()],
                     type_: "goto".to_string(),
                     key: Some("visited".to_string()),
@@ -121,6 +123,7 @@ mod integration_tests {
                     impacts: None,
                 },
             ],
+            ..Default::default()
         };
 
         // 2. Create Mock API client
@@ -227,18 +230,21 @@ mod integration_tests {
             lang: "zh-TW".to_string(),
             paragraphs: "A mysterious atmosphere permeates the magical forest".to_string(),
             choices: vec!["Use magic".to_string(), "Observe quietly".to_string()],
+            ..Default::default()
         };
 
         let en_text = Text {
             lang: "en".to_string(),
             paragraphs: "A mysterious atmosphere permeates the magical forest".to_string(),
             choices: vec!["Use magic".to_string(), "Observe quietly".to_string()],
+            ..Default::default()
         };
 
         let ja_text = Text {
             lang: "ja".to_string(),
             paragraphs: "魔法の森には神秘的な雰囲気が漂っている".to_string(),
             choices: vec!["魔法を使う".to_string(), "静かに観察する".to_string()],
+            ..Default::default()
         };
 
         let multilingual_paragraph = Paragraph {
@@ -246,7 +252,7 @@ mod integration_tests {
             chapter_id: "ch2".to_string(),
             texts: vec![zh_text, en_text, ja_text],
             choices: vec![
-                ParagraphChoice::Complex {
+                ParagraphChoice {
                     to: vec!["magic_scene".to_string()],
                     type_: "set".to_string(),
                     key: Some("magic_used".to_string()),
@@ -256,8 +262,9 @@ mod integration_tests {
                     timeout_to: None,
                     impacts: None,
                 },
-                ParagraphChoice::Simple(vec!["observation_scene".to_string()]),
+                ParagraphChoice::goto(vec!["observation_scene".to_string()]),
             ],
+            ..Default::default()
         };
 
         let mock_client = MockApiClient::new().with_paragraphs(vec![multilingual_paragraph]);
@@ -321,9 +328,10 @@ mod integration_tests {
                     "Cast spell".to_string(),
                     "Escape".to_string(),
                 ],
+                ..Default::default()
             }],
             choices: vec![
-                ParagraphChoice::Complex {
+                ParagraphChoice {
                     to: vec!["attack_scene".to_string()],
                     type_: "goto".to_string(),
                     key: None,
@@ -333,7 +341,7 @@ mod integration_tests {
                     timeout_to: None,
                     impacts: None,
                 },
-                ParagraphChoice::Complex {
+                ParagraphChoice {
                     to: vec!["cover_scene".to_string()],
                     type_: "goto".to_string(),
                     key: None,
@@ -343,7 +351,7 @@ mod integration_tests {
                     timeout_to: None,
                     impacts: None,
                 },
-                ParagraphChoice::Complex {
+                ParagraphChoice {
                     to: vec!["spell_scene".to_string()],
                     type_: "set".to_string(),
                     key: Some("spell_cast".to_string()),
@@ -353,8 +361,9 @@ mod integration_tests {
                     timeout_to: None,
                     impacts: None,
                 },
-                ParagraphChoice::Simple(vec!["escape_scene".to_string()]),
+                ParagraphChoice::goto(vec!["escape_scene".to_string()]),
             ],
+            ..Default::default()
         };
 
         let mock_client = MockApiClient::new().with_paragraphs(vec![time_limit_paragraph]);
@@ -430,11 +439,12 @@ mod integration_tests {
             texts: vec![Text {
                 lang: "zh-TW".to_string(),
                 paragraphs: "Edge case test".to_string(),
-                choices: vec![], // Empty choice text
+                choices: vec![], // Empty choice text,
+                ..Default::default()
             }],
             choices: vec![
-                ParagraphChoice::Simple(vec![]), // Empty target
-                ParagraphChoice::Complex {
+                ParagraphChoice::goto(vec![]), // Empty target
+                ParagraphChoice {
                     to: vec!["target1".to_string(), "target2".to_string()], // Multiple targets
                     type_: "multi_goto".to_string(),
                     key: None,
@@ -445,6 +455,7 @@ mod integration_tests {
                     impacts: None,
                 },
             ],
+            ..Default::default()
         };
 
         let choices = paragraph_to_choices(&edge_case_paragraph, "zh-TW");
@@ -843,3 +843,46 @@ mod regression_tests {
         assert!(html.contains("cursor-not-allowed"));
     }
 }
+
+#[cfg(test)]
+mod markup_tests {
+    use super::*;
+
+    #[test]
+    fn test_markup_renders_as_elements() {
+        let props = StoryContentUIProps {
+            paragraph: "@Mara: *Run*, **now**.[^why]\n* * *\n[^why]: The tide.".to_string(),
+            choices: vec![],
+            enabled_choices: HashSet::new(),
+            disabled_by_countdown: vec![],
+            chapter_title: "Markup".to_string(),
+        };
+
+        let html = render_story_content_ui(props);
+        assert!(html.contains("Mara"));
+        assert!(html.contains("<em>Run</em>"));
+        assert!(html.contains("<strong>now</strong>"));
+        assert!(html.contains("role=\"separator\""));
+        assert!(html.contains("The tide."));
+        assert!(!html.contains("[^why]"));
+    }
+
+    #[test]
+    fn test_markup_cannot_inject_html() {
+        let props = StoryContentUIProps {
+            paragraph: "*<script>alert(1)</script>*\n@<img src=x onerror=alert(2)>: **<b>hi</b>**"
+                .to_string(),
+            choices: vec![],
+            enabled_choices: HashSet::new(),
+            disabled_by_countdown: vec![],
+            chapter_title: "Injection".to_string(),
+        };
+
+        let html = render_story_content_ui(props);
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<img"));
+        assert!(!html.contains("<b>"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(html.contains("<em>"));
+    }
+}
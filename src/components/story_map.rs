@@ -0,0 +1,184 @@
+use crate::components::branch_graph::BranchGraph;
+use crate::components::chapter_selector::ChapterSelector;
+use crate::contexts::language_context::LanguageState;
+use crate::contexts::story_context::use_story_context;
+use crate::models::story_graph::StoryGraph;
+use crate::pages::story::Paragraph;
+use crate::services::progress::{load_choice_history, load_progress, save_progress};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+/// `ids` without those of the settings chapter, which is not part of the
+/// story.
+fn story_ids<'a>(ids: &'a [String], paragraphs: &'a [Paragraph]) -> impl Iterator<Item = &'a str> {
+    ids.iter().map(String::as_str).filter(|id| {
+        !paragraphs
+            .iter()
+            .any(|p| p.id == *id && p.chapter_id == "settingschapter")
+    })
+}
+
+/// Keeps the reader's progress record up to date: on first load it folds in
+/// the stored choice history, then every page shown is added. A stored record
+/// that cannot be read is never saved over. Renders nothing.
+#[component]
+pub fn ProgressRecorder(paragraph_id: Signal<String>) -> Element {
+    let story_ctx = use_story_context();
+    let mut loaded = use_signal(|| false);
+    let mut loading = use_signal(|| false);
+    let mut can_save = use_signal(|| false);
+
+    use_effect(move || {
+        let story = story_ctx.read();
+        let paragraphs = story.paragraphs.read().clone();
+        if paragraphs.is_empty() {
+            return;
+        }
+        let mut ids = story.choice_ids.read().clone();
+        ids.push(paragraph_id.read().clone());
+        let mut progress = story.progress;
+        let mut progress_loaded = story.progress_loaded;
+
+        if *loaded.peek() {
+            if progress
+                .write()
+                .record(story_ids(&ids, &paragraphs), &paragraphs)
+                && *can_save.peek()
+            {
+                let snapshot = progress.peek().clone();
+                spawn(async move { save_progress(&snapshot).await });
+            }
+            return;
+        }
+        if *loading.peek() {
+            return;
+        }
+        loading.set(true);
+        spawn(async move {
+            let stored = load_progress().await;
+            ids.extend(load_choice_history().await);
+            let readable = stored.is_ok();
+            let mut recorded = stored.unwrap_or_default();
+            recorded.record(story_ids(&ids, &paragraphs), &paragraphs);
+            if readable {
+                save_progress(&recorded).await;
+                can_save.set(true);
+            }
+            progress.set(recorded);
+            loaded.set(true);
+            progress_loaded.set(true);
+        });
+    });
+
+    rsx! {}
+}
+
+/// Button opening a map of the paragraphs the reader has discovered, chapter
+/// by chapter. Unvisited paragraphs and the choices leading to them stay
+/// hidden.
+#[component]
+pub fn StoryMap(paragraph_id: Signal<String>) -> Element {
+    let story_ctx = use_story_context();
+    let language_state = use_context::<Signal<LanguageState>>();
+    let mut open = use_signal(|| false);
+    // Chapter shown on the map; empty for the current one
+    let mut mapped_chapter = use_signal(String::new);
+    let mut selector_open = use_signal(|| false);
+    let mut search_query = use_signal(String::new);
+
+    let current = paragraph_id.read().clone();
+    let story = story_ctx.read();
+    let paragraphs = story.paragraphs.read();
+    let progress = story.progress.read();
+    let current_chapter = paragraphs
+        .iter()
+        .find(|p| p.id == current)
+        .map(|p| p.chapter_id.clone())
+        .unwrap_or_default();
+    let shown_chapter = if mapped_chapter.read().is_empty() {
+        current_chapter
+    } else {
+        mapped_chapter.read().clone()
+    };
+    // Chapters without a visited paragraph are not listed at all
+    let mut chapters: Vec<_> = story
+        .chapters
+        .read()
+        .iter()
+        .filter(|c| {
+            c.id != "settingschapter"
+                && paragraphs
+                    .iter()
+                    .any(|p| p.chapter_id == c.id && progress.has_visited(&p.id))
+        })
+        .cloned()
+        .collect();
+    chapters.sort_by_key(|c| c.order);
+    let percentages = progress.percentages(&paragraphs);
+    let chapter_progress = progress.chapter(&paragraphs, &shown_chapter);
+    let lang = language_state.read().current_language.clone();
+    let graph = if open() {
+        StoryGraph::discovered(&paragraphs, &shown_chapter, &lang, |id| {
+            progress.has_visited(id)
+        })
+    } else {
+        StoryGraph::default()
+    };
+
+    rsx! {
+        div {
+            class: "relative",
+            button {
+                class: "text-sm px-3 py-1 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#d4c29a] text-gray-700 dark:text-gray-300 paper:text-[#374151] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                aria_expanded: if open() { "true" } else { "false" },
+                onclick: move |evt| {
+                    evt.stop_propagation();
+                    open.toggle();
+                },
+                {t!("story_map")}
+            }
+            if open() {
+                div {
+                    class: "page-turn-block absolute right-0 z-20 mt-2 w-[40rem] max-w-[90vw] p-4 space-y-3 rounded-lg shadow-lg bg-white dark:bg-gray-800 paper:bg-[#fef8e7] border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2]",
+                    onclick: move |evt| evt.stop_propagation(),
+                    onkeydown: move |evt| evt.stop_propagation(),
+                    ChapterSelector {
+                        label: t!("story_map"),
+                        value: shown_chapter.clone(),
+                        chapters,
+                        is_open: selector_open(),
+                        search_query: search_query.read().clone(),
+                        on_toggle: move |_| selector_open.toggle(),
+                        on_search: move |query| search_query.set(query),
+                        on_select: move |chapter: crate::models::chapter::Chapter| {
+                            mapped_chapter.set(chapter.id);
+                            selector_open.set(false);
+                        },
+                        progress: percentages,
+                    }
+                    p {
+                        class: "text-sm text-gray-600 dark:text-gray-400 paper:text-[#4b5563]",
+                        {t!(
+                            "story_map_summary",
+                            visited: chapter_progress.visited.to_string(),
+                            total: chapter_progress.total.to_string(),
+                            endings_reached: chapter_progress.endings_reached.to_string(),
+                            endings: chapter_progress.endings.to_string()
+                        )}
+                    }
+                    if graph.nodes.is_empty() {
+                        p { class: "text-sm text-gray-500 dark:text-gray-400 paper:text-[#6b7280]", {t!("story_map_empty")} }
+                    } else {
+                        BranchGraph {
+                            graph,
+                            selected_id: Some(current.clone()),
+                            editable: false,
+                            on_select: move |_| {},
+                            on_retarget: move |_| {},
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
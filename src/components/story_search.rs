@@ -0,0 +1,171 @@
+use crate::components::language_selector::AVAILABLE_LANGUAGES;
+use crate::contexts::chapter_context::ChapterState;
+use crate::contexts::paragraph_context::ParagraphState;
+use crate::models::search::{MatchField, SearchFilters, SearchIndex};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+/// Hits rendered at once; the count above the list still reports all of them.
+const MAX_RESULTS: usize = 100;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StorySearchProps {
+    /// Language used for chapter titles.
+    pub lang: String,
+    /// Called with the id of the paragraph picked from the results.
+    pub on_select: EventHandler<String>,
+}
+
+/// Full-text search over every paragraph of the story.
+///
+/// The index is rebuilt only when the paragraphs change, so typing a query
+/// only walks the posting lists.
+#[component]
+pub fn StorySearch(props: StorySearchProps) -> Element {
+    let paragraph_state = use_context::<Signal<ParagraphState>>();
+    let chapter_state = use_context::<Signal<ChapterState>>();
+    let mut query = use_signal(String::new);
+    let mut chapter_id = use_signal(String::new);
+    let mut missing_lang = use_signal(String::new);
+    let mut has_impacts = use_signal(|| false);
+    let mut has_timer = use_signal(|| false);
+
+    let index = use_memo(move || SearchIndex::new(&paragraph_state.read().paragraphs));
+    let hits = use_memo(move || {
+        let non_empty = |value: &String| (!value.is_empty()).then(|| value.clone());
+        let filters = SearchFilters {
+            chapter_id: non_empty(&chapter_id.read()),
+            missing_lang: non_empty(&missing_lang.read()),
+            has_impacts: *has_impacts.read(),
+            has_timer: *has_timer.read(),
+        };
+        index.read().search(&query.read(), &filters)
+    });
+
+    let chapters = chapter_state.read().chapters.clone();
+    let lang = props.lang.clone();
+    let title_of = move |id: &str| {
+        chapters
+            .iter()
+            .find(|c| c.id == id)
+            .and_then(|c| {
+                c.title_for(&lang)
+                    .or_else(|| c.titles.first().map(|t| t.title.as_str()))
+            })
+            .unwrap_or(id)
+            .to_string()
+    };
+    let chapter_options: Vec<(String, String)> = chapter_state
+        .read()
+        .chapters
+        .iter()
+        .map(|c| (c.id.clone(), title_of(&c.id)))
+        .collect();
+    let field_label = |field: MatchField| match field {
+        MatchField::Body => t!("search_field_body"),
+        MatchField::Caption(index) => {
+            t!("search_field_caption", number: (index + 1).to_string())
+        }
+    };
+    let total = hits.read().len();
+
+    rsx! {
+        section {
+            class: "mb-6 rounded-lg border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] p-4 space-y-3 text-sm",
+            input {
+                class: "w-full h-10 px-3 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                r#type: "search",
+                placeholder: t!("search_placeholder"),
+                value: "{query}",
+                oninput: move |event: FormEvent| query.set(event.value()),
+            }
+            div {
+                class: "flex flex-wrap items-center gap-3",
+                select {
+                    class: "h-9 px-2 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                    value: chapter_id.read().clone(),
+                    oninput: move |event: FormEvent| chapter_id.set(event.value()),
+                    option { value: "", {t!("search_all_chapters")} }
+                    for (id, title) in chapter_options.iter() {
+                        option { key: "{id}", value: id.clone(), "{title}" }
+                    }
+                }
+                select {
+                    class: "h-9 px-2 rounded-md border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] bg-white dark:bg-gray-700 paper:bg-[#fefaf0]",
+                    value: missing_lang.read().clone(),
+                    oninput: move |event: FormEvent| missing_lang.set(event.value()),
+                    option { value: "", {t!("search_any_translation")} }
+                    for language in AVAILABLE_LANGUAGES.iter() {
+                        option {
+                            key: "{language.code}",
+                            value: language.code,
+                            {t!("search_missing_translation", lang: language.name.to_string())}
+                        }
+                    }
+                }
+                label {
+                    class: "flex items-center gap-2",
+                    input {
+                        r#type: "checkbox",
+                        checked: *has_impacts.read(),
+                        onchange: move |event: FormEvent| has_impacts.set(event.checked()),
+                    }
+                    {t!("search_has_impacts")}
+                }
+                label {
+                    class: "flex items-center gap-2",
+                    input {
+                        r#type: "checkbox",
+                        checked: *has_timer.read(),
+                        onchange: move |event: FormEvent| has_timer.set(event.checked()),
+                    }
+                    {t!("search_has_timer")}
+                }
+            }
+            if total > 0 {
+                p {
+                    class: "text-gray-500 dark:text-gray-400 paper:text-[#4b5563]",
+                    {t!("search_result_count", count: total.to_string())}
+                }
+            } else if !query.read().trim().is_empty() {
+                p {
+                    class: "text-gray-500 dark:text-gray-400 paper:text-[#4b5563]",
+                    {t!("search_no_results")}
+                }
+            }
+            ul {
+                class: "max-h-96 overflow-y-auto divide-y divide-gray-200 dark:divide-gray-700 paper:divide-[#e4d5b2]",
+                for hit in hits.read().iter().take(MAX_RESULTS).cloned() {
+                    li {
+                        key: "{hit.paragraph_id}",
+                        class: "py-2 px-2 cursor-pointer hover:bg-gray-50 dark:hover:bg-gray-700/50 paper:hover:bg-[#f6edda]",
+                        onclick: {
+                            let id = hit.paragraph_id.clone();
+                            move |_| props.on_select.call(id.clone())
+                        },
+                        div {
+                            class: "flex items-center justify-between gap-2",
+                            span { class: "font-mono text-xs", "{hit.paragraph_id}" }
+                            span {
+                                class: "truncate text-xs text-gray-500 dark:text-gray-400 paper:text-[#4b5563]",
+                                {title_of(&hit.chapter_id)}
+                            }
+                        }
+                        for (i, found) in hit.matches.iter().enumerate() {
+                            p {
+                                key: "{i}",
+                                class: "mt-1 text-gray-700 dark:text-gray-300 paper:text-[#374151]",
+                                span {
+                                    class: "mr-2 text-xs font-mono text-gray-500 dark:text-gray-400 paper:text-[#4b5563]",
+                                    "{found.lang} · "
+                                    {field_label(found.field.clone())}
+                                }
+                                "{found.snippet}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,78 +1,14 @@
 use crate::components::form::{ChoiceOptions, TextareaField};
 use crate::components::paragraph_list::{Paragraph as ParagraphListItem, ParagraphList};
 use crate::contexts::chapter_context::Chapter;
+use crate::hooks::choices_reducer::Choice;
 use crate::models::impacts::Impact;
+use crate::models::paragraph::Paragraph;
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
-use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[serde(untagged)]
-pub enum ParagraphChoice {
-    Complex {
-        to: String,
-        #[serde(rename = "type")]
-        type_: String,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        key: Option<String>,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        value: Option<serde_json::Value>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        same_page: Option<bool>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        time_limit: Option<u32>,
-    },
-    Simple(String),
-}
-
-impl ParagraphChoice {
-    pub fn get_to(&self) -> String {
-        match self {
-            ParagraphChoice::Complex { to, .. } => to.clone(),
-            ParagraphChoice::Simple(text) => text.clone(),
-        }
-    }
-
-    pub fn get_type(&self) -> String {
-        match self {
-            ParagraphChoice::Complex { type_, .. } => type_.clone(),
-            ParagraphChoice::Simple(_) => "goto".to_string(),
-        }
-    }
-
-    pub fn get_key(&self) -> Option<String> {
-        match self {
-            ParagraphChoice::Complex { key, .. } => key.clone(),
-            ParagraphChoice::Simple(_) => None,
-        }
-    }
-
-    pub fn get_value(&self) -> Option<serde_json::Value> {
-        match self {
-            ParagraphChoice::Complex { value, .. } => value.clone(),
-            ParagraphChoice::Simple(_) => None,
-        }
-    }
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct Paragraph {
-    pub id: String,
-    #[serde(default)]
-    pub chapter_id: String,
-    pub texts: Vec<Text>,
-    pub choices: Vec<ParagraphChoice>,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct Text {
-    pub lang: String,
-    pub paragraphs: String,
-    pub choices: Vec<String>,
-}
-
 #[derive(Props, Clone, PartialEq)]
 pub struct TranslationFormProps {
     paragraphs: String,
@@ -118,21 +54,7 @@ pub fn TranslationForm(props: TranslationFormProps) -> Element {
     let _available_paragraphs = props.available_paragraphs.clone();
     let selected_paragraph = props.selected_paragraph.clone();
 
-    let mut choices = use_signal(|| {
-        Vec::<(
-            String,
-            Vec<String>,
-            String,
-            Option<String>,
-            Option<serde_json::Value>,
-            String,
-            bool,
-            Option<u32>,
-            Option<String>,
-            String,
-            Vec<Impact>,
-        )>::new()
-    });
+    let mut choices = use_signal(|| Vec::<Choice>::new());
     let mut action_type_open = use_signal(|| vec![false]);
 
     let is_form_valid = {
@@ -191,48 +113,26 @@ pub fn TranslationForm(props: TranslationFormProps) -> Element {
                 ChoiceOptions {
                     choices: choices.read().clone(),
                     on_choice_change: move |(index, field, value): (usize, String, String)| {
-                        let mut choices_write = choices.write();
-                        match field.as_str() {
-                            "caption" => choices_write[index].0 = value,
-                            "goto" => choices_write[index].1 = value.split(',').map(|s| s.trim().to_string()).collect(),
-                            "action_type" => choices_write[index].2 = value,
-                            "action_key" => choices_write[index].3 = Some(value),
-                            "action_value" => choices_write[index].4 = Some(serde_json::Value::String(value)),
-                            "target_chapter" => choices_write[index].5 = value,
-                            "time_limit" => choices_write[index].7 = value.parse::<u32>().ok(),
-                            "timeout_to" => choices_write[index].8 = if value.trim().is_empty() { None } else { Some(value) },
-                            "timeout_target_chapter" => choices_write[index].9 = value,
-                            _ => {}
+                        if let Some(choice) = choices.write().get_mut(index) {
+                            choice.set_field(&field, value);
                         }
                     },
                     on_choice_add_paragraph: move |(index, paragraph_id): (usize, String)| {
                         let mut choices_write = choices.write();
                         if let Some(choice) = choices_write.get_mut(index) {
-                            if !choice.1.contains(&paragraph_id) {
-                                choice.1.push(paragraph_id);
+                            if !choice.stored.to.contains(&paragraph_id) {
+                                choice.stored.to.push(paragraph_id);
                             }
                         }
                     },
                     on_choice_remove_paragraph: move |(index, paragraph_id): (usize, String)| {
                         let mut choices_write = choices.write();
                         if let Some(choice) = choices_write.get_mut(index) {
-                            choice.1.retain(|id| id != &paragraph_id);
+                            choice.stored.to.retain(|id| id != &paragraph_id);
                         }
                     },
                     on_add_choice: move |_| {
-                        choices.write().push((
-                            String::new(),
-                            Vec::<String>::new(),
-                            String::new(),
-                            None,
-                            None,
-                            String::new(),
-                            false,
-                            None,
-                            None,
-                            String::new(),
-                            Vec::new(),
-                        ));
+                        choices.write().push(Choice::default());
                     },
                     on_remove_choice: move |index| {
                         choices.write().remove(index);
@@ -241,7 +141,7 @@ pub fn TranslationForm(props: TranslationFormProps) -> Element {
                     relationships: Vec::new(),
                     on_impacts_change: move |(index, impacts): (usize, Vec<Impact>)| {
                         if let Some(choice) = choices.write().get_mut(index) {
-                            choice.10 = impacts.clone();
+                            choice.set_impacts(impacts.clone());
                         }
                     },
                     available_chapters: available_chapters.read().clone(),
@@ -104,8 +104,19 @@ pub static ATTRIBUTES: &str = "/collections/attributes/records";
 pub static RELATIONSHIPS: &str = "/collections/relationships/records";
 #[allow(dead_code)]
 pub static PUBLIC_COLLECTIONS: &str = "/collections/public";
-#[allow(dead_code)]
-pub static AUTH_TOKEN: &str = ""; // No auth token needed, as collections should be accessible to any user
+pub static SETTINGS: &str = "/collections/settings/records";
+pub static BATCH: &str = "/batch";
+pub static PARAGRAPH_REVISIONS: &str = "/collections/paragraph_revisions/records";
+pub static INVITES: &str = "/collections/invites/records";
+/// Custom route from `pocketbase/hooks/invites.pb.js`.
+pub static INVITES_CHECK: &str = "/invites/check";
+pub static USERS: &str = "/collections/users/records";
+pub static USERS_AUTH_WITH_PASSWORD: &str = "/collections/users/auth-with-password";
+pub static USERS_AUTH_REFRESH: &str = "/collections/users/auth-refresh";
+pub static AUDIO_CUES: &str = "/collections/audio_cues/records";
+pub static ACHIEVEMENTS: &str = "/collections/achievements/records";
+/// File downloads: `{FILES}/{collection}/{record}/{file name}`.
+pub static FILES: &str = "/files";
 pub static LANGUAGES: [Language; 7] = [
     Language {
         name: "English (US)",
@@ -0,0 +1,34 @@
+use crate::models::role::Capabilities;
+use crate::services::auth::{current_session, AuthSession, AuthUser};
+use dioxus::prelude::*;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuthContext {
+    pub session: Option<AuthSession>,
+}
+
+impl AuthContext {
+    /// Picks up the session persisted by a previous visit.
+    pub fn restore() -> Self {
+        Self {
+            session: current_session(),
+        }
+    }
+
+    pub fn is_authenticated(&self) -> bool {
+        self.session.is_some()
+    }
+
+    pub fn user(&self) -> Option<&AuthUser> {
+        self.session.as_ref().map(|session| &session.record)
+    }
+
+    /// Signed-out visitors get the capabilities of a reader.
+    pub fn capabilities(&self) -> Capabilities {
+        self.user().map(AuthUser::capabilities).unwrap_or_default()
+    }
+}
+
+pub fn use_auth_context() -> Signal<AuthContext> {
+    use_context::<Signal<AuthContext>>()
+}
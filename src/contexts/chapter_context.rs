@@ -1,20 +1,8 @@
 use crate::constants::config::{base_api_url, CHAPTERS};
 use dioxus::prelude::*;
-use serde::{Deserialize, Serialize};
 use wasm_bindgen_futures::spawn_local;
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct Chapter {
-    pub id: String,
-    pub titles: Vec<ChapterTitle>,
-    pub order: i32,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct ChapterTitle {
-    pub lang: String,
-    pub title: String,
-}
+pub use crate::models::chapter::{Chapter, ChapterTitle};
 
 #[derive(Clone)]
 pub struct ChapterState {
@@ -34,6 +22,19 @@ impl ChapterState {
         self.chapters = chapters;
         self.loaded = true;
     }
+
+    /// Inserts or replaces `chapter` and keeps the list sorted by `order`.
+    pub fn upsert(&mut self, chapter: Chapter) {
+        match self.chapters.iter_mut().find(|c| c.id == chapter.id) {
+            Some(slot) => *slot = chapter,
+            None => self.chapters.push(chapter),
+        }
+        self.chapters.sort_by_key(|c| c.order);
+    }
+
+    pub fn remove(&mut self, chapter_id: &str) {
+        self.chapters.retain(|c| c.id != chapter_id);
+    }
 }
 
 #[derive(Props, Clone, PartialEq)]
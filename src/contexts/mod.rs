@@ -1,3 +1,4 @@
+pub mod auth_context;
 pub mod chapter_context;
 pub mod language_context;
 pub mod paragraph_context;
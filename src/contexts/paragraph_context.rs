@@ -1,126 +1,9 @@
-use crate::{
-    constants::config::{base_api_url, PARAGRAPHS},
-    models::impacts::Impact,
-};
+use crate::constants::config::{base_api_url, PARAGRAPHS};
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen_futures::spawn_local;
 
-// Reuse paragraph and text structures from translation_form
-// pub use crate::components::translation_form::{Paragraph as ContextParagraph, Text as ContextText, ParagraphChoice as ContextParagraphChoice};
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[serde(untagged)]
-pub enum ParagraphChoice {
-    Complex {
-        to: Vec<String>,
-        #[serde(rename = "type")]
-        type_: String,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        key: Option<String>,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        value: Option<serde_json::Value>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        same_page: Option<bool>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        time_limit: Option<u32>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        timeout_to: Option<String>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        impacts: Option<Vec<Impact>>,
-    },
-    ComplexOld {
-        to: String,
-        #[serde(rename = "type")]
-        type_: String,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        key: Option<String>,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        value: Option<serde_json::Value>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        same_page: Option<bool>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        time_limit: Option<u32>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        timeout_to: Option<String>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        impacts: Option<Vec<Impact>>,
-    },
-    Simple(Vec<String>),
-    SimpleOld(String),
-}
-
-#[allow(dead_code)]
-impl ParagraphChoice {
-    pub fn get_to(&self) -> Vec<String> {
-        match self {
-            ParagraphChoice::Complex { to, .. } => to.clone(),
-            ParagraphChoice::ComplexOld { to, .. } => vec![to.clone()],
-            ParagraphChoice::Simple(texts) => texts.clone(),
-            ParagraphChoice::SimpleOld(text) => vec![text.clone()],
-        }
-    }
-
-    pub fn get_type(&self) -> String {
-        match self {
-            ParagraphChoice::Complex { type_, .. } => type_.clone(),
-            ParagraphChoice::ComplexOld { type_, .. } => type_.clone(),
-            ParagraphChoice::Simple(_) => "goto".to_string(),
-            ParagraphChoice::SimpleOld(_) => "goto".to_string(),
-        }
-    }
-
-    pub fn get_key(&self) -> Option<String> {
-        match self {
-            ParagraphChoice::Complex { key, .. } => key.clone(),
-            ParagraphChoice::ComplexOld { key, .. } => key.clone(),
-            ParagraphChoice::Simple(_) => None,
-            ParagraphChoice::SimpleOld(_) => None,
-        }
-    }
-
-    pub fn get_value(&self) -> Option<serde_json::Value> {
-        match self {
-            ParagraphChoice::Complex { value, .. } => value.clone(),
-            ParagraphChoice::ComplexOld { value, .. } => value.clone(),
-            ParagraphChoice::Simple(_) => None,
-            ParagraphChoice::SimpleOld(_) => None,
-        }
-    }
-
-    pub fn get_same_page(&self) -> Option<bool> {
-        match self {
-            ParagraphChoice::Complex { same_page, .. } => *same_page,
-            ParagraphChoice::ComplexOld { same_page, .. } => *same_page,
-            ParagraphChoice::Simple(_) => None,
-            ParagraphChoice::SimpleOld(_) => None,
-        }
-    }
-
-    pub fn get_time_limit(&self) -> Option<u32> {
-        match self {
-            ParagraphChoice::Complex { time_limit, .. } => *time_limit,
-            ParagraphChoice::ComplexOld { time_limit, .. } => *time_limit,
-            ParagraphChoice::Simple(_) => None,
-            ParagraphChoice::SimpleOld(_) => None,
-        }
-    }
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct Paragraph {
-    pub id: String,
-    #[serde(default)]
-    pub chapter_id: String,
-    pub texts: Vec<Text>,
-    pub choices: Vec<ParagraphChoice>,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct Text {
-    pub lang: String,
-    pub paragraphs: String,
-    pub choices: Vec<String>,
-}
+pub use crate::models::paragraph::{Paragraph, ParagraphChoice, Text};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ParagraphData {
@@ -157,6 +40,22 @@ impl ParagraphState {
     pub fn get_by_id(&self, id: &str) -> Option<Paragraph> {
         self.paragraphs.iter().find(|p| p.id == id).cloned()
     }
+
+    /// Swaps in the server copy of a single paragraph after a partial update.
+    pub fn replace(&mut self, paragraph: Paragraph) {
+        if let Some(slot) = self.paragraphs.iter_mut().find(|p| p.id == paragraph.id) {
+            *slot = paragraph;
+        }
+    }
+
+    /// Adds records created on the server, e.g. copies of a subtree.
+    pub fn extend(&mut self, paragraphs: Vec<Paragraph>) {
+        self.paragraphs.extend(paragraphs);
+    }
+
+    pub fn remove(&mut self, ids: &[String]) {
+        self.paragraphs.retain(|p| !ids.contains(&p.id));
+    }
 }
 
 #[derive(Props, Clone, PartialEq)]
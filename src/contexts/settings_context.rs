@@ -6,6 +6,8 @@ pub struct SettingsContext {
     pub settings: HashMap<String, String>,
     pub loaded: bool,
     pub settings_done: Signal<bool>,
+    /// Whether the settings menu is open, which pauses countdowns.
+    pub panel_open: Signal<bool>,
 }
 
 pub fn use_settings_context() -> Signal<SettingsContext> {
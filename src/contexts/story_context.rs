@@ -1,4 +1,7 @@
 use crate::components::story_content::Choice;
+use crate::models::achievements::Achievement;
+use crate::models::audio::AudioCues;
+use crate::models::progress::ReadingProgress;
 use crate::pages::story::Paragraph;
 use dioxus::prelude::*;
 
@@ -8,10 +11,19 @@ pub struct StoryContext {
     pub current_choices: Vec<Choice>,
     pub target_paragraph_id: Option<String>,
     pub countdowns: Signal<Vec<u32>>,
+    /// Speed of each countdown, see [`RunningCountdowns`](crate::models::countdown::RunningCountdowns).
+    pub countdown_rates: Signal<Vec<f64>>,
     pub paragraphs: Signal<Vec<Paragraph>>,
     pub chapters: Signal<Vec<crate::pages::story::Chapter>>,
     pub is_settings_chapter: Signal<bool>,
     pub choice_ids: Signal<Vec<String>>,
+    pub audio_cues: Signal<AudioCues>,
+    pub progress: Signal<ReadingProgress>,
+    /// Set once `ProgressRecorder` has read the stored progress.
+    pub progress_loaded: Signal<bool>,
+    pub achievements: Signal<Vec<Achievement>>,
+    /// Author flag: timed choices ignore the reader's relaxed timers.
+    pub timers_mandatory: Signal<bool>,
 }
 
 impl StoryContext {
@@ -20,10 +32,16 @@ impl StoryContext {
             current_choices: Vec::new(),
             target_paragraph_id: None,
             countdowns: Signal::new(Vec::new()),
+            countdown_rates: Signal::new(Vec::new()),
             paragraphs: Signal::new(Vec::new()),
             chapters: Signal::new(Vec::new()),
             is_settings_chapter: Signal::new(false),
             choice_ids: Signal::new(Vec::new()),
+            audio_cues: Signal::new(AudioCues::default()),
+            progress: Signal::new(ReadingProgress::default()),
+            progress_loaded: Signal::new(false),
+            achievements: Signal::new(Vec::new()),
+            timers_mandatory: Signal::new(false),
         }
     }
 
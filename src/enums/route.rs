@@ -1,6 +1,8 @@
+use crate::models::role::Role;
 use crate::{
     layout::Layout,
     pages::{
+        achievements::Achievements,
         auth::{InviteCheckEmail, InviteRequest, Login, Register},
         dashboard::Dashboard,
         home::Home,
@@ -21,6 +23,9 @@ pub enum Route {
     #[route("/:lang")]
     Story { lang: String },
 
+    #[route("/:lang/achievements")]
+    Achievements { lang: String },
+
     #[route("/:lang/dashboard")]
     Dashboard { lang: String },
 
@@ -41,6 +46,14 @@ pub enum Route {
 }
 
 impl Route {
+    /// Minimum role needed to open this route; `None` means public.
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            Route::Dashboard { .. } => Some(Role::Translator),
+            _ => None,
+        }
+    }
+
     pub fn default_language() -> String {
         // Try to get browser language
         if let Some(window) = window() {
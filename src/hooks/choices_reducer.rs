@@ -1,107 +1,107 @@
 use crate::{
-    components::paragraph_list::Paragraph as ParagraphListParagraph, models::impacts::Impact,
+    components::paragraph_list::Paragraph as ParagraphListParagraph,
+    models::{
+        impacts::Impact,
+        input_prompt::{InputPrompt, INPUT_ACTION},
+        paragraph::ParagraphChoice,
+    },
 };
 use dioxus::prelude::*;
 use gloo_timers::callback::Timeout;
 use serde_json::Value;
 use std::rc::Rc;
 
-/// 單一選項的資料結構（對應原本 tuple）
-#[derive(Clone, PartialEq)]
+/// 單一選項的編輯狀態
+#[derive(Clone, PartialEq, Default)]
 pub struct Choice {
+    /// Caption in the language being edited.
     pub caption: String,
-    pub goto: Vec<String>,
-    pub action_type: String,
-    pub action_key: Option<String>,
-    pub action_value: Option<Value>,
+    /// Language-independent part, in the shape saved to `paragraphs`.
+    pub stored: ParagraphChoice,
+    /// Chapters the target pickers are browsing; never saved.
     pub target_chapter: String,
-    pub same_page: bool,
-    pub time_limit: Option<u32>,
-    pub timeout_to: Option<String>,
     pub timeout_target_chapter: String,
-    pub impacts: Vec<Impact>,
 }
 
-impl Default for Choice {
-    fn default() -> Self {
+impl Choice {
+    /// Builds the editor state for a stored choice; chapters are resolved by the caller.
+    pub fn from_paragraph_choice(
+        caption: String,
+        choice: &ParagraphChoice,
+        target_chapter: String,
+        timeout_target_chapter: String,
+    ) -> Self {
         Self {
-            caption: String::new(),
-            goto: Vec::new(),
-            action_type: String::new(),
-            action_key: None,
-            action_value: None,
-            target_chapter: String::new(),
-            same_page: false,
-            time_limit: None,
-            timeout_to: None,
-            timeout_target_chapter: String::new(),
-            impacts: Vec::new(),
+            caption,
+            stored: choice.clone(),
+            target_chapter,
+            timeout_target_chapter,
         }
     }
-}
 
-impl Choice {
-    /// Convert Choice into the tuple format originally used in Dashboard
-    pub fn to_tuple(
-        &self,
-    ) -> (
-        String,
-        Vec<String>,
-        String,
-        Option<String>,
-        Option<Value>,
-        String,
-        bool,
-        Option<u32>,
-        Option<String>,
-        String,
-        Vec<Impact>,
-    ) {
-        (
-            self.caption.clone(),
-            self.goto.clone(),
-            self.action_type.clone(),
-            self.action_key.clone(),
-            self.action_value.clone(),
-            self.target_chapter.clone(),
-            self.same_page,
-            self.time_limit,
-            self.timeout_to.clone(),
-            self.timeout_target_chapter.clone(),
-            self.impacts.clone(),
-        )
+    /// Language-independent part of the choice as it is stored in `paragraphs`.
+    pub fn to_paragraph_choice(&self) -> ParagraphChoice {
+        ParagraphChoice {
+            key: self.stored.key.clone().filter(|key| !key.is_empty()),
+            same_page: Some(self.same_page()),
+            impacts: self
+                .stored
+                .impacts
+                .clone()
+                .filter(|impacts| !impacts.is_empty()),
+            ..self.stored.clone()
+        }
     }
 
-    /// Build Choice from the original tuple format
-    pub fn from_tuple(
-        tup: (
-            String,
-            Vec<String>,
-            String,
-            Option<String>,
-            Option<Value>,
-            String,
-            bool,
-            Option<u32>,
-            Option<String>,
-            String,
-            Vec<Impact>,
-        ),
-    ) -> Self {
-        Self {
-            caption: tup.0,
-            goto: tup.1,
-            action_type: tup.2,
-            action_key: tup.3,
-            action_value: tup.4,
-            target_chapter: tup.5,
-            same_page: tup.6,
-            time_limit: tup.7,
-            timeout_to: tup.8,
-            timeout_target_chapter: tup.9,
-            impacts: tup.10,
+    pub fn same_page(&self) -> bool {
+        self.stored.same_page.unwrap_or(false)
+    }
+
+    pub fn impacts(&self) -> &[Impact] {
+        self.stored.impacts.as_deref().unwrap_or_default()
+    }
+
+    /// Applies an edit from [`ChoiceOptions`](crate::components::form::ChoiceOptions).
+    pub fn set_field(&mut self, field: &str, value: String) {
+        let stored = &mut self.stored;
+        match field {
+            "caption" => self.caption = value,
+            "goto" => {
+                stored.to = value.split(',').map(|s| s.trim().to_string()).collect();
+            }
+            "action_type" => {
+                // A new input choice starts from the default rules
+                let has_prompt = stored
+                    .value
+                    .as_ref()
+                    .and_then(InputPrompt::from_value)
+                    .is_some();
+                if value == INPUT_ACTION && !has_prompt {
+                    stored.value = Some(InputPrompt::default().to_value());
+                }
+                stored.type_ = value;
+            }
+            "action_key" => stored.key = Some(value),
+            "action_value" => stored.value = Some(Value::String(value)),
+            "action_prompt" => stored.value = serde_json::from_str(&value).ok(),
+            "target_chapter" => self.target_chapter = value,
+            "same_page" => stored.same_page = Some(value == "true"),
+            "time_limit" => stored.time_limit = value.parse().ok(),
+            "timeout_to" => {
+                if value.trim().is_empty() {
+                    stored.timeout_to = None;
+                } else {
+                    stored.timeout_to = Some(value);
+                }
+            }
+            "timeout_target_chapter" => self.timeout_target_chapter = value,
+            _ => {}
         }
     }
+
+    pub fn set_impacts(&mut self, impacts: Vec<Impact>) {
+        self.stored.impacts = Some(impacts);
+    }
 }
 
 /// 所有與 choice 相關的 UI 狀態
@@ -221,33 +221,12 @@ pub fn use_choices() -> (Signal<ChoicesState>, Rc<dyn Fn(Action)>) {
                 }
                 Action::SetField { idx, field, value } => {
                     if let Some(choice) = st.list.get_mut(idx) {
-                        match field {
-                            "caption" => choice.caption = value,
-                            "goto" => {
-                                choice.goto =
-                                    value.split(',').map(|s| s.trim().to_string()).collect();
-                            }
-                            "action_type" => choice.action_type = value,
-                            "action_key" => choice.action_key = Some(value),
-                            "action_value" => choice.action_value = Some(Value::String(value)),
-                            "target_chapter" => choice.target_chapter = value,
-                            "same_page" => choice.same_page = value == "true",
-                            "time_limit" => choice.time_limit = value.parse().ok(),
-                            "timeout_to" => {
-                                if value.trim().is_empty() {
-                                    choice.timeout_to = None;
-                                } else {
-                                    choice.timeout_to = Some(value);
-                                }
-                            }
-                            "timeout_target_chapter" => choice.timeout_target_chapter = value,
-                            _ => {}
-                        }
+                        choice.set_field(field, value);
                     }
                 }
                 Action::SetEffects { idx, impacts } => {
                     if let Some(choice) = st.list.get_mut(idx) {
-                        choice.impacts = impacts;
+                        choice.set_impacts(impacts);
                     }
                 }
 
@@ -2,6 +2,7 @@ use crate::{
     components::{navbar::Navbar, story_content::Choice},
     constants::config::{app_env_label, app_version_label, should_show_version_label},
     contexts::{
+        auth_context::use_auth_context,
         language_context::LanguageState,
         settings_context::use_settings_context,
         story_context::{use_story_context, StoryContext},
@@ -44,11 +45,23 @@ pub fn Layout() -> Element {
     let closure_signal = use_signal(|| None::<Closure<dyn FnMut(WebEvent)>>);
     let app_version = app_version_label();
     let app_env = app_env_label();
+    let auth_context = use_auth_context();
+
+    // 權限不足時不渲染受保護的頁面內容，改為導向登入頁
+    let login_redirect_lang = match (&route, route.required_role()) {
+        (Route::Dashboard { lang }, Some(role))
+            if !auth_context.read().capabilities().has_role(role) =>
+        {
+            Some(lang.clone())
+        }
+        _ => None,
+    };
 
     use_effect(move || {
         let lang = match &route {
             Route::Home {} => "zh-TW",
             Route::Story { lang }
+            | Route::Achievements { lang }
             | Route::Dashboard { lang }
             | Route::InviteRequest { lang }
             | Route::InviteCheckEmail { lang }
@@ -155,7 +168,11 @@ pub fn Layout() -> Element {
             Navbar { closure_signal: closure_signal }
             div {
                 class: "container mx-auto px-4 py-8",
-                Outlet::<Route> {}
+                if let Some(lang) = login_redirect_lang {
+                    LoginRedirect { lang }
+                } else {
+                    Outlet::<Route> {}
+                }
             }
             if should_show_version_label() {
                 div {
@@ -167,6 +184,15 @@ pub fn Layout() -> Element {
     }
 }
 
+#[component]
+fn LoginRedirect(lang: String) -> Element {
+    let navigator = use_navigator();
+    use_effect(move || {
+        navigator.replace(Route::Login { lang: lang.clone() });
+    });
+    rsx! {}
+}
+
 #[allow(dead_code)]
 fn handle_choice_selection(
     state: &KeyboardState,
@@ -18,12 +18,17 @@ mod services;
 mod utils;
 
 use crate::{
+    constants::config::{base_api_url, FILES},
     contexts::{
-        chapter_context::ChapterProvider, language_context::LanguageProvider,
-        paragraph_context::ParagraphProvider, settings_context::SettingsContext,
-        story_context::StoryContext,
+        auth_context::AuthContext,
+        chapter_context::ChapterProvider,
+        language_context::LanguageProvider,
+        paragraph_context::ParagraphProvider,
+        settings_context::SettingsContext,
+        story_context::{use_story_context, StoryContext},
     },
     enums::route::Route,
+    models::audio::AudioCues,
 };
 use dioxus::prelude::*;
 use dioxus::web;
@@ -120,6 +125,11 @@ fn main() {
         tracing_wasm::set_as_global_default();
         init_dom_logger();
         cache_initial_query_and_hash();
+        let worker_url = match staging_prefix() {
+            Some(prefix) => format!("/{prefix}/media-sw.js"),
+            None => "/media-sw.js".to_string(),
+        };
+        services::media_cache::register_service_worker(&worker_url);
     }
 
     // 這裡一定要指定 root id = "app-root"
@@ -130,11 +140,27 @@ fn main() {
 #[component]
 fn App() -> Element {
     let _settings_context = use_context_provider(|| Signal::new(SettingsContext::default()));
+    let _auth_context = use_context_provider(|| Signal::new(AuthContext::restore()));
 
     #[cfg(target_arch = "wasm32")]
     {
         use_effect(restore_initial_query_and_hash_if_stripped);
 
+        // 啟動時若 token 即將過期就換發，伺服器拒絕則登出
+        use_effect(move || {
+            let mut auth_context = _auth_context;
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = services::api::HttpApiClient::new();
+                let now = (js_sys::Date::now() / 1000.0) as i64;
+                match services::auth::refresh_if_needed(&client, now).await {
+                    Ok(session) => auth_context.write().session = session,
+                    Err(error) => {
+                        tracing::warn!(error = %error, "Auth refresh failed, keeping session");
+                    }
+                }
+            });
+        });
+
         let node_runtime = use_signal(GameRuntimeState::default);
         let story_nodes = use_signal(sample_story_nodes);
         let show_node_runtime_demo = should_show_node_runtime_demo();
@@ -151,18 +177,18 @@ fn App() -> Element {
                                     },
                                     Router::<Route> {}
                                 }
+                                // Inside the story context for its audio cues
+                                if should_show_node_runtime_demo() {
+                                    NodeRuntimePanel {
+                                        runtime: node_runtime,
+                                        nodes: story_nodes,
+                                    }
+                                }
                             }
                         }
                     }
                 }
             }
-
-            if should_show_node_runtime_demo() {
-                NodeRuntimePanel {
-                    runtime: node_runtime,
-                    nodes: story_nodes,
-                }
-            }
         }
     }
 
@@ -372,6 +398,7 @@ struct NodeOutcome {
 #[derive(Debug, Clone, PartialEq)]
 struct NodeResolveResult {
     next_node_id: String,
+    tier: EventOutcomeTier,
     outcome_tier: String,
     text: Option<String>,
     text_key: Option<String>,
@@ -403,6 +430,7 @@ fn NodeRuntimePanel(
     runtime: Signal<GameRuntimeState>,
     nodes: Signal<HashMap<String, StoryNode>>,
 ) -> Element {
+    let story_context = use_story_context();
     let state = runtime.read().clone();
     let outcome_tier = state
         .last_outcome_tier
@@ -425,8 +453,9 @@ fn NodeRuntimePanel(
             button {
                 class: "mt-3 rounded bg-blue-600 px-3 py-2 text-white",
                 onclick: move |_| {
+                    let audio_cues = story_context.read().audio_cues;
                     spawn(async move {
-                        run_current_node(runtime, nodes).await;
+                        run_current_node(runtime, nodes, audio_cues).await;
                     });
                 },
                 "下一步（runCurrentNode）"
@@ -444,6 +473,7 @@ fn goto_node(next_node_id: String, mut runtime: Signal<GameRuntimeState>) {
 async fn run_current_node(
     mut runtime: Signal<GameRuntimeState>,
     nodes: Signal<HashMap<String, StoryNode>>,
+    audio_cues: Signal<AudioCues>,
 ) {
     let current_node_id = runtime.read().current_node_id.clone();
     let node = nodes.read().get(&current_node_id).cloned();
@@ -472,6 +502,10 @@ async fn run_current_node(
 
     match resolve_node_check_and_jump(&node).await {
         Ok(result) => {
+            if let Some(cue) = audio_cues.read().tier_effect(None, &result.tier) {
+                let files_base = format!("{}{}", base_api_url(), FILES);
+                services::audio::play_effect(&cue.file_url(&files_base));
+            }
             runtime.with_mut(|state| {
                 state.current_text =
                     resolve_node_text(result.text.clone(), result.text_key.clone());
@@ -519,6 +553,7 @@ async fn resolve_node_check_and_jump(node: &StoryNode) -> Result<NodeResolveResu
 
     Ok(NodeResolveResult {
         next_node_id: outcome.next_node_id.clone(),
+        tier: event_run_result.resolution.outcome_tier.clone(),
         outcome_tier: tier_key,
         text: outcome.text.clone(),
         text_key: outcome.text_key.clone(),
@@ -0,0 +1,133 @@
+//! Achievements authors define to reward exploration.
+//!
+//! Records of the `achievements` collection hold a title and description per
+//! language and a `trigger`: an expression in the syntax of
+//! [`interpolation`](crate::models::interpolation), e.g.
+//! `flags.saved_the_boy && courage >= 60`. On top of settings, attributes and
+//! flags, a trigger can read the reader's progress:
+//!
+//! - `visited.<paragraph id>` and `ending.<paragraph id>`: whether that
+//!   paragraph was visited, or reached as an ending
+//! - `visited_count` and `endings_count`: how many were
+//!
+//! A trigger that does not parse never unlocks.
+
+use crate::models::interpolation::{parse_expr, StoryVariables, Value};
+use crate::models::progress::ReadingProgress;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementText {
+    pub lang: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Record in the `achievements` collection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Achievement {
+    pub id: String,
+    #[serde(default)]
+    pub texts: Vec<AchievementText>,
+    pub trigger: String,
+    /// Position in the gallery.
+    #[serde(default)]
+    pub order: i32,
+}
+
+fn progress_value(progress: &ReadingProgress, path: &[String]) -> Option<Value> {
+    match path {
+        [name] if name == "visited_count" => Some(Value::Number(progress.visited.len() as f64)),
+        [name] if name == "endings_count" => Some(Value::Number(progress.endings.len() as f64)),
+        [name, id] if name == "visited" => Some(Value::Bool(progress.visited.contains(id))),
+        [name, id] if name == "ending" => Some(Value::Bool(progress.endings.contains(id))),
+        _ => None,
+    }
+}
+
+impl Achievement {
+    /// Text in `lang`, else in English, else the first there is.
+    pub fn text_for(&self, lang: &str) -> Option<&AchievementText> {
+        self.texts
+            .iter()
+            .find(|t| t.lang == lang)
+            .or_else(|| self.texts.iter().find(|t| t.lang == "en-US"))
+            .or_else(|| self.texts.first())
+    }
+
+    /// Title in `lang`, falling back to the id.
+    pub fn title_for(&self, lang: &str) -> String {
+        self.text_for(lang)
+            .map(|t| t.title.clone())
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| self.id.clone())
+    }
+
+    pub fn is_met(&self, variables: &StoryVariables, progress: &ReadingProgress) -> bool {
+        match parse_expr(&self.trigger) {
+            Ok(expr) => variables
+                .eval_with(&expr, &|path| progress_value(progress, path))
+                .truthy(),
+            Err(error) => {
+                tracing::warn!(id = %self.id, error = %error, "Achievement trigger does not parse");
+                false
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Unlock {
+    pub id: String,
+    /// Milliseconds since the Unix epoch.
+    pub unlocked_at: i64,
+}
+
+/// Achievements this reader has unlocked, persisted as one JSON document in
+/// IndexedDB.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnlockedAchievements {
+    pub unlocks: Vec<Unlock>,
+}
+
+impl UnlockedAchievements {
+    pub fn from_json(raw: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(raw)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{\"unlocks\":[]}".to_string())
+    }
+
+    pub fn unlocked_at(&self, id: &str) -> Option<i64> {
+        self.unlocks
+            .iter()
+            .find(|u| u.id == id)
+            .map(|u| u.unlocked_at)
+    }
+
+    /// Unlocks every achievement whose trigger is now met, stamped `now`.
+    /// Returns the ones unlocked by this call, in the order given.
+    pub fn unlock_met<'a>(
+        &mut self,
+        achievements: &'a [Achievement],
+        variables: &StoryVariables,
+        progress: &ReadingProgress,
+        now: i64,
+    ) -> Vec<&'a Achievement> {
+        let mut unlocked = Vec::new();
+        for achievement in achievements {
+            if self.unlocked_at(&achievement.id).is_none()
+                && achievement.is_met(variables, progress)
+            {
+                self.unlocks.push(Unlock {
+                    id: achievement.id.clone(),
+                    unlocked_at: now,
+                });
+                unlocked.push(achievement);
+            }
+        }
+        unlocked
+    }
+}
@@ -0,0 +1,204 @@
+//! Audio cues attached to chapters and paragraphs.
+//!
+//! Cues are records of the `audio_cues` collection, each holding one sound
+//! in its `file` field:
+//!
+//! - `ambience` cues loop for as long as the reader stays in `chapter_id`
+//! - `effect` cues play once when the reader reaches `paragraph_id`, or,
+//!   when `tier` is set, when a check ends with that outcome (for that
+//!   paragraph, or anywhere when `paragraph_id` is empty)
+
+use crate::models::multi_attr_check::EventOutcomeTier;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Master volume from `0` to `1`.
+pub const AUDIO_VOLUME_SETTING: &str = "audio_volume";
+pub const AUDIO_MUTED_SETTING: &str = "audio_muted";
+
+/// How long two ambience loops overlap when the chapter changes.
+pub const CROSSFADE_MS: u32 = 1_500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CueKind {
+    Ambience,
+    Effect,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioCue {
+    pub id: String,
+    #[serde(rename = "collectionId", default)]
+    pub collection_id: String,
+    pub kind: CueKind,
+    #[serde(default)]
+    pub chapter_id: String,
+    #[serde(default)]
+    pub paragraph_id: String,
+    /// PocketBase stores an unset select as an empty string.
+    #[serde(default, with = "optional_tier")]
+    pub tier: Option<EventOutcomeTier>,
+    /// Stored file name.
+    pub file: String,
+}
+
+mod optional_tier {
+    use super::EventOutcomeTier;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        tier: &Option<EventOutcomeTier>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match tier {
+            Some(tier) => tier.serialize(serializer),
+            None => serializer.serialize_str(""),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<EventOutcomeTier>, D::Error> {
+        let raw = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+        if raw.is_empty() {
+            return Ok(None);
+        }
+        serde_json::from_value(serde_json::Value::String(raw))
+            .map(Some)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl AudioCue {
+    /// Download URL of the cue's file under `files_base`, the API's `/files`
+    /// endpoint.
+    pub fn file_url(&self, files_base: &str) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            files_base.trim_end_matches('/'),
+            self.collection_id,
+            self.id,
+            self.file
+        )
+    }
+}
+
+/// Every cue of the story, looked up by what the reader is doing.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AudioCues {
+    cues: Vec<AudioCue>,
+}
+
+impl AudioCues {
+    /// Drops cues without a file.
+    pub fn new(cues: Vec<AudioCue>) -> Self {
+        Self {
+            cues: cues.into_iter().filter(|c| !c.file.is_empty()).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cues.is_empty()
+    }
+
+    pub fn ambience_for(&self, chapter_id: &str) -> Option<&AudioCue> {
+        if chapter_id.is_empty() {
+            return None;
+        }
+        self.cues
+            .iter()
+            .find(|c| c.kind == CueKind::Ambience && c.chapter_id == chapter_id)
+    }
+
+    /// Effects played when the reader arrives at `paragraph_id`.
+    pub fn effects_for(&self, paragraph_id: &str) -> Vec<&AudioCue> {
+        if paragraph_id.is_empty() {
+            return Vec::new();
+        }
+        self.cues
+            .iter()
+            .filter(|c| {
+                c.kind == CueKind::Effect && c.tier.is_none() && c.paragraph_id == paragraph_id
+            })
+            .collect()
+    }
+
+    /// Effect for a check outcome, preferring one set on `paragraph_id`.
+    pub fn tier_effect(
+        &self,
+        paragraph_id: Option<&str>,
+        tier: &EventOutcomeTier,
+    ) -> Option<&AudioCue> {
+        let for_tier = |c: &&AudioCue| c.kind == CueKind::Effect && c.tier.as_ref() == Some(tier);
+        paragraph_id
+            .filter(|id| !id.is_empty())
+            .and_then(|id| {
+                self.cues
+                    .iter()
+                    .filter(for_tier)
+                    .find(|c| c.paragraph_id == id)
+            })
+            .or_else(|| {
+                self.cues
+                    .iter()
+                    .filter(for_tier)
+                    .find(|c| c.paragraph_id.is_empty())
+            })
+    }
+
+    /// Every file URL, for caching ahead of offline play.
+    pub fn urls(&self, files_base: &str) -> Vec<String> {
+        self.cues.iter().map(|c| c.file_url(files_base)).collect()
+    }
+}
+
+/// Master volume and mute as kept in the settings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Volume {
+    pub level: f32,
+    pub muted: bool,
+}
+
+impl Default for Volume {
+    fn default() -> Self {
+        Self {
+            level: 0.8,
+            muted: false,
+        }
+    }
+}
+
+impl Volume {
+    pub fn from_settings(settings: &HashMap<String, String>) -> Self {
+        let default = Self::default();
+        Self {
+            level: settings
+                .get(AUDIO_VOLUME_SETTING)
+                .and_then(|v| v.trim().parse::<f32>().ok())
+                .filter(|v| v.is_finite())
+                .map(|v| v.clamp(0.0, 1.0))
+                .unwrap_or(default.level),
+            muted: settings
+                .get(AUDIO_MUTED_SETTING)
+                .map(|v| v == "true")
+                .unwrap_or(default.muted),
+        }
+    }
+
+    /// Gain to apply to every sound.
+    pub fn gain(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.level
+        }
+    }
+}
+
+/// Gains of the outgoing and incoming loop `progress` (0 to 1) through a
+/// crossfade. The curves keep the combined loudness steady.
+pub fn crossfade_gains(progress: f32) -> (f32, f32) {
+    let angle = progress.clamp(0.0, 1.0) * std::f32::consts::FRAC_PI_2;
+    (angle.cos(), angle.sin())
+}
@@ -0,0 +1,163 @@
+//! Where the reader is within a page, and the passages they bookmark.
+//!
+//! An offset only means something on the page it was taken on: the same
+//! last paragraph, shown the same way. Reader mode keeps the whole path on
+//! one page, so offsets into earlier paragraphs stay valid as it grows.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Characters of text kept to identify a bookmarked passage.
+pub const EXCERPT_CHARS: usize = 80;
+
+/// The `page_turn_mode` setting, falling back to the theme's default.
+pub fn page_turn_mode(settings: &HashMap<String, String>) -> String {
+    if let Some(mode) = settings.get("page_turn_mode") {
+        return mode.clone();
+    }
+    match settings.get("theme_mode").map(String::as_str) {
+        Some("paper") => "horizontal".to_string(),
+        _ => "scroll".to_string(),
+    }
+}
+
+/// How far down the page the reader is.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum PagePosition {
+    /// Page number in the horizontal and vertical page-turn modes, from 0.
+    Page { index: u32 },
+    /// Pixels scrolled in scroll mode.
+    Scroll { offset: f64 },
+}
+
+impl PagePosition {
+    /// Position for the current scroll offset; a page is one `page_height`.
+    pub fn capture(page_turn_mode: &str, scroll_top: f64, page_height: f64) -> Self {
+        if page_turn_mode == "scroll" || page_height <= 0.0 {
+            PagePosition::Scroll {
+                offset: scroll_top.max(0.0).round(),
+            }
+        } else {
+            PagePosition::Page {
+                index: (scroll_top / page_height).round().max(0.0) as u32,
+            }
+        }
+    }
+
+    /// Scroll offset that returns to this position, within `max_scroll_top`.
+    pub fn scroll_top(&self, page_height: f64, max_scroll_top: f64) -> f64 {
+        let top = match *self {
+            PagePosition::Page { index } => index as f64 * page_height,
+            PagePosition::Scroll { offset } => offset,
+        };
+        top.clamp(0.0, max_scroll_top.max(0.0))
+    }
+}
+
+/// The page on screen.
+#[derive(Debug, Clone, Copy)]
+pub struct CurrentPage<'a> {
+    /// Last paragraph shown.
+    pub paragraph_id: &'a str,
+    pub reader_mode: bool,
+    /// Paragraphs the reader went through, oldest first.
+    pub path: &'a [String],
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReadingPosition {
+    /// Last paragraph shown when the position was taken.
+    pub paragraph_id: String,
+    #[serde(default)]
+    pub reader_mode: bool,
+    pub position: PagePosition,
+}
+
+impl ReadingPosition {
+    pub fn new(page: &CurrentPage<'_>, position: PagePosition) -> Self {
+        Self {
+            paragraph_id: page.paragraph_id.to_string(),
+            reader_mode: page.reader_mode,
+            position,
+        }
+    }
+
+    pub fn from_json(raw: &str) -> Option<Self> {
+        serde_json::from_str(raw).ok()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Whether this was taken on exactly the page on screen, e.g. before a
+    /// reload.
+    pub fn is_current(&self, page: &CurrentPage<'_>) -> bool {
+        self.reader_mode == page.reader_mode && self.paragraph_id == page.paragraph_id
+    }
+
+    /// Whether the passage it points at is still on the page on screen.
+    pub fn is_on(&self, page: &CurrentPage<'_>) -> bool {
+        self.is_current(page)
+            || (self.reader_mode && page.reader_mode && page.path.contains(&self.paragraph_id))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub id: String,
+    pub chapter_id: String,
+    pub place: ReadingPosition,
+    /// Opening words of the passage, see [`excerpt`].
+    pub excerpt: String,
+    #[serde(default)]
+    pub note: String,
+}
+
+/// Every bookmark, persisted as one JSON document in IndexedDB.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Bookmarks {
+    pub items: Vec<Bookmark>,
+}
+
+impl Bookmarks {
+    pub fn from_json(raw: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(raw)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{\"items\":[]}".to_string())
+    }
+
+    /// Adds `bookmark`; one already at the same place takes its note instead.
+    pub fn add(&mut self, bookmark: Bookmark) {
+        match self.items.iter_mut().find(|b| b.place == bookmark.place) {
+            Some(existing) => existing.note = bookmark.note,
+            None => self.items.push(bookmark),
+        }
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.items.retain(|b| b.id != id);
+    }
+
+    /// Bookmarks of `chapter_id` in the order they were made.
+    pub fn in_chapter(&self, chapter_id: &str) -> Vec<Bookmark> {
+        self.items
+            .iter()
+            .filter(|b| b.chapter_id == chapter_id)
+            .cloned()
+            .collect()
+    }
+}
+
+/// `text` on one line, cut to `max_chars` with an ellipsis.
+pub fn excerpt(text: &str, max_chars: usize) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= max_chars {
+        return line;
+    }
+    let cut: String = line.chars().take(max_chars.saturating_sub(1)).collect();
+    format!("{}…", cut.trim_end())
+}
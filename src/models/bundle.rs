@@ -0,0 +1,397 @@
+use crate::models::chapter::Chapter;
+use crate::models::impacts::Impact;
+use crate::models::paragraph::Paragraph;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Value of the `format` field every bundle starts with.
+pub const BUNDLE_FORMAT: &str = "ifecaro-story";
+/// Bundle layout written by this build. Older versions are still read.
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Fields PocketBase fills in by itself; they are dropped on export and
+/// never sent back on import.
+const SYSTEM_FIELDS: [&str; 5] = [
+    "collectionId",
+    "collectionName",
+    "created",
+    "updated",
+    "expand",
+];
+
+/// A whole story as one JSON document.
+///
+/// Chapters and paragraphs use the typed models so that legacy choice shapes
+/// are normalised on the way through; the other collections are kept as raw
+/// records.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoryBundle {
+    pub format: String,
+    pub version: u32,
+    #[serde(default)]
+    pub exported_at: String,
+    #[serde(default)]
+    pub chapters: Vec<Chapter>,
+    #[serde(default)]
+    pub paragraphs: Vec<Paragraph>,
+    #[serde(default)]
+    pub characters: Vec<Value>,
+    #[serde(default)]
+    pub relationships: Vec<Value>,
+    /// Attribute records of each character.
+    #[serde(default)]
+    pub attributes: Vec<Value>,
+    /// Key/value records of the `settings` collection.
+    #[serde(default)]
+    pub settings: Vec<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BundleError {
+    Parse(String),
+    WrongFormat(String),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BundleError::Parse(msg) => write!(f, "Invalid bundle: {}", msg),
+            BundleError::WrongFormat(format) => {
+                write!(f, "Not a story bundle (format \"{}\")", format)
+            }
+            BundleError::UnsupportedVersion(version) => write!(
+                f,
+                "Bundle version {} is not supported (latest is {})",
+                version, BUNDLE_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BundleError {}
+
+impl StoryBundle {
+    pub fn new(exported_at: impl Into<String>) -> Self {
+        Self {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            exported_at: exported_at.into(),
+            chapters: Vec::new(),
+            paragraphs: Vec::new(),
+            characters: Vec::new(),
+            relationships: Vec::new(),
+            attributes: Vec::new(),
+            settings: Vec::new(),
+        }
+    }
+
+    /// Reads a bundle, checking the format marker and version before the
+    /// contents so that a foreign file gets a useful error.
+    pub fn parse(json: &str) -> Result<Self, BundleError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| BundleError::Parse(e.to_string()))?;
+        let format = value.get("format").and_then(Value::as_str).unwrap_or("");
+        if format != BUNDLE_FORMAT {
+            return Err(BundleError::WrongFormat(format.to_string()));
+        }
+        let version = value.get("version").and_then(Value::as_u64).unwrap_or(0) as u32;
+        if version == 0 || version > BUNDLE_VERSION {
+            return Err(BundleError::UnsupportedVersion(version));
+        }
+        serde_json::from_value(value).map_err(|e| BundleError::Parse(e.to_string()))
+    }
+}
+
+/// `record` without the fields PocketBase manages itself.
+pub fn strip_system_fields(mut record: Value) -> Value {
+    if let Some(fields) = record.as_object_mut() {
+        for field in SYSTEM_FIELDS {
+            fields.remove(field);
+        }
+    }
+    record
+}
+
+/// Collections covered by a bundle, in the order they are imported so that
+/// records exist before anything refers to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BundleCollection {
+    Chapters,
+    Characters,
+    Attributes,
+    Relationships,
+    Paragraphs,
+    Settings,
+}
+
+impl BundleCollection {
+    pub const ALL: [BundleCollection; 6] = [
+        BundleCollection::Chapters,
+        BundleCollection::Characters,
+        BundleCollection::Attributes,
+        BundleCollection::Relationships,
+        BundleCollection::Paragraphs,
+        BundleCollection::Settings,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BundleCollection::Chapters => "chapters",
+            BundleCollection::Characters => "characters",
+            BundleCollection::Attributes => "attributes",
+            BundleCollection::Relationships => "relationships",
+            BundleCollection::Paragraphs => "paragraphs",
+            BundleCollection::Settings => "settings",
+        }
+    }
+}
+
+/// How record ids from the bundle are treated on import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdStrategy {
+    /// Keep the bundle's ids; records that already exist are overwritten.
+    Keep,
+    /// Give every record a fresh id and rewrite all references to it, so the
+    /// bundle lands next to the existing story as a copy.
+    Remap,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordAction {
+    Create,
+    Update,
+}
+
+/// One write of an import.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedRecord {
+    pub collection: BundleCollection,
+    /// Id of the record in the bundle.
+    pub source_id: String,
+    /// Id the record is saved under.
+    pub id: String,
+    pub action: RecordAction,
+    /// Record fields to send, without `id`.
+    pub body: Value,
+}
+
+/// Records already on the server, used to tell creates from updates.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExistingRecords {
+    pub ids: HashMap<BundleCollection, HashSet<String>>,
+    /// Setting record ids by their `key`.
+    pub settings: HashMap<String, String>,
+}
+
+impl ExistingRecords {
+    pub fn contains(&self, collection: BundleCollection, id: &str) -> bool {
+        self.ids
+            .get(&collection)
+            .is_some_and(|ids| ids.contains(id))
+    }
+}
+
+/// Everything an import would write, in import order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportPlan {
+    pub records: Vec<PlannedRecord>,
+}
+
+impl ImportPlan {
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn count(&self, collection: BundleCollection, action: RecordAction) -> usize {
+        self.records
+            .iter()
+            .filter(|r| r.collection == collection && r.action == action)
+            .count()
+    }
+
+    /// Old id → new id for every record whose id changes.
+    pub fn id_map(&self) -> HashMap<String, String> {
+        self.records
+            .iter()
+            .filter(|r| r.id != r.source_id)
+            .map(|r| (r.source_id.clone(), r.id.clone()))
+            .collect()
+    }
+}
+
+fn record_id(record: &Value) -> String {
+    record
+        .get("id")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn remap_field(record: &mut Value, field: &str, ids: &HashMap<String, String>) {
+    if let Some(slot) = record.get_mut(field) {
+        if let Some(new_id) = slot.as_str().and_then(|id| ids.get(id)) {
+            *slot = Value::String(new_id.clone());
+        }
+    }
+}
+
+fn remap_paragraph(paragraph: &mut Paragraph, ids: &HashMap<String, String>) {
+    let relink = |id: &str| ids.get(id).cloned().unwrap_or_else(|| id.to_string());
+    paragraph.chapter_id = relink(&paragraph.chapter_id);
+    for choice in &mut paragraph.choices {
+        for target in &mut choice.to {
+            *target = relink(target);
+        }
+        if choice.timeout_to.is_some() {
+            let targets: Vec<String> = choice.timeout_targets().iter().map(|t| relink(t)).collect();
+            choice.timeout_to = Some(targets.join(","));
+        }
+        for impact in choice.impacts.iter_mut().flatten() {
+            match impact {
+                Impact::CharacterAttribute { character_id, .. }
+                | Impact::Flag { character_id, .. } => *character_id = relink(character_id),
+                Impact::Relationship { from_id, to_id, .. } => {
+                    *from_id = relink(from_id);
+                    *to_id = relink(to_id);
+                }
+            }
+        }
+    }
+}
+
+/// Record body without `id`, which is sent separately.
+fn body_of(record: Value) -> Value {
+    let mut body = strip_system_fields(record);
+    if let Some(fields) = body.as_object_mut() {
+        fields.remove("id");
+    }
+    body
+}
+
+/// Works out every write needed to bring `bundle` into a story that already
+/// holds `existing`, without touching the server.
+///
+/// Settings are always matched by `key`, whatever the strategy, since their
+/// ids differ between installations. With [`IdStrategy::Remap`] every other
+/// record is created under an id from `new_id`; references between bundled
+/// records follow, while references to records outside the bundle are kept.
+pub fn plan_import(
+    bundle: &StoryBundle,
+    existing: &ExistingRecords,
+    strategy: IdStrategy,
+    mut new_id: impl FnMut() -> String,
+) -> ImportPlan {
+    let mut ids: HashMap<String, String> = HashMap::new();
+    if strategy == IdStrategy::Remap {
+        let bundled = bundle
+            .chapters
+            .iter()
+            .map(|c| c.id.clone())
+            .chain(bundle.paragraphs.iter().map(|p| p.id.clone()))
+            .chain(
+                bundle
+                    .characters
+                    .iter()
+                    .chain(&bundle.attributes)
+                    .chain(&bundle.relationships)
+                    .map(record_id),
+            );
+        for id in bundled.filter(|id| !id.is_empty()) {
+            ids.entry(id).or_insert_with(&mut new_id);
+        }
+    }
+    let target_of = |collection: BundleCollection, source_id: &str| {
+        let id = ids
+            .get(source_id)
+            .cloned()
+            .unwrap_or_else(|| source_id.to_string());
+        let action = if existing.contains(collection, &id) {
+            RecordAction::Update
+        } else {
+            RecordAction::Create
+        };
+        (id, action)
+    };
+
+    let mut records = Vec::new();
+    for collection in BundleCollection::ALL {
+        match collection {
+            BundleCollection::Chapters => {
+                for chapter in &bundle.chapters {
+                    let (id, action) = target_of(collection, &chapter.id);
+                    let body = serde_json::to_value(chapter).unwrap_or_default();
+                    records.push(PlannedRecord {
+                        collection,
+                        source_id: chapter.id.clone(),
+                        id,
+                        action,
+                        body: body_of(body),
+                    });
+                }
+            }
+            BundleCollection::Paragraphs => {
+                for paragraph in &bundle.paragraphs {
+                    let (id, action) = target_of(collection, &paragraph.id);
+                    let mut copy = paragraph.clone();
+                    remap_paragraph(&mut copy, &ids);
+                    let body = serde_json::to_value(&copy).unwrap_or_default();
+                    records.push(PlannedRecord {
+                        collection,
+                        source_id: paragraph.id.clone(),
+                        id,
+                        action,
+                        body: body_of(body),
+                    });
+                }
+            }
+            BundleCollection::Settings => {
+                for setting in &bundle.settings {
+                    let source_id = record_id(setting);
+                    let key = setting.get("key").and_then(Value::as_str).unwrap_or("");
+                    let (id, action) = match existing.settings.get(key) {
+                        Some(id) => (id.clone(), RecordAction::Update),
+                        None if strategy == IdStrategy::Remap || source_id.is_empty() => {
+                            (new_id(), RecordAction::Create)
+                        }
+                        None => (source_id.clone(), RecordAction::Create),
+                    };
+                    records.push(PlannedRecord {
+                        collection,
+                        source_id,
+                        id,
+                        action,
+                        body: body_of(setting.clone()),
+                    });
+                }
+            }
+            BundleCollection::Characters
+            | BundleCollection::Attributes
+            | BundleCollection::Relationships => {
+                let (source, references): (&[Value], &[&str]) = match collection {
+                    BundleCollection::Characters => (&bundle.characters, &[]),
+                    BundleCollection::Attributes => (&bundle.attributes, &["character_id"]),
+                    _ => (&bundle.relationships, &["from_id", "to_id"]),
+                };
+                for record in source {
+                    let source_id = record_id(record);
+                    let (id, action) = target_of(collection, &source_id);
+                    let mut body = body_of(record.clone());
+                    for field in references {
+                        remap_field(&mut body, field, &ids);
+                    }
+                    records.push(PlannedRecord {
+                        collection,
+                        source_id,
+                        id,
+                        action,
+                        body,
+                    });
+                }
+            }
+        }
+    }
+    ImportPlan { records }
+}
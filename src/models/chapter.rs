@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// Record in the `chapters` collection.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Chapter {
+    pub id: String,
+    pub titles: Vec<ChapterTitle>,
+    pub order: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChapterTitle {
+    pub lang: String,
+    pub title: String,
+}
+
+impl Chapter {
+    pub fn title_for(&self, lang: &str) -> Option<&str> {
+        self.titles
+            .iter()
+            .find(|t| t.lang == lang)
+            .map(|t| t.title.as_str())
+    }
+
+    /// Sets the title for `lang`; an empty title removes that translation.
+    pub fn set_title(&mut self, lang: &str, title: &str) {
+        let title = title.trim();
+        self.titles.retain(|t| t.lang != lang);
+        if !title.is_empty() {
+            self.titles.push(ChapterTitle {
+                lang: lang.to_string(),
+                title: title.to_string(),
+            });
+        }
+    }
+}
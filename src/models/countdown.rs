@@ -0,0 +1,137 @@
+//! How long timed choices give the reader.
+//!
+//! A choice with a `time_limit` counts down once its paragraph is shown and,
+//! when it runs out, is disabled or leads to its `timeout_to` paragraph.
+//! Readers can stretch every limit or turn timers off in the settings menu,
+//! unless the author marked timers as mandatory for the story: then every
+//! limit is kept as written. Either way the countdown pauses while the page
+//! is hidden or the settings menu is open. Changing the options while a
+//! countdown runs scales the time it has left instead of restarting it.
+
+use crate::models::paragraph::ParagraphChoice;
+use std::collections::HashMap;
+
+/// Reader setting holding the factor applied to every time limit, or
+/// [`TIMERS_OFF`].
+pub const RELAXED_TIMERS_SETTING: &str = "relaxed_timers";
+/// Key of the record in the `settings` collection with which the author makes
+/// timers mandatory.
+pub const TIMERS_MANDATORY_SETTING: &str = "timers_mandatory";
+/// [`RELAXED_TIMERS_SETTING`] value that turns timers off.
+pub const TIMERS_OFF: &str = "off";
+
+/// Factors offered in the settings menu.
+pub const RELAXED_STEPS: [f32; 4] = [1.0, 1.5, 2.0, 3.0];
+
+const MIN_FACTOR: f32 = 1.0;
+const MAX_FACTOR: f32 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimerOptions {
+    /// Factor applied to every time limit; `None` when timers are off.
+    pub factor: Option<f32>,
+    /// Set by the author, overriding the reader's factor.
+    pub mandatory: bool,
+}
+
+impl Default for TimerOptions {
+    fn default() -> Self {
+        Self {
+            factor: Some(1.0),
+            mandatory: false,
+        }
+    }
+}
+
+/// Reads a stored factor. Unreadable values fall back to `1`, and limits can
+/// only be stretched, never shortened.
+pub fn parse_factor(value: Option<&str>) -> Option<f32> {
+    match value.map(str::trim) {
+        Some(TIMERS_OFF) => None,
+        value => Some(
+            value
+                .and_then(|v| v.parse::<f32>().ok())
+                .filter(|factor| factor.is_finite())
+                .map(|factor| factor.clamp(MIN_FACTOR, MAX_FACTOR))
+                .unwrap_or(1.0),
+        ),
+    }
+}
+
+impl TimerOptions {
+    pub fn from_settings(settings: &HashMap<String, String>, mandatory: bool) -> Self {
+        Self {
+            factor: parse_factor(settings.get(RELAXED_TIMERS_SETTING).map(String::as_str)),
+            mandatory,
+        }
+    }
+
+    /// Seconds a choice limited to `time_limit` gets; `0` means no countdown.
+    pub fn effective_limit(&self, time_limit: u32) -> u32 {
+        if time_limit == 0 || self.mandatory {
+            return time_limit;
+        }
+        match self.factor {
+            Some(factor) => (time_limit as f32 * factor).ceil() as u32,
+            None => 0,
+        }
+    }
+
+    /// [`effective_limit`](Self::effective_limit) of every choice, in order.
+    pub fn limits(&self, choices: &[ParagraphChoice]) -> Vec<u32> {
+        choices
+            .iter()
+            .map(|choice| self.effective_limit(choice.time_limit.unwrap_or(0)))
+            .collect()
+    }
+}
+
+/// Countdowns of the paragraph on screen, followed across changes of the
+/// timer options.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RunningCountdowns {
+    paragraph_id: String,
+    /// Seconds each countdown was started with, the length of its progress
+    /// bar; `0` means no countdown.
+    pub durations: Vec<u32>,
+    /// Speed of each countdown, so one started with its duration runs out at
+    /// the current limit while keeping the progress it made.
+    pub rates: Vec<f64>,
+}
+
+impl RunningCountdowns {
+    /// Follows the `limits` of `paragraph_id`'s choices and returns whether
+    /// anything changed. A new paragraph starts every countdown; on the same
+    /// paragraph a changed limit rescales what is left, a removed one stops
+    /// and an added one starts. Unchanged limits leave everything running.
+    pub fn update(&mut self, paragraph_id: &str, limits: Vec<u32>) -> bool {
+        if self.paragraph_id != paragraph_id || self.durations.len() != limits.len() {
+            *self = Self {
+                paragraph_id: paragraph_id.to_string(),
+                rates: vec![1.0; limits.len()],
+                durations: limits,
+            };
+            return true;
+        }
+        let mut changed = false;
+        for (index, limit) in limits.into_iter().enumerate() {
+            let (duration, rate) = match (self.durations[index], limit) {
+                (_, 0) => (0, 1.0),
+                (0, limit) => (limit, 1.0),
+                (started, limit) => (started, started as f64 / limit as f64),
+            };
+            if duration != self.durations[index] || rate != self.rates[index] {
+                self.durations[index] = duration;
+                self.rates[index] = rate;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// Whether running countdowns should hold: the choices are still waiting to
+/// be revealed, the page is hidden, or the settings menu is open.
+pub fn should_pause(waiting: bool, page_hidden: bool, settings_open: bool) -> bool {
+    waiting || page_hidden || settings_open
+}
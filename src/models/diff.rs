@@ -0,0 +1,134 @@
+use crate::models::paragraph::{ParagraphChoice, Text};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Same(String),
+    Added(String),
+    Removed(String),
+}
+
+/// Line diff of `old` against `new` based on their longest common subsequence.
+pub fn diff_lines(old: &[String], new: &[String]) -> Vec<DiffLine> {
+    // lcs[i][j] = length of the common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(DiffLine::Same(old[i].clone()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(DiffLine::Removed(old[i].clone()));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new[j].clone()));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().cloned().map(DiffLine::Removed));
+    lines.extend(new[j..].iter().cloned().map(DiffLine::Added));
+    lines
+}
+
+/// Changes to one translation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextDiff {
+    pub lang: String,
+    pub body: Vec<DiffLine>,
+    pub captions: Vec<DiffLine>,
+}
+
+impl TextDiff {
+    pub fn has_changes(&self) -> bool {
+        self.body
+            .iter()
+            .chain(&self.captions)
+            .any(|line| !matches!(line, DiffLine::Same(_)))
+    }
+}
+
+fn body_lines(text: Option<&Text>) -> Vec<String> {
+    text.map(|t| t.paragraphs.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Per-language diff of body text and choice captions; unchanged languages
+/// are left out.
+pub fn diff_texts(old: &[Text], new: &[Text]) -> Vec<TextDiff> {
+    let mut langs: Vec<&str> = old.iter().chain(new).map(|t| t.lang.as_str()).collect();
+    langs.sort_unstable();
+    langs.dedup();
+    langs
+        .into_iter()
+        .map(|lang| {
+            let before = old.iter().find(|t| t.lang == lang);
+            let after = new.iter().find(|t| t.lang == lang);
+            TextDiff {
+                lang: lang.to_string(),
+                body: diff_lines(&body_lines(before), &body_lines(after)),
+                captions: diff_lines(
+                    &before.map(|t| t.choices.clone()).unwrap_or_default(),
+                    &after.map(|t| t.choices.clone()).unwrap_or_default(),
+                ),
+            }
+        })
+        .filter(TextDiff::has_changes)
+        .collect()
+}
+
+/// A choice slot whose content differs; `None` means the slot does not exist
+/// on that side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChoiceChange {
+    pub index: usize,
+    pub before: Option<ParagraphChoice>,
+    pub after: Option<ParagraphChoice>,
+}
+
+pub fn diff_choices(old: &[ParagraphChoice], new: &[ParagraphChoice]) -> Vec<ChoiceChange> {
+    (0..old.len().max(new.len()))
+        .filter_map(|index| {
+            let before = old.get(index);
+            let after = new.get(index);
+            (before != after).then(|| ChoiceChange {
+                index,
+                before: before.cloned(),
+                after: after.cloned(),
+            })
+        })
+        .collect()
+}
+
+/// One-line summary of where a choice leads, e.g. `goto → a, b · 10s → c`.
+///
+/// Same-page and impact details are left to the caller, which knows how to
+/// label them in the interface language.
+pub fn describe_choice(choice: &ParagraphChoice) -> String {
+    let mut summary = format!("{} → {}", choice.type_, choice.to.join(", "));
+    if let Some(key) = &choice.key {
+        summary.push_str(&format!(" · {}", key));
+        if let Some(value) = &choice.value {
+            summary.push_str(&format!(" = {}", value));
+        }
+    }
+    let timeouts = choice.timeout_targets();
+    if !timeouts.is_empty() {
+        summary.push_str(&format!(
+            " · {}s → {}",
+            choice.time_limit.unwrap_or_default(),
+            timeouts.join(", ")
+        ));
+    }
+    summary
+}
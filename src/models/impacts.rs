@@ -199,6 +199,9 @@ pub struct RelationshipState {
 pub struct CharacterStateSnapshot {
     pub characters: HashMap<String, CharacterAttributes>,
     pub relationships: Vec<RelationshipState>,
+    /// Answers to `input` choices, by choice key.
+    #[serde(default)]
+    pub answers: HashMap<String, String>,
 }
 
 impl CharacterStateSnapshot {
@@ -231,13 +234,17 @@ impl CharacterStateSnapshot {
         Self {
             characters: state.characters,
             relationships,
+            answers: HashMap::new(),
         }
     }
 
     pub fn apply_impacts(&self, impacts: &[Impact]) -> Self {
         let base = self.to_preview_state();
         let updated = apply_impacts_preview(&base.characters, &base.relationships, impacts);
-        CharacterStateSnapshot::from_preview_state(updated)
+        CharacterStateSnapshot {
+            answers: self.answers.clone(),
+            ..CharacterStateSnapshot::from_preview_state(updated)
+        }
     }
 }
 
@@ -413,9 +420,10 @@ pub fn apply_impacts_preview(
                 path,
                 value,
             } => {
-                if let Some(attr) = characters.get_mut(character_id) {
-                    set_flag_path(&mut attr.traits_flags, path, value.clone());
-                }
+                let attr = characters
+                    .entry(character_id.clone())
+                    .or_insert_with(CharacterAttributes::default);
+                set_flag_path(&mut attr.traits_flags, path, value.clone());
             }
         }
     }
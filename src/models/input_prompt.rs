@@ -0,0 +1,220 @@
+use crate::models::audio::{AUDIO_MUTED_SETTING, AUDIO_VOLUME_SETTING};
+use crate::models::countdown::{RELAXED_TIMERS_SETTING, TIMERS_MANDATORY_SETTING};
+use crate::models::interpolation::PLAYER_CHARACTER_SETTING;
+use crate::models::narration::{NARRATION_RATE_SETTING, NARRATION_VOICE_SETTING};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Choice type whose caption submits a value typed or picked by the reader.
+///
+/// The choice `key` names the answer, which is kept with the playthrough's
+/// character state so `{...}` expressions can read it, and `value` holds the
+/// [`InputPrompt`].
+pub const INPUT_ACTION: &str = "input";
+
+/// Setting names an answer must not use, since expressions would read the
+/// answer in their place.
+pub const RESERVED_KEYS: &[&str] = &[
+    "theme_mode",
+    "page_turn_mode",
+    "reader_mode",
+    RELAXED_TIMERS_SETTING,
+    TIMERS_MANDATORY_SETTING,
+    AUDIO_VOLUME_SETTING,
+    AUDIO_MUTED_SETTING,
+    NARRATION_RATE_SETTING,
+    NARRATION_VOICE_SETTING,
+    PLAYER_CHARACTER_SETTING,
+];
+
+/// What the reader is asked for and the rules the answer must meet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputPrompt {
+    #[serde(flatten)]
+    pub kind: PromptKind,
+    #[serde(default)]
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PromptKind {
+    Text {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_length: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_length: Option<usize>,
+        /// Regular expression the whole answer must match.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pattern: Option<String>,
+    },
+    Number {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<f64>,
+        #[serde(default)]
+        integer: bool,
+    },
+    /// Pick one of `options`.
+    Select { options: Vec<String> },
+}
+
+impl Default for InputPrompt {
+    fn default() -> Self {
+        Self {
+            kind: PromptKind::Text {
+                min_length: None,
+                max_length: Some(40),
+                pattern: None,
+            },
+            required: true,
+        }
+    }
+}
+
+/// Why an answer was refused.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PromptError {
+    Required,
+    TooShort(usize),
+    TooLong(usize),
+    PatternMismatch,
+    NotANumber,
+    NotAnInteger,
+    BelowMin(f64),
+    AboveMax(f64),
+    NotAnOption,
+}
+
+/// Mistakes in an authored prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PromptConfigError {
+    MissingKey,
+    /// The key is one of [`RESERVED_KEYS`].
+    ReservedKey(String),
+    InvalidPattern(String),
+    EmptyRange,
+    NoOptions,
+}
+
+fn full_match(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{})$", pattern))
+}
+
+impl InputPrompt {
+    /// Reads the prompt stored in a choice's `value`; a JSON string holding
+    /// the object is accepted too.
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(raw) => serde_json::from_str(raw).ok(),
+            other => serde_json::from_value(other.clone()).ok(),
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+
+    /// Checks `raw` and returns the answer to store: trimmed text, a
+    /// normalised number, or the picked option.
+    pub fn validate(&self, raw: &str) -> Result<String, PromptError> {
+        let answer = raw.trim();
+        if answer.is_empty() {
+            return if self.required {
+                Err(PromptError::Required)
+            } else {
+                Ok(String::new())
+            };
+        }
+        match &self.kind {
+            PromptKind::Text {
+                min_length,
+                max_length,
+                pattern,
+            } => {
+                let length = answer.chars().count();
+                if let Some(min) = min_length.filter(|min| length < *min) {
+                    return Err(PromptError::TooShort(min));
+                }
+                if let Some(max) = max_length.filter(|max| length > *max) {
+                    return Err(PromptError::TooLong(max));
+                }
+                // A pattern that does not compile is reported in the dashboard
+                // and not enforced
+                let compiled = pattern.as_deref().and_then(|p| full_match(p).ok());
+                if compiled.is_some_and(|regex| !regex.is_match(answer)) {
+                    return Err(PromptError::PatternMismatch);
+                }
+                Ok(answer.to_string())
+            }
+            PromptKind::Number { min, max, integer } => {
+                let number: f64 = answer.parse().map_err(|_| PromptError::NotANumber)?;
+                if !number.is_finite() {
+                    return Err(PromptError::NotANumber);
+                }
+                if *integer && number.fract() != 0.0 {
+                    return Err(PromptError::NotAnInteger);
+                }
+                if let Some(min) = min.filter(|min| number < *min) {
+                    return Err(PromptError::BelowMin(min));
+                }
+                if let Some(max) = max.filter(|max| number > *max) {
+                    return Err(PromptError::AboveMax(max));
+                }
+                Ok(if number.fract() == 0.0 {
+                    format!("{}", number as i64)
+                } else {
+                    number.to_string()
+                })
+            }
+            PromptKind::Select { options } => options
+                .iter()
+                .find(|option| option.as_str() == answer)
+                .cloned()
+                .ok_or(PromptError::NotAnOption),
+        }
+    }
+
+    /// Problems an author should fix before readers see the prompt.
+    pub fn check(&self, key: Option<&str>) -> Vec<PromptConfigError> {
+        let mut errors = Vec::new();
+        match key.map(str::trim) {
+            None | Some("") => errors.push(PromptConfigError::MissingKey),
+            Some(key) if RESERVED_KEYS.contains(&key) => {
+                errors.push(PromptConfigError::ReservedKey(key.to_string()))
+            }
+            Some(_) => {}
+        }
+        match &self.kind {
+            PromptKind::Text {
+                min_length,
+                max_length,
+                pattern,
+            } => {
+                if let (Some(min), Some(max)) = (min_length, max_length) {
+                    if min > max {
+                        errors.push(PromptConfigError::EmptyRange);
+                    }
+                }
+                if let Some(Err(error)) = pattern.as_deref().map(full_match) {
+                    errors.push(PromptConfigError::InvalidPattern(error.to_string()));
+                }
+            }
+            PromptKind::Number { min, max, .. } => {
+                if let (Some(min), Some(max)) = (min, max) {
+                    if min > max {
+                        errors.push(PromptConfigError::EmptyRange);
+                    }
+                }
+            }
+            PromptKind::Select { options } => {
+                if options.iter().all(|o| o.trim().is_empty()) {
+                    errors.push(PromptConfigError::NoOptions);
+                }
+            }
+        }
+        errors
+    }
+}
@@ -0,0 +1,756 @@
+//! `{...}` expressions in paragraph text and choice captions.
+//!
+//! An expression is a value, optionally followed by `| filter`s:
+//!
+//! - names: `player_name`, `stress`, `flags.saved_the_boy`, `<character id>.courage`
+//! - literals: `60`, `'calm'`, `"calm"`
+//! - `!`, `==`, `!=`, `<`, `<=`, `>`, `>=`, `&&`, `||`, parentheses and
+//!   `condition ? a : b`
+//! - filters: `upper`, `lower`, `capitalize`, `round` and `default 'text'`
+//!
+//! A bare name is a setting first, then an attribute or flag of the player
+//! character: the one named by the `player_character` setting, or the only
+//! character with tracked state. `\{` is a literal brace. Expressions that do
+//! not parse are left in the text as written.
+
+use crate::models::impacts::{CharacterAttributes, CharacterStateSnapshot, Impact};
+use crate::models::input_prompt::INPUT_ACTION;
+use crate::models::markup;
+use crate::models::paragraph::Paragraph;
+use serde_json::Value as Json;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Setting naming the character that bare attribute and flag names refer to.
+pub const PLAYER_CHARACTER_SETTING: &str = "player_character";
+
+/// Attribute names, as in [`CharacterAttributes`].
+pub const ATTRIBUTES: [&str; 21] = [
+    "honesty",
+    "empathy",
+    "affability",
+    "intimidation",
+    "aggression",
+    "discipline",
+    "curiosity",
+    "courage",
+    "impulsivity",
+    "idealism",
+    "pragmatism",
+    "loyalty",
+    "opportunism",
+    "stoicism",
+    "morality",
+    "health",
+    "stress",
+    "fatigue",
+    "pain",
+    "morale",
+    "intox",
+];
+
+/// Path segment that selects a character's flags.
+const FLAGS: &str = "flags";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    Upper,
+    Lower,
+    Capitalize,
+    Round,
+    Default(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Text(String),
+    Path(Vec<String>),
+    Not(Box<Expr>),
+    Binary(Box<Expr>, BinaryOp, Box<Expr>),
+    Conditional(Box<Expr>, Box<Expr>, Box<Expr>),
+    Filtered(Box<Expr>, Filter),
+}
+
+impl Expr {
+    /// Every name the expression reads.
+    pub fn paths(&self) -> Vec<&[String]> {
+        let mut paths = Vec::new();
+        self.collect_paths(&mut paths);
+        paths
+    }
+
+    fn collect_paths<'a>(&'a self, paths: &mut Vec<&'a [String]>) {
+        match self {
+            Expr::Number(_) | Expr::Text(_) => {}
+            Expr::Path(path) => paths.push(path),
+            Expr::Not(inner) | Expr::Filtered(inner, _) => inner.collect_paths(paths),
+            Expr::Binary(left, _, right) => {
+                left.collect_paths(paths);
+                right.collect_paths(paths);
+            }
+            Expr::Conditional(condition, then, otherwise) => {
+                condition.collect_paths(paths);
+                then.collect_paths(paths);
+                otherwise.collect_paths(paths);
+            }
+        }
+    }
+}
+
+/// Result of evaluating an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Missing,
+}
+
+impl Value {
+    fn from_json(json: &Json) -> Self {
+        match json {
+            Json::Null => Value::Missing,
+            Json::Bool(b) => Value::Bool(*b),
+            Json::Number(n) => n.as_f64().map(Value::Number).unwrap_or(Value::Missing),
+            Json::String(s) => Value::Text(s.clone()),
+            other => Value::Text(other.to_string()),
+        }
+    }
+
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            Value::Text(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn truthy(&self) -> bool {
+        match self {
+            Value::Number(n) => *n != 0.0,
+            Value::Text(s) => !s.is_empty() && s != "false",
+            Value::Bool(b) => *b,
+            Value::Missing => false,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(n) if n.fract() == 0.0 => write!(f, "{}", *n as i64),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Text(s) => write!(f, "{}", s),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Missing => Ok(()),
+        }
+    }
+}
+
+/// A piece of a template.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment {
+    Text(String),
+    /// `source` is the text between the braces.
+    Expr {
+        source: String,
+        expr: Result<Expr, String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    Name(String),
+    Symbol(&'static str),
+}
+
+const SYMBOLS: [&str; 15] = [
+    "==", "!=", "<=", ">=", "&&", "||", "<", ">", "!", "(", ")", "?", ":", "|", ".",
+];
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if c == '\'' || c == '"' {
+            let end = rest[1..]
+                .find(c)
+                .ok_or_else(|| "unterminated string".to_string())?;
+            tokens.push(Token::Text(rest[1..=end].to_string()));
+            rest = &rest[end + 2..];
+        } else if c.is_alphanumeric() || c == '_' {
+            let mut end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            // Record ids may start with a digit, so only all-digit words are numbers
+            if rest[..end].chars().all(|c| c.is_ascii_digit()) {
+                let fraction = rest[end..]
+                    .strip_prefix('.')
+                    .map(|r| r.len() - r.trim_start_matches(|c: char| c.is_ascii_digit()).len())
+                    .unwrap_or(0);
+                if fraction > 0 {
+                    end += 1 + fraction;
+                }
+                let number = rest[..end]
+                    .parse()
+                    .map_err(|_| format!("bad number \"{}\"", &rest[..end]))?;
+                tokens.push(Token::Number(number));
+            } else {
+                tokens.push(Token::Name(rest[..end].to_string()));
+            }
+            rest = &rest[end..];
+        } else if let Some(symbol) = SYMBOLS.iter().find(|s| rest.starts_with(**s)) {
+            tokens.push(Token::Symbol(symbol));
+            rest = &rest[symbol.len()..];
+        } else {
+            return Err(format!("unexpected \"{}\"", c));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek_symbol(&self, symbol: &str) -> bool {
+        matches!(self.tokens.get(self.pos), Some(Token::Symbol(s)) if *s == symbol)
+    }
+
+    fn eat(&mut self, symbol: &str) -> bool {
+        let found = self.peek_symbol(symbol);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<(), String> {
+        if self.eat(symbol) {
+            Ok(())
+        } else {
+            Err(format!("expected \"{}\"", symbol))
+        }
+    }
+
+    fn filtered(&mut self) -> Result<Expr, String> {
+        let mut expr = self.conditional()?;
+        while self.eat("|") {
+            let filter = match self.tokens.get(self.pos).cloned() {
+                Some(Token::Name(name)) => {
+                    self.pos += 1;
+                    match name.as_str() {
+                        "upper" => Filter::Upper,
+                        "lower" => Filter::Lower,
+                        "capitalize" => Filter::Capitalize,
+                        "round" => Filter::Round,
+                        "default" => match self.tokens.get(self.pos).cloned() {
+                            Some(Token::Text(text)) => {
+                                self.pos += 1;
+                                Filter::Default(text)
+                            }
+                            _ => return Err("default needs a quoted text".to_string()),
+                        },
+                        other => return Err(format!("unknown filter \"{}\"", other)),
+                    }
+                }
+                _ => return Err("expected a filter name".to_string()),
+            };
+            expr = Expr::Filtered(Box::new(expr), filter);
+        }
+        Ok(expr)
+    }
+
+    fn conditional(&mut self) -> Result<Expr, String> {
+        let condition = self.binary(0)?;
+        if !self.eat("?") {
+            return Ok(condition);
+        }
+        let then = self.conditional()?;
+        self.expect(":")?;
+        let otherwise = self.conditional()?;
+        Ok(Expr::Conditional(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    /// Binary operators by level: `||`, then `&&`, then comparisons.
+    fn binary(&mut self, level: usize) -> Result<Expr, String> {
+        const LEVELS: [&[(&str, BinaryOp)]; 3] = [
+            &[("||", BinaryOp::Or)],
+            &[("&&", BinaryOp::And)],
+            &[
+                ("==", BinaryOp::Eq),
+                ("!=", BinaryOp::Ne),
+                ("<=", BinaryOp::Le),
+                (">=", BinaryOp::Ge),
+                ("<", BinaryOp::Lt),
+                (">", BinaryOp::Gt),
+            ],
+        ];
+        let Some(operators) = LEVELS.get(level) else {
+            return self.unary();
+        };
+        let mut left = self.binary(level + 1)?;
+        'outer: loop {
+            for (symbol, op) in operators.iter() {
+                if self.eat(symbol) {
+                    let right = self.binary(level + 1)?;
+                    left = Expr::Binary(Box::new(left), *op, Box::new(right));
+                    continue 'outer;
+                }
+            }
+            return Ok(left);
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.eat("!") {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        if self.eat("(") {
+            let inner = self.conditional()?;
+            self.expect(")")?;
+            return Ok(inner);
+        }
+        match self.tokens.get(self.pos).cloned() {
+            Some(Token::Number(n)) => {
+                self.pos += 1;
+                Ok(Expr::Number(n))
+            }
+            Some(Token::Text(text)) => {
+                self.pos += 1;
+                Ok(Expr::Text(text))
+            }
+            Some(Token::Name(name)) => {
+                self.pos += 1;
+                let mut path = vec![name];
+                while self.eat(".") {
+                    match self.tokens.get(self.pos).cloned() {
+                        Some(Token::Name(name)) => {
+                            self.pos += 1;
+                            path.push(name);
+                        }
+                        Some(Token::Number(n)) if n.fract() == 0.0 => {
+                            self.pos += 1;
+                            path.push((n as i64).to_string());
+                        }
+                        _ => return Err("expected a name after \".\"".to_string()),
+                    }
+                }
+                Ok(Expr::Path(path))
+            }
+            Some(Token::Symbol(symbol)) => Err(format!("unexpected \"{}\"", symbol)),
+            None => Err("expression ends too early".to_string()),
+        }
+    }
+}
+
+/// Parses the text between a pair of braces.
+pub fn parse_expr(source: &str) -> Result<Expr, String> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        pos: 0,
+    };
+    if parser.tokens.is_empty() {
+        return Err("empty expression".to_string());
+    }
+    let expr = parser.filtered()?;
+    match parser.tokens.get(parser.pos) {
+        None => Ok(expr),
+        Some(_) => Err("unexpected text after the expression".to_string()),
+    }
+}
+
+/// Splits `text` into literal text and `{...}` expressions. A `{` without a
+/// closing brace is literal.
+pub fn parse_template(text: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if let Some(escaped) = rest
+            .strip_prefix('\\')
+            .filter(|r| r.starts_with(['{', '}']))
+        {
+            literal.push_str(&escaped[..1]);
+            rest = &escaped[1..];
+            continue;
+        }
+        if c == '{' {
+            if let Some(end) = closing_brace(&rest[1..]) {
+                if !literal.is_empty() {
+                    segments.push(Segment::Text(std::mem::take(&mut literal)));
+                }
+                let source = rest[1..=end].trim().to_string();
+                segments.push(Segment::Expr {
+                    expr: parse_expr(&source),
+                    source,
+                });
+                rest = &rest[end + 2..];
+                continue;
+            }
+        }
+        literal.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Text(literal));
+    }
+    segments
+}
+
+/// Byte offset of the `}` closing an expression, skipping quoted text.
+fn closing_brace(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '}') => return Some(index),
+            (None, '{' | '\n') => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// State that expressions are evaluated against.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StoryVariables {
+    pub settings: HashMap<String, String>,
+    pub state: CharacterStateSnapshot,
+}
+
+fn attribute(attributes: &CharacterAttributes, name: &str) -> Option<Value> {
+    if !ATTRIBUTES.contains(&name) {
+        return None;
+    }
+    serde_json::to_value(attributes)
+        .ok()?
+        .get(name)
+        .map(Value::from_json)
+}
+
+fn flag(attributes: &CharacterAttributes, path: &[String]) -> Option<Value> {
+    let mut cursor = &attributes.traits_flags;
+    for key in path {
+        cursor = cursor.get(key)?;
+    }
+    Some(Value::from_json(cursor))
+}
+
+impl StoryVariables {
+    fn player(&self) -> Option<&CharacterAttributes> {
+        match self.settings.get(PLAYER_CHARACTER_SETTING) {
+            Some(id) => self.state.characters.get(id),
+            None if self.state.characters.len() == 1 => self.state.characters.values().next(),
+            None => None,
+        }
+    }
+
+    fn character_value(attributes: &CharacterAttributes, path: &[String]) -> Option<Value> {
+        match path {
+            [name] => attribute(attributes, name).or_else(|| flag(attributes, path)),
+            [flags, rest @ ..] if flags == FLAGS => flag(attributes, rest),
+            _ => flag(attributes, path),
+        }
+    }
+
+    pub fn lookup(&self, path: &[String]) -> Value {
+        let found = match path {
+            [name] if self.settings.contains_key(name) => {
+                Some(Value::Text(self.settings[name].clone()))
+            }
+            [name] if self.state.answers.contains_key(name) => {
+                Some(Value::Text(self.state.answers[name].clone()))
+            }
+            [character, rest @ ..]
+                if !rest.is_empty() && self.state.characters.contains_key(character) =>
+            {
+                Self::character_value(&self.state.characters[character], rest)
+            }
+            _ => self
+                .player()
+                .and_then(|player| Self::character_value(player, path)),
+        };
+        found.unwrap_or(Value::Missing)
+    }
+
+    pub fn eval(&self, expr: &Expr) -> Value {
+        self.eval_with(expr, &|_| None)
+    }
+
+    /// Like [`eval`](Self::eval), but names `extra` knows are looked up
+    /// there first.
+    pub fn eval_with(&self, expr: &Expr, extra: &dyn Fn(&[String]) -> Option<Value>) -> Value {
+        let eval = |expr: &Expr| self.eval_with(expr, extra);
+        match expr {
+            Expr::Number(n) => Value::Number(*n),
+            Expr::Text(text) => Value::Text(text.clone()),
+            Expr::Path(path) => extra(path).unwrap_or_else(|| self.lookup(path)),
+            Expr::Not(inner) => Value::Bool(!eval(inner).truthy()),
+            Expr::Binary(left, BinaryOp::And, right) => {
+                Value::Bool(eval(left).truthy() && eval(right).truthy())
+            }
+            Expr::Binary(left, BinaryOp::Or, right) => {
+                Value::Bool(eval(left).truthy() || eval(right).truthy())
+            }
+            Expr::Binary(left, op, right) => Value::Bool(compare(&eval(left), *op, &eval(right))),
+            Expr::Conditional(condition, then, otherwise) => {
+                if eval(condition).truthy() {
+                    eval(then)
+                } else {
+                    eval(otherwise)
+                }
+            }
+            Expr::Filtered(inner, filter) => apply_filter(eval(inner), filter),
+        }
+    }
+}
+
+fn compare(left: &Value, op: BinaryOp, right: &Value) -> bool {
+    if let (Some(l), Some(r)) = (left.as_number(), right.as_number()) {
+        return match op {
+            BinaryOp::Eq => l == r,
+            BinaryOp::Ne => l != r,
+            BinaryOp::Lt => l < r,
+            BinaryOp::Le => l <= r,
+            BinaryOp::Gt => l > r,
+            BinaryOp::Ge => l >= r,
+            BinaryOp::And | BinaryOp::Or => unreachable!("handled by eval"),
+        };
+    }
+    let (l, r) = (left.to_string(), right.to_string());
+    match op {
+        BinaryOp::Eq => l == r,
+        BinaryOp::Ne => l != r,
+        // Ordering needs numbers on both sides
+        _ => false,
+    }
+}
+
+fn apply_filter(value: Value, filter: &Filter) -> Value {
+    match filter {
+        Filter::Upper => Value::Text(value.to_string().to_uppercase()),
+        Filter::Lower => Value::Text(value.to_string().to_lowercase()),
+        Filter::Capitalize => {
+            let text = value.to_string();
+            let mut chars = text.chars();
+            Value::Text(match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            })
+        }
+        Filter::Round => match value.as_number() {
+            Some(n) => Value::Number(n.round()),
+            None => value,
+        },
+        Filter::Default(text) if value.to_string().is_empty() => Value::Text(text.clone()),
+        Filter::Default(_) => value,
+    }
+}
+
+/// Replaces every expression in `text` with its value.
+pub fn interpolate(text: &str, variables: &StoryVariables) -> String {
+    interpolate_with(text, variables, str::to_string)
+}
+
+/// Like [`interpolate`] for paragraph text: values are escaped so an answer
+/// or setting reads as typed instead of as markup.
+pub fn interpolate_markup(text: &str, variables: &StoryVariables) -> String {
+    interpolate_with(text, variables, markup::escape)
+}
+
+fn interpolate_with(
+    text: &str,
+    variables: &StoryVariables,
+    value_text: fn(&str) -> String,
+) -> String {
+    if !text.contains('{') && !text.contains('\\') {
+        return text.to_string();
+    }
+    parse_template(text)
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => text,
+            Segment::Expr { expr: Ok(expr), .. } => value_text(&variables.eval(&expr).to_string()),
+            Segment::Expr {
+                source,
+                expr: Err(_),
+            } => format!("{{{}}}", source),
+        })
+        .collect()
+}
+
+/// Names an author can refer to, gathered from the story.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct KnownKeys {
+    pub settings: HashSet<String>,
+    /// First segment of every flag path set by an impact.
+    pub flags: HashSet<String>,
+    /// Character record ids.
+    pub characters: HashSet<String>,
+}
+
+impl KnownKeys {
+    /// Settings written by `setting` choices, answers to `input` choices and
+    /// flags set by impacts in `paragraphs`, plus the given characters.
+    pub fn from_story(
+        paragraphs: &[Paragraph],
+        characters: impl IntoIterator<Item = String>,
+    ) -> Self {
+        let mut known = KnownKeys {
+            characters: characters.into_iter().collect(),
+            ..Default::default()
+        };
+        known.settings.insert(PLAYER_CHARACTER_SETTING.to_string());
+        for choice in paragraphs.iter().flat_map(|p| &p.choices) {
+            if choice.type_ == "setting" || choice.type_ == INPUT_ACTION {
+                if let Some(key) = &choice.key {
+                    known.settings.insert(key.clone());
+                }
+            }
+            for impact in choice.impacts.iter().flatten() {
+                if let Impact::Flag { path, .. } = impact {
+                    if let Some(first) = path.first() {
+                        known.flags.insert(first.clone());
+                    }
+                }
+            }
+        }
+        known
+    }
+
+    fn character_path_known(&self, path: &[String]) -> bool {
+        match path {
+            [name] => ATTRIBUTES.contains(&name.as_str()) || self.flags.contains(name),
+            [flags, first, ..] if flags == FLAGS => self.flags.contains(first),
+            [first, ..] => self.flags.contains(first),
+            [] => false,
+        }
+    }
+
+    /// Whether `path` names something that can have a value, following the
+    /// lookup order of [`StoryVariables::lookup`].
+    pub fn knows(&self, path: &[String]) -> bool {
+        match path {
+            [name] if self.settings.contains(name) => true,
+            [character, rest @ ..] if !rest.is_empty() && self.characters.contains(character) => {
+                self.character_path_known(rest)
+            }
+            _ => self.character_path_known(path),
+        }
+    }
+}
+
+/// A problem with an expression in a paragraph's text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LintIssue {
+    UnknownKey {
+        paragraph_id: String,
+        lang: String,
+        key: String,
+    },
+    Syntax {
+        paragraph_id: String,
+        lang: String,
+        expression: String,
+        message: String,
+    },
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintIssue::UnknownKey {
+                paragraph_id,
+                lang,
+                key,
+            } => write!(f, "{} ({}): unknown name \"{}\"", paragraph_id, lang, key),
+            LintIssue::Syntax {
+                paragraph_id,
+                lang,
+                expression,
+                message,
+            } => write!(
+                f,
+                "{} ({}): {{{}}}: {}",
+                paragraph_id, lang, expression, message
+            ),
+        }
+    }
+}
+
+/// Checks every expression in one piece of text; each unknown name is
+/// reported once.
+pub fn lint_text(paragraph_id: &str, lang: &str, text: &str, known: &KnownKeys) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    let mut reported = HashSet::new();
+    for segment in parse_template(text) {
+        let Segment::Expr { source, expr } = segment else {
+            continue;
+        };
+        match expr {
+            Ok(expr) => {
+                for path in expr.paths() {
+                    let key = path.join(".");
+                    if !known.knows(path) && reported.insert(key.clone()) {
+                        issues.push(LintIssue::UnknownKey {
+                            paragraph_id: paragraph_id.to_string(),
+                            lang: lang.to_string(),
+                            key,
+                        });
+                    }
+                }
+            }
+            Err(message) => issues.push(LintIssue::Syntax {
+                paragraph_id: paragraph_id.to_string(),
+                lang: lang.to_string(),
+                expression: source,
+                message,
+            }),
+        }
+    }
+    issues
+}
+
+/// Lints the text and choice captions of every translation.
+pub fn lint_paragraphs(paragraphs: &[Paragraph], known: &KnownKeys) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    for paragraph in paragraphs {
+        for text in &paragraph.texts {
+            let captions = text.choices.join("\n");
+            let mut found = lint_text(&paragraph.id, &text.lang, &text.paragraphs, known);
+            for issue in lint_text(&paragraph.id, &text.lang, &captions, known) {
+                if !found.contains(&issue) {
+                    found.push(issue);
+                }
+            }
+            issues.extend(found);
+        }
+    }
+    issues
+}
@@ -0,0 +1,372 @@
+//! Markup dialect for paragraph text.
+//!
+//! Each non-empty line is a block:
+//!
+//! - `@Name: words` is a line of dialogue spoken by `Name`
+//! - a line of three or more `*` or `-` (spaces allowed) is a scene break
+//! - `[^label]: words` defines a footnote, shown after the text
+//! - `![alt text](url)` is an illustration; the reader writes one for a
+//!   paragraph's image file, see [`illustration`]. Only `http(s)://` and
+//!   root-relative `/` URLs are accepted
+//! - anything else is a plain paragraph
+//!
+//! Inside a block `*words*` is emphasis, `**words**` is strong and `[^label]`
+//! refers to a footnote. Markers only open before and close after a
+//! non-space, so `2 * 3` stays as written. A backslash makes the next
+//! character literal.
+//! Markers that are never closed, and references to undefined footnotes,
+//! stay in the text as written. The parser only produces text nodes, so
+//! nothing in the source can become HTML.
+
+use std::collections::HashMap;
+
+/// Emphasis and strong nested deeper than this are read as plain text.
+const MAX_NESTING: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inline {
+    Text(String),
+    Emphasis(Vec<Inline>),
+    Strong(Vec<Inline>),
+    /// 1-based number of a footnote in [`Document::footnotes`].
+    FootnoteRef(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Paragraph(Vec<Inline>),
+    Speaker {
+        name: String,
+        content: Vec<Inline>,
+    },
+    SceneBreak,
+    /// An image; `alt` is empty for a decorative one.
+    Illustration {
+        src: String,
+        alt: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Footnote {
+    pub label: String,
+    pub content: Vec<Inline>,
+}
+
+/// Parsed paragraph text.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Document {
+    pub blocks: Vec<Block>,
+    /// Footnotes in the order they are defined; numbered from 1.
+    pub footnotes: Vec<Footnote>,
+}
+
+impl Block {
+    /// The block's text with all markup removed; `None` for blocks without
+    /// text. A speaker's line reads `Name: words`.
+    pub fn plain_text(&self) -> Option<String> {
+        match self {
+            Block::Paragraph(content) => Some(inline_text(content)),
+            Block::Speaker { name, content } => Some(format!("{}: {}", name, inline_text(content))),
+            Block::SceneBreak | Block::Illustration { .. } => None,
+        }
+    }
+}
+
+impl Document {
+    /// The text with all markup removed, one block per line.
+    pub fn plain_text(&self) -> String {
+        self.blocks
+            .iter()
+            .filter_map(Block::plain_text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn inline_text(content: &[Inline]) -> String {
+    content
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => text.clone(),
+            Inline::Emphasis(children) | Inline::Strong(children) => inline_text(children),
+            Inline::FootnoteRef(_) => String::new(),
+        })
+        .collect()
+}
+
+fn is_scene_break(line: &str) -> bool {
+    let marks: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+    marks.len() >= 3 && (marks.iter().all(|&c| c == '*') || marks.iter().all(|&c| c == '-'))
+}
+
+/// `[^label]` at the start of `text`, with the byte length it takes.
+fn footnote_label(text: &str) -> Option<(&str, usize)> {
+    let rest = text.strip_prefix("[^")?;
+    let end = rest.find(']')?;
+    let label = &rest[..end];
+    if label.is_empty() || label.chars().any(char::is_whitespace) {
+        return None;
+    }
+    Some((label, end + 3))
+}
+
+fn footnote_definition(line: &str) -> Option<(&str, &str)> {
+    let (label, used) = footnote_label(line)?;
+    let body = line[used..].strip_prefix(':')?;
+    Some((label, body.trim()))
+}
+
+/// The markup line for an illustration at `src`.
+///
+/// ```
+/// use ifecaro::models::markup::{illustration, parse, Block};
+///
+/// let line = illustration("/api/files/paragraphs/p1/fox.webp", "A fox\nat dusk");
+/// assert_eq!(line, "![A fox at dusk](/api/files/paragraphs/p1/fox.webp)");
+/// assert_eq!(
+///     parse(&line).blocks,
+///     vec![Block::Illustration {
+///         src: "/api/files/paragraphs/p1/fox.webp".to_string(),
+///         alt: "A fox at dusk".to_string(),
+///     }]
+/// );
+/// ```
+pub fn illustration(src: &str, alt: &str) -> String {
+    let alt = alt.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!("![{}]({})", alt, src)
+}
+
+/// `text` as markup that reads back as exactly that text on one line, for
+/// values from outside the story such as a reader's answers.
+///
+/// ```
+/// use ifecaro::models::markup::{escape, parse};
+///
+/// let text = "@Rex: **good** [^1]\n---";
+/// assert_eq!(parse(&escape(text)).plain_text(), "@Rex: **good** [^1] ---");
+/// ```
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '*' | '-' | '[' | ']' | '!' | '@' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' | '\r' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn illustration_line(line: &str) -> Option<(&str, &str)> {
+    let inner = line.strip_prefix("![")?.strip_suffix(')')?;
+    let (alt, src) = inner.rsplit_once("](")?;
+    let allowed = ["https://", "http://", "/"]
+        .iter()
+        .any(|scheme| src.starts_with(scheme));
+    if !allowed || src.starts_with("//") || src.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((alt.trim(), src))
+}
+
+fn speaker_line(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix('@')?;
+    let (name, words) = rest.split_once(':')?;
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+    Some((name, words.trim()))
+}
+
+enum Token {
+    Text(String),
+    Marker {
+        marker: &'static str,
+        opens: bool,
+        closes: bool,
+    },
+    Footnote(usize),
+}
+
+fn push_text(tokens: &mut Vec<Token>, text: &str) {
+    match tokens.last_mut() {
+        Some(Token::Text(last)) => last.push_str(text),
+        _ => tokens.push(Token::Text(text.to_string())),
+    }
+}
+
+fn tokenize(text: &str, footnotes: &HashMap<String, usize>) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        if let Some(escaped) = rest.strip_prefix('\\').and_then(|r| r.chars().next()) {
+            push_text(&mut tokens, escaped.encode_utf8(&mut [0; 4]));
+            pos += 1 + escaped.len_utf8();
+            continue;
+        }
+        if let Some((label, used)) = footnote_label(rest) {
+            if let Some(&number) = footnotes.get(label) {
+                tokens.push(Token::Footnote(number));
+                pos += used;
+                continue;
+            }
+        }
+        if rest.starts_with('*') {
+            let run = rest.len() - rest.trim_start_matches('*').len();
+            let opens = text[pos + run..].starts_with(|c: char| !c.is_whitespace());
+            let closes = text[..pos].ends_with(|c: char| !c.is_whitespace());
+            // A run of three closes strong then emphasis, and opens the other
+            // way round, so `***both***` nests
+            let markers: &[&'static str] = match (run, closes) {
+                (1, _) => &["*"],
+                (2, _) => &["**"],
+                (3, true) => &["**", "*"],
+                (3, false) => &["*", "**"],
+                _ => &[],
+            };
+            if markers.is_empty() {
+                push_text(&mut tokens, &rest[..run]);
+            }
+            for &marker in markers {
+                tokens.push(Token::Marker {
+                    marker,
+                    opens,
+                    closes,
+                });
+            }
+            pos += run;
+            continue;
+        }
+        let c = rest.chars().next().expect("text remains");
+        push_text(&mut tokens, &rest[..c.len_utf8()]);
+        pos += c.len_utf8();
+    }
+    tokens
+}
+
+/// Pairs each opening marker with the nearest later closing marker of the
+/// same kind; openers left between a pair are not closed.
+fn match_markers(tokens: &[Token]) -> Vec<Option<usize>> {
+    let mut closer_of = vec![None; tokens.len()];
+    let mut open: Vec<usize> = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+        let Token::Marker {
+            marker,
+            opens,
+            closes,
+        } = token
+        else {
+            continue;
+        };
+        if *closes {
+            let opener = open.iter().rposition(|&at| {
+                at + 1 < index
+                    && matches!(&tokens[at], Token::Marker { marker: m, .. } if m == marker)
+            });
+            if let Some(depth) = opener {
+                closer_of[open[depth]] = Some(index);
+                open.truncate(depth);
+                continue;
+            }
+        }
+        if *opens && open.len() < MAX_NESTING {
+            open.push(index);
+        }
+    }
+    closer_of
+}
+
+fn build(tokens: &[Token], closer_of: &[Option<usize>], from: usize, to: usize) -> Vec<Inline> {
+    let mut out: Vec<Inline> = Vec::new();
+    let mut index = from;
+    while index < to {
+        match &tokens[index] {
+            Token::Text(text) => push_inline_text(&mut out, text),
+            Token::Footnote(number) => out.push(Inline::FootnoteRef(*number)),
+            Token::Marker { marker, .. } => match closer_of[index] {
+                Some(end) => {
+                    let children = build(tokens, closer_of, index + 1, end);
+                    out.push(if *marker == "**" {
+                        Inline::Strong(children)
+                    } else {
+                        Inline::Emphasis(children)
+                    });
+                    index = end;
+                }
+                None => push_inline_text(&mut out, marker),
+            },
+        }
+        index += 1;
+    }
+    out
+}
+
+fn push_inline_text(out: &mut Vec<Inline>, text: &str) {
+    match out.last_mut() {
+        Some(Inline::Text(last)) => last.push_str(text),
+        _ => out.push(Inline::Text(text.to_string())),
+    }
+}
+
+fn parse_inlines(text: &str, footnotes: &HashMap<String, usize>) -> Vec<Inline> {
+    let tokens = tokenize(text, footnotes);
+    let closer_of = match_markers(&tokens);
+    build(&tokens, &closer_of, 0, tokens.len())
+}
+
+/// Parses paragraph text into blocks and footnotes.
+pub fn parse(text: &str) -> Document {
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect();
+
+    let mut numbers: HashMap<String, usize> = HashMap::new();
+    let mut definitions = Vec::new();
+    for line in &lines {
+        if let Some((label, body)) = footnote_definition(line) {
+            if !numbers.contains_key(label) {
+                numbers.insert(label.to_string(), numbers.len() + 1);
+                definitions.push((label, body));
+            }
+        }
+    }
+
+    let mut document = Document::default();
+    for line in lines {
+        if footnote_definition(line).is_some() {
+            continue;
+        }
+        let block = if is_scene_break(line) {
+            Block::SceneBreak
+        } else if let Some((alt, src)) = illustration_line(line) {
+            Block::Illustration {
+                src: src.to_string(),
+                alt: alt.to_string(),
+            }
+        } else if let Some((name, words)) = speaker_line(line) {
+            Block::Speaker {
+                name: name.to_string(),
+                content: parse_inlines(words, &numbers),
+            }
+        } else {
+            Block::Paragraph(parse_inlines(line, &numbers))
+        };
+        document.blocks.push(block);
+    }
+    document.footnotes = definitions
+        .into_iter()
+        .map(|(label, body)| Footnote {
+            label: label.to_string(),
+            content: parse_inlines(body, &numbers),
+        })
+        .collect();
+    document
+}
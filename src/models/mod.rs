@@ -1,3 +1,23 @@
+pub mod achievements;
+pub mod audio;
+pub mod bookmarks;
+pub mod bundle;
+pub mod chapter;
+pub mod countdown;
+pub mod diff;
 pub mod impacts;
+pub mod input_prompt;
+pub mod interpolation;
+pub mod markup;
 pub mod multi_attr_check;
-pub mod story;
+pub mod narration;
+pub mod paragraph;
+pub mod progress;
+pub mod references;
+pub mod replace;
+pub mod role;
+pub mod search;
+pub mod story_export;
+pub mod story_graph;
+pub mod subtree;
+pub mod twee;
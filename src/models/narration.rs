@@ -0,0 +1,140 @@
+//! Sentence splitting and settings for reading the story aloud.
+//!
+//! Narration reads the plain text of the merged paragraph one sentence at a
+//! time so the sentence being spoken can be highlighted. A sentence ends at
+//! `.`, `!`, `?` or `…` followed by a space and a word that is not in lower
+//! case, or by the end of the line, and at the full-width `。`, `！` and `？`
+//! wherever they appear. Closing quotes and brackets right after the mark
+//! stay with the sentence, so `"Run!" she said.` is read as one.
+
+use crate::models::markup;
+use std::ops::Range;
+
+/// Setting holding the speech rate, where `1` is the voice's normal speed.
+pub const NARRATION_RATE_SETTING: &str = "narration_rate";
+/// Setting holding the name of the chosen voice; absent means the browser's
+/// default voice for the story language.
+pub const NARRATION_VOICE_SETTING: &str = "narration_voice";
+
+/// Rates offered in the settings menu.
+pub const RATE_STEPS: [f32; 4] = [0.75, 1.0, 1.25, 1.5];
+
+const MIN_RATE: f32 = 0.5;
+const MAX_RATE: f32 = 2.0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sentence {
+    pub text: String,
+    /// Index of the line the sentence belongs to.
+    pub block: usize,
+    /// Character offsets of `text` in its line.
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Reads a stored rate, falling back to `1` and keeping it in a range every
+/// browser accepts.
+pub fn parse_rate(value: Option<&str>) -> f32 {
+    value
+        .and_then(|v| v.trim().parse::<f32>().ok())
+        .filter(|rate| rate.is_finite())
+        .map(|rate| rate.clamp(MIN_RATE, MAX_RATE))
+        .unwrap_or(1.0)
+}
+
+fn is_closing(c: char) -> bool {
+    matches!(
+        c,
+        '"' | '\'' | '”' | '’' | '」' | '』' | ')' | '）' | '》' | '〉'
+    )
+}
+
+fn split_line(line: &str, block: usize, sentences: &mut Vec<Sentence>) {
+    let chars: Vec<char> = line.chars().collect();
+    let mut start = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let full_width = matches!(c, '。' | '！' | '？');
+        if full_width || matches!(c, '.' | '!' | '?' | '…') {
+            let mut end = i + 1;
+            while end < chars.len()
+                && (matches!(chars[end], '.' | '!' | '?' | '…' | '。' | '！' | '？')
+                    || is_closing(chars[end]))
+            {
+                end += 1;
+            }
+            let next_word = chars[end..].iter().find(|c| !c.is_whitespace());
+            let ends_sentence = full_width
+                || end == chars.len()
+                || (chars[end].is_whitespace() && !next_word.is_some_and(|c| c.is_lowercase()));
+            if ends_sentence {
+                push_sentence(&chars, start..end, block, sentences);
+                start = end;
+            }
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    push_sentence(&chars, start..chars.len(), block, sentences);
+}
+
+fn push_sentence(chars: &[char], range: Range<usize>, block: usize, sentences: &mut Vec<Sentence>) {
+    let piece = &chars[range.clone()];
+    let Some(first) = piece.iter().position(|c| !c.is_whitespace()) else {
+        return;
+    };
+    let last = piece
+        .iter()
+        .rposition(|c| !c.is_whitespace())
+        .unwrap_or(first);
+    sentences.push(Sentence {
+        text: piece[first..=last].iter().collect(),
+        block,
+        start: range.start + first,
+        end: range.start + last + 1,
+    });
+}
+
+/// Splits plain text into sentences, numbering the non-empty lines.
+pub fn split_sentences(text: &str) -> Vec<Sentence> {
+    let mut sentences = Vec::new();
+    for (block, line) in text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+    {
+        split_line(line, block, &mut sentences);
+    }
+    sentences
+}
+
+/// Sentences of paragraph text written in the story markup, one line per
+/// block that has text; see [`markup::Block::plain_text`].
+pub fn sentences_for_paragraph(text: &str) -> Vec<Sentence> {
+    let mut sentences = Vec::new();
+    let document = markup::parse(text);
+    for (block, line) in document
+        .blocks
+        .iter()
+        .filter_map(markup::Block::plain_text)
+        .enumerate()
+    {
+        split_line(&line, block, &mut sentences);
+    }
+    sentences
+}
+
+/// Where to carry on after the text changed from `previous` to `current`.
+///
+/// When a choice adds a paragraph to the same page the earlier sentences are
+/// unchanged, so narration continues with the first new one; any other
+/// change starts again from the top.
+pub fn resume_index(previous: &[Sentence], current: &[Sentence]) -> usize {
+    if !previous.is_empty() && current.starts_with(previous) {
+        previous.len()
+    } else {
+        0
+    }
+}
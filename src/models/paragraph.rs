@@ -0,0 +1,236 @@
+use crate::models::impacts::Impact;
+use serde::{Deserialize, Serialize};
+
+/// Version of the choice shape written by this build.
+///
+/// * v0 – a bare target id: `"p2"`
+/// * v1 – a list of target ids `["p2", "p3"]`, or an object whose `to` is a single id
+/// * v2 – an object with a `to` list plus optional timers and impacts
+pub const CHOICE_SCHEMA_VERSION: u32 = 2;
+
+/// Canonical paragraph record shared by the API client, the reader and the dashboard.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct Paragraph {
+    pub id: String,
+    #[serde(default)]
+    pub chapter_id: String,
+    pub texts: Vec<Text>,
+    pub choices: Vec<ParagraphChoice>,
+    /// File name of the image uploaded to the record, empty when there is none.
+    /// Never written back: the file field only changes through an upload.
+    #[serde(default, skip_serializing)]
+    pub illustration: String,
+}
+
+/// One translation of a paragraph; `choices` holds the captions in choice order.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct Text {
+    pub lang: String,
+    pub paragraphs: String,
+    pub choices: Vec<String>,
+    /// Describes the paragraph's illustration to screen readers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub illustration_alt: Option<String>,
+}
+
+/// Language-independent part of a choice.
+///
+/// Every legacy shape is accepted on read (see [`LegacyChoice`]) and the
+/// v2 object is always written back.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(from = "LegacyChoice")]
+pub struct ParagraphChoice {
+    pub to: Vec<String>,
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub same_page: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_limit: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impacts: Option<Vec<Impact>>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+enum Targets {
+    Many(Vec<String>),
+    One(String),
+}
+
+impl Default for Targets {
+    fn default() -> Self {
+        Targets::Many(Vec::new())
+    }
+}
+
+impl Targets {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Targets::Many(targets) => targets,
+            Targets::One(target) if target.is_empty() => Vec::new(),
+            Targets::One(target) => vec![target],
+        }
+    }
+}
+
+/// Object form of a stored choice (v1 and v2).
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct RawChoice {
+    #[serde(default)]
+    to: Targets,
+    #[serde(rename = "type", default)]
+    type_: String,
+    #[serde(default)]
+    key: Option<String>,
+    #[serde(default)]
+    value: Option<serde_json::Value>,
+    #[serde(default)]
+    same_page: Option<bool>,
+    #[serde(default)]
+    time_limit: Option<u32>,
+    #[serde(default)]
+    timeout_to: Option<String>,
+    #[serde(default)]
+    impacts: Option<Vec<Impact>>,
+}
+
+/// Any choice shape that has been stored in the `paragraphs` collection.
+///
+/// `Object` must stay last: serde also accepts a sequence for a struct, so
+/// trying it first would misread a plain target list.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum LegacyChoice {
+    Target(String),
+    Targets(Vec<String>),
+    Object(RawChoice),
+}
+
+impl LegacyChoice {
+    /// Schema version this shape was written with.
+    pub fn version(&self) -> u32 {
+        match self {
+            LegacyChoice::Target(_) => 0,
+            LegacyChoice::Targets(_) => 1,
+            LegacyChoice::Object(raw) => match raw.to {
+                Targets::One(_) => 1,
+                Targets::Many(_) => CHOICE_SCHEMA_VERSION,
+            },
+        }
+    }
+}
+
+impl From<LegacyChoice> for ParagraphChoice {
+    fn from(legacy: LegacyChoice) -> Self {
+        match legacy {
+            LegacyChoice::Target(target) => ParagraphChoice::goto(Targets::One(target).into_vec()),
+            LegacyChoice::Targets(targets) => ParagraphChoice::goto(targets),
+            LegacyChoice::Object(raw) => ParagraphChoice {
+                to: raw.to.into_vec(),
+                // 舊資料可能缺少 type，一律視為一般跳轉
+                type_: if raw.type_.is_empty() {
+                    "goto".to_string()
+                } else {
+                    raw.type_
+                },
+                key: raw.key,
+                value: raw.value,
+                same_page: raw.same_page,
+                time_limit: raw.time_limit,
+                timeout_to: raw.timeout_to,
+                impacts: raw.impacts,
+            },
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ParagraphChoice {
+    /// Plain jump to one of `targets`.
+    pub fn goto(targets: Vec<String>) -> Self {
+        Self {
+            to: targets,
+            type_: "goto".to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn get_to(&self) -> Vec<String> {
+        self.to.clone()
+    }
+
+    pub fn get_type(&self) -> String {
+        self.type_.clone()
+    }
+
+    pub fn get_key(&self) -> Option<String> {
+        self.key.clone()
+    }
+
+    pub fn get_value(&self) -> Option<serde_json::Value> {
+        self.value.clone()
+    }
+
+    pub fn get_same_page(&self) -> Option<bool> {
+        self.same_page
+    }
+
+    pub fn get_time_limit(&self) -> Option<u32> {
+        self.time_limit
+    }
+
+    /// Timeout target ids; stored as one comma separated string.
+    pub fn timeout_targets(&self) -> Vec<String> {
+        self.timeout_to
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(|id| id.trim().to_string())
+            .filter(|id| !id.is_empty())
+            .collect()
+    }
+}
+
+impl Paragraph {
+    pub fn text_for(&self, lang: &str) -> Option<&Text> {
+        self.texts.iter().find(|text| text.lang == lang)
+    }
+
+    /// Where the illustration is served, given the API's `/files` URL.
+    pub fn illustration_url(&self, files_base: &str) -> Option<String> {
+        if self.illustration.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "{}/paragraphs/{}/{}",
+            files_base.trim_end_matches('/'),
+            self.id,
+            self.illustration
+        ))
+    }
+}
+
+/// Parses a stored choice and upgrades it to the current shape.
+///
+/// ```
+/// use ifecaro::models::paragraph::migrate_choice;
+///
+/// let (choice, from_version) = migrate_choice(serde_json::json!("p2")).unwrap();
+/// assert_eq!(from_version, 0);
+/// assert_eq!(choice.to, vec!["p2".to_string()]);
+/// assert_eq!(choice.type_, "goto");
+/// ```
+pub fn migrate_choice(
+    value: serde_json::Value,
+) -> Result<(ParagraphChoice, u32), serde_json::Error> {
+    let legacy: LegacyChoice = serde_json::from_value(value)?;
+    let version = legacy.version();
+    Ok((legacy.into(), version))
+}
@@ -0,0 +1,105 @@
+//! What a reader has seen of the story, kept across playthroughs.
+//!
+//! Choice history is rewritten when the reader goes back or clears it, so the
+//! record only ever grows: it is the union of every path taken.
+
+use crate::models::paragraph::Paragraph;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// Paragraphs visited and endings reached, persisted as one JSON document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReadingProgress {
+    #[serde(default)]
+    pub visited: BTreeSet<String>,
+    /// Visited paragraphs that had no choices when they were reached.
+    #[serde(default)]
+    pub endings: BTreeSet<String>,
+}
+
+/// How much of one chapter has been seen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChapterProgress {
+    pub visited: usize,
+    pub total: usize,
+    pub endings_reached: usize,
+    pub endings: usize,
+}
+
+impl ChapterProgress {
+    /// Share of the chapter's paragraphs visited, rounded down so 100 means
+    /// every one.
+    pub fn percent(&self) -> u8 {
+        if self.total == 0 {
+            return 0;
+        }
+        (self.visited * 100 / self.total) as u8
+    }
+}
+
+fn is_ending(paragraph: &Paragraph) -> bool {
+    paragraph.choices.iter().all(|choice| choice.to.is_empty())
+}
+
+impl ReadingProgress {
+    pub fn from_json(raw: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(raw)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self)
+            .unwrap_or_else(|_| "{\"visited\":[],\"endings\":[]}".to_string())
+    }
+
+    pub fn has_visited(&self, paragraph_id: &str) -> bool {
+        self.visited.contains(paragraph_id)
+    }
+
+    /// Marks `ids` as visited, e.g. a stored choice history. Ids missing from
+    /// `paragraphs` are kept, so endings are only known for loaded ones.
+    ///
+    /// Returns whether anything new was recorded.
+    pub fn record<'a>(
+        &mut self,
+        ids: impl IntoIterator<Item = &'a str>,
+        paragraphs: &[Paragraph],
+    ) -> bool {
+        let mut changed = false;
+        for id in ids {
+            if id.is_empty() {
+                continue;
+            }
+            changed |= self.visited.insert(id.to_string());
+            if paragraphs.iter().any(|p| p.id == id && is_ending(p)) {
+                changed |= self.endings.insert(id.to_string());
+            }
+        }
+        changed
+    }
+
+    pub fn chapter(&self, paragraphs: &[Paragraph], chapter_id: &str) -> ChapterProgress {
+        paragraphs
+            .iter()
+            .filter(|p| p.chapter_id == chapter_id)
+            .fold(ChapterProgress::default(), |mut progress, paragraph| {
+                let visited = self.has_visited(&paragraph.id);
+                progress.total += 1;
+                progress.visited += visited as usize;
+                if is_ending(paragraph) {
+                    progress.endings += 1;
+                    progress.endings_reached += visited as usize;
+                }
+                progress
+            })
+    }
+
+    /// [`ChapterProgress::percent`] of every chapter with paragraphs, by id.
+    pub fn percentages(&self, paragraphs: &[Paragraph]) -> HashMap<String, u8> {
+        let chapter_ids: BTreeSet<&str> =
+            paragraphs.iter().map(|p| p.chapter_id.as_str()).collect();
+        chapter_ids
+            .into_iter()
+            .map(|id| (id.to_string(), self.chapter(paragraphs, id).percent()))
+            .collect()
+    }
+}
@@ -0,0 +1,148 @@
+use crate::models::paragraph::Paragraph;
+use crate::models::story_graph::EdgeSlot;
+use std::collections::HashSet;
+
+/// A choice target that points at a paragraph about to disappear.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboundReference {
+    pub paragraph_id: String,
+    pub choice_index: usize,
+    pub slot: EdgeSlot,
+    pub target: String,
+}
+
+/// Every `to` and `timeout_to` entry that points into `targets`.
+///
+/// Paragraphs inside `targets` are skipped: they go away together with the
+/// paragraphs they point at.
+pub fn inbound_references(
+    paragraphs: &[Paragraph],
+    targets: &HashSet<&str>,
+) -> Vec<InboundReference> {
+    let mut references = Vec::new();
+    for paragraph in paragraphs {
+        if targets.contains(paragraph.id.as_str()) {
+            continue;
+        }
+        for (choice_index, choice) in paragraph.choices.iter().enumerate() {
+            let goto_slots = choice
+                .to
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(i, target)| (EdgeSlot::Target(i), target));
+            let timeout_slots = choice
+                .timeout_targets()
+                .into_iter()
+                .enumerate()
+                .map(|(i, target)| (EdgeSlot::Timeout(i), target));
+            for (slot, target) in goto_slots.chain(timeout_slots) {
+                if targets.contains(target.as_str()) {
+                    references.push(InboundReference {
+                        paragraph_id: paragraph.id.clone(),
+                        choice_index,
+                        slot,
+                        target,
+                    });
+                }
+            }
+        }
+    }
+    references
+}
+
+/// What to do with choices that point at a paragraph being deleted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReferenceRepair {
+    /// Point every reference at this paragraph instead.
+    Retarget(String),
+    /// Drop the choices; a dangling timeout only loses its countdown.
+    RemoveChoices,
+}
+
+/// Applies `repair` to `references` and returns the paragraphs that changed.
+///
+/// Removing a choice also removes its caption from every translation so the
+/// captions stay aligned with `choices`.
+pub fn repair_references(
+    paragraphs: &[Paragraph],
+    references: &[InboundReference],
+    repair: &ReferenceRepair,
+) -> Vec<Paragraph> {
+    let mut updated = Vec::new();
+    for paragraph in paragraphs {
+        let own: Vec<&InboundReference> = references
+            .iter()
+            .filter(|r| r.paragraph_id == paragraph.id)
+            .collect();
+        if own.is_empty() {
+            continue;
+        }
+        let mut paragraph = paragraph.clone();
+        match repair {
+            ReferenceRepair::Retarget(new_target) => {
+                for reference in &own {
+                    let Some(choice) = paragraph.choices.get_mut(reference.choice_index) else {
+                        continue;
+                    };
+                    match reference.slot {
+                        EdgeSlot::Target(i) => {
+                            if let Some(slot) = choice.to.get_mut(i) {
+                                *slot = new_target.clone();
+                            }
+                        }
+                        EdgeSlot::Timeout(i) => {
+                            let mut targets = choice.timeout_targets();
+                            if let Some(slot) = targets.get_mut(i) {
+                                *slot = new_target.clone();
+                                choice.timeout_to = Some(targets.join(","));
+                            }
+                        }
+                    }
+                }
+            }
+            ReferenceRepair::RemoveChoices => {
+                let mut removed: Vec<usize> = own
+                    .iter()
+                    .filter(|r| matches!(r.slot, EdgeSlot::Target(_)))
+                    .map(|r| r.choice_index)
+                    .collect();
+                removed.sort_unstable();
+                removed.dedup();
+
+                for reference in &own {
+                    if removed.contains(&reference.choice_index) {
+                        continue;
+                    }
+                    let Some(choice) = paragraph.choices.get_mut(reference.choice_index) else {
+                        continue;
+                    };
+                    let targets: Vec<String> = choice
+                        .timeout_targets()
+                        .into_iter()
+                        .filter(|target| *target != reference.target)
+                        .collect();
+                    if targets.is_empty() {
+                        choice.timeout_to = None;
+                        choice.time_limit = None;
+                    } else {
+                        choice.timeout_to = Some(targets.join(","));
+                    }
+                }
+
+                for &index in removed.iter().rev() {
+                    if index < paragraph.choices.len() {
+                        paragraph.choices.remove(index);
+                    }
+                    for text in &mut paragraph.texts {
+                        if index < text.choices.len() {
+                            text.choices.remove(index);
+                        }
+                    }
+                }
+            }
+        }
+        updated.push(paragraph);
+    }
+    updated
+}
@@ -0,0 +1,217 @@
+use crate::models::paragraph::Paragraph;
+use crate::models::search::MatchField;
+use regex::{Regex, RegexBuilder};
+use std::collections::HashSet;
+
+/// Characters of context shown on each side of a match.
+const CONTEXT_CHARS: usize = 30;
+
+/// What to look for and where.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FindOptions {
+    pub pattern: String,
+    /// Treat `pattern` as a regular expression; the replacement may then
+    /// refer to groups as `$1` or `${name}`.
+    pub regex: bool,
+    pub case_sensitive: bool,
+    /// Only this language; every language when `None`.
+    pub lang: Option<String>,
+    /// Only this chapter; the whole story when `None`.
+    pub chapter_id: Option<String>,
+}
+
+impl FindOptions {
+    pub fn compile(&self) -> Result<Regex, regex::Error> {
+        let source = if self.regex {
+            self.pattern.clone()
+        } else {
+            regex::escape(&self.pattern)
+        };
+        RegexBuilder::new(&source)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+    }
+}
+
+/// One occurrence of the pattern, with the text that would replace it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplaceMatch {
+    pub paragraph_id: String,
+    pub lang: String,
+    pub field: MatchField,
+    /// Byte range of the match inside the field.
+    pub start: usize,
+    pub end: usize,
+    pub before: String,
+    pub matched: String,
+    pub after: String,
+    pub replacement: String,
+}
+
+impl ReplaceMatch {
+    /// Stable identity used to opt single matches out of a replace.
+    pub fn key(&self) -> String {
+        let field = match self.field {
+            MatchField::Body => "body".to_string(),
+            MatchField::Caption(index) => format!("choice{}", index),
+        };
+        format!(
+            "{}/{}/{}/{}",
+            self.paragraph_id, self.lang, field, self.start
+        )
+    }
+}
+
+fn context_before(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let from = chars.len().saturating_sub(CONTEXT_CHARS);
+    let context: String = chars[from..].iter().collect();
+    if from > 0 {
+        format!("…{}", context)
+    } else {
+        context
+    }
+}
+
+fn context_after(text: &str) -> String {
+    let mut chars = text.chars();
+    let context: String = chars.by_ref().take(CONTEXT_CHARS).collect();
+    if chars.next().is_some() {
+        format!("{}…", context)
+    } else {
+        context
+    }
+}
+
+fn field_matches(
+    regex: &Regex,
+    options: &FindOptions,
+    replacement: &str,
+    paragraph_id: &str,
+    lang: &str,
+    field: MatchField,
+    text: &str,
+) -> Vec<ReplaceMatch> {
+    regex
+        .captures_iter(text)
+        .filter_map(|captures| {
+            let whole = captures.get(0)?;
+            // Patterns such as `a*` also match the empty string between characters
+            if whole.is_empty() {
+                return None;
+            }
+            let mut expanded = String::new();
+            if options.regex {
+                captures.expand(replacement, &mut expanded);
+            } else {
+                expanded.push_str(replacement);
+            }
+            Some(ReplaceMatch {
+                paragraph_id: paragraph_id.to_string(),
+                lang: lang.to_string(),
+                field: field.clone(),
+                start: whole.start(),
+                end: whole.end(),
+                before: context_before(&text[..whole.start()]),
+                matched: whole.as_str().to_string(),
+                after: context_after(&text[whole.end()..]),
+                replacement: expanded,
+            })
+        })
+        .collect()
+}
+
+/// Every match of `options` in body texts and choice captions, in story order.
+///
+/// An empty pattern finds nothing; an invalid regular expression is returned
+/// as an error so the form can show it.
+pub fn find_matches(
+    paragraphs: &[Paragraph],
+    options: &FindOptions,
+    replacement: &str,
+) -> Result<Vec<ReplaceMatch>, regex::Error> {
+    if options.pattern.is_empty() {
+        return Ok(Vec::new());
+    }
+    let regex = options.compile()?;
+    let mut matches = Vec::new();
+    for paragraph in paragraphs {
+        if let Some(chapter_id) = &options.chapter_id {
+            if paragraph.chapter_id != *chapter_id {
+                continue;
+            }
+        }
+        for text in &paragraph.texts {
+            if options.lang.as_ref().is_some_and(|lang| *lang != text.lang) {
+                continue;
+            }
+            let fields = std::iter::once((MatchField::Body, &text.paragraphs)).chain(
+                text.choices
+                    .iter()
+                    .enumerate()
+                    .map(|(index, caption)| (MatchField::Caption(index), caption)),
+            );
+            for (field, value) in fields {
+                matches.extend(field_matches(
+                    &regex,
+                    options,
+                    replacement,
+                    &paragraph.id,
+                    &text.lang,
+                    field,
+                    value,
+                ));
+            }
+        }
+    }
+    Ok(matches)
+}
+
+fn splice(text: &mut String, mut matches: Vec<&ReplaceMatch>) {
+    matches.sort_by_key(|m| std::cmp::Reverse(m.start));
+    for found in matches {
+        text.replace_range(found.start..found.end, &found.replacement);
+    }
+}
+
+/// Applies the matches not listed in `excluded` (by [`ReplaceMatch::key`])
+/// and returns the paragraphs that changed.
+pub fn apply_matches(
+    paragraphs: &[Paragraph],
+    matches: &[ReplaceMatch],
+    excluded: &HashSet<String>,
+) -> Vec<Paragraph> {
+    let selected: Vec<&ReplaceMatch> = matches
+        .iter()
+        .filter(|m| !excluded.contains(&m.key()))
+        .collect();
+    paragraphs
+        .iter()
+        .filter(|p| selected.iter().any(|m| m.paragraph_id == p.id))
+        .map(|paragraph| {
+            let mut edited = paragraph.clone();
+            for text in &mut edited.texts {
+                let in_text = |field: &MatchField| {
+                    selected
+                        .iter()
+                        .copied()
+                        .filter(|m| {
+                            m.paragraph_id == paragraph.id
+                                && m.lang == text.lang
+                                && m.field == *field
+                        })
+                        .collect::<Vec<_>>()
+                };
+                let body = in_text(&MatchField::Body);
+                let captions: Vec<Vec<&ReplaceMatch>> = (0..text.choices.len())
+                    .map(|index| in_text(&MatchField::Caption(index)))
+                    .collect();
+                splice(&mut text.paragraphs, body);
+                for (caption, found) in text.choices.iter_mut().zip(captions) {
+                    splice(caption, found);
+                }
+            }
+            edited
+        })
+        .collect()
+}
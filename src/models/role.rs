@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// Role stored on a `users` record, ordered from least to most privileged.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    #[default]
+    Reader,
+    Translator,
+    Editor,
+    Admin,
+}
+
+/// What a signed-in user may do in the dashboard.
+///
+/// These checks only shape the UI; the PocketBase collection rules and hooks
+/// under `pocketbase/` are what actually reject unauthorised writes.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Capabilities {
+    pub role: Role,
+    /// Languages a translator is assigned to; ignored for editors and admins.
+    pub languages: Vec<String>,
+}
+
+impl Capabilities {
+    pub fn new(role: Role, languages: Vec<String>) -> Self {
+        Self { role, languages }
+    }
+
+    pub fn has_role(&self, minimum: Role) -> bool {
+        self.role >= minimum
+    }
+
+    pub fn can_access_dashboard(&self) -> bool {
+        self.has_role(Role::Translator)
+    }
+
+    /// Editing a paragraph's `Text` entry for `lang`.
+    pub fn can_edit_language(&self, lang: &str) -> bool {
+        match self.role {
+            Role::Reader => false,
+            Role::Translator => self.languages.iter().any(|assigned| assigned == lang),
+            Role::Editor | Role::Admin => true,
+        }
+    }
+
+    /// Creating paragraphs and changing chapters, choices and impacts.
+    pub fn can_edit_structure(&self) -> bool {
+        self.has_role(Role::Editor)
+    }
+
+    pub fn can_review_invites(&self) -> bool {
+        self.has_role(Role::Admin)
+    }
+}
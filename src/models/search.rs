@@ -0,0 +1,284 @@
+use crate::models::paragraph::Paragraph;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Characters of context kept on each side of a match in a snippet.
+const SNIPPET_CONTEXT: usize = 30;
+
+/// Where in a paragraph a match was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchField {
+    Body,
+    /// Caption of the choice at this index.
+    Caption(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    pub lang: String,
+    pub field: MatchField,
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit {
+    pub paragraph_id: String,
+    pub chapter_id: String,
+    /// Empty when the query was empty and the hit only passed the filters.
+    pub matches: Vec<SearchMatch>,
+}
+
+/// Restrictions applied on top of the text query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchFilters {
+    pub chapter_id: Option<String>,
+    /// Only paragraphs that have no translation in this language.
+    pub missing_lang: Option<String>,
+    pub has_impacts: bool,
+    pub has_timer: bool,
+}
+
+impl SearchFilters {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn accepts(&self, paragraph: &Paragraph) -> bool {
+        if let Some(chapter_id) = &self.chapter_id {
+            if paragraph.chapter_id != *chapter_id {
+                return false;
+            }
+        }
+        if let Some(lang) = &self.missing_lang {
+            if paragraph.text_for(lang).is_some() {
+                return false;
+            }
+        }
+        if self.has_impacts
+            && !paragraph
+                .choices
+                .iter()
+                .any(|c| c.impacts.as_ref().is_some_and(|i| !i.is_empty()))
+        {
+            return false;
+        }
+        if self.has_timer
+            && !paragraph
+                .choices
+                .iter()
+                .any(|c| c.time_limit.is_some_and(|limit| limit > 0))
+        {
+            return false;
+        }
+        true
+    }
+}
+
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Scripts written without spaces between words; each character is indexed
+/// as a token of its own.
+fn is_unspaced(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30ff}'   // Hiragana, Katakana
+        | '\u{3400}'..='\u{4dbf}' // CJK Extension A
+        | '\u{4e00}'..='\u{9fff}' // CJK Unified Ideographs
+        | '\u{f900}'..='\u{faff}' // CJK Compatibility Ideographs
+        | '\u{0e00}'..='\u{0e7f}' // Thai
+    )
+}
+
+/// Lowercased words of `text`, with unspaced scripts split per character.
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    for c in text.chars().map(fold) {
+        if is_unspaced(c) {
+            if !word.is_empty() {
+                tokens.push(std::mem::take(&mut word));
+            }
+            tokens.push(c.to_string());
+        } else if c.is_alphanumeric() {
+            word.push(c);
+        } else if !word.is_empty() {
+            tokens.push(std::mem::take(&mut word));
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    tokens
+}
+
+/// Lowercased words of `query` like [`tokenize`], except that each run of
+/// unspaced characters stays one phrase.
+fn query_parts(query: &str) -> Vec<Vec<char>> {
+    let mut parts = Vec::new();
+    let mut part: Vec<char> = Vec::new();
+    for c in query.chars().map(fold) {
+        let kept = c.is_alphanumeric() || is_unspaced(c);
+        let same_script = part
+            .last()
+            .is_none_or(|&last| is_unspaced(last) == is_unspaced(c));
+        if !part.is_empty() && (!kept || !same_script) {
+            parts.push(std::mem::take(&mut part));
+        }
+        if kept {
+            part.push(c);
+        }
+    }
+    if !part.is_empty() {
+        parts.push(part);
+    }
+    parts
+}
+
+/// Char index of the first case-insensitive occurrence of `needle`.
+fn find_folded(haystack: &[char], needle: &[char]) -> Option<usize> {
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return None;
+    }
+    (0..=haystack.len() - needle.len())
+        .find(|&start| (0..needle.len()).all(|i| fold(haystack[start + i]) == needle[i]))
+}
+
+/// Whether `phrase` appears unbroken in the body or a caption of any
+/// translation.
+fn contains_phrase(paragraph: &Paragraph, phrase: &[char]) -> bool {
+    paragraph.texts.iter().any(|text| {
+        std::iter::once(&text.paragraphs)
+            .chain(&text.choices)
+            .any(|field| find_folded(&field.chars().collect::<Vec<_>>(), phrase).is_some())
+    })
+}
+
+/// The first occurrence of any term in `text` with some context around it.
+fn snippet_around(text: &str, terms: &[Vec<char>]) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let (start, len) = terms
+        .iter()
+        .filter_map(|term| find_folded(&chars, term).map(|start| (start, term.len())))
+        .min()?;
+    let from = start.saturating_sub(SNIPPET_CONTEXT);
+    let to = (start + len + SNIPPET_CONTEXT).min(chars.len());
+    let body: String = chars[from..to]
+        .iter()
+        .map(|&c| if c == '\n' { ' ' } else { c })
+        .collect();
+    let prefix = if from > 0 { "…" } else { "" };
+    let suffix = if to < chars.len() { "…" } else { "" };
+    Some(format!("{}{}{}", prefix, body.trim(), suffix))
+}
+
+/// In-memory inverted index over the body text and choice captions of every
+/// translation.
+///
+/// A query matches a paragraph when each of its words is a prefix of some
+/// word in the paragraph, in any language. Unspaced characters are looked up
+/// one by one, so a run of them must also appear unbroken in one field.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchIndex {
+    paragraphs: Vec<Paragraph>,
+    postings: BTreeMap<String, BTreeSet<usize>>,
+}
+
+impl SearchIndex {
+    pub fn new(paragraphs: &[Paragraph]) -> Self {
+        let mut postings: BTreeMap<String, BTreeSet<usize>> = BTreeMap::new();
+        for (index, paragraph) in paragraphs.iter().enumerate() {
+            for text in &paragraph.texts {
+                let fields = std::iter::once(&text.paragraphs).chain(&text.choices);
+                for token in fields.flat_map(|field| tokenize(field)) {
+                    postings.entry(token).or_default().insert(index);
+                }
+            }
+        }
+        Self {
+            paragraphs: paragraphs.to_vec(),
+            postings,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.paragraphs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paragraphs.is_empty()
+    }
+
+    /// Paragraphs with a word starting with `prefix`.
+    fn with_prefix(&self, prefix: &str) -> BTreeSet<usize> {
+        self.postings
+            .range(prefix.to_string()..)
+            .take_while(|(token, _)| token.starts_with(prefix))
+            .flat_map(|(_, indexes)| indexes.iter().copied())
+            .collect()
+    }
+
+    /// Hits in index order. An empty query lists every paragraph that passes
+    /// `filters`, or nothing when there are no filters either.
+    pub fn search(&self, query: &str, filters: &SearchFilters) -> Vec<SearchHit> {
+        let terms = tokenize(query);
+        if terms.is_empty() {
+            if !query.trim().is_empty() || filters.is_empty() {
+                return Vec::new();
+            }
+            return self
+                .paragraphs
+                .iter()
+                .filter(|p| filters.accepts(p))
+                .map(|p| SearchHit {
+                    paragraph_id: p.id.clone(),
+                    chapter_id: p.chapter_id.clone(),
+                    matches: Vec::new(),
+                })
+                .collect();
+        }
+
+        let mut candidates = self.with_prefix(&terms[0]);
+        for term in &terms[1..] {
+            let next = self.with_prefix(term);
+            candidates.retain(|index| next.contains(index));
+        }
+
+        let needles = query_parts(query);
+        let phrases: Vec<&Vec<char>> = needles
+            .iter()
+            .filter(|part| part.len() > 1 && is_unspaced(part[0]))
+            .collect();
+        candidates
+            .into_iter()
+            .map(|index| &self.paragraphs[index])
+            .filter(|p| filters.accepts(p))
+            .filter(|p| phrases.iter().all(|phrase| contains_phrase(p, phrase)))
+            .map(|paragraph| {
+                let mut matches = Vec::new();
+                for text in &paragraph.texts {
+                    if let Some(snippet) = snippet_around(&text.paragraphs, &needles) {
+                        matches.push(SearchMatch {
+                            lang: text.lang.clone(),
+                            field: MatchField::Body,
+                            snippet,
+                        });
+                    }
+                    for (i, caption) in text.choices.iter().enumerate() {
+                        if let Some(snippet) = snippet_around(caption, &needles) {
+                            matches.push(SearchMatch {
+                                lang: text.lang.clone(),
+                                field: MatchField::Caption(i),
+                                snippet,
+                            });
+                        }
+                    }
+                }
+                SearchHit {
+                    paragraph_id: paragraph.id.clone(),
+                    chapter_id: paragraph.chapter_id.clone(),
+                    matches,
+                }
+            })
+            .collect()
+    }
+}
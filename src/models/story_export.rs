@@ -0,0 +1,697 @@
+use crate::models::bundle::StoryBundle;
+use crate::models::impacts::{Impact, NumericOp};
+use crate::models::paragraph::{Paragraph, ParagraphChoice, Text};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Caption used when a choice has none in the exported language.
+const FALLBACK_CAPTION: &str = "→";
+/// ChoiceScript variable holding the roll for random multi-target choices.
+const ROLL_VARIABLE: &str = "random_roll";
+
+/// One generated source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportFile {
+    pub name: String,
+    pub content: String,
+}
+
+/// Something the target format cannot express the way the engine does, so a
+/// re-import would not give back the same story.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportIssue {
+    /// The time limit and timeout target are dropped; the choice waits.
+    Timer { paragraph_id: String, choice: usize },
+    /// Several targets are picked at random by generated code.
+    RandomTargets {
+        paragraph_id: String,
+        choice: usize,
+        targets: usize,
+    },
+    /// A choice that changes a reader setting; only its jump is kept.
+    SettingChoice { paragraph_id: String, choice: usize },
+    /// A flag impact whose value is not a bool, number or string.
+    UnsupportedFlag { paragraph_id: String, choice: usize },
+    /// No text in the exported language; the first translation is used.
+    MissingTranslation { paragraph_id: String },
+    /// A line of prose that ChoiceScript would read as code: its indentation
+    /// is dropped and a leading `*` is kept as text with a zero-width space.
+    RewrittenLine { paragraph_id: String, line: usize },
+    /// The paragraph has an uploaded image; files are not part of any export.
+    Illustration { paragraph_id: String },
+    /// A character or flag whose identifier another one already took, e.g.
+    /// two names written only in CJK; it gets a numeric suffix.
+    RenamedVariable { source: String, name: String },
+}
+
+impl fmt::Display for ExportIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportIssue::Timer {
+                paragraph_id,
+                choice,
+            } => write!(
+                f,
+                "{} choice {}: time limit and timeout target are not exported",
+                paragraph_id,
+                choice + 1
+            ),
+            ExportIssue::RandomTargets {
+                paragraph_id,
+                choice,
+                targets,
+            } => write!(
+                f,
+                "{} choice {}: random pick among {} targets is generated code",
+                paragraph_id,
+                choice + 1,
+                targets
+            ),
+            ExportIssue::SettingChoice {
+                paragraph_id,
+                choice,
+            } => write!(
+                f,
+                "{} choice {}: reader setting change is not exported",
+                paragraph_id,
+                choice + 1
+            ),
+            ExportIssue::UnsupportedFlag {
+                paragraph_id,
+                choice,
+            } => write!(
+                f,
+                "{} choice {}: flag with a list or object value is not exported",
+                paragraph_id,
+                choice + 1
+            ),
+            ExportIssue::MissingTranslation { paragraph_id } => write!(
+                f,
+                "{}: no text in the exported language, first translation used",
+                paragraph_id
+            ),
+            ExportIssue::RewrittenLine { paragraph_id, line } => write!(
+                f,
+                "{} line {}: leading `*` or indentation is ChoiceScript syntax and was changed",
+                paragraph_id,
+                line + 1
+            ),
+            ExportIssue::Illustration { paragraph_id } => write!(
+                f,
+                "{}: illustration file is not exported and has to be uploaded again",
+                paragraph_id
+            ),
+            ExportIssue::RenamedVariable { source, name } => write!(
+                f,
+                "{}: identifier already taken, exported as {}",
+                source, name
+            ),
+        }
+    }
+}
+
+/// Files of one export and what did not carry over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoryExport {
+    pub files: Vec<ExportFile>,
+    pub issues: Vec<ExportIssue>,
+}
+
+/// Lowercase identifier safe in both Ink and ChoiceScript.
+fn identifier(raw: &str) -> String {
+    let mut name: String = raw
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert(0, 'v');
+    }
+    name
+}
+
+/// `base`, or `base_2`, `base_3`… when `taken` already holds it.
+fn unique_name(base: String, taken: impl Fn(&str) -> bool) -> String {
+    let mut name = base.clone();
+    let mut suffix = 1;
+    while taken(&name) {
+        suffix += 1;
+        name = format!("{}_{}", base, suffix);
+    }
+    name
+}
+
+/// `Honesty` → `honesty`; every attribute and relationship field is one word.
+fn field_name(field: &impl fmt::Debug) -> String {
+    format!("{:?}", field).to_lowercase()
+}
+
+fn knot(paragraph_id: &str) -> String {
+    format!("p_{}", identifier(paragraph_id))
+}
+
+/// A variable change produced by an impact.
+#[derive(Debug, Clone, PartialEq)]
+enum Assignment {
+    Number {
+        variable: String,
+        op: NumericOp,
+        value: i32,
+    },
+    Flag {
+        variable: String,
+        value: String,
+    },
+}
+
+fn literal(value: &Value) -> Option<(String, String)> {
+    // (literal, initial value of the variable)
+    match value {
+        Value::Bool(flag) => Some((flag.to_string(), "false".to_string())),
+        Value::Number(number) => Some((number.to_string(), "0".to_string())),
+        Value::String(text) => Some((
+            format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"")),
+            "\"\"".to_string(),
+        )),
+        _ => None,
+    }
+}
+
+/// Names characters by their `char_id` and lays out every variable the
+/// impacts of the story touch.
+struct Variables {
+    names: HashMap<String, String>,
+    /// Flag variable of each `character.path`.
+    flags: HashMap<String, String>,
+    /// Variable → initial value, sorted for stable output.
+    declared: BTreeMap<String, String>,
+}
+
+impl Variables {
+    fn new(bundle: &StoryBundle, issues: &mut Vec<ExportIssue>) -> Self {
+        let mut names: HashMap<String, String> = HashMap::new();
+        for c in &bundle.characters {
+            let Some(id) = c.get("id").and_then(Value::as_str) else {
+                continue;
+            };
+            if names.contains_key(id) {
+                continue;
+            }
+            let source = c.get("char_id").and_then(Value::as_str).unwrap_or(id);
+            let base = identifier(source);
+            let name = unique_name(base.clone(), |name| names.values().any(|n| n == name));
+            if name != base {
+                issues.push(ExportIssue::RenamedVariable {
+                    source: source.to_string(),
+                    name: name.clone(),
+                });
+            }
+            names.insert(id.to_string(), name);
+        }
+        Self {
+            names,
+            flags: HashMap::new(),
+            declared: BTreeMap::new(),
+        }
+    }
+
+    fn character(&self, id: &str) -> String {
+        self.names
+            .get(id)
+            .cloned()
+            .unwrap_or_else(|| identifier(id))
+    }
+
+    fn flag(
+        &mut self,
+        character_id: &str,
+        path: &[String],
+        issues: &mut Vec<ExportIssue>,
+    ) -> String {
+        let character = self.character(character_id);
+        let source = format!("{}.{}", character, path.join("."));
+        if let Some(variable) = self.flags.get(&source) {
+            return variable.clone();
+        }
+        let base = identifier(&format!("flag_{}_{}", character, path.join("_")));
+        let variable = unique_name(base.clone(), |name| self.flags.values().any(|v| v == name));
+        if variable != base {
+            issues.push(ExportIssue::RenamedVariable {
+                source: source.clone(),
+                name: variable.clone(),
+            });
+        }
+        self.flags.insert(source, variable.clone());
+        variable
+    }
+
+    fn assignment(&mut self, impact: &Impact, issues: &mut Vec<ExportIssue>) -> Option<Assignment> {
+        let assignment = match impact {
+            Impact::CharacterAttribute {
+                character_id,
+                field,
+                op,
+                value,
+            } => Assignment::Number {
+                variable: format!("{}_{}", self.character(character_id), field_name(field)),
+                op: op.clone(),
+                value: *value,
+            },
+            Impact::Relationship {
+                from_id,
+                to_id,
+                field,
+                op,
+                value,
+            } => Assignment::Number {
+                variable: format!(
+                    "rel_{}_{}_{}",
+                    self.character(from_id),
+                    self.character(to_id),
+                    field_name(field)
+                ),
+                op: op.clone(),
+                value: *value,
+            },
+            Impact::Flag {
+                character_id,
+                path,
+                value,
+            } => {
+                let (value, initial) = literal(value)?;
+                let variable = self.flag(character_id, path, issues);
+                self.declared.entry(variable.clone()).or_insert(initial);
+                return Some(Assignment::Flag { variable, value });
+            }
+        };
+        if let Assignment::Number { variable, .. } = &assignment {
+            self.declared
+                .entry(variable.clone())
+                .or_insert_with(|| "0".to_string());
+        }
+        Some(assignment)
+    }
+}
+
+/// Text of `paragraph` in `lang`, falling back to its first translation.
+fn text_in<'a>(
+    paragraph: &'a Paragraph,
+    lang: &str,
+    issues: &mut Vec<ExportIssue>,
+) -> Option<&'a Text> {
+    let text = paragraph.text_for(lang);
+    if text.is_none() && !paragraph.texts.is_empty() {
+        issues.push(ExportIssue::MissingTranslation {
+            paragraph_id: paragraph.id.clone(),
+        });
+    }
+    text.or_else(|| paragraph.texts.first())
+}
+
+fn note_illustration(paragraph: &Paragraph, issues: &mut Vec<ExportIssue>) {
+    if !paragraph.illustration.is_empty() {
+        issues.push(ExportIssue::Illustration {
+            paragraph_id: paragraph.id.clone(),
+        });
+    }
+}
+
+/// Issues of a bundle export: only the illustration files are left out.
+pub fn illustration_issues(paragraphs: &[Paragraph]) -> Vec<ExportIssue> {
+    let mut issues = Vec::new();
+    for paragraph in paragraphs {
+        note_illustration(paragraph, &mut issues);
+    }
+    issues
+}
+
+fn caption(text: Option<&Text>, index: usize) -> String {
+    text.and_then(|t| t.choices.get(index))
+        .map(|c| c.trim())
+        .filter(|c| !c.is_empty())
+        .unwrap_or(FALLBACK_CAPTION)
+        .to_string()
+}
+
+/// Notes what `choice` loses in any export, and its impacts as assignments.
+fn inspect_choice(
+    paragraph: &Paragraph,
+    index: usize,
+    choice: &ParagraphChoice,
+    variables: &mut Variables,
+    issues: &mut Vec<ExportIssue>,
+) -> Vec<Assignment> {
+    let paragraph_id = paragraph.id.clone();
+    if choice.time_limit.is_some_and(|limit| limit > 0) || choice.timeout_to.is_some() {
+        issues.push(ExportIssue::Timer {
+            paragraph_id: paragraph_id.clone(),
+            choice: index,
+        });
+    }
+    if choice.to.len() > 1 {
+        issues.push(ExportIssue::RandomTargets {
+            paragraph_id: paragraph_id.clone(),
+            choice: index,
+            targets: choice.to.len(),
+        });
+    }
+    if choice.type_ == "settings" || choice.type_ == "setting" {
+        issues.push(ExportIssue::SettingChoice {
+            paragraph_id: paragraph_id.clone(),
+            choice: index,
+        });
+    }
+    let mut assignments = Vec::new();
+    for impact in choice.impacts.iter().flatten() {
+        match variables.assignment(impact, issues) {
+            Some(assignment) => assignments.push(assignment),
+            None => issues.push(ExportIssue::UnsupportedFlag {
+                paragraph_id: paragraph_id.clone(),
+                choice: index,
+            }),
+        }
+    }
+    assignments
+}
+
+/// Paragraphs in reading order: chapter by chapter, with paragraphs of
+/// unknown chapters last. The first one is where the story starts.
+fn ordered(bundle: &StoryBundle) -> Vec<&Paragraph> {
+    let mut chapters: Vec<_> = bundle.chapters.iter().collect();
+    chapters.sort_by_key(|c| c.order);
+    let rank: HashMap<&str, usize> = chapters
+        .iter()
+        .enumerate()
+        .map(|(rank, c)| (c.id.as_str(), rank))
+        .collect();
+    let mut paragraphs: Vec<&Paragraph> = bundle.paragraphs.iter().collect();
+    paragraphs.sort_by_key(|p| {
+        rank.get(p.chapter_id.as_str())
+            .copied()
+            .unwrap_or(usize::MAX)
+    });
+    paragraphs
+}
+
+fn escape_ink(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '{' | '}' | '[' | ']' | '|' | '#' | '~' | '/') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    // Ink ignores indentation, so an indented `*` still starts a choice
+    let indent = out.len() - out.trim_start().len();
+    if matches!(
+        out[indent..].chars().next(),
+        Some('*' | '+' | '-' | '=' | '<' | '>')
+    ) {
+        out.insert(indent, '\\');
+    }
+    out
+}
+
+fn ink_assignment(assignment: &Assignment) -> String {
+    match assignment {
+        Assignment::Number {
+            variable,
+            op: NumericOp::Add,
+            value,
+        } => format!("~ {} += {}", variable, value),
+        Assignment::Number {
+            variable,
+            op: NumericOp::Set,
+            value,
+        } => format!("~ {} = {}", variable, value),
+        Assignment::Number {
+            variable,
+            op: NumericOp::Scale,
+            value,
+        } => format!("~ {0} = INT({0} * {1} / 100.0 + 0.5)", variable, value),
+        Assignment::Flag { variable, value } => format!("~ {} = {}", variable, value),
+    }
+}
+
+fn ink_divert(targets: &[String]) -> String {
+    match targets {
+        [] => "-> END".to_string(),
+        [target] => format!("-> {}", knot(target)),
+        _ => {
+            let diverts: Vec<String> = targets.iter().map(|t| format!("-> {}", knot(t))).collect();
+            format!("{{~{}}}", diverts.join("|"))
+        }
+    }
+}
+
+/// The story as a single Ink file: one knot per paragraph, a sticky choice
+/// per engine choice, and impacts as variable assignments.
+pub fn export_ink(bundle: &StoryBundle, lang: &str) -> StoryExport {
+    let mut issues = Vec::new();
+    let mut variables = Variables::new(bundle, &mut issues);
+    let paragraphs = ordered(bundle);
+    let titles: HashMap<&str, &str> = bundle
+        .chapters
+        .iter()
+        .filter_map(|c| {
+            let title = c
+                .title_for(lang)
+                .or_else(|| c.titles.first().map(|t| t.title.as_str()))?;
+            Some((c.id.as_str(), title))
+        })
+        .collect();
+
+    let mut knots = Vec::new();
+    for paragraph in &paragraphs {
+        let text = text_in(paragraph, lang, &mut issues);
+        note_illustration(paragraph, &mut issues);
+        let mut lines = vec![format!("=== {} ===", knot(&paragraph.id))];
+        if let Some(title) = titles.get(paragraph.chapter_id.as_str()) {
+            lines.push(format!("// Chapter: {}", title));
+        }
+        if let Some(text) = text {
+            lines.extend(text.paragraphs.lines().map(escape_ink));
+        }
+        for (index, choice) in paragraph.choices.iter().enumerate() {
+            let assignments = inspect_choice(paragraph, index, choice, &mut variables, &mut issues);
+            lines.push(format!("+ [{}]", escape_ink(&caption(text, index))));
+            lines.extend(
+                assignments
+                    .iter()
+                    .map(|a| format!("    {}", ink_assignment(a))),
+            );
+            lines.push(format!("    {}", ink_divert(&choice.to)));
+        }
+        if paragraph.choices.is_empty() {
+            lines.push("-> END".to_string());
+        }
+        knots.push(lines.join("\n"));
+    }
+
+    let mut content: Vec<String> = variables
+        .declared
+        .iter()
+        .map(|(name, initial)| format!("VAR {} = {}", name, initial))
+        .collect();
+    if !content.is_empty() {
+        content.push(String::new());
+    }
+    content.push(match paragraphs.first() {
+        Some(start) => format!("-> {}", knot(&start.id)),
+        None => "-> END".to_string(),
+    });
+    content.push(String::new());
+    content.push(knots.join("\n\n"));
+    StoryExport {
+        files: vec![ExportFile {
+            name: "story.ink".to_string(),
+            content: format!("{}\n", content.join("\n")),
+        }],
+        issues,
+    }
+}
+
+fn scene(chapter_id: &str) -> String {
+    format!("ch_{}", identifier(chapter_id))
+}
+
+fn choicescript_assignment(assignment: &Assignment) -> Vec<String> {
+    match assignment {
+        Assignment::Number {
+            variable,
+            op: NumericOp::Add,
+            value,
+        } if *value < 0 => vec![format!("*set {} -{}", variable, -(*value as i64))],
+        Assignment::Number {
+            variable,
+            op: NumericOp::Add,
+            value,
+        } => vec![format!("*set {} +{}", variable, value)],
+        Assignment::Number {
+            variable,
+            op: NumericOp::Set,
+            value,
+        } => vec![format!("*set {} {}", variable, value)],
+        Assignment::Number {
+            variable,
+            op: NumericOp::Scale,
+            value,
+        } => vec![
+            format!("*set {} *{}", variable, value),
+            format!("*set {} /100", variable),
+            format!("*set {0} round({0})", variable),
+        ],
+        Assignment::Flag { variable, value } => vec![format!("*set {} {}", variable, value)],
+    }
+}
+
+/// `line` as ChoiceScript prose, or `None` when it is already safe.
+///
+/// ChoiceScript has no escape for a leading `*` (a command) or leading
+/// whitespace (indentation), so the indentation is dropped and a zero-width
+/// space goes in front of the `*`.
+fn escape_choicescript(line: &str) -> Option<String> {
+    let trimmed = line.trim_start();
+    if trimmed.is_empty() {
+        return (!line.is_empty()).then(String::new);
+    }
+    let escaped = if trimmed.starts_with('*') {
+        format!("\u{200B}{}", trimmed)
+    } else {
+        trimmed.to_string()
+    };
+    (escaped != line).then_some(escaped)
+}
+
+fn choicescript_goto(target: &str, from_scene: &str, scenes: &HashMap<&str, String>) -> String {
+    match scenes.get(target) {
+        Some(target_scene) if target_scene != from_scene => {
+            format!("*goto_scene {} {}", target_scene, knot(target))
+        }
+        Some(_) => format!("*goto {}", knot(target)),
+        None => "*ending".to_string(),
+    }
+}
+
+/// The story as ChoiceScript scenes: `startup.txt` declares the variables and
+/// lists one scene per chapter, each paragraph is a `*label` followed by a
+/// `*choice` block.
+pub fn export_choicescript(bundle: &StoryBundle, lang: &str) -> StoryExport {
+    let mut issues = Vec::new();
+    let mut variables = Variables::new(bundle, &mut issues);
+    let paragraphs = ordered(bundle);
+    let scenes: HashMap<&str, String> = paragraphs
+        .iter()
+        .map(|p| (p.id.as_str(), scene(&p.chapter_id)))
+        .collect();
+
+    let mut scene_order: Vec<String> = Vec::new();
+    let mut scene_lines: HashMap<String, Vec<String>> = HashMap::new();
+    let mut needs_roll = false;
+    for paragraph in &paragraphs {
+        let name = scenes[paragraph.id.as_str()].clone();
+        if !scene_lines.contains_key(&name) {
+            scene_order.push(name.clone());
+        }
+        let lines = scene_lines.entry(name.clone()).or_default();
+        let text = text_in(paragraph, lang, &mut issues);
+        note_illustration(paragraph, &mut issues);
+        lines.push(format!("*label {}", knot(&paragraph.id)));
+        if let Some(text) = text {
+            for (number, line) in text.paragraphs.lines().enumerate() {
+                match escape_choicescript(line) {
+                    Some(escaped) => {
+                        if !escaped.is_empty() {
+                            issues.push(ExportIssue::RewrittenLine {
+                                paragraph_id: paragraph.id.clone(),
+                                line: number,
+                            });
+                        }
+                        lines.push(escaped);
+                    }
+                    None => lines.push(line.to_string()),
+                }
+            }
+        }
+        lines.push(String::new());
+        if paragraph.choices.is_empty() {
+            lines.push("*ending".to_string());
+            lines.push(String::new());
+            continue;
+        }
+        lines.push("*choice".to_string());
+        for (index, choice) in paragraph.choices.iter().enumerate() {
+            let assignments = inspect_choice(paragraph, index, choice, &mut variables, &mut issues);
+            lines.push(format!("  #{}", caption(text, index)));
+            for assignment in &assignments {
+                lines.extend(
+                    choicescript_assignment(assignment)
+                        .into_iter()
+                        .map(|line| format!("    {}", line)),
+                );
+            }
+            match choice.to.as_slice() {
+                [] => lines.push("    *ending".to_string()),
+                [target] => {
+                    lines.push(format!("    {}", choicescript_goto(target, &name, &scenes)))
+                }
+                [.., last] => {
+                    needs_roll = true;
+                    lines.push(format!("    *rand {} 1 {}", ROLL_VARIABLE, choice.to.len()));
+                    for (roll, target) in choice.to[..choice.to.len() - 1].iter().enumerate() {
+                        lines.push(format!("    *if {} = {}", ROLL_VARIABLE, roll + 1));
+                        lines.push(format!(
+                            "      {}",
+                            choicescript_goto(target, &name, &scenes)
+                        ));
+                    }
+                    lines.push(format!("    {}", choicescript_goto(last, &name, &scenes)));
+                }
+            }
+        }
+        lines.push(String::new());
+    }
+
+    let mut startup = vec!["*scene_list".to_string(), "  startup".to_string()];
+    startup.extend(scene_order.iter().map(|name| format!("  {}", name)));
+    startup.push(String::new());
+    if needs_roll {
+        variables
+            .declared
+            .insert(ROLL_VARIABLE.to_string(), "0".to_string());
+    }
+    startup.extend(
+        variables
+            .declared
+            .iter()
+            .map(|(name, initial)| format!("*create {} {}", name, initial)),
+    );
+    startup.push(String::new());
+    startup.push(match paragraphs.first() {
+        Some(start) => format!(
+            "*goto_scene {} {}",
+            scenes[start.id.as_str()],
+            knot(&start.id)
+        ),
+        None => "*ending".to_string(),
+    });
+
+    let mut files = vec![ExportFile {
+        name: "startup.txt".to_string(),
+        content: format!("{}\n", startup.join("\n")),
+    }];
+    files.extend(scene_order.into_iter().map(|name| {
+        let lines = &scene_lines[&name];
+        ExportFile {
+            name: format!("{}.txt", name),
+            content: format!("{}\n", lines.join("\n").trim_end()),
+        }
+    }));
+    StoryExport { files, issues }
+}
@@ -0,0 +1,291 @@
+use crate::models::paragraph::Paragraph;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Width of a node box in graph units.
+pub const NODE_WIDTH: f64 = 180.0;
+/// Height of a node box in graph units.
+pub const NODE_HEIGHT: f64 = 56.0;
+const COLUMN_GAP: f64 = 90.0;
+const ROW_GAP: f64 = 28.0;
+const LABEL_CHARS: usize = 28;
+
+/// How a choice leads from one paragraph to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+    /// Single `goto` target.
+    Normal,
+    /// One of several `to` targets picked at random.
+    Random,
+    /// Followed when the choice countdown runs out.
+    Timeout,
+    /// Continues on the same page.
+    SamePage,
+}
+
+/// Which target of a choice an edge stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeSlot {
+    /// Index into `ParagraphChoice::to`.
+    Target(usize),
+    /// Index into the comma separated `timeout_to` list.
+    Timeout(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphNode {
+    pub id: String,
+    pub label: String,
+    pub x: f64,
+    pub y: f64,
+    /// Target that lives in another chapter (or no longer exists).
+    pub external: bool,
+}
+
+impl GraphNode {
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.x && x <= self.x + NODE_WIDTH && y >= self.y && y <= self.y + NODE_HEIGHT
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    pub choice_index: usize,
+    pub slot: EdgeSlot,
+    pub kind: EdgeKind,
+}
+
+/// Paragraphs of one chapter laid out in columns by distance from the entry points.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StoryGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+fn node_label(paragraph: &Paragraph, lang: &str) -> String {
+    let text = paragraph
+        .text_for(lang)
+        .or_else(|| paragraph.texts.first())
+        .map(|text| text.paragraphs.lines().next().unwrap_or("").trim())
+        .unwrap_or("");
+    if text.is_empty() {
+        return paragraph.id.clone();
+    }
+    let mut label: String = text.chars().take(LABEL_CHARS).collect();
+    if text.chars().count() > LABEL_CHARS {
+        label.push('…');
+    }
+    label
+}
+
+fn chapter_edges(members: &[&Paragraph]) -> Vec<GraphEdge> {
+    let mut edges = Vec::new();
+    for paragraph in members {
+        for (choice_index, choice) in paragraph.choices.iter().enumerate() {
+            let kind = if choice.same_page == Some(true) {
+                EdgeKind::SamePage
+            } else if choice.to.len() > 1 {
+                EdgeKind::Random
+            } else {
+                EdgeKind::Normal
+            };
+            for (i, target) in choice.to.iter().enumerate() {
+                if target.is_empty() {
+                    continue;
+                }
+                edges.push(GraphEdge {
+                    from: paragraph.id.clone(),
+                    to: target.clone(),
+                    choice_index,
+                    slot: EdgeSlot::Target(i),
+                    kind,
+                });
+            }
+            for (i, target) in choice.timeout_targets().into_iter().enumerate() {
+                edges.push(GraphEdge {
+                    from: paragraph.id.clone(),
+                    to: target,
+                    choice_index,
+                    slot: EdgeSlot::Timeout(i),
+                    kind: EdgeKind::Timeout,
+                });
+            }
+        }
+    }
+    edges
+}
+
+impl StoryGraph {
+    /// Builds the graph for `chapter_id`; captions and labels use `lang`.
+    ///
+    /// Targets outside the chapter become `external` nodes in the last column
+    /// so edges leaving the chapter stay visible.
+    pub fn for_chapter(paragraphs: &[Paragraph], chapter_id: &str, lang: &str) -> Self {
+        let members: Vec<&Paragraph> = paragraphs
+            .iter()
+            .filter(|p| p.chapter_id == chapter_id)
+            .collect();
+        let edges = chapter_edges(&members);
+        Self::layout(paragraphs, members, edges, lang)
+    }
+
+    /// The part of `chapter_id` a reader has discovered: the paragraphs
+    /// `visited` accepts and the choices between them.
+    ///
+    /// It is laid out on its own, so gaps never hint at unvisited branches.
+    pub fn discovered(
+        paragraphs: &[Paragraph],
+        chapter_id: &str,
+        lang: &str,
+        visited: impl Fn(&str) -> bool,
+    ) -> Self {
+        let members: Vec<&Paragraph> = paragraphs
+            .iter()
+            .filter(|p| p.chapter_id == chapter_id && visited(&p.id))
+            .collect();
+        let mut edges = chapter_edges(&members);
+        edges.retain(|edge| visited(&edge.to));
+        Self::layout(paragraphs, members, edges, lang)
+    }
+
+    fn layout(
+        paragraphs: &[Paragraph],
+        members: Vec<&Paragraph>,
+        edges: Vec<GraphEdge>,
+        lang: &str,
+    ) -> Self {
+        let member_ids: HashSet<&str> = members.iter().map(|p| p.id.as_str()).collect();
+
+        // Column = shortest distance from a paragraph nobody in the chapter points at
+        let inbound: HashSet<&str> = edges
+            .iter()
+            .filter(|edge| edge.from != edge.to)
+            .map(|edge| edge.to.as_str())
+            .collect();
+        let mut column: HashMap<&str, usize> = HashMap::new();
+        let mut queue = VecDeque::new();
+        let mut roots: Vec<&str> = members
+            .iter()
+            .map(|p| p.id.as_str())
+            .filter(|id| !inbound.contains(id))
+            .collect();
+        if roots.is_empty() {
+            roots.extend(members.first().map(|p| p.id.as_str()));
+        }
+        loop {
+            for root in roots.drain(..) {
+                column.insert(root, 0);
+                queue.push_back(root);
+            }
+            while let Some(id) = queue.pop_front() {
+                let next = column[id] + 1;
+                for edge in edges.iter().filter(|edge| edge.from == id) {
+                    let target = edge.to.as_str();
+                    if member_ids.contains(target) && !column.contains_key(target) {
+                        column.insert(target, next);
+                        queue.push_back(target);
+                    }
+                }
+            }
+            // Cycles without an entry point: start again from the first unplaced paragraph
+            match members.iter().find(|p| !column.contains_key(p.id.as_str())) {
+                Some(p) => roots.push(p.id.as_str()),
+                None => break,
+            }
+        }
+
+        let last_column = column.values().copied().max().map_or(0, |max| max + 1);
+        let mut rows: HashMap<usize, usize> = HashMap::new();
+        let mut place = |col: usize| {
+            let row = rows.entry(col).or_insert(0);
+            let position = (
+                col as f64 * (NODE_WIDTH + COLUMN_GAP),
+                *row as f64 * (NODE_HEIGHT + ROW_GAP),
+            );
+            *row += 1;
+            position
+        };
+
+        let mut nodes: Vec<GraphNode> = members
+            .iter()
+            .map(|paragraph| {
+                let (x, y) = place(column[paragraph.id.as_str()]);
+                GraphNode {
+                    id: paragraph.id.clone(),
+                    label: node_label(paragraph, lang),
+                    x,
+                    y,
+                    external: false,
+                }
+            })
+            .collect();
+
+        let mut seen_external = HashSet::new();
+        for edge in &edges {
+            if member_ids.contains(edge.to.as_str()) || !seen_external.insert(edge.to.clone()) {
+                continue;
+            }
+            let label = paragraphs
+                .iter()
+                .find(|p| p.id == edge.to)
+                .map(|p| node_label(p, lang))
+                .unwrap_or_else(|| edge.to.clone());
+            let (x, y) = place(last_column);
+            nodes.push(GraphNode {
+                id: edge.to.clone(),
+                label,
+                x,
+                y,
+                external: true,
+            });
+        }
+
+        Self { nodes, edges }
+    }
+
+    pub fn node(&self, id: &str) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Node under the point `(x, y)` in graph units.
+    pub fn node_at(&self, x: f64, y: f64) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.contains(x, y))
+    }
+
+    /// Size of the area covered by all nodes.
+    pub fn bounds(&self) -> (f64, f64) {
+        self.nodes.iter().fold((0.0, 0.0), |(w, h), node| {
+            (w.max(node.x + NODE_WIDTH), h.max(node.y + NODE_HEIGHT))
+        })
+    }
+}
+
+/// Points the target behind `edge` at `new_target`.
+///
+/// Returns the updated paragraph, or `None` when the edge does not belong to
+/// `paragraph` or no longer matches its choices.
+pub fn retarget_edge(
+    paragraph: &Paragraph,
+    edge: &GraphEdge,
+    new_target: &str,
+) -> Option<Paragraph> {
+    if edge.from != paragraph.id || new_target.is_empty() {
+        return None;
+    }
+    let mut updated = paragraph.clone();
+    let choice = updated.choices.get_mut(edge.choice_index)?;
+    match edge.slot {
+        EdgeSlot::Target(i) => {
+            let slot = choice.to.get_mut(i).filter(|target| **target == edge.to)?;
+            *slot = new_target.to_string();
+        }
+        EdgeSlot::Timeout(i) => {
+            let mut targets = choice.timeout_targets();
+            let slot = targets.get_mut(i).filter(|target| **target == edge.to)?;
+            *slot = new_target.to_string();
+            choice.timeout_to = Some(targets.join(","));
+        }
+    }
+    Some(updated)
+}
@@ -0,0 +1,81 @@
+use crate::models::paragraph::Paragraph;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Ids of `root_id` and every paragraph of the same chapter reachable from it
+/// in at most `depth` choices, in breadth-first order.
+///
+/// Depth 0 is the root alone. Both `to` and `timeout_to` targets are followed;
+/// targets in other chapters are not.
+pub fn subtree_ids(paragraphs: &[Paragraph], root_id: &str, depth: usize) -> Vec<String> {
+    let by_id: HashMap<&str, &Paragraph> = paragraphs.iter().map(|p| (p.id.as_str(), p)).collect();
+    let Some(root) = by_id.get(root_id) else {
+        return Vec::new();
+    };
+    let mut seen: HashSet<&str> = HashSet::from([root_id]);
+    let mut order = vec![root_id.to_string()];
+    let mut queue = VecDeque::from([(*root, 0)]);
+    while let Some((paragraph, level)) = queue.pop_front() {
+        if level == depth {
+            continue;
+        }
+        for choice in &paragraph.choices {
+            let timeouts = choice.timeout_targets();
+            let targets = choice
+                .to
+                .iter()
+                .map(String::as_str)
+                .chain(timeouts.iter().map(String::as_str));
+            for target in targets {
+                let Some(next) = by_id.get(target) else {
+                    continue;
+                };
+                if next.chapter_id == root.chapter_id && seen.insert(next.id.as_str()) {
+                    order.push(next.id.clone());
+                    queue.push_back((*next, level + 1));
+                }
+            }
+        }
+    }
+    order
+}
+
+/// Copies the paragraphs in `ids` under fresh ids from `new_id`.
+///
+/// Links between copied paragraphs point at the copies; every other link,
+/// translation and impact is kept as is. Uploaded illustrations are not
+/// copied, so the copies have none. The result follows the order of `ids`.
+pub fn clone_paragraphs(
+    paragraphs: &[Paragraph],
+    ids: &[String],
+    mut new_id: impl FnMut() -> String,
+) -> Vec<Paragraph> {
+    let remap: HashMap<&str, String> = ids.iter().map(|id| (id.as_str(), new_id())).collect();
+    let relink = |target: &str| {
+        remap
+            .get(target)
+            .cloned()
+            .unwrap_or_else(|| target.to_string())
+    };
+    ids.iter()
+        .filter_map(|id| paragraphs.iter().find(|p| p.id == *id))
+        .map(|paragraph| {
+            let mut copy = paragraph.clone();
+            copy.id = remap[paragraph.id.as_str()].clone();
+            copy.illustration.clear();
+            for choice in &mut copy.choices {
+                for target in &mut choice.to {
+                    *target = relink(target);
+                }
+                if choice.timeout_to.is_some() {
+                    let targets: Vec<String> = choice
+                        .timeout_targets()
+                        .into_iter()
+                        .map(|t| relink(&t))
+                        .collect();
+                    choice.timeout_to = Some(targets.join(","));
+                }
+            }
+            copy
+        })
+        .collect()
+}
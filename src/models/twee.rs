@@ -0,0 +1,321 @@
+use crate::models::bundle::StoryBundle;
+use crate::models::chapter::{Chapter, ChapterTitle};
+use crate::models::paragraph::{Paragraph, ParagraphChoice, Text};
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Chapter title for passages without tags when the story has no title.
+const DEFAULT_CHAPTER: &str = "Twine";
+/// Passages holding story code rather than prose.
+const CODE_TAGS: [&str; 2] = ["script", "stylesheet"];
+
+/// One `:: Name [tags] {metadata}` block of a Twee 3 file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TweePassage {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub body: String,
+}
+
+/// A `[[...]]` link in a passage body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TweeLink {
+    pub caption: String,
+    pub target: String,
+}
+
+/// Something in the source the importer did not carry over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TweeWarning {
+    /// A Harlowe `(name: ...)` or SugarCube `<<name>>` macro; it is left in
+    /// the text as written.
+    UnsupportedMacro { passage: String, name: String },
+    /// A link to a passage that does not exist; no choice is made for it.
+    BrokenLink { passage: String, target: String },
+    /// A second passage with a name already used; it is dropped.
+    DuplicatePassage { passage: String },
+    /// A `script` or `stylesheet` passage.
+    CodePassage { passage: String },
+}
+
+impl fmt::Display for TweeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TweeWarning::UnsupportedMacro { passage, name } => {
+                write!(f, "{}: unsupported macro \"{}\"", passage, name)
+            }
+            TweeWarning::BrokenLink { passage, target } => {
+                write!(f, "{}: link to missing passage \"{}\"", passage, target)
+            }
+            TweeWarning::DuplicatePassage { passage } => {
+                write!(f, "{}: duplicate passage name, later copy skipped", passage)
+            }
+            TweeWarning::CodePassage { passage } => {
+                write!(f, "{}: script or stylesheet passage skipped", passage)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TweeError {
+    /// The source holds no story passages.
+    Empty,
+}
+
+impl fmt::Display for TweeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TweeError::Empty => write!(f, "No passages found in the Twee source"),
+        }
+    }
+}
+
+impl std::error::Error for TweeError {}
+
+/// Result of converting a Twee story.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TweeImport {
+    pub bundle: StoryBundle,
+    pub warnings: Vec<TweeWarning>,
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                out.push(next);
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Splits a header line (without the leading `::`) into name and tags.
+/// Metadata in `{...}` is ignored.
+fn parse_header(header: &str) -> (String, Vec<String>) {
+    let mut name_end = header.len();
+    let mut escaped = false;
+    for (index, c) in header.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' | '{' => {
+                name_end = index;
+                break;
+            }
+            _ => {}
+        }
+    }
+    let name = unescape(header[..name_end].trim());
+    let rest = &header[name_end..];
+    let tags = match (rest.starts_with('['), rest.find(']')) {
+        (true, Some(close)) => rest[1..close]
+            .split_whitespace()
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    };
+    (name, tags)
+}
+
+/// Passages of a Twee 3 source in file order.
+pub fn parse_passages(source: &str) -> Vec<TweePassage> {
+    let mut passages: Vec<TweePassage> = Vec::new();
+    for line in source.lines() {
+        if let Some(header) = line.strip_prefix("::") {
+            let (name, tags) = parse_header(header);
+            passages.push(TweePassage {
+                name,
+                tags,
+                body: String::new(),
+            });
+        } else if let Some(passage) = passages.last_mut() {
+            passage.body.push_str(line);
+            passage.body.push('\n');
+        }
+    }
+    for passage in &mut passages {
+        passage.body = passage.body.trim().to_string();
+    }
+    passages
+}
+
+fn link_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"\[\[(.+?)\]\]").expect("valid link pattern"))
+}
+
+fn macro_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"\(([A-Za-z][\w-]*):|<<\s*/?([A-Za-z][\w-]*)").expect("valid macro pattern")
+    })
+}
+
+/// Reads the inside of a `[[...]]` link the way Twine 2 does: the rightmost
+/// `->`, then the leftmost `<-`, then `|`, else the text names the target.
+pub fn parse_link(inner: &str) -> TweeLink {
+    let (caption, target) = if let Some(at) = inner.rfind("->") {
+        (&inner[..at], &inner[at + 2..])
+    } else if let Some(at) = inner.find("<-") {
+        (&inner[at + 2..], &inner[..at])
+    } else if let Some(at) = inner.find('|') {
+        (&inner[..at], &inner[at + 1..])
+    } else {
+        (inner, inner)
+    };
+    TweeLink {
+        caption: caption.trim().to_string(),
+        target: target.trim().to_string(),
+    }
+}
+
+/// Links of `body` in order, and the body with the link markup removed.
+fn split_links(body: &str) -> (Vec<TweeLink>, String) {
+    let links = link_pattern()
+        .captures_iter(body)
+        .map(|captures| parse_link(&captures[1]))
+        .collect();
+    let prose = link_pattern().replace_all(body, "");
+    // Drop lines that only held links, keeping paragraph breaks
+    let mut lines: Vec<&str> = Vec::new();
+    for (original, line) in body.lines().zip(prose.lines()) {
+        if line.trim().is_empty() && !original.trim().is_empty() {
+            continue;
+        }
+        lines.push(line.trim_end());
+    }
+    (links, lines.join("\n").trim().to_string())
+}
+
+fn macro_names(body: &str) -> BTreeSet<String> {
+    macro_pattern()
+        .captures_iter(body)
+        .filter_map(|c| c.get(1).or_else(|| c.get(2)))
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+/// Converts a Twee 3 story into a bundle with fresh ids from `new_id`.
+///
+/// Every passage becomes a paragraph whose text is in `lang`, and each link
+/// a `goto` choice captioned with the link text. A passage's first tag names
+/// its chapter; untagged passages go to a chapter named after the story. The
+/// start passage from `StoryData` is listed first.
+pub fn twee_to_bundle(
+    source: &str,
+    lang: &str,
+    exported_at: &str,
+    mut new_id: impl FnMut() -> String,
+) -> Result<TweeImport, TweeError> {
+    let mut warnings = Vec::new();
+    let mut title = String::new();
+    let mut start = None::<String>;
+    let mut passages: Vec<TweePassage> = Vec::new();
+    for passage in parse_passages(source) {
+        match passage.name.as_str() {
+            "StoryTitle" => title = passage.body.clone(),
+            "StoryData" => {
+                start = serde_json::from_str::<serde_json::Value>(&passage.body)
+                    .ok()
+                    .and_then(|data| data.get("start")?.as_str().map(str::to_string));
+            }
+            _ if passage.tags.iter().any(|t| CODE_TAGS.contains(&t.as_str())) => {
+                warnings.push(TweeWarning::CodePassage {
+                    passage: passage.name,
+                });
+            }
+            _ if passages.iter().any(|p| p.name == passage.name) => {
+                warnings.push(TweeWarning::DuplicatePassage {
+                    passage: passage.name,
+                });
+            }
+            _ => passages.push(passage),
+        }
+    }
+    if passages.is_empty() {
+        return Err(TweeError::Empty);
+    }
+    let start = start.unwrap_or_else(|| "Start".to_string());
+    if let Some(index) = passages.iter().position(|p| p.name == start) {
+        let first = passages.remove(index);
+        passages.insert(0, first);
+    }
+
+    let ids: HashMap<&str, String> = passages
+        .iter()
+        .map(|p| (p.name.as_str(), new_id()))
+        .collect();
+    let default_chapter = if title.is_empty() {
+        DEFAULT_CHAPTER.to_string()
+    } else {
+        title
+    };
+    let mut bundle = StoryBundle::new(exported_at);
+    let mut chapter_ids: HashMap<String, String> = HashMap::new();
+    for passage in &passages {
+        let chapter_title = passage
+            .tags
+            .first()
+            .cloned()
+            .unwrap_or_else(|| default_chapter.clone());
+        let chapter_id = match chapter_ids.get(&chapter_title) {
+            Some(id) => id.clone(),
+            None => {
+                let id = new_id();
+                bundle.chapters.push(Chapter {
+                    id: id.clone(),
+                    titles: vec![ChapterTitle {
+                        lang: lang.to_string(),
+                        title: chapter_title.clone(),
+                    }],
+                    order: bundle.chapters.len() as i32 + 1,
+                });
+                chapter_ids.insert(chapter_title, id.clone());
+                id
+            }
+        };
+
+        for name in macro_names(&passage.body) {
+            warnings.push(TweeWarning::UnsupportedMacro {
+                passage: passage.name.clone(),
+                name,
+            });
+        }
+        let (links, prose) = split_links(&passage.body);
+        let mut captions = Vec::new();
+        let mut choices = Vec::new();
+        for link in links {
+            match ids.get(link.target.as_str()) {
+                Some(target) => {
+                    captions.push(link.caption);
+                    choices.push(ParagraphChoice::goto(vec![target.clone()]));
+                }
+                None => warnings.push(TweeWarning::BrokenLink {
+                    passage: passage.name.clone(),
+                    target: link.target,
+                }),
+            }
+        }
+        bundle.paragraphs.push(Paragraph {
+            id: ids[passage.name.as_str()].clone(),
+            chapter_id,
+            texts: vec![Text {
+                lang: lang.to_string(),
+                paragraphs: prose,
+                choices: captions,
+                illustration_alt: None,
+            }],
+            choices,
+            illustration: String::new(),
+        });
+    }
+    Ok(TweeImport { bundle, warnings })
+}
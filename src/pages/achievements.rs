@@ -0,0 +1,98 @@
+use crate::models::achievements::{Achievement, UnlockedAchievements};
+use crate::services::achievements::{load_achievements, load_unlocked};
+use crate::services::api::HttpApiClient;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use wasm_bindgen::JsValue;
+
+/// Unlock date in the reader's language.
+fn unlocked_on(unlocked_at: i64, lang: &str) -> String {
+    if !cfg!(target_arch = "wasm32") {
+        return String::new();
+    }
+    js_sys::Date::new(&JsValue::from_f64(unlocked_at as f64))
+        .to_locale_date_string(lang, &JsValue::UNDEFINED)
+        .into()
+}
+
+/// Every achievement of the story, unlocked ones first in gallery order.
+#[component]
+pub fn Achievements(lang: String) -> Element {
+    let mut achievements = use_signal(Vec::<Achievement>::new);
+    let mut unlocked = use_signal(UnlockedAchievements::default);
+    let mut loaded = use_signal(|| false);
+
+    use_hook(move || {
+        spawn(async move {
+            let client = HttpApiClient::new();
+            match load_achievements(&client).await {
+                Ok(list) => achievements.set(list),
+                Err(error) => tracing::info!(error = %error, "No achievements loaded"),
+            }
+            unlocked.set(load_unlocked().await.unwrap_or_default());
+            loaded.set(true);
+        })
+    });
+
+    let record = unlocked.read().clone();
+    let mut entries: Vec<(Achievement, Option<i64>)> = achievements
+        .read()
+        .iter()
+        .map(|a| (a.clone(), record.unlocked_at(&a.id)))
+        .collect();
+    // Stable, so each group keeps the gallery order
+    entries.sort_by_key(|(_, at)| at.is_none());
+    let unlocked_count = entries.iter().filter(|(_, at)| at.is_some()).count();
+    let total = entries.len();
+
+    rsx! {
+        div {
+            class: "container mx-auto px-4 pt-8 pb-24 sm:pt-24",
+            div {
+                class: "max-w-3xl mx-auto space-y-6",
+                div {
+                    class: "flex items-baseline justify-between gap-4",
+                    h1 { class: "text-2xl font-bold text-gray-900 dark:text-white paper:text-[#1f2937]", {t!("achievements")} }
+                    if total > 0 {
+                        span {
+                            class: "text-sm text-gray-600 dark:text-gray-400 paper:text-[#4b5563]",
+                            {t!("achievements_unlocked_count", unlocked: unlocked_count.to_string(), total: total.to_string())}
+                        }
+                    }
+                }
+                if loaded() && total == 0 {
+                    p { class: "text-gray-600 dark:text-gray-400 paper:text-[#4b5563]", {t!("achievements_empty")} }
+                }
+                ul {
+                    class: "grid gap-4 sm:grid-cols-2",
+                    for (achievement, at) in entries {
+                        {
+                            let text = achievement.text_for(&lang).cloned();
+                            let title = achievement.title_for(&lang);
+                            let description = text.map(|t| t.description).unwrap_or_default();
+                            let status = match at {
+                                Some(at) => t!("achievement_unlocked_on", date: unlocked_on(at, &lang)),
+                                None => t!("achievement_locked"),
+                            };
+                            rsx! {
+                                li {
+                                    key: "{achievement.id}",
+                                    class: if at.is_some() {
+                                        "p-4 rounded-lg border border-amber-300 dark:border-amber-500/60 paper:border-[#d4a72c] bg-amber-50 dark:bg-amber-900/20 paper:bg-[#fdf0cf]"
+                                    } else {
+                                        "p-4 rounded-lg border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] opacity-60"
+                                    },
+                                    h2 { class: "font-semibold text-gray-900 dark:text-white paper:text-[#1f2937]", "{title}" }
+                                    if !description.is_empty() {
+                                        p { class: "mt-1 text-sm text-gray-600 dark:text-gray-300 paper:text-[#4b5563]", "{description}" }
+                                    }
+                                    p { class: "mt-2 text-xs text-gray-500 dark:text-gray-400 paper:text-[#6b7280]", "{status}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
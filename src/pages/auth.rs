@@ -1,4 +1,8 @@
+use crate::contexts::auth_context::use_auth_context;
 use crate::enums::route::Route;
+use crate::services::api::{ApiError, HttpApiClient};
+use crate::services::auth::{login, SignUpRequest};
+use crate::services::invite::{register_with_invite, request_invite, InviteCodeError, InviteError};
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
@@ -8,6 +12,45 @@ const INPUT_CLASS: &str = "w-full px-4 py-3 rounded-lg border border-gray-300 da
 const LABEL_CLASS: &str = "block text-sm font-semibold text-gray-700 dark:text-gray-200 paper:text-[#374151] mb-2";
 const BUTTON_CLASS: &str = "w-full py-3 px-4 bg-indigo-600 hover:bg-indigo-700 text-white font-semibold rounded-lg shadow transition disabled:opacity-60 disabled:cursor-not-allowed";
 
+/// Translated messages for failed auth calls, fetched in component scope
+/// because `t!` is unavailable inside spawned futures.
+struct AuthErrorTexts {
+    rejected: String,
+    network: String,
+    unexpected: String,
+}
+
+impl AuthErrorTexts {
+    fn message_for(&self, error: &ApiError) -> String {
+        match error {
+            // PocketBase answers 400 for bad credentials and failed validation
+            ApiError::ServerError(400) => self.rejected.clone(),
+            ApiError::NetworkError(_) | ApiError::Timeout => self.network.clone(),
+            other => format!("{} ({})", self.unexpected, other),
+        }
+    }
+}
+
+struct InviteCodeTexts {
+    unknown: String,
+    not_approved: String,
+    expired: String,
+    already_used: String,
+    email_mismatch: String,
+}
+
+impl InviteCodeTexts {
+    fn message_for(&self, reason: InviteCodeError) -> String {
+        match reason {
+            InviteCodeError::Unknown => self.unknown.clone(),
+            InviteCodeError::NotApproved => self.not_approved.clone(),
+            InviteCodeError::Expired => self.expired.clone(),
+            InviteCodeError::AlreadyUsed => self.already_used.clone(),
+            InviteCodeError::EmailMismatch => self.email_mismatch.clone(),
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct InviteRequestProps {
     pub lang: String,
@@ -19,10 +62,14 @@ pub fn InviteRequest(props: InviteRequestProps) -> Element {
     let mut email = use_signal(String::new);
     let mut message = use_signal(String::new);
     let mut error = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
     let navigator = use_navigator();
 
     let handle_submit = move |evt: FormEvent| {
         evt.prevent_default();
+        if *is_submitting.read() {
+            return;
+        }
         let has_missing = name.read().is_empty() || email.read().is_empty();
         if has_missing {
             error.set(t!("invite_request_error_required"));
@@ -30,9 +77,26 @@ pub fn InviteRequest(props: InviteRequestProps) -> Element {
         }
 
         error.set(String::new());
+        is_submitting.set(true);
+        let (name_value, email_value, reason) =
+            (name.read().clone(), email.read().clone(), message.read().clone());
+        let error_texts = AuthErrorTexts {
+            rejected: t!("invite_request_error_rejected"),
+            network: t!("auth_error_network"),
+            unexpected: t!("auth_error_unexpected"),
+        };
         let lang = props.lang.clone();
         let nav = navigator.clone();
-        nav.push(Route::InviteCheckEmail { lang });
+        spawn(async move {
+            let client = HttpApiClient::new();
+            match request_invite(&client, &name_value, &email_value, &reason).await {
+                Ok(_) => {
+                    nav.push(Route::InviteCheckEmail { lang });
+                }
+                Err(e) => error.set(error_texts.message_for(&e)),
+            }
+            is_submitting.set(false);
+        });
     };
 
     rsx! {
@@ -78,7 +142,7 @@ pub fn InviteRequest(props: InviteRequestProps) -> Element {
                     if !error.read().is_empty() {
                         p { class: "text-sm text-red-500", "{error}" }
                     }
-                    button { class: BUTTON_CLASS, r#type: "submit", "{t!(\"invite_request_submit\")}" }
+                    button { class: BUTTON_CLASS, r#type: "submit", disabled: *is_submitting.read(), "{t!(\"invite_request_submit\")}" }
                 }
                 div { class: "flex items-center gap-2 text-sm text-gray-600 dark:text-gray-300 paper:text-[#374151]",
                     svg {
@@ -166,6 +230,8 @@ pub fn Register(props: RegisterProps) -> Element {
     let mut password = use_signal(String::new);
     let mut confirm = use_signal(String::new);
     let mut error = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+    let mut auth_context = use_auth_context();
     let navigator = use_navigator();
     let submit_nav = navigator.clone();
     let login_link_nav = navigator.clone();
@@ -174,6 +240,9 @@ pub fn Register(props: RegisterProps) -> Element {
 
     let handle_submit = move |evt: FormEvent| {
         evt.prevent_default();
+        if *is_submitting.read() {
+            return;
+        }
         if code.read().is_empty() || name.read().is_empty() || email.read().is_empty() {
             error.set(t!("register_error_missing"));
             return;
@@ -187,8 +256,42 @@ pub fn Register(props: RegisterProps) -> Element {
             return;
         }
         error.set(String::new());
+        is_submitting.set(true);
+
+        let request = SignUpRequest {
+            email: email.read().trim().to_string(),
+            password: password.read().clone(),
+            password_confirm: confirm.read().clone(),
+            name: name.read().trim().to_string(),
+            invite_code: String::new(),
+        };
+        let invite_code = code.read().clone();
+        let error_texts = AuthErrorTexts {
+            rejected: t!("register_error_rejected"),
+            network: t!("auth_error_network"),
+            unexpected: t!("auth_error_unexpected"),
+        };
+        let code_texts = InviteCodeTexts {
+            unknown: t!("invite_code_error_unknown"),
+            not_approved: t!("invite_code_error_not_approved"),
+            expired: t!("invite_code_error_expired"),
+            already_used: t!("invite_code_error_used"),
+            email_mismatch: t!("invite_code_error_email_mismatch"),
+        };
+        let nav = submit_nav.clone();
         let lang = submit_lang.clone();
-        let _ = submit_nav.push(Route::Login { lang });
+        spawn(async move {
+            let client = HttpApiClient::new();
+            match register_with_invite(&client, &invite_code, &request).await {
+                Ok(session) => {
+                    auth_context.write().session = Some(session);
+                    let _ = nav.push(Route::Story { lang });
+                }
+                Err(InviteError::Code(reason)) => error.set(code_texts.message_for(reason)),
+                Err(InviteError::Api(e)) => error.set(error_texts.message_for(&e)),
+            }
+            is_submitting.set(false);
+        });
     };
 
     rsx! {
@@ -228,7 +331,7 @@ pub fn Register(props: RegisterProps) -> Element {
                     if !error.read().is_empty() {
                         p { class: "text-sm text-red-500", "{error}" }
                     }
-                    button { class: BUTTON_CLASS, r#type: "submit", "{t!(\"register_submit\")}" }
+                    button { class: BUTTON_CLASS, r#type: "submit", disabled: *is_submitting.read(), "{t!(\"register_submit\")}" }
                 }
                 div { class: "flex items-center justify-between text-sm text-gray-600 dark:text-gray-300 paper:text-[#374151]",
                     span { "{t!(\"register_login_prompt\")}" }
@@ -257,6 +360,8 @@ pub fn Login(props: LoginProps) -> Element {
     let mut password = use_signal(String::new);
     let mut code = use_signal(String::new);
     let mut error = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+    let mut auth_context = use_auth_context();
 
     let navigator = use_navigator();
     let submit_nav = navigator.clone();
@@ -268,13 +373,38 @@ pub fn Login(props: LoginProps) -> Element {
 
     let handle_submit = move |evt: FormEvent| {
         evt.prevent_default();
+        if *is_submitting.read() {
+            return;
+        }
         if email.read().is_empty() || password.read().is_empty() {
             error.set(t!("login_error_missing"));
             return;
         }
         error.set(String::new());
-        // 後端驗證成功後即可導向故事體驗或儀表板
-        let _ = submit_nav.push(Route::Story { lang: submit_lang.clone() });
+        is_submitting.set(true);
+
+        let identity = email.read().clone();
+        let secret = password.read().clone();
+        let error_texts = AuthErrorTexts {
+            rejected: t!("login_error_invalid"),
+            network: t!("auth_error_network"),
+            unexpected: t!("auth_error_unexpected"),
+        };
+        let nav = submit_nav.clone();
+        let lang = submit_lang.clone();
+        spawn(async move {
+            let client = HttpApiClient::new();
+            match login(&client, &identity, &secret).await {
+                Ok(session) => {
+                    auth_context.write().session = Some(session);
+                    password.set(String::new());
+                    // 後端驗證成功後即可導向故事體驗或儀表板
+                    let _ = nav.push(Route::Story { lang });
+                }
+                Err(e) => error.set(error_texts.message_for(&e)),
+            }
+            is_submitting.set(false);
+        });
     };
 
     rsx! {
@@ -302,7 +432,7 @@ pub fn Login(props: LoginProps) -> Element {
                     if !error.read().is_empty() {
                         p { class: "text-sm text-red-500", "{error}" }
                     }
-                    button { class: BUTTON_CLASS, r#type: "submit", "{t!(\"login_submit\")}" }
+                    button { class: BUTTON_CLASS, r#type: "submit", disabled: *is_submitting.read(), "{t!(\"login_submit\")}" }
                 }
                 div { class: "grid sm:grid-cols-2 gap-3 text-sm text-gray-600 dark:text-gray-300 paper:text-[#374151]",
                     button {
@@ -1,10 +1,24 @@
+use crate::components::branch_graph::BranchGraph;
+use crate::components::chapter_manager::ChapterManager;
 use crate::components::chapter_selector::ChapterSelector;
 use crate::components::choice_impacts_editor::{CharacterOption, RelationshipOption};
 use crate::components::dropdown::Dropdown;
-use crate::components::form::{ChoiceOptions, TextareaField};
+use crate::components::find_replace::FindReplace;
+use crate::components::form::{ChoiceOptions, InputField, TextareaField};
+use crate::components::illustration_upload::IllustrationUpload;
+use crate::components::invite_review::InviteReview;
 use crate::components::language_selector::{Language, AVAILABLE_LANGUAGES};
+use crate::components::markup_text::MarkupText;
+use crate::components::paragraph_clone::ParagraphCloneControls;
+use crate::components::paragraph_delete::ParagraphDeleteDialog;
 use crate::components::paragraph_list::Paragraph as ParagraphListParagraph;
-use crate::constants::config::{base_api_url, CHAPTERS, CHARACTERS, PARAGRAPHS, RELATIONSHIPS};
+use crate::components::revision_history::RevisionHistory;
+use crate::components::story_bundle::StoryBundlePanel;
+use crate::components::story_search::StorySearch;
+use crate::constants::config::{
+    base_api_url, BATCH, CHAPTERS, CHARACTERS, PARAGRAPHS, RELATIONSHIPS,
+};
+use crate::contexts::auth_context::use_auth_context;
 use crate::contexts::chapter_context::{Chapter, ChapterState, ChapterTitle};
 use crate::contexts::language_context::LanguageState;
 use crate::contexts::paragraph_context::{
@@ -13,6 +27,14 @@ use crate::contexts::paragraph_context::{
 };
 use crate::hooks::choices_reducer::{use_choices, Action as CAct, Choice as ChoiceStruct};
 use crate::models::impacts::Impact;
+use crate::models::interpolation::{lint_text, KnownKeys, LintIssue};
+use crate::models::role::Capabilities;
+use crate::models::story_graph::{retarget_edge, GraphEdge, StoryGraph};
+use crate::services::api::HttpApiClient;
+use crate::services::auth::with_auth;
+use crate::services::outbox::{flush_outbox, queue_request, OutboxEntry, OutboxMethod};
+use crate::services::paragraphs::{build_submit_batch, new_record_id, save_paragraph_choices};
+use crate::services::retry::{send_with_retry, RetryPolicy, RetryingApiClient};
 use dioxus::events::FormEvent;
 use dioxus::hooks::use_context;
 use dioxus::prelude::*;
@@ -28,20 +50,6 @@ pub struct DashboardProps {
     pub lang: String,
 }
 
-type ChoiceTuple = (
-    String,
-    Vec<String>,
-    String,
-    Option<String>,
-    Option<serde_json::Value>,
-    String,
-    bool,
-    Option<u32>,
-    Option<String>,
-    String,
-    Vec<Impact>,
-);
-
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Data {
     pub items: Vec<ContextParagraph>,
@@ -138,24 +146,42 @@ fn push_toast(toast: &ToastHandle, kind: ToastKind, message: impl Into<String>,
     toast.push(ToastRequest::new(kind, message).with_timeout(Duration::from_millis(timeout_ms)));
 }
 
+/// `lang` when the user may edit it, otherwise their first assigned language.
+fn editable_language(capabilities: &Capabilities, lang: &str) -> String {
+    if capabilities.can_edit_language(lang) {
+        lang.to_string()
+    } else {
+        capabilities
+            .languages
+            .first()
+            .cloned()
+            .unwrap_or_else(|| lang.to_string())
+    }
+}
+
 #[allow(non_snake_case)]
 pub fn Dashboard(_props: DashboardProps) -> Element {
     let language_state = use_context::<Signal<LanguageState>>();
     let chapter_state = use_context::<Signal<ChapterState>>();
-    let paragraph_state = use_context::<Signal<ParagraphState>>();
+    let mut paragraph_state = use_context::<Signal<ParagraphState>>();
     let current_lang = language_state.read().current_language.clone();
+    let auth = use_auth_context();
+    let capabilities = auth.read().capabilities();
+    let can_edit_structure = capabilities.can_edit_structure();
 
-    // Initialize paragraph_language to current interface language
-    let mut paragraph_language = use_signal(|| current_lang.clone());
+    // Initialize paragraph_language to current interface language, or to the
+    // first assigned language when a translator may not edit the interface one
+    let mut paragraph_language = use_signal(|| editable_language(&capabilities, &current_lang));
 
     // Update thread_local variable and paragraph_language when language changes
     use_effect(move || {
         let _current_lang = language_state.read().current_language.clone();
+        let _lang = editable_language(&auth.read().capabilities(), &_current_lang);
 
         let paragraph_lang_ref = paragraph_language.clone();
         let mut paragraph_lang_ref2 = paragraph_lang_ref.clone();
         Timeout::new(0, move || {
-            paragraph_lang_ref2.set(_current_lang);
+            paragraph_lang_ref2.set(_lang);
         })
         .forget();
 
@@ -165,7 +191,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
     let (choices_state, dispatch_choice) = use_choices();
 
     let mut paragraphs = use_signal(|| String::new());
-    let mut choices = use_signal(|| Vec::<ChoiceTuple>::new());
+    let mut choices = use_signal(|| Vec::<ChoiceStruct>::new());
     let _init_done = use_signal(|| false);
     let mut is_open = use_signal(|| false);
     let mut search_query = use_signal(|| String::new());
@@ -179,8 +205,42 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
     let mut is_chapter_open = use_signal(|| false);
     let mut chapter_search_query = use_signal(|| String::new());
     let mut selected_paragraph = use_signal(|| None::<ContextParagraph>);
-    let mut is_edit_mode = use_signal(|| false);
+    // Translators cannot create paragraphs, so they always work in edit mode
+    let mut is_edit_mode = use_signal(|| !can_edit_structure);
     let _has_loaded = use_signal(|| paragraph_state.read().loaded);
+    let mut show_graph = use_signal(|| false);
+    let mut show_chapter_manager = use_signal(|| false);
+    let mut show_search = use_signal(|| false);
+    let mut show_find_replace = use_signal(|| false);
+    let mut show_bundle = use_signal(|| false);
+    let mut show_markup_preview = use_signal(|| false);
+    // Alt text for the selected paragraph's illustration, in the language being edited
+    let mut illustration_alt = use_signal(String::new);
+    use_effect(move || {
+        let alt = selected_paragraph
+            .read()
+            .as_ref()
+            .and_then(|p| {
+                p.text_for(&paragraph_language.read())?
+                    .illustration_alt
+                    .clone()
+            })
+            .unwrap_or_default();
+        illustration_alt.set(alt);
+    });
+    let mut show_delete_dialog = use_signal(|| false);
+    // Paragraph to open once it shows up in the paragraph list, e.g. a new copy
+    let mut pending_select = use_signal(|| None::<String>);
+    let mut show_history = use_signal(|| false);
+    // Bumped after each recorded save so the history panel reloads
+    let mut revision_epoch = use_signal(|| 0_u32);
+    let story_graph = use_memo(move || {
+        StoryGraph::for_chapter(
+            &paragraph_state.read().paragraphs,
+            &selected_chapter.read(),
+            &paragraph_language.read(),
+        )
+    });
     let mut _should_scroll = use_signal(|| false);
     let _target_chapter = use_signal(|| String::new());
 
@@ -344,15 +404,19 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
         });
     }
 
-    let filtered_languages = use_memo(move || {
-        let query = search_query.read().to_lowercase();
-        AVAILABLE_LANGUAGES
-            .iter()
-            .filter(|l| {
-                l.name.to_lowercase().contains(&query) || l.code.to_lowercase().contains(&query)
-            })
-            .collect::<Vec<_>>()
-    });
+    let filtered_languages = {
+        let capabilities = capabilities.clone();
+        use_memo(move || {
+            let query = search_query.read().to_lowercase();
+            AVAILABLE_LANGUAGES
+                .iter()
+                .filter(|l| capabilities.can_edit_language(l.code))
+                .filter(|l| {
+                    l.name.to_lowercase().contains(&query) || l.code.to_lowercase().contains(&query)
+                })
+                .collect::<Vec<_>>()
+        })
+    };
 
     let _dropdown_class = use_memo(move || {
         if *is_open.read() {
@@ -387,16 +451,14 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
             false
         };
         let choices_valid = if let Ok(choices) = choices.try_read() {
-            choices.iter().all(
-                |(choice_text, _, _, _, _, _, _, _, _, _, _): &ChoiceTuple| {
-                    let has_content = !choice_text.is_empty();
-                    if has_content {
-                        !choice_text.is_empty()
-                    } else {
-                        true
-                    }
-                },
-            )
+            choices.iter().all(|choice: &ChoiceStruct| {
+                let has_content = !choice.caption.is_empty();
+                if has_content {
+                    !choice.caption.is_empty()
+                } else {
+                    true
+                }
+            })
         } else {
             false
         };
@@ -447,23 +509,19 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                         }
 
                         // Check each option's detailed changes
-                        for (
-                            i,
-                            (
-                                new_choice_text,
-                                new_to,
-                                new_type,
-                                new_key,
-                                new_value,
-                                _new_target_chapter,
-                                new_same_page,
-                                new_time_limit,
-                                new_timeout_to,
-                                _new_timeout_target_chapter,
-                                _new_impacts,
-                            ),
-                        ) in new_choices.iter().enumerate()
-                        {
+                        for (i, new_choice) in new_choices.iter().enumerate() {
+                            let new_choice_text = &new_choice.caption;
+                            let ContextParagraphChoice {
+                                to: new_to,
+                                type_: new_type,
+                                key: new_key,
+                                value: new_value,
+                                time_limit: new_time_limit,
+                                timeout_to: new_timeout_to,
+                                ..
+                            } = &new_choice.stored;
+                            let new_same_page = &new_choice.same_page();
+
                             // Check if option text changes
                             if let Some(old_choice_text) = current_text_choices.get(i) {
                                 if old_choice_text != new_choice_text {
@@ -481,62 +539,15 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                                     old_same_page,
                                     old_time_limit,
                                     old_choice_timeout,
-                                ) = match old_choice {
-                                    ContextParagraphChoice::Simple(texts) => (
-                                        texts.clone(),
-                                        "goto".to_string(),
-                                        None,
-                                        None,
-                                        false,
-                                        None,
-                                        None,
-                                    ),
-                                    ContextParagraphChoice::SimpleOld(text) => (
-                                        vec![text.clone()],
-                                        "goto".to_string(),
-                                        None,
-                                        None,
-                                        false,
-                                        None,
-                                        None,
-                                    ),
-                                    ContextParagraphChoice::Complex {
-                                        to,
-                                        type_,
-                                        key,
-                                        value,
-                                        same_page,
-                                        time_limit,
-                                        timeout_to,
-                                        ..
-                                    } => (
-                                        to.clone(),
-                                        type_.clone(),
-                                        key.clone(),
-                                        value.clone(),
-                                        same_page.unwrap_or(false),
-                                        time_limit.clone(),
-                                        timeout_to.clone(),
-                                    ),
-                                    ContextParagraphChoice::ComplexOld {
-                                        to,
-                                        type_,
-                                        key,
-                                        value,
-                                        same_page,
-                                        time_limit,
-                                        timeout_to,
-                                        ..
-                                    } => (
-                                        vec![to.clone()],
-                                        type_.clone(),
-                                        key.clone(),
-                                        value.clone(),
-                                        same_page.unwrap_or(false),
-                                        time_limit.clone(),
-                                        timeout_to.clone(),
-                                    ),
-                                };
+                                ) = (
+                                    old_choice.to.clone(),
+                                    old_choice.type_.clone(),
+                                    old_choice.key.clone(),
+                                    old_choice.value.clone(),
+                                    old_choice.same_page.unwrap_or(false),
+                                    old_choice.time_limit,
+                                    old_choice.timeout_to.clone(),
+                                );
 
                                 // Compare all attributes
                                 if old_to != *new_to
@@ -567,16 +578,14 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                         false
                     };
                 let has_valid_choices = if let Ok(choices) = choices.try_read() {
-                    choices
-                        .iter()
-                        .any(|(choice_text, _, _, _, _, _, _, _, _, _, _)| {
-                            let has_content = !choice_text.is_empty();
-                            if has_content {
-                                !choice_text.is_empty()
-                            } else {
-                                false
-                            }
-                        })
+                    choices.iter().any(|choice| {
+                        let has_content = !choice.caption.is_empty();
+                        if has_content {
+                            !choice.caption.is_empty()
+                        } else {
+                            false
+                        }
+                    })
                 } else {
                     false
                 };
@@ -629,6 +638,11 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                 field: "action_value",
                 value: value.clone(),
             }),
+            "action_prompt" => (dispatch.clone())(CAct::SetField {
+                idx: index,
+                field: "action_prompt",
+                value: value.clone(),
+            }),
             "target_chapter" => (dispatch.clone())(CAct::SetField {
                 idx: index,
                 field: "target_chapter",
@@ -760,7 +774,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
         let choices_state = choices_state.clone();
         move |(index, paragraph_id): (usize, String)| {
             if let Some(choice) = choices_state.read().list.get(index) {
-                let mut new_goto = choice.goto.clone();
+                let mut new_goto = choice.stored.to.clone();
                 if !new_goto.contains(&paragraph_id) {
                     new_goto.push(paragraph_id);
                     let joined = new_goto.join(",");
@@ -780,7 +794,8 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
         move |(index, paragraph_id): (usize, String)| {
             if let Some(choice) = choices_state.read().list.get(index) {
                 let new_goto: Vec<String> = choice
-                    .goto
+                    .stored
+                    .to
                     .iter()
                     .cloned()
                     .filter(|id| id != &paragraph_id)
@@ -883,6 +898,91 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
 
     let toast = use_toast();
 
+    // Dashboard writes that failed while offline wait in the IndexedDB outbox
+    let mut pending_changes = use_signal(|| 0usize);
+    let mut is_syncing = use_signal(|| false);
+    let flush_pending = {
+        let toast = toast.clone();
+        let paragraph_state = paragraph_state.clone();
+        let synced_text = t!("pending_changes_synced").to_string();
+        let rejected_text = t!("pending_changes_rejected").to_string();
+        move || {
+            if *is_syncing.peek() {
+                return;
+            }
+            is_syncing.set(true);
+            let mut is_syncing = is_syncing;
+            let toast = toast.clone();
+            let mut paragraph_state = paragraph_state.clone();
+            let synced_text = synced_text.clone();
+            let rejected_text = rejected_text.clone();
+            spawn_local(async move {
+                let report = flush_outbox(&RetryPolicy::default()).await;
+                pending_changes.set(report.remaining);
+                if !report.dropped.is_empty() {
+                    push_toast(
+                        &toast,
+                        ToastKind::Error,
+                        format!("{}: {}", rejected_text, report.dropped.len()),
+                        5000,
+                    );
+                }
+                if report.sent > 0 {
+                    push_toast(&toast, ToastKind::Success, synced_text, 3000);
+                    let paragraphs_url = format!("{}{}", base_api_url(), PARAGRAPHS);
+                    let client = reqwest::Client::new();
+                    if let Ok(response) =
+                        send_with_retry(&RetryPolicy::default(), || client.get(&paragraphs_url))
+                            .await
+                    {
+                        if let Ok(data) = response.json::<Data>().await {
+                            paragraph_state.write().set_paragraphs(data.items);
+                        }
+                    }
+                }
+                is_syncing.set(false);
+            });
+        }
+    };
+
+    // Replay the outbox on mount and whenever the browser comes back online
+    {
+        let flush_pending = flush_pending.clone();
+        use_effect(move || {
+            let mut flush_on_mount = flush_pending.clone();
+            flush_on_mount();
+        });
+    }
+    let online_listener = {
+        let flush_pending = flush_pending.clone();
+        use_hook(move || {
+            use wasm_bindgen::JsCast;
+            if !cfg!(target_arch = "wasm32") {
+                return None;
+            }
+            let mut flush_on_online = flush_pending.clone();
+            let on_online =
+                wasm_bindgen::closure::Closure::<dyn FnMut()>::new(move || flush_on_online());
+            let window = web_sys::window()?;
+            let _ = window
+                .add_event_listener_with_callback("online", on_online.as_ref().unchecked_ref());
+            Some(std::rc::Rc::new(on_online))
+        })
+    };
+    // The listener writes this component's signals, so it must not outlive it
+    use_drop(move || {
+        use wasm_bindgen::JsCast;
+        let Some(on_online) = online_listener.as_ref() else {
+            return;
+        };
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback(
+                "online",
+                on_online.as_ref().as_ref().unchecked_ref(),
+            );
+        }
+    });
+
     let mut handle_submit = {
         let paragraph_state = paragraph_state.clone();
         let mut selected_paragraph = selected_paragraph.clone();
@@ -895,10 +995,20 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
         // Clone dispatcher so we can use it inside inner async block without move issues
         let dispatch_choice_outer = dispatch_choice.clone();
         let selected_chapter = selected_chapter.clone();
+        let capabilities = capabilities.clone();
         move |_| {
             if *is_submitting.read() {
                 return;
             }
+            if !capabilities.can_edit_language(&paragraph_language.read()) {
+                push_toast(
+                    &toast,
+                    ToastKind::Error,
+                    t!("dashboard_language_not_assigned"),
+                    4000,
+                );
+                return;
+            }
             is_submitting.set(true);
             let text = ContextText {
                 lang: paragraph_language.read().clone(),
@@ -906,8 +1016,10 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                 choices: choices_signal
                     .read()
                     .iter()
-                    .map(|(choice_text, _, _, _, _, _, _, _, _, _, _)| choice_text.clone())
+                    .map(|choice| choice.caption.clone())
                     .collect(),
+                illustration_alt: Some(illustration_alt.read().trim().to_string())
+                    .filter(|alt| !alt.is_empty()),
             };
 
             // Optimistically keep local paragraph data in sync so that
@@ -929,57 +1041,19 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
             let paragraph_choices: Vec<ContextParagraphChoice> = choices_signal
                 .read()
                 .iter()
-                .map(
-                    |(
-                        choice_text,
-                        to_list,
-                        type_,
-                        key,
-                        value,
-                        _target_chapter,
-                        same_page,
-                        time_limit,
-                        timeout_to,
-                        _timeout_target_chapter,
-                        impacts,
-                    )| {
-                        let mut complex = ContextParagraphChoice::Complex {
-                            to: to_list.clone(),
-                            type_: type_.clone(),
-                            key: None,
-                            value: None,
-                            same_page: Some(*same_page),
-                            time_limit: *time_limit,
-                            timeout_to: timeout_to.clone(),
-                            impacts: if impacts.is_empty() {
-                                None
-                            } else {
-                                Some(impacts.clone())
-                            },
-                        };
-                        if let Some(k) = key {
-                            if !k.is_empty() {
-                                if let ContextParagraphChoice::Complex { key, .. } = &mut complex {
-                                    *key = Some(k.to_string());
-                                }
-                            }
-                        }
-                        if let Some(v) = value {
-                            if let ContextParagraphChoice::Complex { value, .. } = &mut complex {
-                                *value = Some(v.clone());
-                            }
-                        }
-                        complex
-                    },
-                )
+                .map(ChoiceStruct::to_paragraph_choice)
                 .collect();
 
+            let author = auth.read().user().cloned();
+
             // Pre-fetch translation strings inside component scope where `I18n` context is available
             let submit_success_text = t!("submit_success").to_string();
             let submit_failed_text = t!("submit_failed").to_string();
+            let submit_queued_text = t!("submit_queued_offline").to_string();
 
             spawn({
                 let mut is_submitting = is_submitting.clone();
+                let mut pending_changes = pending_changes.clone();
                 let mut paragraph_state = paragraph_state.clone();
                 let mut toast = toast.clone();
                 let submit_success_text = submit_success_text.clone();
@@ -993,6 +1067,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                 let dispatch_choice = dispatch_choice_outer.clone();
                 let mut selected_chapter = selected_chapter.clone();
                 let is_edit_mode_flag = *is_edit_mode_signal.read();
+                let mut revision_epoch = revision_epoch.clone();
                 async move {
                     let client = reqwest::Client::new();
 
@@ -1033,31 +1108,89 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                         })
                     };
 
-                    // Publish to paragraph collection
-                    let paragraphs_url = format!("{}{}", base_api_url(), PARAGRAPHS);
+                    // Translators only send their `Text` entry; structure stays untouched
+                    let mut new_paragraph = new_paragraph;
+                    if !can_edit_structure {
+                        if let Some(fields) = new_paragraph.as_object_mut() {
+                            fields.remove("chapter_id");
+                            fields.remove("choices");
+                        }
+                    }
 
-                    let response = if is_edit_mode_flag {
-                        // Edit mode: Use PATCH method to update existing paragraph
-                        if let Some(paragraph) = selected_paragraph.read().as_ref() {
-                            let update_url =
-                                format!("{}{}/{}", base_api_url(), PARAGRAPHS, paragraph.id);
-                            client.patch(&update_url).json(&new_paragraph).send().await
-                        } else {
-                            return;
+                    // Edits are replayed like updates, creates like creates
+                    let (method, create) = if is_edit_mode_flag {
+                        (OutboxMethod::Patch, false)
+                    } else {
+                        (OutboxMethod::Post, true)
+                    };
+
+                    // The paragraph as it will be stored, for the revision that
+                    // records this save
+                    let base = if create {
+                        // Creates carry their own id so that a replay from the
+                        // outbox cannot create the paragraph twice
+                        ContextParagraph {
+                            id: new_record_id(),
+                            ..Default::default()
                         }
+                    } else if let Some(paragraph) = selected_paragraph.read().clone() {
+                        paragraph
                     } else {
-                        // New mode: Use POST method to create new paragraph
-                        client
-                            .post(&paragraphs_url)
-                            .json(&new_paragraph)
-                            .send()
-                            .await
+                        return;
+                    };
+                    let saved = ContextParagraph {
+                        chapter_id: if can_edit_structure && !chapter_id.is_empty() {
+                            chapter_id.clone()
+                        } else {
+                            base.chapter_id.clone()
+                        },
+                        texts: serde_json::from_value(new_paragraph["texts"].clone())
+                            .unwrap_or_default(),
+                        choices: if can_edit_structure {
+                            paragraph_choices.clone()
+                        } else {
+                            base.choices.clone()
+                        },
+                        ..base
+                    };
+
+                    // The write and its revision go out as one batch so the
+                    // history never misses a save, including replayed ones
+                    let requests =
+                        match build_submit_batch(new_paragraph, &saved, create, author.as_ref()) {
+                            Ok(requests) => requests,
+                            Err(error) => {
+                                push_toast(
+                                    &toast,
+                                    ToastKind::Error,
+                                    format!("{}: {}", submit_failed_text.clone(), error),
+                                    3000,
+                                );
+                                is_submitting.set(false);
+                                return;
+                            }
+                        };
+                    let batch_body = serde_json::json!({ "requests": requests });
+                    let target_url = format!("{}{}", base_api_url(), BATCH);
+
+                    // A create that timed out may still have gone through, so only
+                    // updates are retried in place.
+                    let policy = if create {
+                        RetryPolicy::no_retry()
+                    } else {
+                        RetryPolicy::default()
                     };
+                    let response = send_with_retry(&policy, || {
+                        with_auth(client.post(&target_url)).json(&batch_body)
+                    })
+                    .await;
 
                     match response {
                         Ok(response) => {
                             let status = response.status();
                             if status.is_success() {
+                                revision_epoch += 1;
+
                                 // Reload paragraph data
                                 let paragraphs_url = format!("{}{}", base_api_url(), PARAGRAPHS);
                                 match client.get(&paragraphs_url).send().await {
@@ -1127,11 +1260,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                                                                 // Sync reducer state so UI reflects latest data
                                                                 let dispatch =
                                                                     dispatch_choice.clone();
-                                                                let converted = new_choices
-                                                                    .iter()
-                                                                    .cloned()
-                                                                    .map(ChoiceStruct::from_tuple)
-                                                                    .collect::<Vec<_>>();
+                                                                let converted = new_choices.clone();
                                                                 (dispatch.clone())(CAct::SetList(
                                                                     converted,
                                                                 ));
@@ -1207,6 +1336,23 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                                 is_submitting.set(false);
                             }
                         }
+                        Err(e) if OutboxEntry::should_queue(method, &e) => {
+                            // Offline or unreachable: keep the write and replay it later
+                            let entry = OutboxEntry::new(
+                                OutboxMethod::Post,
+                                target_url,
+                                Some(batch_body.clone()),
+                            );
+                            let pending = queue_request(entry).await;
+                            pending_changes.set(pending);
+                            push_toast(
+                                &toast,
+                                ToastKind::Warning,
+                                submit_queued_text.clone(),
+                                4000,
+                            );
+                            is_submitting.set(false);
+                        }
                         Err(e) => {
                             push_toast(
                                 &toast,
@@ -1234,7 +1380,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
         let mut choices = choices.clone();
         move |(index, new_impacts): (usize, Vec<Impact>)| {
             if let Some(choice) = choices.write().get_mut(index) {
-                choice.10 = new_impacts.clone();
+                choice.set_impacts(new_impacts.clone());
             }
             (dispatch.clone())(CAct::SetEffects {
                 idx: index,
@@ -1283,11 +1429,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
 
                         // sync to reducer
                         let dispatch = dispatch_ps.clone();
-                        let converted = new_choices
-                            .iter()
-                            .cloned()
-                            .map(ChoiceStruct::from_tuple)
-                            .collect::<Vec<_>>();
+                        let converted = new_choices.clone();
                         (dispatch.clone())(CAct::SetList(converted));
 
                         // Set paragraph cache for each option
@@ -1297,7 +1439,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
 
                         // === New: set timeout paragraph cache as well ===
                         for (idx, choice) in new_choices.iter().enumerate() {
-                            let timeout_target_chapter_id = &choice.9;
+                            let timeout_target_chapter_id = &choice.timeout_target_chapter;
                             if !timeout_target_chapter_id.is_empty() {
                                 let selected_lang = paragraph_language.read().clone();
                                 let interface_lang = language_state.read().current_language.clone();
@@ -1354,9 +1496,10 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                             lang: paragraph_language.read().clone(),
                             paragraphs: String::new(),
                             choices: vec![String::new(); placeholder_choices_count],
+                            illustration_alt: None,
                         };
 
-                        // Re-use existing helper to derive the full choice tuples
+                        // Re-use existing helper to derive the full editor choices
                         let (new_choices, _new_paragraphs) = process_paragraph_select(
                             &placeholder_text,
                             &full_paragraph,
@@ -1368,11 +1511,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
 
                         // Sync reducer state so UI components reflect the updated choices list
                         let dispatch = dispatch_ps.clone();
-                        let converted = new_choices
-                            .iter()
-                            .cloned()
-                            .map(ChoiceStruct::from_tuple)
-                            .collect::<Vec<_>>();
+                        let converted = new_choices.clone();
                         (dispatch.clone())(CAct::SetList(converted));
                     }
                 }
@@ -1380,18 +1519,93 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
         }
     };
 
+    let mut select_from_graph = {
+        let mut handle_paragraph_select = handle_paragraph_select.clone();
+        move |id: String| {
+            if !*is_edit_mode.peek() {
+                is_edit_mode.set(true);
+            }
+            let index = available_paragraphs.peek().iter().position(|p| p.id == id);
+            if let Some(index) = index {
+                handle_paragraph_select(index);
+            }
+        }
+    };
+
+    {
+        let mut select_from_graph = select_from_graph.clone();
+        use_effect(move || {
+            let listed = available_paragraphs.read();
+            let Some(id) = pending_select.peek().clone() else {
+                return;
+            };
+            if listed.iter().any(|p| p.id == id) {
+                drop(listed);
+                pending_select.set(None);
+                select_from_graph(id);
+            }
+        });
+    }
+
+    let handle_graph_retarget = {
+        let select_from_graph = select_from_graph.clone();
+        let toast = toast.clone();
+        let success_text = t!("graph_retarget_success");
+        let failed_text = t!("graph_retarget_failed");
+        move |(edge, new_target): (GraphEdge, String)| {
+            let Some(paragraph) = paragraph_state.peek().get_by_id(&edge.from) else {
+                return;
+            };
+            let Some(updated) = retarget_edge(&paragraph, &edge, &new_target) else {
+                return;
+            };
+            let author = auth.peek().user().cloned();
+            let success_text = success_text.clone();
+            let failed_text = failed_text.clone();
+            let mut paragraph_state = paragraph_state.clone();
+            let mut select_from_graph = select_from_graph.clone();
+            let toast = toast.clone();
+            spawn_local(async move {
+                let client = RetryingApiClient::new(HttpApiClient::new(), RetryPolicy::default());
+                match save_paragraph_choices(&client, &updated, author.as_ref()).await {
+                    Ok(()) => {
+                        let saved_id = updated.id.clone();
+                        paragraph_state.write().replace(updated);
+                        // Reload the editor so a later submit does not restore the old target
+                        let is_open_in_editor = selected_paragraph
+                            .peek()
+                            .as_ref()
+                            .map(|p| p.id == saved_id)
+                            .unwrap_or(false);
+                        if is_open_in_editor {
+                            select_from_graph(saved_id);
+                        }
+                        push_toast(&toast, ToastKind::Success, success_text, 3000);
+                    }
+                    Err(error) => {
+                        push_toast(
+                            &toast,
+                            ToastKind::Error,
+                            format!("{}: {}", failed_text, error),
+                            4000,
+                        );
+                    }
+                }
+            });
+        }
+    };
+
     // Keep local `choices` Signal in sync with reducer (read-only sync)
     {
         let choices = choices.clone();
         let choices_state = choices_state.clone();
         use_effect(move || {
             let list = choices_state.read().list.clone();
-            let tuple_list = list.iter().map(ChoiceStruct::to_tuple).collect::<Vec<_>>();
-            if tuple_list != *choices.read() {
+            if list != *choices.read() {
                 // 同樣排程至下一 tick 再 set，避免同步 render 期間修改 hook list
                 let mut choices_ref = choices.clone();
                 Timeout::new(0, move || {
-                    choices_ref.set(tuple_list);
+                    choices_ref.set(list);
                 })
                 .forget();
             }
@@ -1430,11 +1644,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                         );
                         choices_ref.set(new_choices.clone());
 
-                        let converted = new_choices
-                            .iter()
-                            .cloned()
-                            .map(ChoiceStruct::from_tuple)
-                            .collect::<Vec<_>>();
+                        let converted = new_choices.clone();
                         (dispatch_ref.clone())(CAct::SetList(converted));
                     } else {
                         paragraphs_ref.set(String::new());
@@ -1442,35 +1652,10 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                         let current_choices = choices_ref.read().clone();
                         let new_choices = current_choices
                             .iter()
-                            .map(
-                                |(
-                                    _,
-                                    to,
-                                    type_,
-                                    key,
-                                    value,
-                                    target_chapter,
-                                    same_page,
-                                    time_limit,
-                                    timeout_to,
-                                    timeout_target_chapter,
-                                    impacts,
-                                )| {
-                                    (
-                                        String::new(),
-                                        to.clone(),
-                                        type_.clone(),
-                                        key.clone(),
-                                        value.clone(),
-                                        target_chapter.clone(),
-                                        *same_page,
-                                        *time_limit,
-                                        timeout_to.clone(),
-                                        timeout_target_chapter.clone(),
-                                        impacts.clone(),
-                                    )
-                                },
-                            )
+                            .map(|choice| ChoiceStruct {
+                                caption: String::new(),
+                                ..choice.clone()
+                            })
                             .collect();
                         choices_ref.set(new_choices);
                     }
@@ -1482,6 +1667,35 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
         // No cleanup necessary
     });
 
+    // Reopens a restored paragraph so the editor shows the restored content
+    let mut select_restored = select_from_graph.clone();
+    let mut select_replaced = select_from_graph.clone();
+
+    // Expressions in the text being edited that read names the story never sets
+    let interpolation_issues: Vec<String> = {
+        let known = KnownKeys::from_story(
+            &paragraph_state.read().paragraphs,
+            character_options.read().iter().map(|c| c.id.clone()),
+        );
+        let captions: Vec<String> = choices.read().iter().map(|c| c.caption.clone()).collect();
+        let text = format!("{}\n{}", paragraphs.read(), captions.join("\n"));
+        lint_text("", &paragraph_language.read(), &text, &known)
+            .into_iter()
+            .map(|issue| match issue {
+                LintIssue::UnknownKey { key, .. } => t!("interpolation_unknown_key", key: key),
+                LintIssue::Syntax {
+                    expression,
+                    message,
+                    ..
+                } => t!(
+                    "interpolation_syntax_error",
+                    expression: expression,
+                    message: message
+                ),
+            })
+            .collect()
+    };
+
     rsx! {
         crate::pages::layout::Layout {
             title: Some("Dashboard"),
@@ -1548,15 +1762,16 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                                                                 lang: current_lang.clone(),
                                                                 paragraphs: String::new(),
                                                                 choices: vec![String::new(); placeholder_choices_count],
+                                                                illustration_alt: None,
                                                             };
 
-                                                            // Re-use existing helper to derive the full choice tuples
+                                                            // Re-use existing helper to derive the full editor choices
                                                             let (new_choices, _new_paragraphs) = process_paragraph_select(&placeholder_text, paragraph, &paragraph_state, &paragraph_language, &language_state.read().current_language.clone());
                                                             choices.set(new_choices.clone());
 
                                                             // Sync reducer state so UI components reflect the updated choices list
                                                             let dispatch = dispatch_dropdown.clone();
-                                                            let converted = new_choices.iter().cloned().map(ChoiceStruct::from_tuple).collect::<Vec<_>>();
+                                                            let converted = new_choices.clone();
                                                             (dispatch.clone())(CAct::SetList(converted));
                                                         }
                                                     }
@@ -1638,8 +1853,76 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                                     }
                                 }
 
-                                // Edit mode control button (right)
+                                // Story search toggle
+                                div {
+                                    class: "flex-shrink-0",
+                                    button {
+                                        class: "w-full lg:w-auto h-10 px-4 inline-flex items-center justify-center rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                                        onclick: move |_| show_search.toggle(),
+                                        {t!("search_toggle")}
+                                    }
+                                }
+
+                                // Find and replace toggle
+                                if can_edit_structure {
+                                    div {
+                                        class: "flex-shrink-0",
+                                        button {
+                                            class: "w-full lg:w-auto h-10 px-4 inline-flex items-center justify-center rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                                            onclick: move |_| show_find_replace.toggle(),
+                                            {t!("find_replace_toggle")}
+                                        }
+                                    }
+                                }
+
+                                // Bundle import and export toggle
+                                if can_edit_structure {
+                                    div {
+                                        class: "flex-shrink-0",
+                                        button {
+                                            class: "w-full lg:w-auto h-10 px-4 inline-flex items-center justify-center rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                                            onclick: move |_| show_bundle.toggle(),
+                                            {t!("bundle_toggle")}
+                                        }
+                                    }
+                                }
+
+                                // Chapter management toggle
+                                if can_edit_structure {
+                                    div {
+                                        class: "flex-shrink-0",
+                                        button {
+                                            class: "w-full lg:w-auto h-10 px-4 inline-flex items-center justify-center rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                                            onclick: move |_| {
+                                                let current = *show_chapter_manager.read();
+                                                show_chapter_manager.set(!current);
+                                            },
+                                            {t!("chapter_manager_toggle")}
+                                        }
+                                    }
+                                }
+
+                                // Story graph toggle
                                 if !selected_chapter.read().is_empty() {
+                                    div {
+                                        class: "flex-shrink-0",
+                                        button {
+                                            class: "w-full lg:w-auto h-10 px-4 inline-flex items-center justify-center rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                                            onclick: move |_| {
+                                                let current = *show_graph.read();
+                                                show_graph.set(!current);
+                                            },
+                                            if *show_graph.read() {
+                                                {t!("graph_view_hide")}
+                                            } else {
+                                                {t!("graph_view_show")}
+                                            }
+                                        }
+                                    }
+                                }
+
+                                // Edit mode control button (right)
+                                if can_edit_structure && !selected_chapter.read().is_empty() {
                                     div {
                                         class: "flex-shrink-0",
                                         button {
@@ -1682,6 +1965,72 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                                 }
                             }
 
+                            // Story search
+                            if *show_search.read() {
+                                StorySearch {
+                                    lang: paragraph_language.read().clone(),
+                                    on_select: move |id: String| {
+                                        let Some(paragraph) = paragraph_state.peek().get_by_id(&id) else {
+                                            return;
+                                        };
+                                        // The paragraph list only holds the selected chapter
+                                        if *selected_chapter.peek() != paragraph.chapter_id {
+                                            validate_field(&paragraph.chapter_id, &mut chapter_error);
+                                            selected_chapter.set(paragraph.chapter_id);
+                                        }
+                                        pending_select.set(Some(id));
+                                    },
+                                }
+                            }
+
+                            // Find and replace
+                            if can_edit_structure && *show_find_replace.read() {
+                                FindReplace {
+                                    lang: paragraph_language.read().clone(),
+                                    on_applied: move |ids: Vec<String>| {
+                                        // Reload the editor so a later submit keeps the replaced text
+                                        let open_id = selected_paragraph.peek().as_ref().map(|p| p.id.clone());
+                                        if let Some(id) = open_id.filter(|id| ids.contains(id)) {
+                                            select_replaced(id);
+                                        }
+                                    },
+                                }
+                            }
+
+                            // Bundle import and export
+                            if can_edit_structure && *show_bundle.read() {
+                                StoryBundlePanel { lang: paragraph_language.read().clone() }
+                            }
+
+                            // Chapter management
+                            if can_edit_structure && *show_chapter_manager.read() {
+                                ChapterManager {
+                                    lang: paragraph_language.read().clone(),
+                                    on_deleted: move |chapter_id: String| {
+                                        if *selected_chapter.peek() == chapter_id {
+                                            selected_chapter.set(String::new());
+                                            selected_paragraph.set(None);
+                                            paragraphs.set(String::new());
+                                            reset_choices();
+                                        }
+                                    },
+                                }
+                            }
+
+                            // Chapter overview
+                            if *show_graph.read() && !selected_chapter.read().is_empty() {
+                                div {
+                                    class: "mb-6",
+                                    BranchGraph {
+                                        graph: story_graph.read().clone(),
+                                        selected_id: selected_paragraph.read().as_ref().map(|p| p.id.clone()),
+                                        editable: can_edit_structure,
+                                        on_select: move |id: String| select_from_graph(id),
+                                        on_retarget: handle_graph_retarget.clone(),
+                                    }
+                                }
+                            }
+
                             // Paragraph selection and content editing area
                             if !selected_chapter.read().is_empty() {
                                 div {
@@ -1709,6 +2058,58 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                                             },
                                             on_blur: move |_| validate_field(&paragraphs.read(), &mut paragraphs_error)
                                         }
+                                        div {
+                                            class: "-mt-4 flex items-center justify-between text-xs text-gray-500 dark:text-gray-400",
+                                            span { {t!("markup_hint")} }
+                                            button {
+                                                class: "px-2 py-1 rounded hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                                                onclick: move |_| show_markup_preview.toggle(),
+                                                if *show_markup_preview.read() {
+                                                    {t!("markup_preview_hide")}
+                                                } else {
+                                                    {t!("markup_preview_show")}
+                                                }
+                                            }
+                                        }
+                                        if *show_markup_preview.read() {
+                                            article {
+                                                class: "mt-3 prose-sm dark:prose-invert max-w-none p-4 rounded-lg border border-gray-200 dark:border-gray-700 paper:border-[#e4d5b2] text-gray-900 dark:text-white space-y-4",
+                                                MarkupText { text: paragraphs.read().to_string() }
+                                            }
+                                        }
+                                        if can_edit_structure && *is_edit_mode.read() {
+                                            if let Some(paragraph_id) = selected_paragraph.read().as_ref().map(|p| p.id.clone()) {
+                                                IllustrationUpload { key: "{paragraph_id}", paragraph_id }
+                                            }
+                                        }
+                                        // Uploads update the paragraph list, not the selection
+                                        if selected_paragraph
+                                            .read()
+                                            .as_ref()
+                                            .and_then(|p| paragraph_state.read().get_by_id(&p.id))
+                                            .is_some_and(|p| !p.illustration.is_empty())
+                                        {
+                                            div {
+                                                class: "mt-4",
+                                                InputField {
+                                                    label: t!("illustration_alt"),
+                                                    placeholder: t!("illustration_alt_placeholder"),
+                                                    value: illustration_alt.read().to_string(),
+                                                    required: false,
+                                                    has_error: false,
+                                                    on_input: move |value: String| illustration_alt.set(value),
+                                                    on_blur: move |_| {},
+                                                }
+                                            }
+                                        }
+                                        if !interpolation_issues.is_empty() {
+                                            ul {
+                                                class: "mt-3 list-disc pl-5 text-xs text-amber-700 dark:text-amber-400",
+                                                for (index, issue) in interpolation_issues.iter().enumerate() {
+                                                    li { key: "{index}", "{issue}" }
+                                                }
+                                            }
+                                        }
                                     }
 
                                     // Option area (keep existing title)
@@ -1758,6 +2159,7 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                                             characters: character_options.read().clone(),
                                             relationships: relationship_options.read().clone(),
                                             on_impacts_change: handle_impacts_change,
+                                            structure_editable: can_edit_structure,
                                         }
                                     }
                                 }
@@ -1785,10 +2187,85 @@ pub fn Dashboard(_props: DashboardProps) -> Element {
                                         },
                                         {t!("submit")}
                                     }
+                                    if can_edit_structure && *is_edit_mode.read() {
+                                        if let Some(paragraph_id) = selected_paragraph.read().as_ref().map(|p| p.id.clone()) {
+                                            if *show_delete_dialog.read() {
+                                                ParagraphDeleteDialog {
+                                                    key: "{paragraph_id}",
+                                                    paragraph_id,
+                                                    lang: paragraph_language.read().clone(),
+                                                    on_cancel: move |_| show_delete_dialog.set(false),
+                                                    on_deleted: move |_| {
+                                                        show_delete_dialog.set(false);
+                                                        selected_paragraph.set(None);
+                                                        paragraphs.set(String::new());
+                                                        reset_choices();
+                                                    },
+                                                }
+                                            } else {
+                                                ParagraphCloneControls {
+                                                    key: "{paragraph_id}",
+                                                    paragraph_id: paragraph_id.clone(),
+                                                    on_cloned: move |id: String| pending_select.set(Some(id)),
+                                                }
+                                                button {
+                                                    class: "mt-3 w-full px-6 py-2 rounded-lg border border-red-500 text-red-600 hover:bg-red-50 dark:hover:bg-red-900/30 paper:hover:bg-[#f0e6cf]",
+                                                    onclick: move |_| show_delete_dialog.set(true),
+                                                    {t!("paragraph_delete_action")}
+                                                }
+                                                button {
+                                                    class: "mt-3 w-full px-6 py-2 rounded-lg border border-gray-300 dark:border-gray-600 paper:border-[#e4d5b2] hover:bg-gray-100 dark:hover:bg-gray-700 paper:hover:bg-[#f0e6cf]",
+                                                    onclick: move |_| show_history.toggle(),
+                                                    if *show_history.read() {
+                                                        {t!("revision_hide_history")}
+                                                    } else {
+                                                        {t!("revision_show_history")}
+                                                    }
+                                                }
+                                                if *show_history.read() {
+                                                    if let Some(saved) = paragraph_state.read().get_by_id(&paragraph_id) {
+                                                        RevisionHistory {
+                                                            key: "{paragraph_id}-{revision_epoch}",
+                                                            paragraph: saved,
+                                                            lang: paragraph_language.read().clone(),
+                                                            on_restored: move |restored: ContextParagraph| {
+                                                                let id = restored.id.clone();
+                                                                paragraph_state.write().replace(restored);
+                                                                revision_epoch += 1;
+                                                                select_restored(id);
+                                                            },
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if *pending_changes.read() > 0 {
+                                        div {
+                                            class: "mt-3 flex items-center justify-between gap-3 text-sm text-amber-700 dark:text-amber-300 paper:text-[#92400e]",
+                                            span {
+                                                {t!("pending_changes", count: pending_changes.read().to_string())}
+                                            }
+                                            button {
+                                                class: "px-3 py-1 rounded-md border border-amber-500 hover:bg-amber-50 dark:hover:bg-amber-900/30 paper:hover:bg-[#f0e6cf] disabled:opacity-50 disabled:cursor-not-allowed",
+                                                disabled: *is_syncing.read(),
+                                                onclick: {
+                                                    let mut flush_pending = flush_pending.clone();
+                                                    move |_| flush_pending()
+                                                },
+                                                {t!("pending_changes_sync_now")}
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
                     }
+
+                    // Invite requests awaiting review (admins only)
+                    if capabilities.can_review_invites() {
+                        InviteReview {}
+                    }
                 }
             }
         }
@@ -1827,140 +2304,43 @@ fn process_paragraph_select(
     paragraph_state: &Signal<ParagraphState>,
     paragraph_language: &Signal<String>,
     interface_language: &str,
-) -> (Vec<ChoiceTuple>, Vec<Vec<ParagraphListParagraph>>) {
+) -> (Vec<ChoiceStruct>, Vec<Vec<ParagraphListParagraph>>) {
     let mut new_choices = Vec::new();
     let mut new_paragraphs = Vec::new();
     let text_choices = &text.choices;
     let paragraph_choices = &full_paragraph.choices;
     for (i, choice_text) in text_choices.iter().enumerate() {
-        let (target_ids, type_, key, value, same_page, time_limit, timeout_to_opt, impacts) =
-            if let Some(choice) = paragraph_choices.get(i) {
-                match choice {
-                    ContextParagraphChoice::Simple(texts) => (
-                        texts.clone(),
-                        "goto".to_string(),
-                        None,
-                        None,
-                        false,
-                        None,
-                        None,
-                        Vec::new(),
-                    ),
-                    ContextParagraphChoice::SimpleOld(text) => (
-                        vec![text.clone()],
-                        "goto".to_string(),
-                        None,
-                        None,
-                        false,
-                        None,
-                        None,
-                        Vec::new(),
-                    ),
-                    ContextParagraphChoice::Complex {
-                        to,
-                        type_,
-                        key,
-                        value,
-                        same_page,
-                        time_limit,
-                        timeout_to,
-                        impacts,
-                        ..
-                    } => (
-                        to.clone(),
-                        type_.clone(),
-                        key.clone(),
-                        value.clone(),
-                        same_page.unwrap_or(false),
-                        *time_limit,
-                        timeout_to.clone(),
-                        impacts.clone().unwrap_or_default(),
-                    ),
-                    ContextParagraphChoice::ComplexOld {
-                        to,
-                        type_,
-                        key,
-                        value,
-                        same_page,
-                        time_limit,
-                        timeout_to,
-                        impacts,
-                        ..
-                    } => (
-                        vec![to.clone()],
-                        type_.clone(),
-                        key.clone(),
-                        value.clone(),
-                        same_page.unwrap_or(false),
-                        *time_limit,
-                        timeout_to.clone(),
-                        impacts.clone().unwrap_or_default(),
-                    ),
-                }
-            } else {
-                (
-                    Vec::new(),
-                    String::new(),
-                    None,
-                    None,
-                    false,
-                    None,
-                    None,
-                    Vec::new(),
-                )
-            };
+        let stored = paragraph_choices.get(i).cloned().unwrap_or_default();
 
         // Get all target paragraph chapter IDs (should be in the same chapter)
-        let target_chapter_id = if !target_ids.is_empty() {
-            if let Some(first_paragraph) = paragraph_state.read().get_by_id(&target_ids[0]) {
-                first_paragraph.chapter_id
-            } else {
-                String::new()
-            }
-        } else {
-            String::new()
-        };
+        let target_chapter_id = stored
+            .to
+            .first()
+            .and_then(|id| paragraph_state.read().get_by_id(id))
+            .map(|p| p.chapter_id)
+            .unwrap_or_default();
 
         // Determine timeout target chapter (if any)
-        let timeout_target_chapter_id = if let Some(ref timeout_para_id) = timeout_to_opt {
-            if let Some(timeout_para) = paragraph_state.read().get_by_id(timeout_para_id) {
-                timeout_para.chapter_id.clone()
-            } else {
-                String::new()
-            }
-        } else {
-            String::new()
-        };
-
-        new_choices.push((
+        let timeout_target_chapter_id = stored
+            .timeout_to
+            .as_ref()
+            .and_then(|id| paragraph_state.read().get_by_id(id))
+            .map(|p| p.chapter_id)
+            .unwrap_or_default();
+
+        let mut choice = ChoiceStruct::from_paragraph_choice(
             choice_text.clone(),
-            if target_chapter_id.is_empty() {
-                Vec::new()
-            } else {
-                target_ids.clone()
-            },
-            if target_chapter_id.is_empty() {
-                String::new()
-            } else {
-                type_
-            },
-            if target_chapter_id.is_empty() {
-                None
-            } else {
-                key
-            },
-            if target_chapter_id.is_empty() {
-                None
-            } else {
-                value
-            },
+            &stored,
             target_chapter_id.clone(),
-            same_page,
-            time_limit,
-            timeout_to_opt.clone(),
-            timeout_target_chapter_id.clone(),
-            impacts,
-        ));
+            timeout_target_chapter_id,
+        );
+        if target_chapter_id.is_empty() {
+            choice.stored.to.clear();
+            choice.stored.type_.clear();
+            choice.stored.key = None;
+            choice.stored.value = None;
+        }
+        new_choices.push(choice);
 
         if !target_chapter_id.is_empty() {
             let selected_lang = paragraph_language.read().clone();
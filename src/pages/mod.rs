@@ -1,3 +1,4 @@
+pub mod achievements;
 pub mod dashboard;
 pub mod home;
 pub mod layout;
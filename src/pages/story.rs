@@ -1,11 +1,22 @@
 #![allow(unused_mut)]
-use crate::components::story_content::{Action, Choice, StoryContent};
-use crate::constants::config::{base_api_url, should_show_story_debug_info, CHAPTERS, PARAGRAPHS};
+use crate::components::audio_director::AudioDirector;
+use crate::components::story_content::{Choice, StoryContent};
+use crate::constants::config::{
+    base_api_url, should_show_story_debug_info, CHAPTERS, FILES, PARAGRAPHS,
+};
 use crate::contexts::language_context::LanguageState;
 use crate::contexts::settings_context::use_settings_context;
 use crate::contexts::story_context::use_story_context;
 use crate::contexts::story_merged_context::StoryMergedContext;
+use crate::models::achievements::UnlockedAchievements;
+use crate::models::countdown::{RunningCountdowns, TimerOptions};
 use crate::models::impacts::{CharacterStateSnapshot, Impact};
+use crate::models::input_prompt::INPUT_ACTION;
+use crate::models::interpolation::{interpolate, interpolate_markup};
+use crate::models::markup::illustration;
+use crate::services::achievements::{load_achievements, load_unlocked, save_unlocked};
+use crate::services::audio::load_audio_cues;
+use crate::services::countdown::load_timers_mandatory;
 use crate::services::indexeddb::get_choice_from_indexeddb;
 use crate::services::indexeddb::get_settings_from_indexeddb;
 use crate::services::indexeddb::set_setting_to_indexeddb;
@@ -13,9 +24,12 @@ use crate::services::indexeddb::{
     get_latest_character_state_from_indexeddb, set_latest_character_state_to_indexeddb,
     set_random_choice_to_indexeddb,
 };
+use crate::services::media_cache::cache_media;
+use crate::services::retry::{send_with_retry, RetryPolicy};
 use crate::utils::theme::{apply_theme_class, ThemeMode};
 use dioxus::prelude::*;
 use dioxus_core::fc_to_builder;
+use dioxus_i18n::t;
 use dioxus_toastr::{use_toast, ToastKind, ToastRequest};
 use futures_util::future::join_all;
 use gloo_timers::callback::Timeout;
@@ -47,156 +61,16 @@ struct Data {
     total_pages: i32,
 }
 
-#[derive(Deserialize, Clone, Debug, PartialEq)]
-#[allow(dead_code)]
-pub struct Paragraph {
-    pub id: String,
-    #[serde(default)]
-    pub chapter_id: String,
-    pub texts: Vec<Text>,
-    pub choices: Vec<ComplexChoice>,
-    #[serde(rename = "collectionId", default)]
-    pub collection_id: String,
-    #[serde(rename = "collectionName", default)]
-    pub collection_name: String,
-    #[serde(default)]
-    pub created: String,
-    #[serde(default)]
-    pub updated: String,
-}
-
-#[derive(Deserialize, Clone, Debug, PartialEq)]
-pub struct Text {
-    pub lang: String,
-    pub paragraphs: String,
-    pub choices: Vec<String>,
-}
-
-#[derive(Clone, Debug, PartialEq)]
-pub struct ComplexChoice {
-    pub to: Vec<String>,
-    pub type_: String,
-    pub key: Option<String>,
-    pub value: Option<serde_json::Value>,
-    pub same_page: Option<bool>,
-    pub time_limit: Option<u32>,
-    pub timeout_to: Option<String>,
-    pub impacts: Option<Vec<Impact>>,
-}
-
-impl<'de> serde::Deserialize<'de> for ComplexChoice {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum ToField {
-            Multiple(Vec<String>),
-            Single(String),
-        }
-
-        impl Default for ToField {
-            fn default() -> Self {
-                ToField::Multiple(Vec::new())
-            }
-        }
-
-        #[derive(Deserialize)]
-        struct Helper {
-            #[serde(default)]
-            to: ToField,
-            #[serde(rename = "type", default)]
-            type_: String,
-            #[serde(default)]
-            key: Option<String>,
-            #[serde(default)]
-            value: Option<serde_json::Value>,
-            #[serde(default)]
-            same_page: Option<bool>,
-            #[serde(default)]
-            time_limit: Option<u32>,
-            #[serde(default, rename = "timeout_to")]
-            timeout_to: Option<String>,
-            #[serde(default)]
-            impacts: Option<Vec<Impact>>,
-        }
-
-        let helper = Helper::deserialize(deserializer)?;
-        let to = match helper.to {
-            ToField::Multiple(vec) => vec,
-            ToField::Single(s) => {
-                if s.is_empty() {
-                    Vec::new()
-                } else {
-                    vec![s]
-                }
-            }
-        };
-
-        Ok(ComplexChoice {
-            to,
-            type_: helper.type_,
-            key: helper.key,
-            value: helper.value,
-            same_page: helper.same_page,
-            time_limit: helper.time_limit,
-            timeout_to: helper.timeout_to,
-            impacts: helper.impacts,
-        })
-    }
-}
-
-#[derive(Deserialize, Clone, Debug, PartialEq)]
-#[serde(untagged)]
-pub enum StoryChoice {
-    Complex(ComplexChoice),
-    Simple(String),
-}
-
-impl From<StoryChoice> for Choice {
-    fn from(choice: StoryChoice) -> Self {
-        match choice {
-            StoryChoice::Complex(complex) => Self {
-                caption: String::new().into(),
-                action: Action {
-                    type_: complex.type_.into(),
-                    key: complex.key,
-                    value: complex.value,
-                    to: Cow::Owned(complex.to.first().cloned().unwrap_or_default()),
-                },
-            },
-            StoryChoice::Simple(text) => Self {
-                caption: Cow::Owned(text.clone()),
-                action: Action {
-                    type_: "goto".into(),
-                    key: None,
-                    value: None,
-                    to: Cow::Owned(text),
-                },
-            },
-        }
-    }
-}
+pub use crate::models::chapter::Chapter;
+use crate::models::chapter::ChapterTitle;
+pub use crate::models::interpolation::StoryVariables;
+pub use crate::models::paragraph::{Paragraph, ParagraphChoice, Text};
 
 #[derive(Props, PartialEq, Clone)]
 pub struct StoryProps {
     pub lang: String,
 }
 
-#[derive(Deserialize, Clone, Debug)]
-struct ChapterTitle {
-    lang: String,
-    title: String,
-}
-
-#[derive(Clone, Debug)]
-pub struct Chapter {
-    id: String,
-    titles: Vec<ChapterTitle>,
-    order: i32,
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum LoadState {
     NotRequested,
@@ -304,7 +178,8 @@ impl Default for ApiRequestDebugState {
     }
 }
 
-/// Merge multiple paragraphs' paragraphs field according to language and reader_mode rules
+/// Merge multiple paragraphs' paragraphs field according to language and reader_mode rules,
+/// resolving `{...}` expressions against `variables`
 #[allow(dead_code)]
 pub fn merge_paragraphs_for_lang(
     expanded: &[Paragraph],
@@ -312,6 +187,7 @@ pub fn merge_paragraphs_for_lang(
     reader_mode: bool,
     _is_settings_chapter: bool,
     _choice_ids: &[String],
+    variables: &StoryVariables,
 ) -> String {
     // 遊戲模式 (reader_mode == false):
     //   • 一般點擊（expanded.len()==1）→ 只顯示最後一段。
@@ -337,12 +213,18 @@ pub fn merge_paragraphs_for_lang(
 
     // If it's a settings chapter in reader mode, we still want to merge paragraphs just like normal chapters.
 
+    let files_base = resolve_api_url(FILES);
     for paragraph in paragraphs_to_process {
         if let Some(text) = paragraph.texts.iter().find(|t| t.lang == current_language) {
             if !merged_paragraph_str.is_empty() {
                 merged_paragraph_str.push_str("\n\n");
             }
-            merged_paragraph_str.push_str(&text.paragraphs);
+            if let Some(url) = paragraph.illustration_url(&files_base) {
+                let alt = text.illustration_alt.as_deref().unwrap_or_default();
+                merged_paragraph_str.push_str(&illustration(&url, alt));
+                merged_paragraph_str.push_str("\n\n");
+            }
+            merged_paragraph_str.push_str(&interpolate_markup(&text.paragraphs, variables));
         }
     }
 
@@ -449,8 +331,6 @@ pub fn Story(props: StoryProps) -> Element {
             Vec::new()
         }
     });
-    let last_paragraph_id = Rc::new(RefCell::new(String::new()));
-    let _last_paragraph_id_effect = last_paragraph_id.clone();
     let _story_context = story_context.clone();
     let _merged_paragraph = use_signal(|| String::new());
     let countdowns = use_signal(|| vec![]);
@@ -458,6 +338,12 @@ pub fn Story(props: StoryProps) -> Element {
     let progress_started = use_signal(|| vec![]);
     let disabled_by_countdown = use_signal(|| vec![]);
     let auto_restored = use_signal(|| false);
+    // Attributes and flags that `{...}` expressions in the text read
+    let mut character_state = use_signal(CharacterStateSnapshot::default);
+    let mut character_state_loaded = use_signal(|| false);
+    // Choices made since the page was opened; achievements are checked after each
+    let mut choices_made = use_signal(|| 0_u32);
+    let mut prompt_answer = use_signal(|| None::<String>);
     let show_chapter_title = use_signal(|| true);
     let mut paragraphs_load_state = use_signal(|| LoadState::NotRequested);
     let mut chapters_load_state = use_signal(|| LoadState::NotRequested);
@@ -574,7 +460,8 @@ pub fn Story(props: StoryProps) -> Element {
                 paragraphs_load_state.set(LoadState::Loading);
                 let paragraphs_url = resolve_api_url(PARAGRAPHS);
                 let client = reqwest::Client::new();
-                match client.get(&paragraphs_url).send().await {
+                match send_with_retry(&RetryPolicy::default(), || client.get(&paragraphs_url)).await
+                {
                     Ok(response) => {
                         let status = response.status();
                         let content_type = response
@@ -633,6 +520,13 @@ pub fn Story(props: StoryProps) -> Element {
                                                     *paragraphs = data.items.clone();
                                                 }
                                             }
+                                            let files_base = resolve_api_url(FILES);
+                                            let illustrations: Vec<String> = data
+                                                .items
+                                                .iter()
+                                                .filter_map(|p| p.illustration_url(&files_base))
+                                                .collect();
+                                            cache_media(&illustrations);
                                             paragraphs_load_state.set(LoadState::Loaded);
                                             if let Ok(mut debug) = api_debug_state.try_write() {
                                                 debug.paragraphs = LoadState::Loaded;
@@ -741,7 +635,7 @@ pub fn Story(props: StoryProps) -> Element {
 
                 let chapters_url = resolve_api_url(CHAPTERS);
                 let client = reqwest::Client::new();
-                match client.get(&chapters_url).send().await {
+                match send_with_retry(&RetryPolicy::default(), || client.get(&chapters_url)).await {
                     Ok(response) => {
                         let status = response.status();
                         let content_type = response
@@ -929,20 +823,23 @@ pub fn Story(props: StoryProps) -> Element {
                     .find(|t| t.lang == state().current_language)
                 {
                     current_text.set(Some(text.clone()));
+                    let variables = StoryVariables {
+                        settings: _settings_context.read().settings.clone(),
+                        state: character_state.read().clone(),
+                    };
                     let choices: Vec<Choice> = paragraph
                         .choices
                         .iter()
                         .enumerate()
                         .map(|(index, c)| {
-                            let choice: StoryChoice = StoryChoice::Complex(c.clone());
-                            let mut choice_obj: Choice = choice.into();
+                            let mut choice_obj: Choice = c.clone().into();
                             if let Some(text) = paragraph
                                 .texts
                                 .iter()
                                 .find(|t| t.lang == state().current_language)
                             {
                                 if let Some(caption) = text.choices.get(index) {
-                                    choice_obj.caption = caption.clone().into();
+                                    choice_obj.caption = interpolate(caption, &variables).into();
                                 }
                             }
                             // Check if there are multiple targets, if so perform random selection
@@ -1505,7 +1402,12 @@ pub fn Story(props: StoryProps) -> Element {
                                         timeout_id_clone
                                     ));
                                     let client = reqwest::Client::new();
-                                    if let Ok(resp) = client.get(&fetch_url).send().await {
+                                    if let Ok(resp) =
+                                        send_with_retry(&RetryPolicy::default(), || {
+                                            client.get(&fetch_url)
+                                        })
+                                        .await
+                                    {
                                         if resp.status().is_success() {
                                             if let Ok(txt) = resp.text().await {
                                                 if let Ok(paragraph) =
@@ -1571,6 +1473,114 @@ pub fn Story(props: StoryProps) -> Element {
         });
     }
 
+    // Audio is optional: without the collection the story just stays silent
+    let files_base = resolve_api_url(FILES);
+    {
+        let story_context = story_context.clone();
+        let files_base = files_base.clone();
+        use_hook(move || {
+            spawn_local(async move {
+                let client = crate::services::api::HttpApiClient::new();
+                match load_audio_cues(&client).await {
+                    Ok(cues) => {
+                        cache_media(&cues.urls(&files_base));
+                        let mut audio_cues = story_context.read().audio_cues;
+                        audio_cues.set(cues);
+                    }
+                    Err(error) => tracing::info!(error = %error, "No audio cues loaded"),
+                }
+            });
+        });
+    }
+
+    // Without the author's flag, timers follow the reader's settings
+    {
+        let story_context = story_context.clone();
+        use_hook(move || {
+            spawn_local(async move {
+                let client = crate::services::api::HttpApiClient::new();
+                match load_timers_mandatory(&client).await {
+                    Ok(mandatory) => {
+                        let mut timers_mandatory = story_context.read().timers_mandatory;
+                        timers_mandatory.set(mandatory);
+                    }
+                    Err(error) => tracing::info!(error = %error, "No timer flag loaded"),
+                }
+            });
+        });
+    }
+
+    // Achievements are optional as well. They are checked after each choice,
+    // once the character state and the reading progress have been loaded, and
+    // again as the choice's new state and paragraph come in.
+    {
+        let story_context = story_context.clone();
+        let toast = toast.clone();
+        let mut unlocked = use_signal(|| None::<UnlockedAchievements>);
+        use_hook(move || {
+            spawn_local(async move {
+                let client = crate::services::api::HttpApiClient::new();
+                match load_achievements(&client).await {
+                    Ok(loaded) => {
+                        let mut achievements = story_context.read().achievements;
+                        achievements.set(loaded);
+                    }
+                    Err(error) => tracing::info!(error = %error, "No achievements loaded"),
+                }
+                // Unreadable unlocks stay None, so nothing is saved over them
+                unlocked.set(load_unlocked().await.ok());
+            });
+        });
+        use_effect(move || {
+            let achievements = story_context.read().achievements.read().clone();
+            let progress = story_context.read().progress.read().clone();
+            let state = character_state.read().clone();
+            let loaded =
+                *character_state_loaded.read() && *story_context.read().progress_loaded.read();
+            if *choices_made.read() == 0 || !loaded {
+                return;
+            }
+            let variables = StoryVariables {
+                settings: settings_context.peek().settings.clone(),
+                state,
+            };
+            let Some(mut record) = unlocked.read().clone() else {
+                return;
+            };
+            let lang = state.peek().current_language.clone();
+            let now = js_sys::Date::now() as i64;
+            let newly: Vec<String> = record
+                .unlock_met(&achievements, &variables, &progress, now)
+                .into_iter()
+                .map(|achievement| achievement.title_for(&lang))
+                .collect();
+            if newly.is_empty() {
+                return;
+            }
+            unlocked.set(Some(record.clone()));
+            spawn_local(async move { save_unlocked(&record).await });
+            for title in newly {
+                toast.push(
+                    ToastRequest::new(ToastKind::Success, t!("achievement_unlocked", title: title))
+                        .with_timeout(Duration::from_millis(4500)),
+                );
+            }
+        });
+    }
+
+    use_hook(move || {
+        spawn_local(async move {
+            let loaded: CharacterStateSnapshot = get_latest_character_state_from_indexeddb()
+                .await
+                .ok()
+                .and_then(|val| val.as_string())
+                .and_then(|raw| serde_json::from_str(&raw).ok())
+                .unwrap_or_default();
+            character_state.set(loaded);
+            character_state_loaded.set(true);
+        });
+    });
+
     let on_choice_click = {
         let mut _expanded_paragraphs = _expanded_paragraphs.clone();
         let mut story_context = story_context.clone();
@@ -1578,46 +1588,68 @@ pub fn Story(props: StoryProps) -> Element {
         let mut show_chapter_title = show_chapter_title.clone();
         let mut auto_restored_click = auto_restored.clone();
         move |(goto, choice_index): (String, usize)| {
-            // 一旦使用者點擊，就禁止自動還原 impact 再次跑
-            auto_restored_click.set(true);
-
             let expanded_vec = _expanded_paragraphs.read().clone();
             let last_paragraph = expanded_vec.last().cloned();
 
-            if let Some(ref last) = last_paragraph {
-                if let Some(choice) = last.choices.get(choice_index) {
-                    if let Some(impacts) = &choice.impacts {
-                        let relevant: Vec<Impact> = impacts
-                            .iter()
-                            .filter(|impact| {
-                                matches!(
-                                    impact,
-                                    Impact::CharacterAttribute { .. } | Impact::Relationship { .. }
-                                )
-                            })
-                            .cloned()
-                            .collect();
-
-                        if !relevant.is_empty() {
-                            spawn_local(async move {
-                                let base_state: CharacterStateSnapshot =
-                                    get_latest_character_state_from_indexeddb()
-                                        .await
-                                        .ok()
-                                        .and_then(|val| val.as_string())
-                                        .and_then(|raw| serde_json::from_str(&raw).ok())
-                                        .unwrap_or_default();
+            // An input choice only continues with a checked answer, which keyboard
+            // shortcuts never provide. The answer is saved with the character
+            // state below, under its key.
+            let input_key = last_paragraph
+                .as_ref()
+                .and_then(|p| p.choices.get(choice_index))
+                .filter(|c| c.type_ == INPUT_ACTION)
+                .map(|c| c.key.clone().unwrap_or_default());
+            let mut answer = None;
+            if let Some(key) = input_key {
+                let Some(text) = prompt_answer.write().take() else {
+                    return;
+                };
+                if !key.is_empty() {
+                    answer = Some((key, text));
+                }
+            }
 
-                                let updated = base_state.apply_impacts(&relevant);
+            // 一旦使用者點擊，就禁止自動還原 impact 再次跑
+            auto_restored_click.set(true);
+            choices_made += 1;
+
+            let relevant: Vec<Impact> = last_paragraph
+                .as_ref()
+                .and_then(|last| last.choices.get(choice_index))
+                .and_then(|choice| choice.impacts.as_ref())
+                .into_iter()
+                .flatten()
+                .filter(|impact| {
+                    matches!(
+                        impact,
+                        Impact::CharacterAttribute { .. }
+                            | Impact::Relationship { .. }
+                            | Impact::Flag { .. }
+                    )
+                })
+                .cloned()
+                .collect();
+
+            if !relevant.is_empty() || answer.is_some() {
+                spawn_local(async move {
+                    let base_state: CharacterStateSnapshot =
+                        get_latest_character_state_from_indexeddb()
+                            .await
+                            .ok()
+                            .and_then(|val| val.as_string())
+                            .and_then(|raw| serde_json::from_str(&raw).ok())
+                            .unwrap_or_default();
+
+                    let mut updated = base_state.apply_impacts(&relevant);
+                    if let Some((key, text)) = answer {
+                        updated.answers.insert(key, text);
+                    }
+                    character_state.set(updated.clone());
 
-                                if let Ok(serialized) = serde_json::to_string(&updated) {
-                                    let _ =
-                                        set_latest_character_state_to_indexeddb(&serialized).await;
-                                }
-                            });
-                        }
+                    if let Ok(serialized) = serde_json::to_string(&updated) {
+                        let _ = set_latest_character_state_to_indexeddb(&serialized).await;
                     }
-                }
+                });
             }
 
             if let Some(ref last) = last_paragraph {
@@ -1844,7 +1876,10 @@ pub fn Story(props: StoryProps) -> Element {
                             goto_id
                         ));
                         let client = reqwest::Client::new();
-                        if let Ok(response) = client.get(&fetch_url).send().await {
+                        if let Ok(response) =
+                            send_with_retry(&RetryPolicy::default(), || client.get(&fetch_url))
+                                .await
+                        {
                             if response.status().is_success() {
                                 if let Ok(text) = response.text().await {
                                     // Attempt to deserialize directly into a `Paragraph` record.
@@ -1902,12 +1937,17 @@ pub fn Story(props: StoryProps) -> Element {
                     .unwrap_or_default();
                 let _is_settings_chapter = chapter_id == "settingschapter";
                 let choice_ids = story_context.read().choice_ids.read().clone();
+                let variables = StoryVariables {
+                    settings: settings_context.read().settings.clone(),
+                    state: character_state.read().clone(),
+                };
                 let merged_paragraph_str = merge_paragraphs_for_lang(
                     &expanded,
                     &state.read().current_language,
                     reader_mode,
                     _is_settings_chapter,
                     &choice_ids,
+                    &variables,
                 );
                 let mut merged_paragraph_signal =
                     story_merged_context.read().merged_paragraph.clone();
@@ -1918,11 +1958,15 @@ pub fn Story(props: StoryProps) -> Element {
     }
 
     // Main impact: Set settings, update paragraph_id, initialize countdown
+    let running_countdowns = Rc::new(RefCell::new(RunningCountdowns::default()));
     use_effect(move || {
         let mut story_context = story_context.clone();
         let expanded = _expanded_paragraphs.read();
         let state = state.clone();
-        let last_paragraph_id = last_paragraph_id.clone();
+        let running_countdowns = running_countdowns.clone();
+        let mandatory = *story_context.read().timers_mandatory.read();
+        let timer_options =
+            TimerOptions::from_settings(&settings_context.read().settings, mandatory);
         let expanded = expanded.as_slice();
         if let Some(paragraph) = expanded.last() {
             let _is_settings_chapter = paragraph.chapter_id == "settingschapter";
@@ -1930,21 +1974,24 @@ pub fn Story(props: StoryProps) -> Element {
                 .write()
                 .is_settings_chapter
                 .set(_is_settings_chapter);
-            if *last_paragraph_id.borrow() != paragraph.id {
-                *last_paragraph_id.borrow_mut() = paragraph.id.clone();
-                // Initialize countdown only when paragraph ID changes
-                if let Some(_text) = paragraph
-                    .texts
-                    .iter()
-                    .find(|t| t.lang == state().current_language)
-                {
-                    let countdowns_vec = paragraph
-                        .choices
-                        .iter()
-                        .map(|c| c.time_limit.unwrap_or(0))
-                        .collect::<Vec<u32>>();
-                    // Logs cleared
-                    story_context.write().countdowns.set(countdowns_vec);
+            // Initialize countdown when the paragraph ID changes, and follow the
+            // effective limits so a changed option rescales running countdowns
+            if let Some(_text) = paragraph
+                .texts
+                .iter()
+                .find(|t| t.lang == state().current_language)
+            {
+                let limits = timer_options.limits(&paragraph.choices);
+                let mut running = running_countdowns.borrow_mut();
+                if running.update(&paragraph.id, limits) {
+                    story_context
+                        .write()
+                        .countdown_rates
+                        .set(running.rates.clone());
+                    story_context
+                        .write()
+                        .countdowns
+                        .set(running.durations.clone());
                 }
             }
         }
@@ -2099,7 +2146,9 @@ pub fn Story(props: StoryProps) -> Element {
             reader_mode: reader_mode,
             chapter_title: chapter_title,
             current_paragraph_id: current_paragraph_id,
+            prompt_answer: prompt_answer,
         }
+        AudioDirector { paragraph_id: current_paragraph_id, files_base: files_base.clone() }
     }
 }
 
@@ -1,5 +1,7 @@
 use crate::components::story_content::Choice;
-use crate::pages::story::{merge_paragraphs_for_lang, ComplexChoice, Paragraph, StoryChoice, Text};
+use crate::pages::story::{
+    merge_paragraphs_for_lang, Paragraph, ParagraphChoice, StoryVariables, Text,
+};
 use serde_json;
 use std::collections::HashSet;
 
@@ -21,9 +23,9 @@ fn create_test_paragraph(
     text: &str,
     choices: Vec<(&str, &str)>,
 ) -> Paragraph {
-    let complex_choices: Vec<ComplexChoice> = choices
+    let complex_choices: Vec<ParagraphChoice> = choices
         .into_iter()
-        .map(|(_, to)| ComplexChoice {
+        .map(|(_, to)| ParagraphChoice {
             to: vec![to.to_string()],
             type_: "goto".into(),
             key: None,
@@ -45,12 +47,10 @@ fn create_test_paragraph(
                 .iter()
                 .map(|c| c.to.first().unwrap_or(&String::new()).clone())
                 .collect(),
+            ..Default::default()
         }],
         choices: complex_choices,
-        collection_id: "test_collection".to_string(),
-        collection_name: "Test Collection".to_string(),
-        created: "2024-01-01T00:00:00Z".to_string(),
-        updated: "2024-01-01T00:00:00Z".to_string(),
+        ..Default::default()
     }
 }
 
@@ -63,7 +63,7 @@ fn create_multilingual_paragraph(
     let mut paragraph = Paragraph {
         id: id.to_string(),
         texts: vec![],
-        choices: vec![ComplexChoice {
+        choices: vec![ParagraphChoice {
             to: vec!["default_target".to_string()],
             type_: "goto".into(),
             key: None,
@@ -74,10 +74,7 @@ fn create_multilingual_paragraph(
             impacts: None,
         }],
         chapter_id: chapter_id.to_string(),
-        collection_id: "test_collection".to_string(),
-        collection_name: "Test Collection".to_string(),
-        created: "2024-01-01T00:00:00Z".to_string(),
-        updated: "2024-01-01T00:00:00Z".to_string(),
+        ..Default::default()
     };
 
     for (lang, text_content) in texts {
@@ -85,6 +82,7 @@ fn create_multilingual_paragraph(
             lang: lang.to_string(),
             paragraphs: text_content.to_string(),
             choices: vec!["default_target".to_string()],
+            ..Default::default()
         });
     }
 
@@ -102,7 +100,14 @@ fn test_merge_paragraphs_basic_integration() {
     let choice_ids = vec!["p1".to_string(), "p2".to_string()];
 
     // Test the actual merge function from main program
-    let result = merge_paragraphs_for_lang(&paragraphs, "zh", false, false, &choice_ids);
+    let result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        false,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(result, "第一段\n\n第二段");
 }
 
@@ -116,11 +121,25 @@ fn test_merge_paragraphs_reader_mode_integration() {
     let choice_ids = vec!["p1".to_string(), "p3".to_string()]; // Only include p1 and p3
 
     // In normal mode, all paragraphs are included
-    let normal_result = merge_paragraphs_for_lang(&paragraphs, "zh", false, false, &choice_ids);
+    let normal_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        false,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(normal_result, "第一段\n\n第二段\n\n第三段");
 
     // In NEW reader mode, all paragraphs in the expanded path are included
-    let reader_result = merge_paragraphs_for_lang(&paragraphs, "zh", true, false, &choice_ids);
+    let reader_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        true,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(reader_result, "第一段\n\n第二段\n\n第三段"); // All paragraphs included in reader mode
 }
 
@@ -135,8 +154,22 @@ fn test_settings_chapter_behavior() {
     let choice_ids = vec!["settings1".to_string()];
 
     // Settings chapter should include all paragraphs regardless of reader mode
-    let normal_result = merge_paragraphs_for_lang(&paragraphs, "zh", false, true, &choice_ids);
-    let reader_result = merge_paragraphs_for_lang(&paragraphs, "zh", true, true, &choice_ids);
+    let normal_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        false,
+        true,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
+    let reader_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        true,
+        true,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
 
     assert_eq!(normal_result, reader_result);
     assert_eq!(normal_result, "設定段落1\n\n設定段落2");
@@ -146,9 +179,9 @@ fn test_settings_chapter_behavior() {
 fn test_paragraph_with_time_limit_integration() {
     let mut p = create_test_paragraph("p1", "c1", "zh", "Time limited paragraph", vec![]);
 
-    // Add choices with time limits using actual ComplexChoice structure
+    // Add choices with time limits using actual ParagraphChoice structure
     p.choices = vec![
-        ComplexChoice {
+        ParagraphChoice {
             to: vec!["p2".to_string()],
             type_: "goto".into(),
             key: None,
@@ -158,7 +191,7 @@ fn test_paragraph_with_time_limit_integration() {
             timeout_to: None,
             impacts: None,
         },
-        ComplexChoice {
+        ParagraphChoice {
             to: vec!["p3".to_string()],
             type_: "goto".into(),
             key: None,
@@ -168,7 +201,7 @@ fn test_paragraph_with_time_limit_integration() {
             timeout_to: None,
             impacts: None,
         },
-        ComplexChoice {
+        ParagraphChoice {
             to: vec!["p4".to_string()],
             type_: "goto".into(),
             key: None,
@@ -210,18 +243,48 @@ fn test_countdown_disable_logic() {
     assert_eq!(disabled_by_countdown, vec![false, true, false]);
 }
 
+#[test]
+fn test_merge_paragraphs_interpolates_text() {
+    let p1 = create_test_paragraph("p1", "c1", "en", "Hi {player_name}", vec![]);
+    let p2 = create_test_paragraph(
+        "p2",
+        "c1",
+        "en",
+        "{lamp == 'on' ? 'Light.' : 'Dark.'}",
+        vec![],
+    );
+    let mut variables = StoryVariables::default();
+    variables
+        .settings
+        .insert("player_name".to_string(), "Ada".to_string());
+    variables
+        .settings
+        .insert("lamp".to_string(), "on".to_string());
+
+    let result = merge_paragraphs_for_lang(&[p1, p2], "en", true, false, &[], &variables);
+    assert_eq!(result, "Hi Ada\n\nLight.");
+}
+
 // ================== Advanced Story Logic Tests ==================
 
 #[test]
 fn test_merge_paragraphs_empty_cases() {
     // Test empty paragraph list
-    let result = merge_paragraphs_for_lang(&[], "zh", false, false, &[]);
+    let result =
+        merge_paragraphs_for_lang(&[], "zh", false, false, &[], &StoryVariables::default());
     assert_eq!(result, "");
 
     // Test paragraph with no matching language
     let p1 = create_test_paragraph("p1", "c1", "en", "English text", vec![]);
     let paragraphs = vec![p1];
-    let result = merge_paragraphs_for_lang(&paragraphs, "zh", false, false, &["p1".to_string()]);
+    let result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        false,
+        false,
+        &["p1".to_string()],
+        &StoryVariables::default(),
+    );
     assert_eq!(result, "");
 }
 
@@ -236,11 +299,25 @@ fn test_merge_paragraphs_complex_filtering() {
     let choice_ids = vec!["p1".to_string(), "p4".to_string()];
 
     // In NEW reader mode, all paragraphs in the expanded path are included
-    let reader_result = merge_paragraphs_for_lang(&paragraphs, "zh", true, false, &choice_ids);
+    let reader_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        true,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(reader_result, "第一段\n\n第二段\n\n設定段落\n\n第四段"); // All paragraphs included
 
     // Test with settings chapter flag enabled - should behave the same in new reader mode
-    let settings_result = merge_paragraphs_for_lang(&paragraphs, "zh", true, true, &choice_ids);
+    let settings_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        true,
+        true,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(settings_result, "第一段\n\n第二段\n\n設定段落\n\n第四段"); // All included
 }
 
@@ -253,7 +330,14 @@ fn test_merge_paragraphs_whitespace_handling() {
     let paragraphs = vec![p1, p2, p3];
     let choice_ids = vec!["p1".to_string(), "p2".to_string(), "p3".to_string()];
 
-    let result = merge_paragraphs_for_lang(&paragraphs, "zh", false, false, &choice_ids);
+    let result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        false,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(result, "段落一\n\n\n\n段落三"); // Empty paragraph still adds spacing
 }
 
@@ -279,13 +363,34 @@ fn test_multilingual_paragraph_handling() {
     let choice_ids = vec!["p1".to_string(), "p2".to_string()];
 
     // Test different languages
-    let zh_result = merge_paragraphs_for_lang(&paragraphs, "zh", false, false, &choice_ids);
+    let zh_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        false,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(zh_result, "中文內容\n\n第二段中文");
 
-    let en_result = merge_paragraphs_for_lang(&paragraphs, "en", false, false, &choice_ids);
+    let en_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "en",
+        false,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(en_result, "English content\n\nSecond paragraph English");
 
-    let ja_result = merge_paragraphs_for_lang(&paragraphs, "ja", false, false, &choice_ids);
+    let ja_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "ja",
+        false,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(ja_result, "日本語コンテンツ"); // Only first paragraph has Japanese
 }
 
@@ -303,7 +408,7 @@ fn test_complex_choice_deserialization() {
         "time_limit": 10
     }"#;
 
-    let choice: Result<ComplexChoice, _> = serde_json::from_str(json_single);
+    let choice: Result<ParagraphChoice, _> = serde_json::from_str(json_single);
     assert!(choice.is_ok());
     let choice = choice.unwrap();
     assert_eq!(choice.to, vec!["single_target"]);
@@ -316,7 +421,7 @@ fn test_complex_choice_deserialization() {
         "type": "random_goto"
     }"#;
 
-    let choice: Result<ComplexChoice, _> = serde_json::from_str(json_multi);
+    let choice: Result<ParagraphChoice, _> = serde_json::from_str(json_multi);
     assert!(choice.is_ok());
     let choice = choice.unwrap();
     assert_eq!(choice.to, vec!["target1", "target2", "target3"]);
@@ -329,7 +434,7 @@ fn test_complex_choice_deserialization() {
         "type": "goto"
     }"#;
 
-    let choice: Result<ComplexChoice, _> = serde_json::from_str(json_empty);
+    let choice: Result<ParagraphChoice, _> = serde_json::from_str(json_empty);
     assert!(choice.is_ok());
     let choice = choice.unwrap();
     assert_eq!(choice.to, Vec::<String>::new()); // Empty string becomes empty vec
@@ -337,8 +442,8 @@ fn test_complex_choice_deserialization() {
 
 #[test]
 fn test_complex_choice_structure_validation() {
-    // Test creating ComplexChoice with various configurations
-    let basic_choice = ComplexChoice {
+    // Test creating ParagraphChoice with various configurations
+    let basic_choice = ParagraphChoice {
         to: vec!["target1".to_string()],
         type_: "goto".into(),
         key: None,
@@ -354,7 +459,7 @@ fn test_complex_choice_structure_validation() {
     assert!(basic_choice.key.is_none());
 
     // Test complex choice with all fields
-    let complex_choice = ComplexChoice {
+    let complex_choice = ParagraphChoice {
         to: vec!["target1".to_string(), "target2".to_string()],
         type_: "custom_action".into(),
         key: Some("special_key".to_string()),
@@ -373,7 +478,7 @@ fn test_complex_choice_structure_validation() {
 #[test]
 fn test_story_choice_conversion() {
     // Test Complex choice conversion
-    let complex_choice = ComplexChoice {
+    let complex_choice = ParagraphChoice {
         to: vec!["target".to_string()],
         type_: "custom_action".into(),
         key: Some("special_key".to_string()),
@@ -384,21 +489,20 @@ fn test_story_choice_conversion() {
         impacts: None,
     };
 
-    let story_choice = StoryChoice::Complex(complex_choice.clone());
-    let choice: Choice = story_choice.into();
+    let choice: Choice = complex_choice.clone().into();
 
-    assert_eq!(choice.caption, ""); // Complex choices start with empty caption
+    assert_eq!(choice.caption, ""); // Captions come from the paragraph text
     assert_eq!(choice.action.type_, "custom_action");
     assert_eq!(choice.action.to, "target");
     assert_eq!(choice.action.key, Some("special_key".to_string()));
 
-    // Test Simple choice conversion
-    let simple_choice = StoryChoice::Simple("Go to next page".to_string());
+    // Test legacy bare-string choice conversion
+    let simple_choice: ParagraphChoice = serde_json::from_str(r#""next_page""#).unwrap();
     let choice: Choice = simple_choice.into();
 
-    assert_eq!(choice.caption, "Go to next page");
+    assert_eq!(choice.caption, "");
     assert_eq!(choice.action.type_, "goto");
-    assert_eq!(choice.action.to, "Go to next page");
+    assert_eq!(choice.action.to, "next_page");
     assert_eq!(choice.action.key, None);
 }
 
@@ -440,11 +544,25 @@ fn test_reader_mode_edge_cases() {
     let choice_ids = vec!["second".to_string()]; // Only include second paragraph
 
     // In NEW reader mode, all paragraphs in the expanded path are included
-    let result = merge_paragraphs_for_lang(&paragraphs, "zh", true, false, &choice_ids);
+    let result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        true,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(result, "第一段\n\n第二段\n\n第三段"); // All paragraphs included
 
     // Test with empty choice_ids - still includes all paragraphs
-    let empty_choice_result = merge_paragraphs_for_lang(&paragraphs, "zh", true, false, &[]);
+    let empty_choice_result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        true,
+        false,
+        &[],
+        &StoryVariables::default(),
+    );
     assert_eq!(empty_choice_result, "第一段\n\n第二段\n\n第三段"); // All paragraphs included
 }
 
@@ -464,7 +582,14 @@ fn test_chapter_filtering_in_reader_mode() {
     ];
 
     // In NEW reader mode, all paragraphs in the expanded path are included
-    let result = merge_paragraphs_for_lang(&paragraphs, "zh", true, false, &choice_ids);
+    let result = merge_paragraphs_for_lang(
+        &paragraphs,
+        "zh",
+        true,
+        false,
+        &choice_ids,
+        &StoryVariables::default(),
+    );
     assert_eq!(result, "章節1段落1\n\n章節2段落1\n\n設定段落\n\n章節1段落2"); // All paragraphs included
 }
 
@@ -486,10 +611,22 @@ mod integration_tests {
         let choice_ids = vec!["p1".to_string()];
 
         // Test different language outputs - THIS IS STORY.RS LOGIC, NOT UI
-        let english_result =
-            merge_paragraphs_for_lang(&paragraphs, "en", false, false, &choice_ids);
-        let chinese_result =
-            merge_paragraphs_for_lang(&paragraphs, "zh", false, false, &choice_ids);
+        let english_result = merge_paragraphs_for_lang(
+            &paragraphs,
+            "en",
+            false,
+            false,
+            &choice_ids,
+            &StoryVariables::default(),
+        );
+        let chinese_result = merge_paragraphs_for_lang(
+            &paragraphs,
+            "zh",
+            false,
+            false,
+            &choice_ids,
+            &StoryVariables::default(),
+        );
 
         assert_eq!(english_result, "This is English content");
         assert_eq!(chinese_result, "這是中文內容");
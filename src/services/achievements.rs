@@ -0,0 +1,59 @@
+//! Loading achievement definitions and the reader's unlocks.
+
+use crate::constants::config::ACHIEVEMENTS;
+use crate::models::achievements::{Achievement, UnlockedAchievements};
+use crate::services::api::{ApiClient, ApiResult};
+use crate::services::indexeddb::{get_achievements_from_indexeddb, set_achievements_to_indexeddb};
+
+/// Every achievement of the story in gallery order; records that do not
+/// parse are skipped.
+pub async fn load_achievements<C: ApiClient + ?Sized>(client: &C) -> ApiResult<Vec<Achievement>> {
+    let records = client.list_records(ACHIEVEMENTS).await?;
+    let mut achievements: Vec<Achievement> = records
+        .into_iter()
+        .filter_map(
+            |record| match serde_json::from_value::<Achievement>(record) {
+                Ok(achievement) => Some(achievement),
+                Err(error) => {
+                    tracing::warn!(error = %error, "Skipping malformed achievement");
+                    None
+                }
+            },
+        )
+        .collect();
+    achievements.sort_by_key(|a| a.order);
+    Ok(achievements)
+}
+
+fn enabled() -> bool {
+    // IndexedDB only exists in the browser
+    cfg!(target_arch = "wasm32")
+}
+
+/// The stored unlocks, empty when there are none yet. Fails when they exist
+/// but cannot be read, so callers leave them alone instead of saving over them.
+pub async fn load_unlocked() -> Result<UnlockedAchievements, String> {
+    if !enabled() {
+        return Ok(UnlockedAchievements::default());
+    }
+    let value = get_achievements_from_indexeddb().await.map_err(|error| {
+        tracing::error!(error = ?error, "Failed to read achievements from IndexedDB");
+        format!("{:?}", error)
+    })?;
+    let Some(raw) = value.as_string() else {
+        return Ok(UnlockedAchievements::default());
+    };
+    UnlockedAchievements::from_json(&raw).map_err(|error| {
+        tracing::error!(error = %error, "Failed to parse stored achievements");
+        error.to_string()
+    })
+}
+
+pub async fn save_unlocked(unlocked: &UnlockedAchievements) {
+    if !enabled() {
+        return;
+    }
+    if let Err(error) = set_achievements_to_indexeddb(&unlocked.to_json()).await {
+        tracing::error!(error = ?error, "Failed to write achievements to IndexedDB");
+    }
+}
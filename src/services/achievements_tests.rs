@@ -0,0 +1,33 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::achievements::*;
+    use super::super::api::*;
+    use crate::constants::config::ACHIEVEMENTS;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_load_sorts_by_order_and_skips_malformed() {
+        let client = MockApiClient::new().with_records(
+            ACHIEVEMENTS,
+            vec![
+                json!({"id": "a2", "texts": [{"lang": "en-US", "title": "Survivor"}], "trigger": "endings_count > 0", "order": 2}),
+                json!({"id": "a1", "texts": [], "trigger": "visited.p1", "order": 1}),
+                json!({"id": "a3", "texts": []}),
+            ],
+        );
+
+        let achievements = load_achievements(&client).await.unwrap();
+
+        let ids: Vec<&str> = achievements.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["a1", "a2"]);
+        assert_eq!(achievements[1].title_for("ja-JP"), "Survivor");
+        assert_eq!(achievements[0].title_for("en-US"), "a1");
+    }
+
+    #[tokio::test]
+    async fn test_load_reports_network_errors() {
+        let client = MockApiClient::new().with_failure();
+
+        assert!(load_achievements(&client).await.is_err());
+    }
+}
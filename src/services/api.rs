@@ -1,6 +1,20 @@
-use crate::constants::config::{base_api_url, CHAPTERS, PARAGRAPHS};
-use crate::contexts::paragraph_context::{Paragraph, ParagraphData};
+use crate::constants::config::{
+    base_api_url, BATCH, CHAPTERS, INVITES, INVITES_CHECK, PARAGRAPHS, PARAGRAPH_REVISIONS, USERS,
+    USERS_AUTH_REFRESH, USERS_AUTH_WITH_PASSWORD,
+};
+use crate::contexts::paragraph_context::ParagraphData;
+use crate::models::chapter::Chapter as ChapterRecord;
+use crate::models::paragraph::Paragraph;
+use crate::services::auth::{with_auth, AuthSession, AuthUser, PasswordCredentials, SignUpRequest};
+use crate::services::chapters::{ChapterUpdate, NewChapter};
+use crate::services::invite::{
+    Invite, InviteCheck, InviteCodeError, InviteData, InviteUpdate, NewInvite,
+};
+use crate::services::revisions::{ParagraphRevision, RevisionData};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChapterData {
@@ -14,6 +28,67 @@ pub struct Chapter {
     pub order: i32,
 }
 
+/// One page of a PocketBase list response.
+#[derive(Debug, Deserialize)]
+struct RecordPage<T> {
+    items: Vec<T>,
+    #[serde(rename = "totalPages", default)]
+    total_pages: u32,
+}
+
+/// One write inside a PocketBase `/api/batch` transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BatchRequest {
+    pub method: String,
+    /// Path from the server root, e.g. `/api/collections/paragraphs/records/abc`.
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+}
+
+/// Quotes `value` as a string literal for a PocketBase `filter` expression.
+///
+/// ```
+/// use ifecaro::services::api::filter_string;
+///
+/// assert_eq!(filter_string("p1"), "'p1'");
+/// assert_eq!(filter_string("x' || id != '"), r"'x\' || id != \''");
+/// ```
+pub fn filter_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// Most requests PocketBase accepts in one batch (its default
+/// `batch.maxRequests`).
+pub const BATCH_LIMIT: usize = 50;
+
+impl BatchRequest {
+    /// `endpoint` is one of the collection paths in `constants::config`.
+    pub fn create(endpoint: &str, body: serde_json::Value) -> Self {
+        Self {
+            method: "POST".to_string(),
+            url: format!("/api{}", endpoint),
+            body: Some(body),
+        }
+    }
+
+    pub fn patch(endpoint: &str, id: &str, body: serde_json::Value) -> Self {
+        Self {
+            method: "PATCH".to_string(),
+            url: format!("/api{}/{}", endpoint, id),
+            body: Some(body),
+        }
+    }
+
+    pub fn delete(endpoint: &str, id: &str) -> Self {
+        Self {
+            method: "DELETE".to_string(),
+            url: format!("/api{}/{}", endpoint, id),
+            body: None,
+        }
+    }
+}
+
 // API error types
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -22,6 +97,33 @@ pub enum ApiError {
     ParseError(String),
     NotFound,
     ServerError(u16),
+    Timeout,
+}
+
+#[allow(dead_code)]
+impl ApiError {
+    /// Whether a failed call is worth repeating: transport failures, timeouts,
+    /// rate limiting and 5xx responses are transient; parse errors and other
+    /// 4xx responses will fail the same way again.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ApiError::NetworkError(_) | ApiError::Timeout => true,
+            ApiError::ServerError(status) => *status == 429 || *status >= 500,
+            ApiError::ParseError(_) | ApiError::NotFound => false,
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::NetworkError(message) => write!(f, "network error: {}", message),
+            ApiError::ParseError(message) => write!(f, "parse error: {}", message),
+            ApiError::NotFound => write!(f, "not found"),
+            ApiError::ServerError(status) => write!(f, "server error: HTTP {}", status),
+            ApiError::Timeout => write!(f, "request timed out"),
+        }
+    }
 }
 
 // API result type
@@ -36,63 +138,154 @@ pub trait ApiClient {
     async fn get_chapters(&self) -> ApiResult<ChapterData>;
     async fn get_paragraph_by_id(&self, id: &str) -> ApiResult<Paragraph>;
     async fn update_paragraph(&self, paragraph: &Paragraph) -> ApiResult<()>;
+    async fn delete_paragraph(&self, id: &str) -> ApiResult<()>;
+    async fn create_chapter(&self, chapter: &NewChapter) -> ApiResult<ChapterRecord>;
+    async fn update_chapter(&self, id: &str, update: &ChapterUpdate) -> ApiResult<ChapterRecord>;
+    async fn delete_chapter(&self, id: &str) -> ApiResult<()>;
+    /// Runs all `requests` in one transaction; none are applied if one fails.
+    async fn batch(&self, requests: &[BatchRequest]) -> ApiResult<()>;
+    async fn auth_with_password(&self, credentials: &PasswordCredentials)
+        -> ApiResult<AuthSession>;
+    async fn create_user(&self, request: &SignUpRequest) -> ApiResult<AuthUser>;
+    async fn refresh_auth(&self, token: &str) -> ApiResult<AuthSession>;
+    async fn create_invite(&self, invite: &NewInvite) -> ApiResult<Invite>;
+    async fn list_invites(&self) -> ApiResult<InviteData>;
+    /// Whether `email` can register with `code`, which must already be
+    /// normalized. Only admins can read invites, so the server answers this.
+    async fn check_invite_code(
+        &self,
+        code: &str,
+        email: &str,
+    ) -> ApiResult<Result<(), InviteCodeError>>;
+    async fn update_invite(&self, id: &str, update: &InviteUpdate) -> ApiResult<Invite>;
+    /// Revisions of one paragraph, newest first.
+    async fn list_revisions(&self, paragraph_id: &str) -> ApiResult<RevisionData>;
+    /// Every record of the collection at `endpoint` as raw JSON, across all pages.
+    async fn list_records(&self, endpoint: &str) -> ApiResult<Vec<serde_json::Value>>;
 }
 
 // Real HTTP client implementation
 #[allow(dead_code)]
 pub struct HttpApiClient {
     client: reqwest::Client,
+    base_url: String,
 }
 
 #[allow(dead_code)]
 impl HttpApiClient {
     pub fn new() -> Self {
+        Self::with_base_url(base_api_url())
+    }
+
+    /// Points the client at another PocketBase instance, e.g. a local container.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
         Self {
             client: reqwest::Client::new(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
         }
     }
-}
 
-#[async_trait::async_trait(?Send)]
-impl ApiClient for HttpApiClient {
-    async fn get_paragraphs(&self) -> ApiResult<ParagraphData> {
-        let url = format!("{}{}", base_api_url(), PARAGRAPHS);
+    /// Builds a request for `endpoint`, carrying the session token if signed in.
+    fn request(&self, method: reqwest::Method, endpoint: &str) -> reqwest::RequestBuilder {
+        with_auth(
+            self.client
+                .request(method, format!("{}{}", self.base_url, endpoint)),
+        )
+    }
 
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+    async fn parse_json<T: serde::de::DeserializeOwned>(
+        response: Result<reqwest::Response, reqwest::Error>,
+    ) -> ApiResult<T> {
+        let response = response.map_err(|e| ApiError::NetworkError(e.to_string()))?;
 
         if !response.status().is_success() {
             return Err(ApiError::ServerError(response.status().as_u16()));
         }
 
         response
-            .json::<ParagraphData>()
+            .json::<T>()
             .await
             .map_err(|e| ApiError::ParseError(e.to_string()))
     }
 
-    async fn get_chapters(&self) -> ApiResult<ChapterData> {
-        let url = format!("{}{}", base_api_url(), CHAPTERS);
+    /// Every record `endpoint` lists for `query`, fetched page by page.
+    async fn list_pages<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query: &[(&str, &str)],
+    ) -> ApiResult<Vec<T>> {
+        let mut items = Vec::new();
+        let mut page = 1;
+        loop {
+            let page_number = page.to_string();
+            let response = self
+                .request(reqwest::Method::GET, endpoint)
+                .query(query)
+                .query(&[("page", page_number.as_str()), ("perPage", "500")])
+                .send()
+                .await;
+            let data: RecordPage<T> = Self::parse_json(response).await?;
+            items.extend(data.items);
+            if page >= data.total_pages {
+                return Ok(items);
+            }
+            page += 1;
+        }
+    }
+
+    /// For calls that answer with an empty body, such as deletes.
+    async fn expect_success(response: Result<reqwest::Response, reqwest::Error>) -> ApiResult<()> {
+        let response = response.map_err(|e| ApiError::NetworkError(e.to_string()))?;
+        match response.status().as_u16() {
+            status if (200..300).contains(&status) => Ok(()),
+            404 => Err(ApiError::NotFound),
+            status => Err(ApiError::ServerError(status)),
+        }
+    }
 
+    /// Uploads `bytes` as the file in `field` of the record `endpoint/id`,
+    /// replacing any file there, and returns the updated record. Uploads are
+    /// multipart, so they stay outside [`ApiClient`] and its batches.
+    pub async fn upload_file<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        id: &str,
+        field: &str,
+        file_name: &str,
+        bytes: Vec<u8>,
+    ) -> ApiResult<T> {
+        // PocketBase checks the type from the content, not the part's header
+        let part = reqwest::multipart::Part::bytes(bytes).file_name(file_name.to_string());
+        let form = reqwest::multipart::Form::new().part(field.to_string(), part);
         let response = self
-            .client
-            .get(&url)
+            .request(reqwest::Method::PATCH, &format!("{}/{}", endpoint, id))
+            .multipart(form)
             .send()
-            .await
-            .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+            .await;
+        Self::parse_json(response).await
+    }
 
-        if !response.status().is_success() {
-            return Err(ApiError::ServerError(response.status().as_u16()));
-        }
+    /// Removes the file in `field` of the record `endpoint/id`.
+    pub async fn clear_file(&self, endpoint: &str, id: &str, field: &str) -> ApiResult<()> {
+        let response = self
+            .request(reqwest::Method::PATCH, &format!("{}/{}", endpoint, id))
+            .json(&serde_json::json!({ field: null }))
+            .send()
+            .await;
+        Self::expect_success(response).await
+    }
+}
 
-        response
-            .json::<ChapterData>()
-            .await
-            .map_err(|e| ApiError::ParseError(e.to_string()))
+#[async_trait::async_trait(?Send)]
+impl ApiClient for HttpApiClient {
+    async fn get_paragraphs(&self) -> ApiResult<ParagraphData> {
+        let response = self.request(reqwest::Method::GET, PARAGRAPHS).send().await;
+        Self::parse_json(response).await
+    }
+
+    async fn get_chapters(&self) -> ApiResult<ChapterData> {
+        let response = self.request(reqwest::Method::GET, CHAPTERS).send().await;
+        Self::parse_json(response).await
     }
 
     async fn get_paragraph_by_id(&self, id: &str) -> ApiResult<Paragraph> {
@@ -106,11 +299,10 @@ impl ApiClient for HttpApiClient {
     }
 
     async fn update_paragraph(&self, paragraph: &Paragraph) -> ApiResult<()> {
-        let url = format!("{}{}/{}", base_api_url(), PARAGRAPHS, paragraph.id);
+        let endpoint = format!("{}/{}", PARAGRAPHS, paragraph.id);
 
         let response = self
-            .client
-            .put(&url)
+            .request(reqwest::Method::PUT, &endpoint)
             .json(paragraph)
             .send()
             .await
@@ -122,6 +314,140 @@ impl ApiClient for HttpApiClient {
 
         Ok(())
     }
+
+    async fn delete_paragraph(&self, id: &str) -> ApiResult<()> {
+        let endpoint = format!("{}/{}", PARAGRAPHS, id);
+        let response = self
+            .request(reqwest::Method::DELETE, &endpoint)
+            .send()
+            .await;
+        Self::expect_success(response).await
+    }
+
+    async fn create_chapter(&self, chapter: &NewChapter) -> ApiResult<ChapterRecord> {
+        let response = self
+            .request(reqwest::Method::POST, CHAPTERS)
+            .json(chapter)
+            .send()
+            .await;
+        Self::parse_json(response).await
+    }
+
+    async fn update_chapter(&self, id: &str, update: &ChapterUpdate) -> ApiResult<ChapterRecord> {
+        let endpoint = format!("{}/{}", CHAPTERS, id);
+        let response = self
+            .request(reqwest::Method::PATCH, &endpoint)
+            .json(update)
+            .send()
+            .await;
+        Self::parse_json(response).await
+    }
+
+    async fn delete_chapter(&self, id: &str) -> ApiResult<()> {
+        let endpoint = format!("{}/{}", CHAPTERS, id);
+        let response = self
+            .request(reqwest::Method::DELETE, &endpoint)
+            .send()
+            .await;
+        Self::expect_success(response).await
+    }
+
+    async fn batch(&self, requests: &[BatchRequest]) -> ApiResult<()> {
+        let response = self
+            .request(reqwest::Method::POST, BATCH)
+            .json(&serde_json::json!({ "requests": requests }))
+            .send()
+            .await;
+        Self::expect_success(response).await
+    }
+
+    async fn auth_with_password(
+        &self,
+        credentials: &PasswordCredentials,
+    ) -> ApiResult<AuthSession> {
+        let response = self
+            .client
+            .post(format!("{}{}", self.base_url, USERS_AUTH_WITH_PASSWORD))
+            .json(credentials)
+            .send()
+            .await;
+        Self::parse_json(response).await
+    }
+
+    async fn create_user(&self, request: &SignUpRequest) -> ApiResult<AuthUser> {
+        let response = self
+            .client
+            .post(format!("{}{}", self.base_url, USERS))
+            .json(request)
+            .send()
+            .await;
+        Self::parse_json(response).await
+    }
+
+    async fn refresh_auth(&self, token: &str) -> ApiResult<AuthSession> {
+        let response = self
+            .client
+            .post(format!("{}{}", self.base_url, USERS_AUTH_REFRESH))
+            .header(reqwest::header::AUTHORIZATION, token)
+            .send()
+            .await;
+        Self::parse_json(response).await
+    }
+
+    async fn create_invite(&self, invite: &NewInvite) -> ApiResult<Invite> {
+        let response = self
+            .client
+            .post(format!("{}{}", self.base_url, INVITES))
+            .json(invite)
+            .send()
+            .await;
+        Self::parse_json(response).await
+    }
+
+    async fn list_invites(&self) -> ApiResult<InviteData> {
+        let items = self.list_pages(INVITES, &[("sort", "-created")]).await?;
+        Ok(InviteData { items })
+    }
+
+    async fn check_invite_code(
+        &self,
+        code: &str,
+        email: &str,
+    ) -> ApiResult<Result<(), InviteCodeError>> {
+        let response = self
+            .client
+            .post(format!("{}{}", self.base_url, INVITES_CHECK))
+            .json(&serde_json::json!({ "code": code, "email": email }))
+            .send()
+            .await;
+        let check: InviteCheck = Self::parse_json(response).await?;
+        Ok(check.problem.map_or(Ok(()), Err))
+    }
+
+    async fn update_invite(&self, id: &str, update: &InviteUpdate) -> ApiResult<Invite> {
+        let endpoint = format!("{}/{}", INVITES, id);
+        let response = self
+            .request(reqwest::Method::PATCH, &endpoint)
+            .json(update)
+            .send()
+            .await;
+        Self::parse_json(response).await
+    }
+
+    async fn list_revisions(&self, paragraph_id: &str) -> ApiResult<RevisionData> {
+        let filter = format!("paragraph={}", filter_string(paragraph_id));
+        let items = self
+            .list_pages(
+                PARAGRAPH_REVISIONS,
+                &[("filter", filter.as_str()), ("sort", "-created")],
+            )
+            .await?;
+        Ok(RevisionData { items })
+    }
+
+    async fn list_records(&self, endpoint: &str) -> ApiResult<Vec<serde_json::Value>> {
+        self.list_pages(endpoint, &[]).await
+    }
 }
 
 // Mock client for testing
@@ -130,6 +456,35 @@ pub struct MockApiClient {
     pub paragraphs: Vec<Paragraph>,
     pub chapters: Vec<Chapter>,
     pub should_fail: bool,
+    /// Index of the one batch that fails, to simulate a failure part-way.
+    pub failing_batch: Cell<Option<usize>>,
+    /// How many batches in a row fail from `failing_batch` on.
+    pub batch_failures: Cell<usize>,
+    /// What the failing batch returns. A `Timeout` is applied before it is
+    /// returned, like a response lost after the server committed.
+    pub batch_error: RefCell<ApiError>,
+    pub users: RefCell<Vec<MockUser>>,
+    pub invites: RefCell<Vec<Invite>>,
+    /// Like the server's `users` create hook, refuse sign-ups without a
+    /// redeemable invite code.
+    pub invite_only: bool,
+    /// Time against which invite codes are checked.
+    pub clock: DateTime<Utc>,
+    pub chapter_records: RefCell<Vec<ChapterRecord>>,
+    /// `collection/id` of every record deleted through the mock, in call order.
+    pub deleted: RefCell<Vec<String>>,
+    /// Every batch sent through the mock, in call order.
+    pub batches: RefCell<Vec<Vec<BatchRequest>>>,
+    pub revisions: RefCell<Vec<ParagraphRevision>>,
+    /// Raw records served by `list_records`, keyed by collection endpoint.
+    pub records: RefCell<HashMap<String, Vec<serde_json::Value>>>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct MockUser {
+    pub record: AuthUser,
+    pub password: String,
 }
 
 #[allow(dead_code)]
@@ -139,6 +494,18 @@ impl MockApiClient {
             paragraphs: Vec::new(),
             chapters: Vec::new(),
             should_fail: false,
+            failing_batch: Cell::new(None),
+            batch_failures: Cell::new(1),
+            batch_error: RefCell::new(ApiError::ServerError(400)),
+            users: RefCell::new(Vec::new()),
+            invites: RefCell::new(Vec::new()),
+            invite_only: false,
+            clock: Utc::now(),
+            chapter_records: RefCell::new(Vec::new()),
+            deleted: RefCell::new(Vec::new()),
+            batches: RefCell::new(Vec::new()),
+            revisions: RefCell::new(Vec::new()),
+            records: RefCell::new(HashMap::new()),
         }
     }
 
@@ -156,6 +523,107 @@ impl MockApiClient {
         self.should_fail = true;
         self
     }
+
+    /// Fails the batch at `index` (counting from 0) and accepts the others.
+    pub fn with_failing_batch(self, index: usize) -> Self {
+        self.failing_batch.set(Some(index));
+        self
+    }
+
+    /// Makes the failing batch fail `times` times in a row with `error`.
+    pub fn with_batch_error(self, error: ApiError, times: usize) -> Self {
+        *self.batch_error.borrow_mut() = error;
+        self.batch_failures.set(times.max(1));
+        self
+    }
+
+    /// Stores the revisions created by a committed batch for `list_revisions`.
+    fn keep_revisions(&self, requests: &[BatchRequest]) {
+        let url = format!("/api{}", PARAGRAPH_REVISIONS);
+        let mut revisions = self.revisions.borrow_mut();
+        for request in requests
+            .iter()
+            .filter(|request| request.method == "POST" && request.url == url)
+        {
+            let Some(Ok(mut revision)) = request
+                .body
+                .clone()
+                .map(serde_json::from_value::<ParagraphRevision>)
+            else {
+                continue;
+            };
+            // Later saves sort after earlier ones, like PocketBase timestamps
+            revision.created = format!("2024-01-01 00:00:{:02}.000Z", revisions.len() + 1);
+            revisions.push(revision);
+        }
+    }
+
+    /// Whether a paragraph with `id` was created by one of the batches.
+    fn created_in_batch(&self, id: &str) -> bool {
+        let url = format!("/api{}", PARAGRAPHS);
+        self.batches.borrow().iter().flatten().any(|request| {
+            request.method == "POST"
+                && request.url == url
+                && request.body.as_ref().and_then(|body| body.get("id"))
+                    == Some(&serde_json::Value::from(id))
+        })
+    }
+
+    pub fn with_user(self, email: &str, password: &str) -> Self {
+        let id = format!("user{}", self.users.borrow().len() + 1);
+        self.users.borrow_mut().push(MockUser {
+            record: AuthUser {
+                id,
+                email: email.to_string(),
+                name: String::new(),
+                verified: true,
+                ..Default::default()
+            },
+            password: password.to_string(),
+        });
+        self
+    }
+
+    pub fn with_invite(self, invite: Invite) -> Self {
+        self.invites.borrow_mut().push(invite);
+        self
+    }
+
+    pub fn with_invite_only(mut self) -> Self {
+        self.invite_only = true;
+        self
+    }
+
+    pub fn with_clock(mut self, now: DateTime<Utc>) -> Self {
+        self.clock = now;
+        self
+    }
+
+    fn check_invite(&self, code: &str, email: &str) -> Result<(), InviteCodeError> {
+        self.invites
+            .borrow()
+            .iter()
+            .find(|i| !i.code.is_empty() && i.code == code)
+            .ok_or(InviteCodeError::Unknown)?
+            .check_redeemable(email, self.clock)
+    }
+
+    pub fn with_chapter_record(self, chapter: ChapterRecord) -> Self {
+        self.chapter_records.borrow_mut().push(chapter);
+        self
+    }
+
+    pub fn with_records(self, endpoint: &str, records: Vec<serde_json::Value>) -> Self {
+        self.records
+            .borrow_mut()
+            .insert(endpoint.to_string(), records);
+        self
+    }
+
+    /// Token handed out by the mock; `refresh_auth` accepts only these.
+    pub fn token_for(user_id: &str) -> String {
+        format!("mock-token-{}", user_id)
+    }
 }
 
 #[cfg(test)]
@@ -199,4 +667,265 @@ impl ApiClient for MockApiClient {
         }
         Ok(())
     }
+
+    async fn delete_paragraph(&self, id: &str) -> ApiResult<()> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        let key = format!("paragraphs/{}", id);
+        let exists = self.paragraphs.iter().any(|p| p.id == id) || self.created_in_batch(id);
+        if !exists || self.deleted.borrow().contains(&key) {
+            return Err(ApiError::NotFound);
+        }
+        self.deleted.borrow_mut().push(key);
+        Ok(())
+    }
+
+    async fn create_chapter(&self, chapter: &NewChapter) -> ApiResult<ChapterRecord> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        let mut records = self.chapter_records.borrow_mut();
+        let record = ChapterRecord {
+            id: format!("chapter{}", records.len() + 1),
+            titles: chapter.titles.clone(),
+            order: chapter.order,
+        };
+        records.push(record.clone());
+        Ok(record)
+    }
+
+    async fn update_chapter(&self, id: &str, update: &ChapterUpdate) -> ApiResult<ChapterRecord> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        let mut records = self.chapter_records.borrow_mut();
+        let record = records
+            .iter_mut()
+            .find(|c| c.id == id)
+            .ok_or(ApiError::NotFound)?;
+        if let Some(titles) = &update.titles {
+            record.titles = titles.clone();
+        }
+        if let Some(order) = update.order {
+            record.order = order;
+        }
+        Ok(record.clone())
+    }
+
+    async fn delete_chapter(&self, id: &str) -> ApiResult<()> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        let mut records = self.chapter_records.borrow_mut();
+        let before = records.len();
+        records.retain(|c| c.id != id);
+        if records.len() == before {
+            return Err(ApiError::NotFound);
+        }
+        self.deleted.borrow_mut().push(format!("chapters/{}", id));
+        Ok(())
+    }
+
+    async fn batch(&self, requests: &[BatchRequest]) -> ApiResult<()> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        let mut batches = self.batches.borrow_mut();
+        if self.failing_batch.get() == Some(batches.len()) {
+            let failures = self.batch_failures.get() - 1;
+            self.batch_failures.set(failures);
+            if failures == 0 {
+                self.failing_batch.set(None);
+            }
+            let error = self.batch_error.borrow().clone();
+            if matches!(error, ApiError::Timeout) {
+                batches.push(requests.to_vec());
+                self.keep_revisions(requests);
+            }
+            return Err(error);
+        }
+        batches.push(requests.to_vec());
+        self.keep_revisions(requests);
+        Ok(())
+    }
+
+    async fn auth_with_password(
+        &self,
+        credentials: &PasswordCredentials,
+    ) -> ApiResult<AuthSession> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        // PocketBase answers 400 for unknown identity or wrong password alike
+        self.users
+            .borrow()
+            .iter()
+            .find(|u| u.record.email == credentials.identity && u.password == credentials.password)
+            .map(|u| AuthSession {
+                token: Self::token_for(&u.record.id),
+                record: u.record.clone(),
+            })
+            .ok_or(ApiError::ServerError(400))
+    }
+
+    async fn create_user(&self, request: &SignUpRequest) -> ApiResult<AuthUser> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        let mut users = self.users.borrow_mut();
+        if request.password != request.password_confirm
+            || users.iter().any(|u| u.record.email == request.email)
+        {
+            return Err(ApiError::ServerError(400));
+        }
+        let redeems = self.invite_only || !request.invite_code.is_empty();
+        if redeems
+            && self
+                .check_invite(&request.invite_code, &request.email)
+                .is_err()
+        {
+            return Err(ApiError::ServerError(400));
+        }
+        let record = AuthUser {
+            id: format!("user{}", users.len() + 1),
+            email: request.email.clone(),
+            name: request.name.clone(),
+            verified: false,
+            ..Default::default()
+        };
+        users.push(MockUser {
+            record: record.clone(),
+            password: request.password.clone(),
+        });
+        if redeems {
+            if let Some(invite) = self
+                .invites
+                .borrow_mut()
+                .iter_mut()
+                .find(|i| i.code == request.invite_code)
+            {
+                invite.status = crate::services::invite::InviteStatus::Redeemed;
+                invite.redeemed_by = record.id.clone();
+            }
+        }
+        Ok(record)
+    }
+
+    async fn refresh_auth(&self, token: &str) -> ApiResult<AuthSession> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        self.users
+            .borrow()
+            .iter()
+            .find(|u| Self::token_for(&u.record.id) == token)
+            .map(|u| AuthSession {
+                token: Self::token_for(&u.record.id),
+                record: u.record.clone(),
+            })
+            .ok_or(ApiError::ServerError(401))
+    }
+
+    async fn create_invite(&self, invite: &NewInvite) -> ApiResult<Invite> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        let mut invites = self.invites.borrow_mut();
+        let record = Invite {
+            id: format!("invite{}", invites.len() + 1),
+            name: invite.name.clone(),
+            email: invite.email.clone(),
+            reason: invite.reason.clone(),
+            status: invite.status,
+            code: String::new(),
+            expires: String::new(),
+            redeemed_by: String::new(),
+            created: String::new(),
+        };
+        invites.push(record.clone());
+        Ok(record)
+    }
+
+    async fn list_invites(&self) -> ApiResult<InviteData> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        Ok(InviteData {
+            items: self.invites.borrow().clone(),
+        })
+    }
+
+    async fn check_invite_code(
+        &self,
+        code: &str,
+        email: &str,
+    ) -> ApiResult<Result<(), InviteCodeError>> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        Ok(self.check_invite(code, email))
+    }
+
+    async fn update_invite(&self, id: &str, update: &InviteUpdate) -> ApiResult<Invite> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        let mut invites = self.invites.borrow_mut();
+        let invite = invites
+            .iter_mut()
+            .find(|i| i.id == id)
+            .ok_or(ApiError::NotFound)?;
+        invite.status = update.status;
+        if let Some(code) = &update.code {
+            invite.code = code.clone();
+        }
+        if let Some(expires) = &update.expires {
+            invite.expires = expires.clone();
+        }
+        if let Some(redeemed_by) = &update.redeemed_by {
+            invite.redeemed_by = redeemed_by.clone();
+        }
+        Ok(invite.clone())
+    }
+
+    async fn list_revisions(&self, paragraph_id: &str) -> ApiResult<RevisionData> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        let mut items: Vec<ParagraphRevision> = self
+            .revisions
+            .borrow()
+            .iter()
+            .filter(|r| r.paragraph == paragraph_id)
+            .cloned()
+            .collect();
+        items.sort_by(|a, b| b.created.cmp(&a.created));
+        Ok(RevisionData { items })
+    }
+    async fn list_records(&self, endpoint: &str) -> ApiResult<Vec<serde_json::Value>> {
+        if self.should_fail {
+            return Err(ApiError::NetworkError("Mock network error".to_string()));
+        }
+
+        Ok(self
+            .records
+            .borrow()
+            .get(endpoint)
+            .cloned()
+            .unwrap_or_default())
+    }
 }
@@ -1,7 +1,7 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod tests {
     use super::super::api::*;
-    use crate::contexts::paragraph_context::{Paragraph, ParagraphChoice, Text};
+    use crate::models::paragraph::{Paragraph, ParagraphChoice, Text};
 
     /// Helper function: Create test paragraph
     fn create_test_paragraph(id: &str, chapter_id: &str) -> Paragraph {
@@ -16,6 +16,7 @@ mod tests {
                         "Continue Adventure".to_string(),
                         "Return to Village".to_string(),
                     ],
+                    ..Default::default()
                 },
                 Text {
                     lang: "en".to_string(),
@@ -24,11 +25,12 @@ mod tests {
                         "Continue Adventure".to_string(),
                         "Return to Village".to_string(),
                     ],
+                    ..Default::default()
                 },
             ],
             choices: vec![
-                ParagraphChoice::Simple(vec!["next_scene".to_string()]),
-                ParagraphChoice::Complex {
+                ParagraphChoice::goto(vec!["next_scene".to_string()]),
+                ParagraphChoice {
                     to: vec!["village".to_string()],
                     type_: "goto".to_string(),
                     key: None,
@@ -39,6 +41,7 @@ mod tests {
                     impacts: None,
                 },
             ],
+            ..Default::default()
         }
     }
 
@@ -144,7 +147,7 @@ mod tests {
     async fn test_complex_choice_serialization() {
         use serde_json::json;
 
-        let complex_choice = ParagraphChoice::Complex {
+        let complex_choice = ParagraphChoice {
             to: vec!["target1".to_string(), "target2".to_string()],
             type_: "conditional".to_string(),
             key: Some("player_level".to_string()),
@@ -159,28 +162,24 @@ mod tests {
         let json_str = serde_json::to_string(&complex_choice).unwrap();
         let deserialized: ParagraphChoice = serde_json::from_str(&json_str).unwrap();
 
-        match deserialized {
-            ParagraphChoice::Complex {
-                to,
-                type_,
-                key,
-                value,
-                same_page,
-                time_limit,
-                timeout_to,
-                impacts,
-            } => {
-                assert_eq!(to, vec!["target1", "target2"]);
-                assert_eq!(type_, "conditional");
-                assert_eq!(key, Some("player_level".to_string()));
-                assert!(value.is_some());
-                assert_eq!(same_page, Some(true));
-                assert_eq!(time_limit, Some(30));
-                assert_eq!(timeout_to, None);
-                assert_eq!(impacts, None);
-            }
-            _ => panic!("Expected Complex variant"),
-        }
+        let ParagraphChoice {
+            to,
+            type_,
+            key,
+            value,
+            same_page,
+            time_limit,
+            timeout_to,
+            impacts,
+        } = deserialized;
+        assert_eq!(to, vec!["target1", "target2"]);
+        assert_eq!(type_, "conditional");
+        assert_eq!(key, Some("player_level".to_string()));
+        assert!(value.is_some());
+        assert_eq!(same_page, Some(true));
+        assert_eq!(time_limit, Some(30));
+        assert_eq!(timeout_to, None);
+        assert_eq!(impacts, None);
 
         // Test first complex option
         assert_eq!(complex_choice.get_to(), vec!["target1", "target2"]);
@@ -192,7 +191,7 @@ mod tests {
         // timeout_to is validated above; no getter implemented
 
         // Test second complex option
-        let simple_choice = ParagraphChoice::Simple(vec!["simple_target".to_string()]);
+        let simple_choice = ParagraphChoice::goto(vec!["simple_target".to_string()]);
         assert_eq!(simple_choice.get_to(), vec!["simple_target"]);
         assert_eq!(simple_choice.get_type(), "goto");
         assert_eq!(simple_choice.get_key(), None);
@@ -216,21 +215,24 @@ mod tests {
                         "Continue Adventure".to_string(),
                         "Return to Village".to_string(),
                     ],
+                    ..Default::default()
                 },
                 Text {
                     lang: "zh-CN".to_string(),
                     paragraphs: "This is simplified Chinese content".to_string(),
                     choices: vec!["Option one".to_string(), "Option two".to_string()],
+                    ..Default::default()
                 },
                 Text {
                     lang: "en".to_string(),
                     paragraphs: "This is English content".to_string(),
                     choices: vec!["Option One".to_string(), "Option Two".to_string()],
+                    ..Default::default()
                 },
             ],
             choices: vec![
-                ParagraphChoice::Simple(vec!["next_scene".to_string()]),
-                ParagraphChoice::Complex {
+                ParagraphChoice::goto(vec!["next_scene".to_string()]),
+                ParagraphChoice {
                     to: vec!["village".to_string()],
                     type_: "goto".to_string(),
                     key: None,
@@ -241,6 +243,7 @@ mod tests {
                     impacts: None,
                 },
             ],
+            ..Default::default()
         };
 
         // Verify each language version
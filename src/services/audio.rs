@@ -0,0 +1,177 @@
+//! Loading audio cues and playing them in the browser.
+//!
+//! One ambience loop plays at a time; a new one crossfades in over
+//! [`CROSSFADE_MS`]. Effects are fire-and-forget. All sounds follow the
+//! master gain set with [`set_gain`].
+
+use crate::constants::config::AUDIO_CUES;
+use crate::models::audio::{crossfade_gains, AudioCue, AudioCues, CROSSFADE_MS};
+use crate::services::api::{ApiClient, ApiResult};
+use gloo_timers::callback::{Interval, Timeout};
+use std::cell::RefCell;
+use web_sys::HtmlAudioElement;
+
+/// Every cue of the story; records that do not parse are skipped.
+pub async fn load_audio_cues<C: ApiClient + ?Sized>(client: &C) -> ApiResult<AudioCues> {
+    let records = client.list_records(AUDIO_CUES).await?;
+    let cues = records
+        .into_iter()
+        .filter_map(|record| match serde_json::from_value::<AudioCue>(record) {
+            Ok(cue) => Some(cue),
+            Err(error) => {
+                tracing::warn!(error = %error, "Skipping malformed audio cue");
+                None
+            }
+        })
+        .collect();
+    Ok(AudioCues::new(cues))
+}
+
+const FADE_STEP_MS: u32 = 50;
+
+struct Ambience {
+    url: String,
+    element: HtmlAudioElement,
+}
+
+#[derive(Default)]
+struct Player {
+    gain: f32,
+    current: Option<Ambience>,
+    /// Loop on its way out during a crossfade.
+    outgoing: Option<HtmlAudioElement>,
+    fade: Option<Interval>,
+}
+
+thread_local! {
+    static PLAYER: RefCell<Player> = RefCell::new(Player {
+        gain: 1.0,
+        ..Default::default()
+    });
+}
+
+fn enabled() -> bool {
+    // Native builds (tests, server rendering) have no audio output
+    cfg!(target_arch = "wasm32")
+}
+
+fn new_element(url: &str) -> Option<HtmlAudioElement> {
+    let element = HtmlAudioElement::new_with_src(url).ok()?;
+    element.set_preload("auto");
+    Some(element)
+}
+
+/// Ends a crossfade early or on time, returning its timer for the caller to
+/// drop.
+fn complete_fade(player: &mut Player) -> Option<Interval> {
+    if let Some(old) = player.outgoing.take() {
+        let _ = old.pause();
+    }
+    if let Some(current) = &player.current {
+        current.element.set_volume(player.gain as f64);
+    }
+    player.fade.take()
+}
+
+/// Crossfades to the loop at `url`, or fades out when `url` is `None`.
+/// Asking for the loop already playing does nothing, except start it again
+/// when the browser refused to autoplay it.
+pub fn play_ambience(url: Option<&str>) {
+    if !enabled() {
+        return;
+    }
+    PLAYER.with(|player| {
+        let mut player = player.borrow_mut();
+        if let Some(current) = &player.current {
+            if Some(current.url.as_str()) == url {
+                if current.element.paused() {
+                    // Autoplay may have been refused before the reader interacted
+                    let _ = current.element.play();
+                }
+                return;
+            }
+        }
+        complete_fade(&mut player);
+        let incoming = url.and_then(|url| {
+            let element = new_element(url)?;
+            element.set_loop(true);
+            element.set_volume(0.0);
+            // Autoplay may be refused until the reader interacts with the page
+            let _ = element.play();
+            Some(Ambience {
+                url: url.to_string(),
+                element,
+            })
+        });
+        player.outgoing = player.current.take().map(|a| a.element);
+        player.current = incoming;
+
+        let steps = (CROSSFADE_MS / FADE_STEP_MS).max(1);
+        let mut step = 0;
+        player.fade = Some(Interval::new(FADE_STEP_MS, move || {
+            step += 1;
+            PLAYER.with(|player| {
+                let mut player = player.borrow_mut();
+                let (out_gain, in_gain) = crossfade_gains(step as f32 / steps as f32);
+                let gain = player.gain;
+                if let Some(old) = &player.outgoing {
+                    old.set_volume((out_gain * gain) as f64);
+                }
+                if let Some(current) = &player.current {
+                    current.element.set_volume((in_gain * gain) as f64);
+                }
+                if step >= steps {
+                    // The timer cannot be dropped from inside its own callback
+                    if let Some(timer) = complete_fade(&mut player) {
+                        Timeout::new(0, move || drop(timer)).forget();
+                    }
+                }
+            });
+        }));
+    });
+}
+
+/// Plays `url` once over whatever else is playing.
+pub fn play_effect(url: &str) {
+    if !enabled() {
+        return;
+    }
+    let gain = PLAYER.with(|player| player.borrow().gain);
+    if gain <= 0.0 {
+        return;
+    }
+    if let Some(element) = new_element(url) {
+        element.set_volume(gain as f64);
+        let _ = element.play();
+    }
+}
+
+/// Master gain from `0` (silent) to `1`.
+pub fn set_gain(gain: f32) {
+    if !enabled() {
+        return;
+    }
+    PLAYER.with(|player| {
+        let mut player = player.borrow_mut();
+        player.gain = gain.clamp(0.0, 1.0);
+        if player.fade.is_none() {
+            if let Some(current) = &player.current {
+                current.element.set_volume(player.gain as f64);
+            }
+        }
+    });
+}
+
+/// Stops every sound, e.g. when the reader leaves the story.
+pub fn stop_all() {
+    if !enabled() {
+        return;
+    }
+    PLAYER.with(|player| {
+        let mut player = player.borrow_mut();
+        complete_fade(&mut player);
+        if let Some(current) = player.current.take() {
+            let _ = current.element.pause();
+        }
+    });
+}
@@ -0,0 +1,41 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::api::*;
+    use super::super::audio::*;
+    use crate::constants::config::AUDIO_CUES;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_load_skips_malformed_and_empty_cues() {
+        // Prepare test data
+        let client = MockApiClient::new().with_records(
+            AUDIO_CUES,
+            vec![
+                json!({"id": "a1", "collectionId": "pbc_1", "kind": "ambience", "chapter_id": "c1", "paragraph_id": "", "tier": "", "file": "rain_x1.ogg"}),
+                json!({"id": "a2", "collectionId": "pbc_1", "kind": "effect", "chapter_id": "", "paragraph_id": "", "tier": "disaster", "file": "crash.ogg"}),
+                json!({"id": "a3", "collectionId": "pbc_1", "kind": "effect", "paragraph_id": "p1", "file": ""}),
+                json!({"id": "a4", "collectionId": "pbc_1", "kind": "fanfare", "file": "x.ogg"}),
+            ],
+        );
+
+        // Execute test
+        let cues = load_audio_cues(&client).await.unwrap();
+
+        // Verify results
+        assert_eq!(
+            cues.urls("https://example.com/api/files"),
+            vec![
+                "https://example.com/api/files/pbc_1/a1/rain_x1.ogg".to_string(),
+                "https://example.com/api/files/pbc_1/a2/crash.ogg".to_string(),
+            ]
+        );
+        assert_eq!(cues.ambience_for("c1").map(|c| c.id.as_str()), Some("a1"));
+    }
+
+    #[tokio::test]
+    async fn test_load_reports_network_errors() {
+        let client = MockApiClient::new().with_failure();
+
+        assert!(load_audio_cues(&client).await.is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::models::role::{Capabilities, Role};
+use crate::services::api::{ApiClient, ApiError, ApiResult};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+
+/// Key under which the session is persisted in `localStorage`.
+pub const AUTH_STORAGE_KEY: &str = "ifecaro_auth";
+
+/// Refresh the token when it expires within this many seconds.
+pub const REFRESH_MARGIN_SECS: i64 = 24 * 60 * 60;
+
+/// Record returned by PocketBase for the `users` auth collection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AuthUser {
+    pub id: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub verified: bool,
+    #[serde(default)]
+    pub role: Role,
+    /// Languages assigned to a translator.
+    #[serde(default)]
+    pub languages: Vec<String>,
+}
+
+impl AuthUser {
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::new(self.role, self.languages.clone())
+    }
+}
+
+/// `auth-with-password` / `auth-refresh` response body.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuthSession {
+    pub token: String,
+    pub record: AuthUser,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PasswordCredentials {
+    pub identity: String,
+    pub password: String,
+}
+
+/// Body for creating a record in the `users` collection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SignUpRequest {
+    pub email: String,
+    pub password: String,
+    #[serde(rename = "passwordConfirm")]
+    pub password_confirm: String,
+    pub name: String,
+    /// Checked and redeemed by the `users` create hook on the server.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub invite_code: String,
+}
+
+thread_local! {
+    static SESSION: RefCell<Option<Option<AuthSession>>> = const { RefCell::new(None) };
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_persisted_session() -> Option<AuthSession> {
+    let raw = local_storage()?.get_item(AUTH_STORAGE_KEY).ok().flatten()?;
+    serde_json::from_str(&raw).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_persisted_session() -> Option<AuthSession> {
+    None
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_persisted_session(session: Option<&AuthSession>) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    let result = match session.and_then(|s| serde_json::to_string(s).ok()) {
+        Some(raw) => storage.set_item(AUTH_STORAGE_KEY, &raw),
+        None => storage.remove_item(AUTH_STORAGE_KEY),
+    };
+    if let Err(error) = result {
+        tracing::error!(error = ?error, "Failed to persist auth session");
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_persisted_session(_session: Option<&AuthSession>) {}
+
+/// Current session, read from `localStorage` on first access.
+pub fn current_session() -> Option<AuthSession> {
+    SESSION.with(|cell| {
+        cell.borrow_mut()
+            .get_or_insert_with(read_persisted_session)
+            .clone()
+    })
+}
+
+pub fn store_session(session: &AuthSession) {
+    SESSION.with(|cell| *cell.borrow_mut() = Some(Some(session.clone())));
+    write_persisted_session(Some(session));
+}
+
+pub fn clear_session() {
+    SESSION.with(|cell| *cell.borrow_mut() = Some(None));
+    write_persisted_session(None);
+}
+
+/// Adds the PocketBase `Authorization` header when a session exists.
+pub fn with_auth(builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    match current_session() {
+        Some(session) => builder.header(reqwest::header::AUTHORIZATION, session.token),
+        None => builder,
+    }
+}
+
+fn decode_base64url(input: &str) -> Option<Vec<u8>> {
+    let mut bits: u32 = 0;
+    let mut bit_count = 0;
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+    for byte in input.bytes() {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'-' | b'+' => 62,
+            b'_' | b'/' => 63,
+            b'=' => break,
+            _ => return None,
+        };
+        bits = (bits << 6) | value as u32;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            output.push((bits >> bit_count) as u8);
+            bits &= (1 << bit_count) - 1;
+        }
+    }
+    Some(output)
+}
+
+/// Reads the `exp` claim (unix seconds) from a JWT without verifying it.
+///
+/// ```
+/// use ifecaro::services::auth::token_expires_at;
+///
+/// // {"alg":"HS256"}.{"exp":1700000000}.signature
+/// let token = "eyJhbGciOiJIUzI1NiJ9.eyJleHAiOjE3MDAwMDAwMDB9.c2ln";
+/// assert_eq!(token_expires_at(token), Some(1_700_000_000));
+/// assert_eq!(token_expires_at("not-a-jwt"), None);
+/// ```
+pub fn token_expires_at(token: &str) -> Option<i64> {
+    let payload = token.split('.').nth(1)?;
+    let bytes = decode_base64url(payload)?;
+    let claims: serde_json::Value = serde_json::from_slice(&bytes).ok()?;
+    claims.get("exp")?.as_i64()
+}
+
+/// Whether `session` should be refreshed at `now` (unix seconds). Tokens
+/// without a readable `exp` claim are left alone; the server decides.
+pub fn needs_refresh(session: &AuthSession, now: i64) -> bool {
+    token_expires_at(&session.token)
+        .map(|exp| exp - now < REFRESH_MARGIN_SECS)
+        .unwrap_or(false)
+}
+
+pub async fn login<C: ApiClient + ?Sized>(
+    client: &C,
+    identity: &str,
+    password: &str,
+) -> ApiResult<AuthSession> {
+    let session = client
+        .auth_with_password(&PasswordCredentials {
+            identity: identity.trim().to_string(),
+            password: password.to_string(),
+        })
+        .await?;
+    store_session(&session);
+    Ok(session)
+}
+
+/// Creates the user, then signs in with the same credentials.
+pub async fn register<C: ApiClient + ?Sized>(
+    client: &C,
+    request: &SignUpRequest,
+) -> ApiResult<AuthSession> {
+    client.create_user(request).await?;
+    login(client, &request.email, &request.password).await
+}
+
+/// Refreshes the stored session if it is close to expiry. A rejected token
+/// (401/403) ends the session; transient errors keep it for the next attempt.
+pub async fn refresh_if_needed<C: ApiClient + ?Sized>(
+    client: &C,
+    now: i64,
+) -> ApiResult<Option<AuthSession>> {
+    let Some(session) = current_session() else {
+        return Ok(None);
+    };
+    if !needs_refresh(&session, now) {
+        return Ok(Some(session));
+    }
+    match client.refresh_auth(&session.token).await {
+        Ok(refreshed) => {
+            store_session(&refreshed);
+            Ok(Some(refreshed))
+        }
+        Err(ApiError::ServerError(401 | 403)) => {
+            clear_session();
+            Ok(None)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+pub fn logout() {
+    clear_session();
+}
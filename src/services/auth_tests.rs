@@ -0,0 +1,166 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::api::*;
+    use super::super::auth::*;
+
+    /// Helper function: Build an unsigned JWT carrying only an `exp` claim
+    fn jwt_with_exp(exp: i64) -> String {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+        let payload = format!("{{\"exp\":{}}}", exp);
+        let mut encoded = String::new();
+        for chunk in payload.as_bytes().chunks(3) {
+            let bytes = [
+                chunk[0],
+                *chunk.get(1).unwrap_or(&0),
+                *chunk.get(2).unwrap_or(&0),
+            ];
+            let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+            for i in 0..=chunk.len() {
+                encoded.push(ALPHABET[((n >> (18 - 6 * i)) & 63) as usize] as char);
+            }
+        }
+        format!("eyJhbGciOiJIUzI1NiJ9.{}.sig", encoded)
+    }
+
+    fn sign_up(email: &str, password: &str, confirm: &str) -> SignUpRequest {
+        SignUpRequest {
+            email: email.to_string(),
+            password: password.to_string(),
+            password_confirm: confirm.to_string(),
+            name: "Reader".to_string(),
+            invite_code: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_stores_session() {
+        // Prepare test data
+        let client = MockApiClient::new().with_user("reader@example.com", "correct-horse");
+
+        // Execute test
+        let session = login(&client, " reader@example.com ", "correct-horse")
+            .await
+            .unwrap();
+
+        // Verify result
+        assert_eq!(session.record.email, "reader@example.com");
+        assert_eq!(current_session(), Some(session));
+    }
+
+    #[tokio::test]
+    async fn test_login_with_wrong_password_keeps_signed_out() {
+        let client = MockApiClient::new().with_user("reader@example.com", "correct-horse");
+
+        let result = login(&client, "reader@example.com", "wrong").await;
+
+        assert!(matches!(result, Err(ApiError::ServerError(400))));
+        assert!(current_session().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_register_creates_user_and_signs_in() {
+        let client = MockApiClient::new();
+
+        let session = register(
+            &client,
+            &sign_up("new@example.com", "password1", "password1"),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(session.record.email, "new@example.com");
+        assert_eq!(session.record.name, "Reader");
+        assert_eq!(client.users.borrow().len(), 1);
+        assert!(current_session().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_register_rejects_duplicate_email() {
+        let client = MockApiClient::new().with_user("taken@example.com", "password1");
+
+        let result = register(
+            &client,
+            &sign_up("taken@example.com", "password1", "password1"),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::ServerError(400))));
+        assert!(current_session().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_logout_clears_session() {
+        let client = MockApiClient::new().with_user("reader@example.com", "pw");
+        login(&client, "reader@example.com", "pw").await.unwrap();
+
+        logout();
+
+        assert!(current_session().is_none());
+    }
+
+    #[test]
+    fn test_token_expiry_and_refresh_window() {
+        let now = 1_700_000_000;
+        let session = |token: String| AuthSession {
+            token,
+            record: AuthUser {
+                id: "u1".to_string(),
+                email: String::new(),
+                name: String::new(),
+                verified: true,
+                ..Default::default()
+            },
+        };
+
+        assert_eq!(token_expires_at(&jwt_with_exp(now + 60)), Some(now + 60));
+        assert!(needs_refresh(&session(jwt_with_exp(now + 60)), now));
+        assert!(!needs_refresh(
+            &session(jwt_with_exp(now + 7 * 86_400)),
+            now
+        ));
+        // Opaque tokens are never refreshed proactively
+        assert!(!needs_refresh(&session("opaque".to_string()), now));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_if_needed_drops_rejected_token() {
+        let client = MockApiClient::new().with_user("reader@example.com", "pw");
+        let now = 1_700_000_000;
+        let stale = AuthSession {
+            token: jwt_with_exp(now + 60),
+            record: client.users.borrow()[0].record.clone(),
+        };
+        store_session(&stale);
+
+        // The mock rejects tokens it did not issue, which ends the session
+        let result = refresh_if_needed(&client, now).await.unwrap();
+        assert!(result.is_none());
+        assert!(current_session().is_none());
+
+        // Sessions outside the refresh window are returned untouched
+        let issued = login(&client, "reader@example.com", "pw").await.unwrap();
+        assert_eq!(refresh_if_needed(&client, now).await.unwrap(), Some(issued));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_keeps_session_on_network_error() {
+        let client = MockApiClient::new().with_failure();
+        let now = 1_700_000_000;
+        let session = AuthSession {
+            token: jwt_with_exp(now + 60),
+            record: AuthUser {
+                id: "u1".to_string(),
+                email: String::new(),
+                name: String::new(),
+                verified: true,
+                ..Default::default()
+            },
+        };
+        store_session(&session);
+
+        let result = refresh_if_needed(&client, now).await;
+
+        assert!(matches!(result, Err(ApiError::NetworkError(_))));
+        assert_eq!(current_session(), Some(session));
+    }
+}
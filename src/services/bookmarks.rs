@@ -0,0 +1,64 @@
+//! Reading positions and bookmarks kept in IndexedDB.
+
+use crate::models::bookmarks::{Bookmarks, ReadingPosition};
+use crate::services::indexeddb::{
+    get_bookmarks_from_indexeddb, get_reading_position_from_indexeddb, set_bookmarks_to_indexeddb,
+    set_reading_position_to_indexeddb,
+};
+
+fn enabled() -> bool {
+    // IndexedDB only exists in the browser
+    cfg!(target_arch = "wasm32")
+}
+
+pub async fn load_reading_position(chapter_id: &str) -> Option<ReadingPosition> {
+    if !enabled() {
+        return None;
+    }
+    match get_reading_position_from_indexeddb(chapter_id).await {
+        Ok(value) => value
+            .as_string()
+            .and_then(|raw| ReadingPosition::from_json(&raw)),
+        Err(error) => {
+            tracing::error!(error = ?error, "Failed to read reading position from IndexedDB");
+            None
+        }
+    }
+}
+
+pub async fn save_reading_position(chapter_id: &str, position: &ReadingPosition) {
+    if !enabled() {
+        return;
+    }
+    if let Err(error) = set_reading_position_to_indexeddb(chapter_id, &position.to_json()).await {
+        tracing::error!(error = ?error, "Failed to write reading position to IndexedDB");
+    }
+}
+
+/// The stored bookmarks, empty when there are none yet. Fails when they exist
+/// but cannot be read, so callers leave them alone instead of saving over them.
+pub async fn load_bookmarks() -> Result<Bookmarks, String> {
+    if !enabled() {
+        return Ok(Bookmarks::default());
+    }
+    let value = get_bookmarks_from_indexeddb().await.map_err(|error| {
+        tracing::error!(error = ?error, "Failed to read bookmarks from IndexedDB");
+        format!("{:?}", error)
+    })?;
+    let Some(raw) = value.as_string() else {
+        return Ok(Bookmarks::default());
+    };
+    Bookmarks::from_json(&raw).map_err(|error| {
+        tracing::error!(error = %error, "Failed to parse stored bookmarks");
+        error.to_string()
+    })
+}
+
+pub async fn save_bookmarks(bookmarks: &Bookmarks) {
+    if !enabled() {
+        return;
+    }
+    if let Err(error) = set_bookmarks_to_indexeddb(&bookmarks.to_json()).await {
+        tracing::error!(error = ?error, "Failed to write bookmarks to IndexedDB");
+    }
+}
@@ -0,0 +1,147 @@
+use crate::constants::config::{
+    ATTRIBUTES, CHAPTERS, CHARACTERS, PARAGRAPHS, RELATIONSHIPS, SETTINGS,
+};
+use crate::models::bundle::{
+    plan_import, strip_system_fields, BundleCollection, ExistingRecords, IdStrategy, ImportPlan,
+    PlannedRecord, RecordAction, StoryBundle,
+};
+use crate::services::api::{ApiClient, ApiError, ApiResult, BatchRequest, BATCH_LIMIT};
+use crate::services::paragraphs::new_record_id;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+pub fn collection_endpoint(collection: BundleCollection) -> &'static str {
+    match collection {
+        BundleCollection::Chapters => CHAPTERS,
+        BundleCollection::Characters => CHARACTERS,
+        BundleCollection::Attributes => ATTRIBUTES,
+        BundleCollection::Relationships => RELATIONSHIPS,
+        BundleCollection::Paragraphs => PARAGRAPHS,
+        BundleCollection::Settings => SETTINGS,
+    }
+}
+
+async fn list_typed<C: ApiClient + ?Sized, T: DeserializeOwned>(
+    client: &C,
+    endpoint: &str,
+) -> ApiResult<Vec<T>> {
+    client
+        .list_records(endpoint)
+        .await?
+        .into_iter()
+        .map(|record| {
+            serde_json::from_value(record).map_err(|e| ApiError::ParseError(e.to_string()))
+        })
+        .collect()
+}
+
+async fn list_clean<C: ApiClient + ?Sized>(client: &C, endpoint: &str) -> ApiResult<Vec<Value>> {
+    Ok(client
+        .list_records(endpoint)
+        .await?
+        .into_iter()
+        .map(strip_system_fields)
+        .collect())
+}
+
+/// Reads every collection of the story into a bundle stamped `exported_at`.
+pub async fn export_bundle<C: ApiClient + ?Sized>(
+    client: &C,
+    exported_at: &str,
+) -> ApiResult<StoryBundle> {
+    let mut bundle = StoryBundle::new(exported_at);
+    bundle.chapters = list_typed(client, CHAPTERS).await?;
+    bundle.chapters.sort_by_key(|c| c.order);
+    bundle.paragraphs = list_typed(client, PARAGRAPHS).await?;
+    bundle.characters = list_clean(client, CHARACTERS).await?;
+    bundle.relationships = list_clean(client, RELATIONSHIPS).await?;
+    bundle.attributes = list_clean(client, ATTRIBUTES).await?;
+    bundle.settings = list_clean(client, SETTINGS).await?;
+    Ok(bundle)
+}
+
+/// Plans an import of `bundle` against the records currently on the server.
+/// Nothing is written, so this doubles as the dry run.
+pub async fn plan_bundle_import<C: ApiClient + ?Sized>(
+    client: &C,
+    bundle: &StoryBundle,
+    strategy: IdStrategy,
+) -> ApiResult<ImportPlan> {
+    let mut existing = ExistingRecords::default();
+    for collection in BundleCollection::ALL {
+        let records = client.list_records(collection_endpoint(collection)).await?;
+        if collection == BundleCollection::Settings {
+            existing.settings = records
+                .iter()
+                .filter_map(|r| {
+                    let key = r.get("key")?.as_str()?;
+                    let id = r.get("id")?.as_str()?;
+                    Some((key.to_string(), id.to_string()))
+                })
+                .collect();
+        }
+        existing.ids.insert(
+            collection,
+            records
+                .iter()
+                .filter_map(|r| r.get("id")?.as_str().map(str::to_string))
+                .collect(),
+        );
+    }
+    Ok(plan_import(bundle, &existing, strategy, new_record_id))
+}
+
+/// How far [`apply_import`] got before a batch failed.
+#[derive(Debug, Clone)]
+pub struct ImportFailure {
+    /// Number of leading planned records that were written.
+    pub applied: usize,
+    pub error: ApiError,
+}
+
+fn planned_request(record: &PlannedRecord) -> BatchRequest {
+    let endpoint = collection_endpoint(record.collection);
+    match record.action {
+        RecordAction::Update => BatchRequest::patch(endpoint, &record.id, record.body.clone()),
+        RecordAction::Create => {
+            let mut body = record.body.clone();
+            // Records without an id in the bundle get one from the server
+            if let (Some(fields), false) = (body.as_object_mut(), record.id.is_empty()) {
+                fields.insert("id".to_string(), Value::String(record.id.clone()));
+            }
+            BatchRequest::create(endpoint, body)
+        }
+    }
+}
+
+/// Writes `plan` in batches of [`BATCH_LIMIT`]. Each batch is atomic; a
+/// failure leaves earlier batches applied, which the error reports.
+pub async fn apply_import<C: ApiClient + ?Sized>(
+    client: &C,
+    plan: &ImportPlan,
+) -> Result<(), ImportFailure> {
+    for (chunk_index, chunk) in plan.records.chunks(BATCH_LIMIT).enumerate() {
+        let requests: Vec<BatchRequest> = chunk.iter().map(planned_request).collect();
+        client
+            .batch(&requests)
+            .await
+            .map_err(|error| ImportFailure {
+                applied: chunk_index * BATCH_LIMIT,
+                error,
+            })?;
+    }
+    Ok(())
+}
+
+/// Plans and writes `bundle` in one go, for callers that skip the dry run.
+pub async fn import_bundle<C: ApiClient + ?Sized>(
+    client: &C,
+    bundle: &StoryBundle,
+    strategy: IdStrategy,
+) -> Result<ImportPlan, ImportFailure> {
+    let plan = plan_bundle_import(client, bundle, strategy)
+        .await
+        .map_err(|error| ImportFailure { applied: 0, error })?;
+    apply_import(client, &plan).await?;
+    Ok(plan)
+}
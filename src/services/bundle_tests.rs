@@ -0,0 +1,161 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::api::*;
+    use super::super::bundle::*;
+    use crate::constants::config::{CHAPTERS, CHARACTERS, PARAGRAPHS, SETTINGS};
+    use crate::models::bundle::{BundleCollection, IdStrategy, RecordAction, StoryBundle};
+    use serde_json::json;
+
+    fn story_client() -> MockApiClient {
+        MockApiClient::new()
+            .with_records(
+                CHAPTERS,
+                vec![
+                    json!({"id": "c2", "titles": [], "order": 2, "collectionId": "x", "created": "2024-01-01"}),
+                    json!({"id": "c1", "titles": [{"lang": "en-US", "title": "One"}], "order": 1}),
+                ],
+            )
+            .with_records(
+                PARAGRAPHS,
+                vec![json!({
+                    "id": "p1",
+                    "chapter_id": "c1",
+                    "texts": [{"lang": "en-US", "paragraphs": "Hello", "choices": ["Go"]}],
+                    "choices": [["p1"]],
+                })],
+            )
+            .with_records(
+                CHARACTERS,
+                vec![json!({"id": "hero", "char_id": "Hero", "collectionName": "characters", "updated": "now"})],
+            )
+            .with_records(SETTINGS, vec![json!({"id": "s1", "key": "theme", "value": "dark"})])
+    }
+
+    #[tokio::test]
+    async fn test_export_reads_every_collection_without_system_fields() {
+        // Prepare test data
+        let client = story_client();
+
+        // Execute test
+        let bundle = export_bundle(&client, "2024-05-01T00:00:00Z")
+            .await
+            .unwrap();
+
+        // Verify result
+        let chapter_ids: Vec<&str> = bundle.chapters.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(chapter_ids, vec!["c1", "c2"]);
+        // The legacy list shape comes out as a v2 choice
+        assert_eq!(bundle.paragraphs[0].choices[0].to, vec!["p1".to_string()]);
+        assert_eq!(
+            bundle.characters,
+            vec![json!({"id": "hero", "char_id": "Hero"})]
+        );
+        assert_eq!(bundle.settings.len(), 1);
+        assert!(bundle.relationships.is_empty());
+
+        let text = serde_json::to_string(&bundle).unwrap();
+        assert_eq!(StoryBundle::parse(&text).unwrap(), bundle);
+    }
+
+    #[tokio::test]
+    async fn test_import_updates_existing_records_and_creates_the_rest() {
+        // Prepare test data
+        let client = story_client();
+        let mut bundle = export_bundle(&client, "").await.unwrap();
+        bundle.paragraphs[0].id = "p2".to_string();
+        bundle.settings[0]["id"] = json!("other-install");
+
+        // Execute test
+        let plan = plan_bundle_import(&client, &bundle, IdStrategy::Keep)
+            .await
+            .unwrap();
+        apply_import(&client, &plan).await.unwrap();
+
+        // Verify result
+        assert_eq!(
+            plan.count(BundleCollection::Chapters, RecordAction::Update),
+            2
+        );
+        assert_eq!(
+            plan.count(BundleCollection::Paragraphs, RecordAction::Create),
+            1
+        );
+        assert_eq!(
+            plan.count(BundleCollection::Settings, RecordAction::Update),
+            1
+        );
+        let batches = client.batches.borrow();
+        assert_eq!(batches.len(), 1);
+        let urls: Vec<(&str, &str)> = batches[0]
+            .iter()
+            .map(|r| (r.method.as_str(), r.url.as_str()))
+            .collect();
+        assert_eq!(
+            urls,
+            vec![
+                ("PATCH", "/api/collections/chapters/records/c1"),
+                ("PATCH", "/api/collections/chapters/records/c2"),
+                ("PATCH", "/api/collections/characters/records/hero"),
+                ("POST", "/api/collections/paragraphs/records"),
+                // Matched by key, so the local record is kept
+                ("PATCH", "/api/collections/settings/records/s1"),
+            ]
+        );
+        assert_eq!(batches[0][3].body.as_ref().unwrap()["id"], json!("p2"));
+    }
+
+    #[tokio::test]
+    async fn test_import_failure_reports_applied_records() {
+        // Prepare test data
+        let mut bundle = StoryBundle::new("");
+        bundle.characters = (0..BATCH_LIMIT + 1)
+            .map(|i| json!({"id": format!("char{}", i), "char_id": format!("C{}", i)}))
+            .collect();
+        let plan = crate::models::bundle::plan_import(
+            &bundle,
+            &Default::default(),
+            IdStrategy::Keep,
+            String::new,
+        );
+        let client = MockApiClient::new().with_failure();
+
+        // Execute test
+        let failure = apply_import(&client, &plan).await.unwrap_err();
+
+        // Verify result
+        assert_eq!(failure.applied, 0);
+        assert!(matches!(failure.error, ApiError::NetworkError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_twee_story_is_written_in_one_batch() {
+        // Prepare test data
+        let source = ":: Start\nHello\n[[Next]]\n\n:: Next\nBye\n";
+        let import = crate::models::twee::twee_to_bundle(
+            source,
+            "en-US",
+            "",
+            crate::services::paragraphs::new_record_id,
+        )
+        .unwrap();
+        let client = MockApiClient::new();
+
+        // Execute test
+        let plan = import_bundle(&client, &import.bundle, IdStrategy::Keep)
+            .await
+            .unwrap();
+
+        // Verify result
+        assert_eq!(
+            plan.count(BundleCollection::Chapters, RecordAction::Create),
+            1
+        );
+        assert_eq!(
+            plan.count(BundleCollection::Paragraphs, RecordAction::Create),
+            2
+        );
+        let batches = client.batches.borrow();
+        assert_eq!(batches.len(), 1);
+        assert!(batches[0].iter().all(|r| r.method == "POST"));
+    }
+}
@@ -0,0 +1,196 @@
+use crate::constants::config::CHAPTERS;
+use crate::models::chapter::{Chapter, ChapterTitle};
+use crate::models::paragraph::Paragraph;
+use crate::models::references::{inbound_references, InboundReference, ReferenceRepair};
+use crate::services::api::{ApiClient, ApiError, ApiResult, BatchRequest, BATCH_LIMIT};
+use crate::services::auth::AuthUser;
+use crate::services::paragraphs::build_repair_batch;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Body for a new record in the `chapters` collection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NewChapter {
+    pub titles: Vec<ChapterTitle>,
+    pub order: i32,
+}
+
+/// PATCH body for a chapter; unset fields are left untouched.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ChapterUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub titles: Option<Vec<ChapterTitle>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+}
+
+/// What deleting a chapter takes down with it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterDeletePlan {
+    pub chapter_id: String,
+    /// Paragraphs stored in the chapter; they are deleted too.
+    pub paragraphs: Vec<String>,
+    /// Choices in other chapters that would point at a deleted paragraph.
+    pub inbound: Vec<InboundReference>,
+}
+
+impl ChapterDeletePlan {
+    /// Nothing else changes when the chapter goes away.
+    pub fn is_clean(&self) -> bool {
+        self.paragraphs.is_empty() && self.inbound.is_empty()
+    }
+}
+
+/// Order value for a chapter appended after all existing ones.
+pub fn next_chapter_order(chapters: &[Chapter]) -> i32 {
+    chapters.iter().map(|c| c.order).max().unwrap_or(0) + 1
+}
+
+/// Moves the chapter at `from` to `to` (indices into the list sorted by
+/// `order`) and returns the `(id, order)` pairs that have to be saved.
+///
+/// Orders are renumbered from 1 so gaps and duplicates left by manual edits
+/// disappear on the first move.
+///
+/// ```
+/// use ifecaro::models::chapter::Chapter;
+/// use ifecaro::services::chapters::plan_reorder;
+///
+/// let chapter = |id: &str, order| Chapter { id: id.to_string(), titles: vec![], order };
+/// let chapters = vec![chapter("a", 1), chapter("b", 2), chapter("c", 3)];
+/// assert_eq!(
+///     plan_reorder(&chapters, 2, 0),
+///     vec![("c".to_string(), 1), ("a".to_string(), 2), ("b".to_string(), 3)]
+/// );
+/// ```
+pub fn plan_reorder(chapters: &[Chapter], from: usize, to: usize) -> Vec<(String, i32)> {
+    let mut sorted: Vec<&Chapter> = chapters.iter().collect();
+    sorted.sort_by_key(|c| c.order);
+    if from >= sorted.len() || to >= sorted.len() || from == to {
+        return Vec::new();
+    }
+    let moved = sorted.remove(from);
+    sorted.insert(to, moved);
+    sorted
+        .iter()
+        .enumerate()
+        .filter_map(|(i, chapter)| {
+            let order = i as i32 + 1;
+            (chapter.order != order).then(|| (chapter.id.clone(), order))
+        })
+        .collect()
+}
+
+pub fn plan_chapter_delete(chapter_id: &str, paragraphs: &[Paragraph]) -> ChapterDeletePlan {
+    let members: HashSet<&str> = paragraphs
+        .iter()
+        .filter(|p| p.chapter_id == chapter_id)
+        .map(|p| p.id.as_str())
+        .collect();
+    ChapterDeletePlan {
+        chapter_id: chapter_id.to_string(),
+        paragraphs: paragraphs
+            .iter()
+            .filter(|p| members.contains(p.id.as_str()))
+            .map(|p| p.id.clone())
+            .collect(),
+        inbound: inbound_references(paragraphs, &members),
+    }
+}
+
+pub async fn create_chapter<C: ApiClient + ?Sized>(
+    client: &C,
+    chapters: &[Chapter],
+    lang: &str,
+    title: &str,
+) -> ApiResult<Chapter> {
+    client
+        .create_chapter(&NewChapter {
+            titles: vec![ChapterTitle {
+                lang: lang.to_string(),
+                title: title.trim().to_string(),
+            }],
+            order: next_chapter_order(chapters),
+        })
+        .await
+}
+
+/// Updates the title of one language and keeps the other translations.
+pub async fn rename_chapter<C: ApiClient + ?Sized>(
+    client: &C,
+    chapter: &Chapter,
+    lang: &str,
+    title: &str,
+) -> ApiResult<Chapter> {
+    let mut renamed = chapter.clone();
+    renamed.set_title(lang, title);
+    client
+        .update_chapter(
+            &chapter.id,
+            &ChapterUpdate {
+                titles: Some(renamed.titles),
+                ..Default::default()
+            },
+        )
+        .await
+}
+
+/// Saves a [`plan_reorder`] result in one batch and returns the updated
+/// chapters.
+pub async fn move_chapter<C: ApiClient + ?Sized>(
+    client: &C,
+    chapters: &[Chapter],
+    from: usize,
+    to: usize,
+) -> ApiResult<Vec<Chapter>> {
+    let plan = plan_reorder(chapters, from, to);
+    if plan.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut saved = Vec::new();
+    let mut requests = Vec::new();
+    for (id, order) in plan {
+        let Some(chapter) = chapters.iter().find(|c| c.id == id) else {
+            continue;
+        };
+        let update = ChapterUpdate {
+            order: Some(order),
+            ..Default::default()
+        };
+        let body =
+            serde_json::to_value(&update).map_err(|e| ApiError::ParseError(e.to_string()))?;
+        requests.push(BatchRequest::patch(CHAPTERS, &id, body));
+        saved.push(Chapter {
+            order,
+            ..chapter.clone()
+        });
+    }
+    client.batch(&requests).await?;
+    Ok(saved)
+}
+
+/// Repairs the inbound choices, then deletes the paragraphs of the chapter
+/// and the chapter itself.
+///
+/// Everything goes out as one batch, with a revision for every repaired
+/// paragraph; a chapter too large for that is split into batches of
+/// [`BATCH_LIMIT`] in the same order, so a failure part-way has already
+/// repaired every choice outside the chapter and leaves the chapter record
+/// in place. Returns the repaired paragraphs.
+pub async fn delete_chapter<C: ApiClient + ?Sized>(
+    client: &C,
+    plan: &ChapterDeletePlan,
+    paragraphs: &[Paragraph],
+    repair: &ReferenceRepair,
+    author: Option<&AuthUser>,
+) -> ApiResult<Vec<Paragraph>> {
+    let (repaired, mut requests) =
+        build_repair_batch(paragraphs, &plan.inbound, &plan.paragraphs, repair, author)?;
+    requests.push(BatchRequest::delete(CHAPTERS, &plan.chapter_id));
+    // Repairs come first as patch/revision pairs and the limit is even, so no
+    // patch is split from its revision
+    for chunk in requests.chunks(BATCH_LIMIT) {
+        client.batch(chunk).await?;
+    }
+    Ok(repaired)
+}
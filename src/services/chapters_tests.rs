@@ -0,0 +1,220 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::api::*;
+    use super::super::chapters::*;
+    use crate::models::chapter::{Chapter, ChapterTitle};
+    use crate::models::paragraph::{Paragraph, ParagraphChoice};
+    use crate::models::references::ReferenceRepair;
+    use crate::models::story_graph::EdgeSlot;
+
+    /// Helper function: Create a chapter record with one English title
+    fn chapter(id: &str, title: &str, order: i32) -> Chapter {
+        Chapter {
+            id: id.to_string(),
+            titles: vec![ChapterTitle {
+                lang: "en-US".to_string(),
+                title: title.to_string(),
+            }],
+            order,
+        }
+    }
+
+    fn paragraph(id: &str, chapter_id: &str, targets: &[&str]) -> Paragraph {
+        Paragraph {
+            id: id.to_string(),
+            chapter_id: chapter_id.to_string(),
+            texts: vec![],
+            choices: targets
+                .iter()
+                .map(|target| ParagraphChoice::goto(vec![target.to_string()]))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_chapter_appends_after_last_order() {
+        // Prepare test data
+        let existing = vec![chapter("c1", "Intro", 1), chapter("c2", "Forest", 4)];
+        let client = MockApiClient::new();
+
+        // Execute test
+        let created = create_chapter(&client, &existing, "en-US", "  Castle ")
+            .await
+            .unwrap();
+
+        // Verify result
+        assert_eq!(created.order, 5);
+        assert_eq!(created.title_for("en-US"), Some("Castle"));
+        assert_eq!(client.chapter_records.borrow().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rename_chapter_keeps_other_languages() {
+        let mut record = chapter("c1", "Intro", 1);
+        record.set_title("zh-TW", "序章");
+        let client = MockApiClient::new().with_chapter_record(record.clone());
+
+        let renamed = rename_chapter(&client, &record, "en-US", "Prologue")
+            .await
+            .unwrap();
+
+        assert_eq!(renamed.title_for("en-US"), Some("Prologue"));
+        assert_eq!(renamed.title_for("zh-TW"), Some("序章"));
+        assert_eq!(renamed.order, 1);
+    }
+
+    #[tokio::test]
+    async fn test_move_chapter_saves_only_changed_orders_in_one_batch() {
+        // Prepare test data
+        let chapters = vec![
+            chapter("c1", "One", 1),
+            chapter("c2", "Two", 2),
+            chapter("c3", "Three", 3),
+            chapter("c4", "Four", 4),
+        ];
+        let client = MockApiClient::new();
+
+        // Execute test
+        let saved = move_chapter(&client, &chapters, 0, 2).await.unwrap();
+
+        // Verify result
+        let orders: Vec<(&str, i32)> = saved.iter().map(|c| (c.id.as_str(), c.order)).collect();
+        assert_eq!(orders, vec![("c2", 1), ("c3", 2), ("c1", 3)]);
+        assert_eq!(saved[0].titles, chapters[1].titles);
+        let batches = client.batches.borrow().clone();
+        assert_eq!(batches.len(), 1);
+        let patched: Vec<(&str, &str)> = batches[0]
+            .iter()
+            .map(|r| (r.method.as_str(), r.url.as_str()))
+            .collect();
+        assert_eq!(
+            patched,
+            vec![
+                ("PATCH", "/api/collections/chapters/records/c2"),
+                ("PATCH", "/api/collections/chapters/records/c3"),
+                ("PATCH", "/api/collections/chapters/records/c1"),
+            ]
+        );
+        assert_eq!(batches[0][2].body, Some(serde_json::json!({ "order": 3 })));
+
+        // Moving onto itself saves nothing
+        assert!(move_chapter(&client, &chapters, 1, 1)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(client.batches.borrow().len(), 1);
+    }
+
+    #[test]
+    fn test_delete_plan_lists_paragraphs_and_inbound_choices() {
+        let paragraphs = vec![
+            paragraph("p1", "c1", &["p2"]),
+            paragraph("p2", "c1", &["p1"]),
+            paragraph("p3", "c2", &["p4", "p2"]),
+            paragraph("p4", "c2", &[]),
+        ];
+
+        let plan = plan_chapter_delete("c1", &paragraphs);
+
+        assert_eq!(plan.paragraphs, vec!["p1".to_string(), "p2".to_string()]);
+        assert_eq!(plan.inbound.len(), 1);
+        assert_eq!(plan.inbound[0].paragraph_id, "p3");
+        assert_eq!(plan.inbound[0].choice_index, 1);
+        assert_eq!(plan.inbound[0].slot, EdgeSlot::Target(0));
+        assert!(!plan.is_clean());
+        assert!(plan_chapter_delete("c3", &paragraphs).is_clean());
+    }
+
+    #[tokio::test]
+    async fn test_delete_chapter_repairs_inbound_choices_in_the_same_batch() {
+        // Prepare test data
+        let paragraphs = vec![
+            paragraph("p1", "c1", &[]),
+            paragraph("p2", "c1", &["p1"]),
+            paragraph("p3", "c2", &["p4", "p2"]),
+            paragraph("p4", "c2", &[]),
+        ];
+        let client = MockApiClient::new();
+        let plan = plan_chapter_delete("c1", &paragraphs);
+
+        // Execute test
+        let repaired = delete_chapter(
+            &client,
+            &plan,
+            &paragraphs,
+            &ReferenceRepair::Retarget("p4".to_string()),
+            None,
+        )
+        .await
+        .unwrap();
+
+        // Verify result
+        assert_eq!(repaired.len(), 1);
+        assert_eq!(repaired[0].id, "p3");
+        assert_eq!(repaired[0].choices[1].to, vec!["p4".to_string()]);
+        let batches = client.batches.borrow().clone();
+        assert_eq!(batches.len(), 1);
+        let requests: Vec<(&str, &str)> = batches[0]
+            .iter()
+            .map(|r| (r.method.as_str(), r.url.as_str()))
+            .collect();
+        assert_eq!(
+            requests,
+            vec![
+                ("PATCH", "/api/collections/paragraphs/records/p3"),
+                ("POST", "/api/collections/paragraph_revisions/records"),
+                ("DELETE", "/api/collections/paragraphs/records/p1"),
+                ("DELETE", "/api/collections/paragraphs/records/p2"),
+                ("DELETE", "/api/collections/chapters/records/c1"),
+            ]
+        );
+
+        // Retargeting into the deleted chapter is refused before anything is sent
+        let result = delete_chapter(
+            &client,
+            &plan,
+            &paragraphs,
+            &ReferenceRepair::Retarget("p1".to_string()),
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(ApiError::NotFound)));
+        assert_eq!(client.batches.borrow().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_large_chapter_splits_batches_with_chapter_last() {
+        // Prepare test data
+        let mut paragraphs: Vec<Paragraph> = (0..BATCH_LIMIT + 10)
+            .map(|i| paragraph(&format!("p{}", i), "c1", &[]))
+            .collect();
+        paragraphs.push(paragraph("outside", "c2", &["p0"]));
+        let client = MockApiClient::new();
+        let plan = plan_chapter_delete("c1", &paragraphs);
+
+        // Execute test
+        delete_chapter(
+            &client,
+            &plan,
+            &paragraphs,
+            &ReferenceRepair::RemoveChoices,
+            None,
+        )
+        .await
+        .unwrap();
+
+        // Verify result
+        let batches = client.batches.borrow();
+        assert_eq!(batches.len(), 2);
+        assert!(batches.iter().all(|batch| batch.len() <= BATCH_LIMIT));
+        assert_eq!(
+            batches[0][0].url,
+            "/api/collections/paragraphs/records/outside"
+        );
+        assert_eq!(
+            batches[1].last().unwrap().url,
+            "/api/collections/chapters/records/c1"
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! The author's timer flag, stored in the story's `settings` collection.
+
+use crate::constants::config::SETTINGS;
+use crate::models::countdown::TIMERS_MANDATORY_SETTING;
+use crate::services::api::{ApiClient, ApiResult};
+use serde_json::Value;
+
+/// Whether the author made timers mandatory. The record's value may be a
+/// boolean or the string `"true"`; without a record timers are optional.
+pub async fn load_timers_mandatory<C: ApiClient + ?Sized>(client: &C) -> ApiResult<bool> {
+    let records = client.list_records(SETTINGS).await?;
+    Ok(records
+        .iter()
+        .find(|record| record.get("key").and_then(Value::as_str) == Some(TIMERS_MANDATORY_SETTING))
+        .and_then(|record| record.get("value"))
+        .map(|value| match value {
+            Value::Bool(flag) => *flag,
+            Value::String(raw) => raw.trim() == "true",
+            _ => false,
+        })
+        .unwrap_or(false))
+}
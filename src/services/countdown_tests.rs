@@ -0,0 +1,38 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::api::*;
+    use super::super::countdown::*;
+    use crate::constants::config::SETTINGS;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_mandatory_flag_accepts_bool_and_string_values() {
+        let client = MockApiClient::new().with_records(
+            SETTINGS,
+            vec![
+                json!({"id": "s1", "key": "theme", "value": "dark"}),
+                json!({"id": "s2", "key": "timers_mandatory", "value": true}),
+            ],
+        );
+        assert!(load_timers_mandatory(&client).await.unwrap());
+
+        let client = MockApiClient::new().with_records(
+            SETTINGS,
+            vec![json!({"id": "s2", "key": "timers_mandatory", "value": "false"})],
+        );
+        assert!(!load_timers_mandatory(&client).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_timers_are_optional_without_a_record() {
+        let client = MockApiClient::new().with_records(
+            SETTINGS,
+            vec![json!({"id": "s1", "key": "theme", "value": "dark"})],
+        );
+
+        assert!(!load_timers_mandatory(&client).await.unwrap());
+        assert!(load_timers_mandatory(&MockApiClient::new().with_failure())
+            .await
+            .is_err());
+    }
+}
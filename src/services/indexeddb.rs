@@ -80,6 +80,42 @@ extern "C" {
 
     #[wasm_bindgen(js_name = getLatestCharacterStateFromIndexedDB, catch)]
     pub async fn get_latest_character_state_from_indexeddb() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = setOutboxToIndexedDB, catch)]
+    pub async fn set_outbox_to_indexeddb(outbox_json: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = getOutboxFromIndexedDB, catch)]
+    pub async fn get_outbox_from_indexeddb() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = setReadingPositionToIndexedDB, catch)]
+    pub async fn set_reading_position_to_indexeddb(
+        chapter_id: &str,
+        position_json: &str,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = getReadingPositionFromIndexedDB, catch)]
+    pub async fn get_reading_position_from_indexeddb(chapter_id: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = setBookmarksToIndexedDB, catch)]
+    pub async fn set_bookmarks_to_indexeddb(bookmarks_json: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = getBookmarksFromIndexedDB, catch)]
+    pub async fn get_bookmarks_from_indexeddb() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = getChoiceHistoryFromIndexedDB, catch)]
+    pub async fn get_choice_history_from_indexeddb() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = setProgressToIndexedDB, catch)]
+    pub async fn set_progress_to_indexeddb(progress_json: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = getProgressFromIndexedDB, catch)]
+    pub async fn get_progress_from_indexeddb() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = setAchievementsToIndexedDB, catch)]
+    pub async fn set_achievements_to_indexeddb(unlocked_json: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = getAchievementsFromIndexedDB, catch)]
+    pub async fn get_achievements_from_indexeddb() -> Result<JsValue, JsValue>;
 }
 
 #[allow(dead_code)]
@@ -1,6 +1,6 @@
 const DB_NAME = 'ifecaro';
-const DB_VERSION = 6;
-const STORES = ['settings', 'choices', 'disabled_choices', 'random_choices', 'choice_impacts', 'character_states'];
+const DB_VERSION = 10;
+const STORES = ['settings', 'choices', 'disabled_choices', 'random_choices', 'choice_impacts', 'character_states', 'outbox', 'reading_positions', 'bookmarks', 'progress', 'achievements'];
 
 function logIndexedDbError(stage, error) {
     console.error(`[IndexedDB] ${stage} failed`, {
@@ -10,16 +10,19 @@ function logIndexedDbError(stage, error) {
     });
 }
 
+function createMissingStores(db) {
+    STORES.forEach(storeName => {
+        if (!db.objectStoreNames.contains(storeName)) {
+            db.createObjectStore(storeName);
+        }
+    });
+}
+
 function openDB() {
     return new Promise((resolve, reject) => {
         const request = indexedDB.open(DB_NAME, DB_VERSION);
         request.onupgradeneeded = function (event) {
-            const db = event.target.result;
-            STORES.forEach(storeName => {
-                if (!db.objectStoreNames.contains(storeName)) {
-                    db.createObjectStore(storeName);
-                }
-            });
+            createMissingStores(event.target.result);
         };
         request.onsuccess = function (event) {
             resolve(event.target.result);
@@ -46,25 +49,7 @@ export function setSettingToIndexedDB(key, value) {
     }
 
     request.onupgradeneeded = function (event) {
-        const db = event.target.result;
-        if (!db.objectStoreNames.contains('settings')) {
-            db.createObjectStore('settings');
-        }
-        if (!db.objectStoreNames.contains('choices')) {
-            db.createObjectStore('choices');
-        }
-        if (!db.objectStoreNames.contains('disabled_choices')) {
-            db.createObjectStore('disabled_choices');
-        }
-        if (!db.objectStoreNames.contains('random_choices')) {
-            db.createObjectStore('random_choices');
-        }
-        if (!db.objectStoreNames.contains('choice_impacts')) {
-            db.createObjectStore('choice_impacts');
-        }
-        if (!db.objectStoreNames.contains('character_states')) {
-            db.createObjectStore('character_states');
-        }
+        createMissingStores(event.target.result);
     };
     request.onsuccess = function (event) {
         const db = event.target.result;
@@ -115,25 +100,7 @@ export function getSettingsFromIndexedDB(callback) {
     }
 
     request.onupgradeneeded = function (event) {
-        const db = event.target.result;
-        if (!db.objectStoreNames.contains('settings')) {
-            db.createObjectStore('settings');
-        }
-        if (!db.objectStoreNames.contains('choices')) {
-            db.createObjectStore('choices');
-        }
-        if (!db.objectStoreNames.contains('disabled_choices')) {
-            db.createObjectStore('disabled_choices');
-        }
-        if (!db.objectStoreNames.contains('random_choices')) {
-            db.createObjectStore('random_choices');
-        }
-        if (!db.objectStoreNames.contains('choice_impacts')) {
-            db.createObjectStore('choice_impacts');
-        }
-        if (!db.objectStoreNames.contains('character_states')) {
-            db.createObjectStore('character_states');
-        }
+        createMissingStores(event.target.result);
     };
     request.onsuccess = function (event) {
         const db = event.target.result;
@@ -443,19 +410,7 @@ export async function clearDisabledChoicesForParagraph(paragraphId) {
 export function setRandomChoiceToIndexedDB(paragraphId, choiceIndex, originalChoices, selectedChoice) {
     const request = indexedDB.open('ifecaro', DB_VERSION);
     request.onupgradeneeded = function (event) {
-        const db = event.target.result;
-        if (!db.objectStoreNames.contains('settings')) {
-            db.createObjectStore('settings');
-        }
-        if (!db.objectStoreNames.contains('choices')) {
-            db.createObjectStore('choices');
-        }
-        if (!db.objectStoreNames.contains('disabled_choices')) {
-            db.createObjectStore('disabled_choices');
-        }
-        if (!db.objectStoreNames.contains('random_choices')) {
-            db.createObjectStore('random_choices');
-        }
+        createMissingStores(event.target.result);
     };
     request.onsuccess = function (event) {
         const db = event.target.result;
@@ -481,19 +436,7 @@ export function setRandomChoiceToIndexedDB(paragraphId, choiceIndex, originalCho
 export function getRandomChoiceFromIndexedDB(paragraphId, choiceIndex, callback) {
     const request = indexedDB.open('ifecaro', DB_VERSION);
     request.onupgradeneeded = function (event) {
-        const db = event.target.result;
-        if (!db.objectStoreNames.contains('settings')) {
-            db.createObjectStore('settings');
-        }
-        if (!db.objectStoreNames.contains('choices')) {
-            db.createObjectStore('choices');
-        }
-        if (!db.objectStoreNames.contains('disabled_choices')) {
-            db.createObjectStore('disabled_choices');
-        }
-        if (!db.objectStoreNames.contains('random_choices')) {
-            db.createObjectStore('random_choices');
-        }
+        createMissingStores(event.target.result);
     };
     request.onsuccess = function (event) {
         const db = event.target.result;
@@ -579,5 +522,295 @@ export async function clearAllDisabledChoices() {
     });
 }
 
+// 儲存離線時尚未送出的寫入請求（整份 outbox JSON）
+export async function setOutboxToIndexedDB(outboxJson) {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('outbox', 'readwrite');
+        const store = tx.objectStore('outbox');
+        const putReq = store.put(outboxJson, 'pending');
+
+        putReq.onsuccess = () => { };
+        putReq.onerror = (e) => {
+            console.error("Put request error in setOutboxToIndexedDB: ", e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+            resolve();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in setOutboxToIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 取得離線待送出的寫入請求
+export async function getOutboxFromIndexedDB() {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('outbox', 'readonly');
+        const store = tx.objectStore('outbox');
+        const getReq = store.get('pending');
+
+        getReq.onsuccess = function () {
+            resolve(getReq.result || null);
+        };
+        getReq.onerror = function (e) {
+            console.error("Get request error in getOutboxFromIndexedDB: ", e.target.error);
+            reject(e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in getOutboxFromIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 儲存章節內的閱讀位置（ReadingPosition JSON，以章節 ID 為 key）
+export async function setReadingPositionToIndexedDB(chapterId, positionJson) {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('reading_positions', 'readwrite');
+        const store = tx.objectStore('reading_positions');
+        const putReq = store.put(positionJson, chapterId);
+
+        putReq.onerror = (e) => {
+            console.error("Put request error in setReadingPositionToIndexedDB: ", e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+            resolve();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in setReadingPositionToIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 取得章節內的閱讀位置
+export async function getReadingPositionFromIndexedDB(chapterId) {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('reading_positions', 'readonly');
+        const store = tx.objectStore('reading_positions');
+        const getReq = store.get(chapterId);
+
+        getReq.onsuccess = function () {
+            resolve(getReq.result || null);
+        };
+        getReq.onerror = function (e) {
+            console.error("Get request error in getReadingPositionFromIndexedDB: ", e.target.error);
+            reject(e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in getReadingPositionFromIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 儲存所有書籤（整份 Bookmarks JSON）
+export async function setBookmarksToIndexedDB(bookmarksJson) {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('bookmarks', 'readwrite');
+        const store = tx.objectStore('bookmarks');
+        const putReq = store.put(bookmarksJson, 'all');
+
+        putReq.onerror = (e) => {
+            console.error("Put request error in setBookmarksToIndexedDB: ", e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+            resolve();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in setBookmarksToIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 取得所有書籤
+export async function getBookmarksFromIndexedDB() {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('bookmarks', 'readonly');
+        const store = tx.objectStore('bookmarks');
+        const getReq = store.get('all');
+
+        getReq.onsuccess = function () {
+            resolve(getReq.result || null);
+        };
+        getReq.onerror = function (e) {
+            console.error("Get request error in getBookmarksFromIndexedDB: ", e.target.error);
+            reject(e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in getBookmarksFromIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 取得所有章節的選擇路徑與隨機選項所在段落（讀者走過的段落 ID，可能重複）
+export async function getChoiceHistoryFromIndexedDB() {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction(['choices', 'random_choices'], 'readonly');
+        const ids = [];
+        const choicesReq = tx.objectStore('choices').getAll();
+        choicesReq.onsuccess = function () {
+            (choicesReq.result || []).forEach(arr => {
+                if (Array.isArray(arr)) ids.push(...arr);
+            });
+        };
+        // random_choices 的 key 為 `${paragraphId}:${choiceIndex}`
+        const randomReq = tx.objectStore('random_choices').getAllKeys();
+        randomReq.onsuccess = function () {
+            (randomReq.result || []).forEach(key => {
+                const text = String(key);
+                const split = text.lastIndexOf(':');
+                ids.push(split > 0 ? text.slice(0, split) : text);
+            });
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+            resolve(ids);
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in getChoiceHistoryFromIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 儲存閱讀進度（整份 ReadingProgress JSON）
+export async function setProgressToIndexedDB(progressJson) {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('progress', 'readwrite');
+        const store = tx.objectStore('progress');
+        const putReq = store.put(progressJson, 'all');
+
+        putReq.onerror = (e) => {
+            console.error("Put request error in setProgressToIndexedDB: ", e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+            resolve();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in setProgressToIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 取得閱讀進度
+export async function getProgressFromIndexedDB() {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('progress', 'readonly');
+        const store = tx.objectStore('progress');
+        const getReq = store.get('all');
+
+        getReq.onsuccess = function () {
+            resolve(getReq.result || null);
+        };
+        getReq.onerror = function (e) {
+            console.error("Get request error in getProgressFromIndexedDB: ", e.target.error);
+            reject(e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in getProgressFromIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 儲存已解鎖的成就（整份 UnlockedAchievements JSON）
+export async function setAchievementsToIndexedDB(unlockedJson) {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('achievements', 'readwrite');
+        const store = tx.objectStore('achievements');
+        const putReq = store.put(unlockedJson, 'all');
+
+        putReq.onerror = (e) => {
+            console.error("Put request error in setAchievementsToIndexedDB: ", e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+            resolve();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in setAchievementsToIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
+// 取得已解鎖的成就
+export async function getAchievementsFromIndexedDB() {
+    const db = await openDB();
+    return new Promise((resolve, reject) => {
+        const tx = db.transaction('achievements', 'readonly');
+        const store = tx.objectStore('achievements');
+        const getReq = store.get('all');
+
+        getReq.onsuccess = function () {
+            resolve(getReq.result || null);
+        };
+        getReq.onerror = function (e) {
+            console.error("Get request error in getAchievementsFromIndexedDB: ", e.target.error);
+            reject(e.target.error);
+        };
+
+        tx.oncomplete = function () {
+            db.close();
+        };
+        tx.onerror = function (e) {
+            console.error("Transaction error in getAchievementsFromIndexedDB: ", e.target.error);
+            db.close();
+            reject(e.target.error);
+        };
+    });
+}
+
 // 確保函數被暴露給 window 物件
 window.clearAllDisabledChoices = clearAllDisabledChoices;
@@ -0,0 +1,271 @@
+use crate::services::api::{ApiClient, ApiError, ApiResult};
+use crate::services::auth::{register, AuthSession, SignUpRequest};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Days an approved invite code stays valid.
+pub const INVITE_CODE_TTL_DAYS: i64 = 14;
+
+const INVITE_CODE_PREFIX: &str = "INVITE";
+// 去除 0/O、1/I/L 等易混淆字元，方便讀者手動輸入
+const INVITE_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+    Redeemed,
+}
+
+/// Record in the `invites` collection.
+///
+/// PocketBase returns unset text and date fields as empty strings, so `code`,
+/// `expires` and `redeemed_by` are plain strings rather than options.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Invite {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    #[serde(default)]
+    pub reason: String,
+    #[serde(default)]
+    pub status: InviteStatus,
+    #[serde(default)]
+    pub code: String,
+    #[serde(default)]
+    pub expires: String,
+    #[serde(default)]
+    pub redeemed_by: String,
+    #[serde(default)]
+    pub created: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InviteData {
+    pub items: Vec<Invite>,
+}
+
+/// Body for a new invite request submitted from the public form.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NewInvite {
+    pub name: String,
+    pub email: String,
+    pub reason: String,
+    pub status: InviteStatus,
+}
+
+/// PATCH body for admin review; empty fields are left untouched.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InviteUpdate {
+    pub status: InviteStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redeemed_by: Option<String>,
+}
+
+/// Why an invite code cannot be used to register.
+///
+/// Serialized in snake_case, as `pocketbase/hooks/invites.js` reports it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InviteCodeError {
+    Unknown,
+    NotApproved,
+    Expired,
+    AlreadyUsed,
+    EmailMismatch,
+}
+
+/// Response of the `/invites/check` route; no problem means the code can be
+/// redeemed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InviteCheck {
+    #[serde(default)]
+    pub problem: Option<InviteCodeError>,
+}
+
+#[derive(Debug, Clone)]
+pub enum InviteError {
+    Code(InviteCodeError),
+    Api(ApiError),
+}
+
+impl From<ApiError> for InviteError {
+    fn from(error: ApiError) -> Self {
+        InviteError::Api(error)
+    }
+}
+
+impl From<InviteCodeError> for InviteError {
+    fn from(error: InviteCodeError) -> Self {
+        InviteError::Code(error)
+    }
+}
+
+/// Formats `time` the way PocketBase stores `date` fields.
+pub fn format_pb_datetime(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%d %H:%M:%S%.3fZ").to_string()
+}
+
+/// Parses a PocketBase `date` field; empty or malformed values yield `None`.
+///
+/// ```
+/// use ifecaro::services::invite::parse_pb_datetime;
+///
+/// let parsed = parse_pb_datetime("2024-05-01 12:30:00.000Z").unwrap();
+/// assert_eq!(parsed.timestamp(), 1_714_566_600);
+/// assert!(parse_pb_datetime("").is_none());
+/// ```
+pub fn parse_pb_datetime(value: &str) -> Option<DateTime<Utc>> {
+    let trimmed = value.trim().trim_end_matches('Z');
+    if trimmed.is_empty() {
+        return None;
+    }
+    NaiveDateTime::parse_from_str(trimmed, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(trimmed, "%Y-%m-%dT%H:%M:%S%.f"))
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+/// Random code such as `INVITE-7KQM-X2PD`.
+pub fn generate_invite_code() -> String {
+    let segment = || {
+        (0..4)
+            .map(|_| {
+                let index = rand::random::<usize>() % INVITE_CODE_ALPHABET.len();
+                INVITE_CODE_ALPHABET[index] as char
+            })
+            .collect::<String>()
+    };
+    format!("{}-{}-{}", INVITE_CODE_PREFIX, segment(), segment())
+}
+
+/// Uppercases the code and strips everything but letters, digits and dashes.
+///
+/// The result is safe to embed in a PocketBase filter expression.
+///
+/// ```
+/// use ifecaro::services::invite::normalize_invite_code;
+///
+/// assert_eq!(normalize_invite_code(" invite-7kqm-x2pd "), "INVITE-7KQM-X2PD");
+/// assert_eq!(normalize_invite_code("a' || 1=1"), "A11");
+/// ```
+pub fn normalize_invite_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+impl Invite {
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        parse_pb_datetime(&self.expires)
+    }
+
+    /// Only approved codes expire; a missing expiry never does.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.status == InviteStatus::Approved
+            && self.expires_at().map(|at| at <= now).unwrap_or(false)
+    }
+
+    /// Checks that the invite can be redeemed by `email` at `now`.
+    pub fn check_redeemable(&self, email: &str, now: DateTime<Utc>) -> Result<(), InviteCodeError> {
+        match self.status {
+            InviteStatus::Pending | InviteStatus::Rejected => Err(InviteCodeError::NotApproved),
+            InviteStatus::Redeemed => Err(InviteCodeError::AlreadyUsed),
+            InviteStatus::Approved if self.is_expired(now) => Err(InviteCodeError::Expired),
+            InviteStatus::Approved if !self.email.trim().eq_ignore_ascii_case(email.trim()) => {
+                Err(InviteCodeError::EmailMismatch)
+            }
+            InviteStatus::Approved => Ok(()),
+        }
+    }
+}
+
+impl InviteUpdate {
+    /// Approval issues a fresh code, so re-approving an expired invite
+    /// invalidates the old one.
+    pub fn approve(now: DateTime<Utc>) -> Self {
+        Self {
+            status: InviteStatus::Approved,
+            code: Some(generate_invite_code()),
+            expires: Some(format_pb_datetime(
+                now + Duration::days(INVITE_CODE_TTL_DAYS),
+            )),
+            redeemed_by: None,
+        }
+    }
+
+    pub fn reject() -> Self {
+        Self {
+            status: InviteStatus::Rejected,
+            code: Some(String::new()),
+            expires: Some(String::new()),
+            redeemed_by: None,
+        }
+    }
+}
+
+pub async fn request_invite<C: ApiClient + ?Sized>(
+    client: &C,
+    name: &str,
+    email: &str,
+    reason: &str,
+) -> ApiResult<Invite> {
+    client
+        .create_invite(&NewInvite {
+            name: name.trim().to_string(),
+            email: email.trim().to_string(),
+            reason: reason.trim().to_string(),
+            status: InviteStatus::Pending,
+        })
+        .await
+}
+
+pub async fn approve_invite<C: ApiClient + ?Sized>(
+    client: &C,
+    invite_id: &str,
+    now: DateTime<Utc>,
+) -> ApiResult<Invite> {
+    client
+        .update_invite(invite_id, &InviteUpdate::approve(now))
+        .await
+}
+
+pub async fn reject_invite<C: ApiClient + ?Sized>(
+    client: &C,
+    invite_id: &str,
+) -> ApiResult<Invite> {
+    client
+        .update_invite(invite_id, &InviteUpdate::reject())
+        .await
+}
+
+/// Checks `code`, then creates the account with it.
+///
+/// The server repeats the check when the account is created and redeems the
+/// invite in the same transaction, so the early check only exists to tell the
+/// reader what is wrong with the code.
+pub async fn register_with_invite<C: ApiClient + ?Sized>(
+    client: &C,
+    code: &str,
+    request: &SignUpRequest,
+) -> Result<AuthSession, InviteError> {
+    let code = normalize_invite_code(code);
+    if code.is_empty() {
+        return Err(InviteCodeError::Unknown.into());
+    }
+    client.check_invite_code(&code, &request.email).await??;
+
+    let request = SignUpRequest {
+        invite_code: code,
+        ..request.clone()
+    };
+    Ok(register(client, &request).await?)
+}
@@ -0,0 +1,201 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::api::*;
+    use super::super::auth::{current_session, register, SignUpRequest};
+    use super::super::invite::*;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap()
+    }
+
+    /// Helper function: Build an approved invite that expires `ttl` after `now()`
+    fn approved_invite(code: &str, email: &str, ttl: Duration) -> Invite {
+        Invite {
+            id: "invite1".to_string(),
+            name: "Reader".to_string(),
+            email: email.to_string(),
+            reason: String::new(),
+            status: InviteStatus::Approved,
+            code: code.to_string(),
+            expires: format_pb_datetime(now() + ttl),
+            redeemed_by: String::new(),
+            created: String::new(),
+        }
+    }
+
+    fn sign_up(email: &str) -> SignUpRequest {
+        SignUpRequest {
+            email: email.to_string(),
+            password: "password1".to_string(),
+            password_confirm: "password1".to_string(),
+            name: "Reader".to_string(),
+            invite_code: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_invite_is_stored_as_pending() {
+        // Prepare test data
+        let client = MockApiClient::new();
+
+        // Execute test
+        let invite = request_invite(
+            &client,
+            " Reader ",
+            "reader@example.com",
+            "I love gamebooks",
+        )
+        .await
+        .unwrap();
+
+        // Verify result
+        assert_eq!(invite.name, "Reader");
+        assert_eq!(invite.status, InviteStatus::Pending);
+        assert!(invite.code.is_empty());
+        assert_eq!(client.list_invites().await.unwrap().items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_approve_issues_code_with_expiry_and_reject_clears_it() {
+        let client = MockApiClient::new();
+        let invite = request_invite(&client, "Reader", "reader@example.com", "")
+            .await
+            .unwrap();
+
+        let approved = approve_invite(&client, &invite.id, now()).await.unwrap();
+        assert_eq!(approved.status, InviteStatus::Approved);
+        assert!(approved.code.starts_with("INVITE-"));
+        assert_eq!(normalize_invite_code(&approved.code), approved.code);
+        assert_eq!(
+            approved.expires_at(),
+            Some(now() + Duration::days(INVITE_CODE_TTL_DAYS))
+        );
+
+        let rejected = reject_invite(&client, &invite.id).await.unwrap();
+        assert_eq!(rejected.status, InviteStatus::Rejected);
+        assert!(rejected.code.is_empty());
+        assert!(rejected.expires_at().is_none());
+    }
+
+    #[test]
+    fn test_check_redeemable_covers_every_state() {
+        let email = "reader@example.com";
+        let valid = approved_invite("INVITE-AAAA-BBBB", email, Duration::days(1));
+        assert_eq!(
+            valid.check_redeemable(" Reader@Example.com ", now()),
+            Ok(())
+        );
+        assert_eq!(
+            valid.check_redeemable("other@example.com", now()),
+            Err(InviteCodeError::EmailMismatch)
+        );
+
+        let expired = approved_invite("INVITE-AAAA-BBBB", email, Duration::seconds(-1));
+        assert!(expired.is_expired(now()));
+        assert_eq!(
+            expired.check_redeemable(email, now()),
+            Err(InviteCodeError::Expired)
+        );
+
+        for (status, expected) in [
+            (InviteStatus::Pending, InviteCodeError::NotApproved),
+            (InviteStatus::Rejected, InviteCodeError::NotApproved),
+            (InviteStatus::Redeemed, InviteCodeError::AlreadyUsed),
+        ] {
+            let invite = Invite {
+                status,
+                ..valid.clone()
+            };
+            assert_eq!(invite.check_redeemable(email, now()), Err(expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_with_invite_redeems_code_once() {
+        let client = MockApiClient::new()
+            .with_clock(now())
+            .with_invite(approved_invite(
+                "INVITE-AAAA-BBBB",
+                "reader@example.com",
+                Duration::days(1),
+            ));
+
+        let session =
+            register_with_invite(&client, "invite-aaaa-bbbb", &sign_up("reader@example.com"))
+                .await
+                .unwrap();
+
+        assert_eq!(current_session(), Some(session.clone()));
+        let invite = client.invites.borrow()[0].clone();
+        assert_eq!(invite.status, InviteStatus::Redeemed);
+        assert_eq!(invite.redeemed_by, session.record.id);
+
+        // A second registration with the same code is refused before any account is created
+        let second =
+            register_with_invite(&client, "INVITE-AAAA-BBBB", &sign_up("reader@example.com")).await;
+        assert!(matches!(
+            second,
+            Err(InviteError::Code(InviteCodeError::AlreadyUsed))
+        ));
+        assert_eq!(client.users.borrow().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_register_with_invalid_code_creates_no_account() {
+        let client = MockApiClient::new()
+            .with_clock(now())
+            .with_invite(approved_invite(
+                "INVITE-AAAA-BBBB",
+                "reader@example.com",
+                Duration::days(-1),
+            ));
+
+        let unknown =
+            register_with_invite(&client, "INVITE-ZZZZ-ZZZZ", &sign_up("reader@example.com")).await;
+        let expired =
+            register_with_invite(&client, "INVITE-AAAA-BBBB", &sign_up("reader@example.com")).await;
+
+        assert!(matches!(
+            unknown,
+            Err(InviteError::Code(InviteCodeError::Unknown))
+        ));
+        assert!(matches!(
+            expired,
+            Err(InviteError::Code(InviteCodeError::Expired))
+        ));
+        assert!(client.users.borrow().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sign_up_without_redeemable_code_is_refused_by_the_server() {
+        let client = MockApiClient::new()
+            .with_invite_only()
+            .with_clock(now())
+            .with_invite(approved_invite(
+                "INVITE-AAAA-BBBB",
+                "reader@example.com",
+                Duration::days(1),
+            ));
+
+        // Skipping the client-side check does not get around the invite
+        let without_code = register(&client, &sign_up("reader@example.com")).await;
+        assert!(matches!(without_code, Err(ApiError::ServerError(400))));
+
+        let with_code = SignUpRequest {
+            invite_code: "INVITE-AAAA-BBBB".to_string(),
+            ..sign_up("reader@example.com")
+        };
+        register(&client, &with_code).await.unwrap();
+        let reused = register(
+            &client,
+            &SignUpRequest {
+                email: "other@example.com".to_string(),
+                ..with_code
+            },
+        )
+        .await;
+        assert!(matches!(reused, Err(ApiError::ServerError(400))));
+        assert_eq!(client.users.borrow().len(), 1);
+    }
+}
@@ -0,0 +1,48 @@
+//! Offline copies of story media, kept by the service worker in
+//! `public/media-sw.js`. It only handles media; the rest of the app always
+//! goes to the network.
+//!
+//! Files under `/api/files/` never change once uploaded, so the worker
+//! serves them from its media cache first. [`cache_media`] asks it to fetch
+//! files before the reader needs them.
+
+use serde::Serialize;
+
+/// Message understood by the worker's `message` handler.
+#[derive(Serialize)]
+struct CacheMediaMessage<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    urls: &'a [String],
+}
+
+/// Registers the worker at `script_url`; failures only cost offline support.
+pub fn register_service_worker(script_url: &str) {
+    if !cfg!(target_arch = "wasm32") {
+        return;
+    }
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let _ = window.navigator().service_worker().register(script_url);
+}
+
+/// Asks the active worker to download `urls` into its media cache.
+pub fn cache_media(urls: &[String]) {
+    if !cfg!(target_arch = "wasm32") || urls.is_empty() {
+        return;
+    }
+    let Some(worker) = web_sys::window().and_then(|w| w.navigator().service_worker().controller())
+    else {
+        return;
+    };
+    let message = CacheMediaMessage {
+        kind: "CACHE_MEDIA",
+        urls,
+    };
+    if let Ok(value) = serde_wasm_bindgen::to_value(&message) {
+        if let Err(error) = worker.post_message(&value) {
+            tracing::warn!(?error, "Could not ask the service worker to cache media");
+        }
+    }
+}
@@ -1,5 +1,40 @@
+pub mod achievements;
 pub mod api;
+pub mod audio;
+pub mod auth;
+pub mod bookmarks;
+pub mod bundle;
+pub mod chapters;
+pub mod countdown;
 pub mod indexeddb;
+pub mod invite;
+pub mod media_cache;
+pub mod outbox;
+pub mod paragraphs;
+pub mod progress;
+pub mod retry;
+pub mod revisions;
+pub mod speech;
 
+#[cfg(test)]
+mod achievements_tests;
 #[cfg(test)]
 mod api_tests;
+#[cfg(test)]
+mod audio_tests;
+#[cfg(test)]
+mod auth_tests;
+#[cfg(test)]
+mod bundle_tests;
+#[cfg(test)]
+mod chapters_tests;
+#[cfg(test)]
+mod countdown_tests;
+#[cfg(test)]
+mod invite_tests;
+#[cfg(test)]
+mod paragraphs_tests;
+#[cfg(test)]
+mod retry_tests;
+#[cfg(test)]
+mod revisions_tests;
@@ -0,0 +1,267 @@
+use crate::constants::config::BATCH;
+use crate::services::api::{ApiError, ApiResult};
+use crate::services::auth::with_auth;
+use crate::services::indexeddb::{get_outbox_from_indexeddb, set_outbox_to_indexeddb};
+use crate::services::retry::{send_with_retry, RetryPolicy};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::future::Future;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OutboxMethod {
+    Post,
+    Patch,
+    Put,
+    Delete,
+}
+
+/// A dashboard write that could not reach the server and waits to be replayed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutboxEntry {
+    pub id: String,
+    pub method: OutboxMethod,
+    pub url: String,
+    #[serde(default)]
+    pub body: Option<Value>,
+    pub queued_at: i64,
+    #[serde(default)]
+    pub attempts: u32,
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+impl OutboxEntry {
+    pub fn new(method: OutboxMethod, url: impl Into<String>, body: Option<Value>) -> Self {
+        let queued_at = chrono::Utc::now().timestamp_millis();
+        Self {
+            id: format!("{}-{:08x}", queued_at, rand::random::<u32>()),
+            method,
+            url: url.into(),
+            body,
+            queued_at,
+            attempts: 0,
+            last_error: None,
+        }
+    }
+
+    /// Whether a write that failed with `error` can be queued for replay.
+    ///
+    /// Updates are idempotent, so any transient failure will do. A create is
+    /// only queued when the request failed in transport: after a timeout or a
+    /// 5xx the server may already have created the record.
+    pub fn should_queue(method: OutboxMethod, error: &ApiError) -> bool {
+        match method {
+            OutboxMethod::Post => matches!(error, ApiError::NetworkError(_)),
+            OutboxMethod::Patch | OutboxMethod::Put | OutboxMethod::Delete => error.is_retryable(),
+        }
+    }
+
+    /// URL of a record the entry creates with an id of its own, which makes
+    /// replaying it safe: a second attempt is rejected instead of creating a
+    /// duplicate, and the record existing proves the first one went through.
+    ///
+    /// For a batch this is its last such create, usually the revision that
+    /// records the write.
+    pub fn created_record_url(&self) -> Option<String> {
+        if self.method != OutboxMethod::Post {
+            return None;
+        }
+        let body = self.body.as_ref()?;
+        if let Some(id) = body.get("id").and_then(Value::as_str) {
+            return Some(format!("{}/{}", self.url, id));
+        }
+        let root = self.url.strip_suffix(&format!("/api{}", BATCH))?;
+        body.get("requests")?
+            .as_array()?
+            .iter()
+            .rev()
+            .filter(|request| request.get("method").and_then(Value::as_str) == Some("POST"))
+            .find_map(|request| {
+                let url = request.get("url")?.as_str()?;
+                let id = request.get("body")?.get("id")?.as_str()?;
+                Some(format!("{}{}/{}", root, url, id))
+            })
+    }
+
+    /// Policy for replaying this entry: creates are sent once per flush.
+    pub fn replay_policy(&self, policy: &RetryPolicy) -> RetryPolicy {
+        if self.method == OutboxMethod::Post {
+            policy.clone().with_max_attempts(1)
+        } else {
+            policy.clone()
+        }
+    }
+
+    fn to_request(&self, client: &reqwest::Client) -> reqwest::RequestBuilder {
+        let builder = match self.method {
+            OutboxMethod::Post => client.post(&self.url),
+            OutboxMethod::Patch => client.patch(&self.url),
+            OutboxMethod::Put => client.put(&self.url),
+            OutboxMethod::Delete => client.delete(&self.url),
+        };
+        // 使用重送當下的登入權杖，而非排入佇列時的權杖
+        let builder = with_auth(builder);
+        match &self.body {
+            Some(body) => builder.json(body),
+            None => builder,
+        }
+    }
+}
+
+/// FIFO queue of pending writes, persisted as one JSON document in IndexedDB.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Outbox {
+    pub entries: Vec<OutboxEntry>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OutboxReplayReport {
+    pub sent: usize,
+    /// Entries the server rejected for good (4xx, parse errors); they are removed.
+    pub dropped: Vec<(OutboxEntry, ApiError)>,
+    pub remaining: usize,
+}
+
+impl Outbox {
+    pub fn from_json(raw: &str) -> Self {
+        serde_json::from_str(raw).unwrap_or_else(|error| {
+            tracing::error!(error = %error, "Failed to parse outbox, starting empty");
+            Self::default()
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{\"entries\":[]}".to_string())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Appends `entry`. A queued PATCH/PUT to the same record is replaced in
+    /// place, since the dashboard always sends the full record and only the
+    /// newest body matters; POSTs create records and are never merged.
+    pub fn enqueue(&mut self, entry: OutboxEntry) {
+        let mergeable = matches!(entry.method, OutboxMethod::Patch | OutboxMethod::Put);
+        if mergeable {
+            if let Some(existing) = self
+                .entries
+                .iter_mut()
+                .find(|e| e.method == entry.method && e.url == entry.url)
+            {
+                existing.body = entry.body;
+                existing.queued_at = entry.queued_at;
+                existing.attempts = 0;
+                existing.last_error = None;
+                return;
+            }
+        }
+        self.entries.push(entry);
+    }
+
+    /// Replays entries in order through `send`.
+    ///
+    /// Stops at the first retryable failure so that later writes never overtake
+    /// earlier ones; non-retryable failures are dropped and reported.
+    pub async fn replay_with<F, Fut>(&mut self, mut send: F) -> OutboxReplayReport
+    where
+        F: FnMut(&OutboxEntry) -> Fut,
+        Fut: Future<Output = ApiResult<()>>,
+    {
+        let mut report = OutboxReplayReport::default();
+        while let Some(entry) = self.entries.first().cloned() {
+            match send(&entry).await {
+                Ok(()) => {
+                    self.entries.remove(0);
+                    report.sent += 1;
+                }
+                Err(error) if error.is_retryable() => {
+                    if let Some(head) = self.entries.first_mut() {
+                        head.attempts += 1;
+                        head.last_error = Some(error.to_string());
+                    }
+                    break;
+                }
+                Err(error) => {
+                    self.entries.remove(0);
+                    report.dropped.push((entry, error));
+                }
+            }
+        }
+        report.remaining = self.entries.len();
+        report
+    }
+}
+
+pub async fn load_outbox() -> Outbox {
+    match get_outbox_from_indexeddb().await {
+        Ok(value) => value
+            .as_string()
+            .map(|raw| Outbox::from_json(&raw))
+            .unwrap_or_default(),
+        Err(error) => {
+            tracing::error!(error = ?error, "Failed to read outbox from IndexedDB");
+            Outbox::default()
+        }
+    }
+}
+
+pub async fn save_outbox(outbox: &Outbox) {
+    if let Err(error) = set_outbox_to_indexeddb(&outbox.to_json()).await {
+        tracing::error!(error = ?error, "Failed to write outbox to IndexedDB");
+    }
+}
+
+/// Persists `entry` and returns the new number of pending changes.
+pub async fn queue_request(entry: OutboxEntry) -> usize {
+    let mut outbox = load_outbox().await;
+    outbox.enqueue(entry);
+    save_outbox(&outbox).await;
+    outbox.len()
+}
+
+/// Sends everything queued in IndexedDB and persists what is left.
+pub async fn flush_outbox(policy: &RetryPolicy) -> OutboxReplayReport {
+    let mut outbox = load_outbox().await;
+    if outbox.is_empty() {
+        return OutboxReplayReport::default();
+    }
+    let client = reqwest::Client::new();
+    let report = outbox
+        .replay_with(|entry| {
+            let entry = entry.clone();
+            let client = client.clone();
+            async move {
+                let policy = entry.replay_policy(policy);
+                let response = send_with_retry(&policy, || entry.to_request(&client)).await?;
+                if response.status().is_success() {
+                    return Ok(());
+                }
+                // A create rejected because its id is taken went through earlier
+                if let Some(url) = entry.created_record_url() {
+                    if let Ok(existing) = with_auth(client.get(&url)).send().await {
+                        if existing.status().is_success() {
+                            return Ok(());
+                        }
+                    }
+                }
+                Err(ApiError::ServerError(response.status().as_u16()))
+            }
+        })
+        .await;
+    save_outbox(&outbox).await;
+    for (entry, error) in &report.dropped {
+        tracing::error!(
+            url = %entry.url,
+            method = ?entry.method,
+            error = %error,
+            "Outbox entry rejected by server, dropped"
+        );
+    }
+    report
+}
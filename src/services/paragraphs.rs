@@ -0,0 +1,311 @@
+use crate::constants::config::PARAGRAPHS;
+use crate::models::paragraph::Paragraph;
+use crate::models::references::{
+    inbound_references, repair_references, InboundReference, ReferenceRepair,
+};
+use crate::models::subtree::{clone_paragraphs, subtree_ids};
+use crate::services::api::{ApiClient, ApiError, ApiResult, BatchRequest, BATCH_LIMIT};
+use crate::services::auth::AuthUser;
+use crate::services::revisions::revision_request;
+use std::collections::HashSet;
+
+const RECORD_ID_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+const RECORD_ID_LENGTH: usize = 15;
+/// Paragraphs per batch in [`save_paragraph_texts`]. Each one takes two
+/// requests, which keeps a batch within PocketBase's default limit of 50.
+pub const TEXT_SAVE_CHUNK: usize = 25;
+
+/// Random id in PocketBase's default record id format, so new records can be
+/// linked to each other before they are saved.
+pub fn new_record_id() -> String {
+    (0..RECORD_ID_LENGTH)
+        .map(|_| {
+            let index = rand::random::<usize>() % RECORD_ID_ALPHABET.len();
+            RECORD_ID_ALPHABET[index] as char
+        })
+        .collect()
+}
+
+/// What deleting a single paragraph breaks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParagraphDeletePlan {
+    pub paragraph_id: String,
+    pub inbound: Vec<InboundReference>,
+}
+
+pub fn plan_paragraph_delete(paragraph_id: &str, paragraphs: &[Paragraph]) -> ParagraphDeletePlan {
+    let targets: HashSet<&str> = [paragraph_id].into_iter().collect();
+    ParagraphDeletePlan {
+        paragraph_id: paragraph_id.to_string(),
+        inbound: inbound_references(paragraphs, &targets),
+    }
+}
+
+/// Paragraphs that need saving before `plan` can run, plus the batch that
+/// saves them and deletes the paragraph.
+///
+/// Fails with `NotFound` when `repair` retargets to the deleted paragraph or
+/// to one that does not exist.
+pub fn build_delete_batch(
+    plan: &ParagraphDeletePlan,
+    paragraphs: &[Paragraph],
+    repair: &ReferenceRepair,
+    author: Option<&AuthUser>,
+) -> ApiResult<(Vec<Paragraph>, Vec<BatchRequest>)> {
+    build_repair_batch(
+        paragraphs,
+        &plan.inbound,
+        std::slice::from_ref(&plan.paragraph_id),
+        repair,
+        author,
+    )
+}
+
+/// Patches that apply `repair` to `inbound`, each followed by the revision
+/// recording it, then the deletes of `deleted`; plus the repaired paragraphs.
+///
+/// Repairs come first so a batch split across several requests never leaves
+/// a choice pointing at a paragraph that is already gone. Fails with
+/// `NotFound` when `repair` retargets to a deleted paragraph or to one that
+/// does not exist.
+pub fn build_repair_batch(
+    paragraphs: &[Paragraph],
+    inbound: &[InboundReference],
+    deleted: &[String],
+    repair: &ReferenceRepair,
+    author: Option<&AuthUser>,
+) -> ApiResult<(Vec<Paragraph>, Vec<BatchRequest>)> {
+    if let ReferenceRepair::Retarget(target) = repair {
+        if !inbound.is_empty()
+            && (deleted.contains(target) || !paragraphs.iter().any(|p| p.id == *target))
+        {
+            return Err(ApiError::NotFound);
+        }
+    }
+    let repaired = repair_references(paragraphs, inbound, repair);
+    let mut requests = Vec::with_capacity(repaired.len() * 2 + deleted.len());
+    for paragraph in &repaired {
+        requests.push(BatchRequest::patch(
+            PARAGRAPHS,
+            &paragraph.id,
+            serde_json::json!({
+                "choices": paragraph.choices,
+                "texts": paragraph.texts,
+            }),
+        ));
+        requests.push(revision_request(paragraph, author)?);
+    }
+    requests.extend(
+        deleted
+            .iter()
+            .map(|id| BatchRequest::delete(PARAGRAPHS, id)),
+    );
+    Ok((repaired, requests))
+}
+
+/// Repairs every inbound reference and deletes the paragraph in one batch.
+///
+/// A paragraph with too many inbound references for that is split into
+/// batches of [`BATCH_LIMIT`] in the same order, so the delete goes out last
+/// and a failure part-way leaves the paragraph in place. Returns the repaired
+/// paragraphs so callers can update local state.
+pub async fn delete_paragraph_with_repair<C: ApiClient + ?Sized>(
+    client: &C,
+    plan: &ParagraphDeletePlan,
+    paragraphs: &[Paragraph],
+    repair: &ReferenceRepair,
+    author: Option<&AuthUser>,
+) -> ApiResult<Vec<Paragraph>> {
+    let (repaired, requests) = build_delete_batch(plan, paragraphs, repair, author)?;
+    if requests.len() == 1 {
+        client.delete_paragraph(&plan.paragraph_id).await?;
+        return Ok(repaired);
+    }
+    // Repairs come first as patch/revision pairs and the limit is even, so no
+    // patch is split from its revision
+    for chunk in requests.chunks(BATCH_LIMIT) {
+        client.batch(chunk).await?;
+    }
+    Ok(repaired)
+}
+
+/// Saves the choices of `paragraph` and records a revision in one batch.
+pub async fn save_paragraph_choices<C: ApiClient + ?Sized>(
+    client: &C,
+    paragraph: &Paragraph,
+    author: Option<&AuthUser>,
+) -> ApiResult<()> {
+    let requests = vec![
+        BatchRequest::patch(
+            PARAGRAPHS,
+            &paragraph.id,
+            serde_json::json!({ "choices": paragraph.choices }),
+        ),
+        revision_request(paragraph, author)?,
+    ];
+    client.batch(&requests).await
+}
+
+/// Batch for a dashboard submit: `body` as sent to the `paragraphs`
+/// collection, then a revision of `saved`, the paragraph as it will be stored.
+///
+/// A create gets the id of `saved` so a replay cannot create the paragraph
+/// twice.
+pub fn build_submit_batch(
+    body: serde_json::Value,
+    saved: &Paragraph,
+    create: bool,
+    author: Option<&AuthUser>,
+) -> ApiResult<Vec<BatchRequest>> {
+    let write = if create {
+        let mut body = body;
+        if let Some(fields) = body.as_object_mut() {
+            fields.insert("id".to_string(), saved.id.clone().into());
+        }
+        BatchRequest::create(PARAGRAPHS, body)
+    } else {
+        BatchRequest::patch(PARAGRAPHS, &saved.id, body)
+    };
+    Ok(vec![write, revision_request(saved, author)?])
+}
+
+/// Id of the paragraph `request` creates, when it is a paragraph create sent
+/// with its own id. Revision creates are left out: a paragraph's revisions
+/// are deleted with it.
+fn created_paragraph(request: &BatchRequest) -> Option<&str> {
+    if request.method != "POST" || request.url != format!("/api{}", PARAGRAPHS) {
+        return None;
+    }
+    request.body.as_ref()?.get("id")?.as_str()
+}
+
+/// Why [`clone_subtree`] failed, and which copies it could not take back.
+#[derive(Debug, Clone)]
+pub struct CloneFailure {
+    pub error: ApiError,
+    /// Ids of copies that may still exist because deleting them failed.
+    pub left_behind: Vec<String>,
+}
+
+impl From<ApiError> for CloneFailure {
+    fn from(error: ApiError) -> Self {
+        Self {
+            error,
+            left_behind: Vec::new(),
+        }
+    }
+}
+
+/// Deletes the copies created by `committed`, in batches, and those of
+/// `uncertain`, a batch that timed out and may or may not have gone through,
+/// one at a time so copies that were never created do not fail the rest.
+/// Returns the ids that could not be deleted.
+async fn roll_back_clones<C: ApiClient + ?Sized>(
+    client: &C,
+    committed: &[BatchRequest],
+    uncertain: &[BatchRequest],
+) -> Vec<String> {
+    let mut left_behind = Vec::new();
+    let committed: Vec<&str> = committed.iter().filter_map(created_paragraph).collect();
+    for ids in committed.chunks(BATCH_LIMIT) {
+        let deletes: Vec<BatchRequest> = ids
+            .iter()
+            .map(|id| BatchRequest::delete(PARAGRAPHS, id))
+            .collect();
+        if client.batch(&deletes).await.is_err() {
+            left_behind.extend(ids.iter().map(|id| id.to_string()));
+        }
+    }
+    for id in uncertain.iter().filter_map(created_paragraph) {
+        match client.delete_paragraph(id).await {
+            Ok(()) | Err(ApiError::NotFound) => {}
+            Err(_) => left_behind.push(id.to_string()),
+        }
+    }
+    left_behind
+}
+
+/// Copies `root_id` and its subtree down to `depth` (0 duplicates only the
+/// paragraph) and creates the copies, each with its first revision, in
+/// batches of up to [`BATCH_LIMIT`] requests.
+///
+/// A subtree that needs several batches is all-or-nothing as far as the
+/// client can make it: when a later batch fails, the copies already created
+/// are deleted again before the error is returned, and any that could not be
+/// are listed in it. Returns the copies with the new root first.
+pub async fn clone_subtree<C: ApiClient + ?Sized>(
+    client: &C,
+    paragraphs: &[Paragraph],
+    root_id: &str,
+    depth: usize,
+    author: Option<&AuthUser>,
+) -> Result<Vec<Paragraph>, CloneFailure> {
+    let ids = subtree_ids(paragraphs, root_id, depth);
+    if ids.is_empty() {
+        return Err(ApiError::NotFound.into());
+    }
+    let clones = clone_paragraphs(paragraphs, &ids, new_record_id);
+    let mut requests = Vec::with_capacity(clones.len() * 2);
+    for paragraph in &clones {
+        let body =
+            serde_json::to_value(paragraph).map_err(|e| ApiError::ParseError(e.to_string()))?;
+        requests.push(BatchRequest::create(PARAGRAPHS, body));
+        requests.push(revision_request(paragraph, author)?);
+    }
+    let mut created = 0;
+    // Each copy takes two requests and the limit is even, so no copy is split
+    // from its revision
+    for chunk in requests.chunks(BATCH_LIMIT) {
+        if let Err(error) = client.batch(chunk).await {
+            // A batch that timed out may still have been applied
+            let uncertain = if matches!(error, ApiError::Timeout) {
+                chunk
+            } else {
+                &[]
+            };
+            let left_behind = roll_back_clones(client, &requests[..created], uncertain).await;
+            return Err(CloneFailure { error, left_behind });
+        }
+        created += chunk.len();
+    }
+    Ok(clones)
+}
+
+/// How far [`save_paragraph_texts`] got before a batch failed.
+#[derive(Debug, Clone)]
+pub struct PartialSave {
+    /// Number of leading paragraphs that were saved.
+    pub saved: usize,
+    pub error: ApiError,
+}
+
+/// Saves the texts of `edited` and records a revision for each paragraph.
+///
+/// Paragraphs are sent in batches of [`TEXT_SAVE_CHUNK`]; each batch is
+/// atomic, but a failure leaves earlier batches applied, which the error
+/// reports.
+pub async fn save_paragraph_texts<C: ApiClient + ?Sized>(
+    client: &C,
+    edited: &[Paragraph],
+    author: Option<&AuthUser>,
+) -> Result<(), PartialSave> {
+    for (chunk_index, chunk) in edited.chunks(TEXT_SAVE_CHUNK).enumerate() {
+        let saved = chunk_index * TEXT_SAVE_CHUNK;
+        let mut requests = Vec::with_capacity(chunk.len() * 2);
+        for paragraph in chunk {
+            let revision = revision_request(paragraph, author)
+                .map_err(|error| PartialSave { saved, error })?;
+            requests.push(BatchRequest::patch(
+                PARAGRAPHS,
+                &paragraph.id,
+                serde_json::json!({ "texts": paragraph.texts }),
+            ));
+            requests.push(revision);
+        }
+        client
+            .batch(&requests)
+            .await
+            .map_err(|error| PartialSave { saved, error })?;
+    }
+    Ok(())
+}
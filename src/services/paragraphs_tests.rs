@@ -0,0 +1,421 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::api::*;
+    use super::super::paragraphs::*;
+    use crate::models::paragraph::{Paragraph, ParagraphChoice, Text};
+    use crate::models::references::{repair_references, ReferenceRepair};
+    use serde_json::json;
+
+    /// Helper function: Create a paragraph with one caption per choice
+    fn paragraph(id: &str, choices: Vec<ParagraphChoice>) -> Paragraph {
+        Paragraph {
+            id: id.to_string(),
+            chapter_id: "c1".to_string(),
+            texts: vec![Text {
+                lang: "en-US".to_string(),
+                paragraphs: format!("Paragraph {}", id),
+                choices: (0..choices.len())
+                    .map(|i| format!("Choice {}", i))
+                    .collect(),
+                ..Default::default()
+            }],
+            choices,
+            ..Default::default()
+        }
+    }
+
+    fn goto(target: &str) -> ParagraphChoice {
+        ParagraphChoice::goto(vec![target.to_string()])
+    }
+
+    fn sample() -> Vec<Paragraph> {
+        vec![
+            paragraph(
+                "start",
+                vec![
+                    goto("hall"),
+                    goto("trap"),
+                    ParagraphChoice {
+                        time_limit: Some(10),
+                        timeout_to: Some("trap".to_string()),
+                        ..goto("hall")
+                    },
+                ],
+            ),
+            paragraph("hall", vec![goto("trap"), goto("start")]),
+            paragraph("trap", vec![goto("trap")]),
+        ]
+    }
+
+    #[test]
+    fn test_plan_lists_goto_and_timeout_references() {
+        let plan = plan_paragraph_delete("trap", &sample());
+
+        let sources: Vec<(&str, usize)> = plan
+            .inbound
+            .iter()
+            .map(|r| (r.paragraph_id.as_str(), r.choice_index))
+            .collect();
+        // The self-reference inside "trap" goes away with it
+        assert_eq!(sources, vec![("start", 1), ("start", 2), ("hall", 0)]);
+    }
+
+    #[test]
+    fn test_retarget_repair_rewrites_every_slot() {
+        let paragraphs = sample();
+        let plan = plan_paragraph_delete("trap", &paragraphs);
+
+        let repaired = repair_references(
+            &paragraphs,
+            &plan.inbound,
+            &ReferenceRepair::Retarget("hall".to_string()),
+        );
+
+        assert_eq!(repaired.len(), 2);
+        assert_eq!(repaired[0].choices[1].to, vec!["hall".to_string()]);
+        assert_eq!(repaired[0].choices[2].timeout_to.as_deref(), Some("hall"));
+        assert_eq!(repaired[1].choices[0].to, vec!["hall".to_string()]);
+    }
+
+    #[test]
+    fn test_remove_repair_drops_choices_and_captions() {
+        let paragraphs = sample();
+        let plan = plan_paragraph_delete("trap", &paragraphs);
+
+        let repaired =
+            repair_references(&paragraphs, &plan.inbound, &ReferenceRepair::RemoveChoices);
+
+        let start = &repaired[0];
+        assert_eq!(start.choices.len(), 2);
+        assert_eq!(start.texts[0].choices, vec!["Choice 0", "Choice 2"]);
+        // The timed choice stays, only its countdown is gone
+        assert_eq!(start.choices[1].to, vec!["hall".to_string()]);
+        assert_eq!(start.choices[1].timeout_to, None);
+        assert_eq!(start.choices[1].time_limit, None);
+        assert_eq!(repaired[1].choices, vec![goto("start")]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_with_repair_sends_one_batch() {
+        // Prepare test data
+        let paragraphs = sample();
+        let client = MockApiClient::new().with_paragraphs(paragraphs.clone());
+        let plan = plan_paragraph_delete("trap", &paragraphs);
+
+        // Execute test
+        let repaired = delete_paragraph_with_repair(
+            &client,
+            &plan,
+            &paragraphs,
+            &ReferenceRepair::Retarget("start".to_string()),
+            None,
+        )
+        .await
+        .unwrap();
+
+        // Verify result
+        assert_eq!(repaired.len(), 2);
+        let batches = client.batches.borrow();
+        assert_eq!(batches.len(), 1);
+        let methods: Vec<(&str, &str)> = batches[0]
+            .iter()
+            .map(|r| (r.method.as_str(), r.url.as_str()))
+            .collect();
+        assert_eq!(
+            methods,
+            vec![
+                ("PATCH", "/api/collections/paragraphs/records/start"),
+                ("POST", "/api/collections/paragraph_revisions/records"),
+                ("PATCH", "/api/collections/paragraphs/records/hall"),
+                ("POST", "/api/collections/paragraph_revisions/records"),
+                ("DELETE", "/api/collections/paragraphs/records/trap"),
+            ]
+        );
+        assert_eq!(
+            batches[0][3].body.as_ref().unwrap()["paragraph"],
+            json!("hall")
+        );
+        assert_eq!(
+            batches[0][2].body.as_ref().unwrap()["choices"][0],
+            json!({ "to": ["start"], "type": "goto" })
+        );
+        assert!(client.deleted.borrow().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_with_many_references_is_split_with_the_delete_last() {
+        // Prepare test data: more inbound references than fit in one batch
+        let count = BATCH_LIMIT / 2 + 1;
+        let mut paragraphs: Vec<Paragraph> = (0..count)
+            .map(|i| paragraph(&format!("p{}", i), vec![goto("trap")]))
+            .collect();
+        paragraphs.push(paragraph("trap", vec![]));
+        let client = MockApiClient::new().with_paragraphs(paragraphs.clone());
+        let plan = plan_paragraph_delete("trap", &paragraphs);
+
+        // Execute test
+        let repaired = delete_paragraph_with_repair(
+            &client,
+            &plan,
+            &paragraphs,
+            &ReferenceRepair::RemoveChoices,
+            None,
+        )
+        .await
+        .unwrap();
+
+        // Verify result
+        assert_eq!(repaired.len(), count);
+        let batches = client.batches.borrow();
+        let sizes: Vec<usize> = batches.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![BATCH_LIMIT, 3]);
+        let last = batches[1].last().unwrap();
+        assert_eq!(last.method, "DELETE");
+        assert_eq!(last.url, "/api/collections/paragraphs/records/trap");
+    }
+
+    #[tokio::test]
+    async fn test_delete_rejects_bad_retarget_and_skips_batch_when_unreferenced() {
+        let paragraphs = sample();
+        let client = MockApiClient::new().with_paragraphs(paragraphs.clone());
+
+        let plan = plan_paragraph_delete("trap", &paragraphs);
+        for target in ["trap", "missing"] {
+            let repair = ReferenceRepair::Retarget(target.to_string());
+            let result =
+                delete_paragraph_with_repair(&client, &plan, &paragraphs, &repair, None).await;
+            assert!(matches!(result, Err(ApiError::NotFound)));
+        }
+
+        let plan = plan_paragraph_delete("hall", &paragraphs[1..]);
+        assert!(plan.inbound.is_empty());
+        delete_paragraph_with_repair(
+            &client,
+            &plan,
+            &paragraphs,
+            &ReferenceRepair::RemoveChoices,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(client.batches.borrow().is_empty());
+        assert_eq!(
+            *client.deleted.borrow(),
+            vec!["paragraphs/hall".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_clone_subtree_creates_copies_in_one_batch() {
+        // Prepare test data
+        let paragraphs = sample();
+        let client = MockApiClient::new().with_paragraphs(paragraphs.clone());
+
+        // Execute test
+        let copies = clone_subtree(&client, &paragraphs, "start", 1, None)
+            .await
+            .unwrap();
+        let missing = clone_subtree(&client, &paragraphs, "missing", 1, None).await;
+
+        // Verify result
+        assert!(matches!(
+            missing,
+            Err(CloneFailure {
+                error: ApiError::NotFound,
+                ..
+            })
+        ));
+        assert_eq!(copies.len(), 3);
+        assert!(copies
+            .iter()
+            .all(|p| p.id.len() == 15 && !paragraphs.contains(p)));
+        assert_eq!(copies[0].choices[0].to, vec![copies[1].id.clone()]);
+        let batches = client.batches.borrow();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 6);
+        assert!(batches[0].iter().all(|r| r.method == "POST"));
+        for (copy, pair) in copies.iter().zip(batches[0].chunks(2)) {
+            assert_eq!(pair[0].url, "/api/collections/paragraphs/records");
+            assert_eq!(pair[0].body.as_ref().unwrap()["id"], json!(copy.id));
+            assert_eq!(pair[1].url, "/api/collections/paragraph_revisions/records");
+            assert_eq!(pair[1].body.as_ref().unwrap()["paragraph"], json!(copy.id));
+        }
+    }
+
+    fn chain(count: usize) -> Vec<Paragraph> {
+        (0..count)
+            .map(|i| {
+                let next = (i + 1 < count).then(|| goto(&format!("p{}", i + 1)));
+                paragraph(&format!("p{}", i), next.into_iter().collect())
+            })
+            .collect()
+    }
+
+    fn created_paragraphs(batch: &[BatchRequest]) -> Vec<String> {
+        batch
+            .iter()
+            .filter(|r| r.url == "/api/collections/paragraphs/records")
+            .map(|r| r.body.as_ref().unwrap()["id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_large_clone_is_split_and_rolled_back_on_failure() {
+        // Prepare test data: a chain longer than one batch
+        let count = BATCH_LIMIT + 5;
+        let chain = chain(count);
+
+        // Execute test
+        let client = MockApiClient::new();
+        let copies = clone_subtree(&client, &chain, "p0", count, None)
+            .await
+            .unwrap();
+        let failing = MockApiClient::new().with_failing_batch(1);
+        let result = clone_subtree(&failing, &chain, "p0", count, None).await;
+
+        // Verify result
+        assert_eq!(copies.len(), count);
+        let sizes: Vec<usize> = client.batches.borrow().iter().map(Vec::len).collect();
+        // Two requests per copy: the paragraph and its first revision
+        assert_eq!(sizes, vec![BATCH_LIMIT, BATCH_LIMIT, 10]);
+
+        let failure = result.unwrap_err();
+        assert!(matches!(failure.error, ApiError::ServerError(400)));
+        assert!(failure.left_behind.is_empty());
+        let batches = failing.batches.borrow();
+        assert_eq!(batches.len(), 2);
+        // Only the paragraphs are deleted; their revisions go with them
+        assert!(batches[1].iter().all(|r| r.method == "DELETE"));
+        let removed: Vec<String> = batches[1]
+            .iter()
+            .map(|r| r.url.rsplit('/').next().unwrap().to_string())
+            .collect();
+        assert_eq!(removed, created_paragraphs(&batches[0]));
+    }
+
+    #[tokio::test]
+    async fn test_clone_rolls_back_a_timed_out_batch_and_reports_leftovers() {
+        let count = BATCH_LIMIT + 5;
+        let chain = chain(count);
+
+        // The second batch went through but its response was lost
+        let timed_out = MockApiClient::new()
+            .with_failing_batch(1)
+            .with_batch_error(ApiError::Timeout, 1);
+        let result = clone_subtree(&timed_out, &chain, "p0", count, None).await;
+
+        let failure = result.unwrap_err();
+        assert!(matches!(failure.error, ApiError::Timeout));
+        assert!(failure.left_behind.is_empty());
+        let batches = timed_out.batches.borrow().clone();
+        assert_eq!(batches.len(), 3);
+        let deleted: Vec<String> = timed_out
+            .deleted
+            .borrow()
+            .iter()
+            .map(|key| key.trim_start_matches("paragraphs/").to_string())
+            .collect();
+        assert_eq!(deleted, created_paragraphs(&batches[1]));
+
+        // The rollback batch is rejected too
+        let rejected = MockApiClient::new()
+            .with_failing_batch(1)
+            .with_batch_error(ApiError::ServerError(403), 2);
+        let result = clone_subtree(&rejected, &chain, "p0", count, None).await;
+
+        let failure = result.unwrap_err();
+        assert!(matches!(failure.error, ApiError::ServerError(403)));
+        assert_eq!(
+            failure.left_behind,
+            created_paragraphs(&rejected.batches.borrow()[0])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_choice_and_submit_saves_carry_their_revision() {
+        // Prepare test data
+        let client = MockApiClient::new();
+        let mut edited = sample().remove(1);
+        edited.choices.reverse();
+
+        // Execute test
+        save_paragraph_choices(&client, &edited, None)
+            .await
+            .unwrap();
+        let body = json!({ "texts": edited.texts });
+        let created = build_submit_batch(body.clone(), &edited, true, None).unwrap();
+        let updated = build_submit_batch(body, &edited, false, None).unwrap();
+
+        // Verify result
+        let batches = client.batches.borrow();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0][0].method, "PATCH");
+        assert_eq!(
+            batches[0][0].body,
+            Some(
+                json!({ "choices": [{ "to": ["start"], "type": "goto" }, { "to": ["trap"], "type": "goto" }] })
+            )
+        );
+        let revision = batches[0][1].body.as_ref().unwrap();
+        assert_eq!(revision["paragraph"], json!("hall"));
+        assert_eq!(revision["choices"][0]["to"], json!(["start"]));
+        assert_eq!(revision["id"].as_str().unwrap().len(), 15);
+
+        assert_eq!(created[0].method, "POST");
+        assert_eq!(created[0].body.as_ref().unwrap()["id"], json!("hall"));
+        assert_eq!(updated[0].method, "PATCH");
+        assert_eq!(updated[0].url, "/api/collections/paragraphs/records/hall");
+        for batch in [&created, &updated] {
+            assert_eq!(batch[1].url, "/api/collections/paragraph_revisions/records");
+            assert_eq!(
+                batch[1].body.as_ref().unwrap()["texts"][0]["paragraphs"],
+                json!("Paragraph hall")
+            );
+        }
+        assert_ne!(
+            created[1].body.as_ref().unwrap()["id"],
+            updated[1].body.as_ref().unwrap()["id"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_texts_chunks_batches_with_revisions() {
+        // Prepare test data
+        let edited: Vec<Paragraph> = (0..TEXT_SAVE_CHUNK + 1)
+            .map(|i| paragraph(&format!("p{}", i), vec![]))
+            .collect();
+        let client = MockApiClient::new();
+
+        // Execute test
+        save_paragraph_texts(&client, &edited, None).await.unwrap();
+
+        // Verify result
+        let batches = client.batches.borrow();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].len(), TEXT_SAVE_CHUNK * 2);
+        assert_eq!(batches[1][0].method, "PATCH");
+        assert_eq!(
+            batches[1][0].url,
+            format!("/api/collections/paragraphs/records/p{}", TEXT_SAVE_CHUNK)
+        );
+        assert_eq!(
+            batches[1][0].body,
+            Some(json!({ "texts": edited[TEXT_SAVE_CHUNK].texts }))
+        );
+        assert_eq!(
+            batches[1][1].url,
+            "/api/collections/paragraph_revisions/records"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_texts_reports_how_far_it_got() {
+        let client = MockApiClient::new().with_failure();
+
+        let result = save_paragraph_texts(&client, &sample(), None).await;
+
+        let partial = result.unwrap_err();
+        assert_eq!(partial.saved, 0);
+        assert!(matches!(partial.error, ApiError::NetworkError(_)));
+    }
+}
@@ -0,0 +1,59 @@
+//! The reader's progress record kept in IndexedDB.
+
+use crate::models::progress::ReadingProgress;
+use crate::services::indexeddb::{
+    get_choice_history_from_indexeddb, get_progress_from_indexeddb, set_progress_to_indexeddb,
+};
+
+fn enabled() -> bool {
+    // IndexedDB only exists in the browser
+    cfg!(target_arch = "wasm32")
+}
+
+/// The stored record, empty when there is none yet. Fails when a record
+/// exists but cannot be read, so callers leave it alone instead of saving an
+/// empty one over it.
+pub async fn load_progress() -> Result<ReadingProgress, String> {
+    if !enabled() {
+        return Ok(ReadingProgress::default());
+    }
+    let value = get_progress_from_indexeddb().await.map_err(|error| {
+        tracing::error!(error = ?error, "Failed to read reading progress from IndexedDB");
+        format!("{:?}", error)
+    })?;
+    let Some(raw) = value.as_string() else {
+        return Ok(ReadingProgress::default());
+    };
+    ReadingProgress::from_json(&raw).map_err(|error| {
+        tracing::error!(error = %error, "Failed to parse stored reading progress");
+        error.to_string()
+    })
+}
+
+pub async fn save_progress(progress: &ReadingProgress) {
+    if !enabled() {
+        return;
+    }
+    if let Err(error) = set_progress_to_indexeddb(&progress.to_json()).await {
+        tracing::error!(error = ?error, "Failed to write reading progress to IndexedDB");
+    }
+}
+
+/// Paragraphs found in the stored choice paths of every chapter, plus those
+/// a random choice was drawn on. A random record's target is drawn before the
+/// reader picks, so it is not counted.
+pub async fn load_choice_history() -> Vec<String> {
+    if !enabled() {
+        return Vec::new();
+    }
+    match get_choice_history_from_indexeddb().await {
+        Ok(value) => js_sys::Array::from(&value)
+            .iter()
+            .filter_map(|id| id.as_string())
+            .collect(),
+        Err(error) => {
+            tracing::error!(error = ?error, "Failed to read choice history from IndexedDB");
+            Vec::new()
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use crate::contexts::paragraph_context::ParagraphData;
+use crate::models::chapter::Chapter as ChapterRecord;
+use crate::models::paragraph::Paragraph;
+use crate::services::api::{ApiClient, ApiError, ApiResult, BatchRequest, ChapterData};
+use crate::services::auth::{AuthSession, AuthUser, PasswordCredentials, SignUpRequest};
+use crate::services::chapters::{ChapterUpdate, NewChapter};
+use crate::services::invite::{Invite, InviteCodeError, InviteData, InviteUpdate, NewInvite};
+use crate::services::revisions::RevisionData;
+use std::future::Future;
+
+/// Retry, backoff and timeout settings shared by every API call path.
+///
+/// `max_attempts` counts the first try, so `1` disables retrying. Backoff grows
+/// exponentially from `initial_backoff_ms` and is capped at `max_backoff_ms`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub timeout_ms: Option<u64>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 8_000,
+            timeout_ms: Some(15_000),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl RetryPolicy {
+    /// Single attempt, still bounded by the default timeout.
+    pub fn no_retry() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_backoff(mut self, initial_backoff_ms: u64, max_backoff_ms: u64) -> Self {
+        self.initial_backoff_ms = initial_backoff_ms;
+        self.max_backoff_ms = max_backoff_ms.max(initial_backoff_ms);
+        self
+    }
+
+    pub fn with_timeout_ms(mut self, timeout_ms: Option<u64>) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Delay to wait after the `failed_attempt`-th failure (1-based).
+    ///
+    /// ```
+    /// use ifecaro::services::retry::RetryPolicy;
+    ///
+    /// let policy = RetryPolicy::default().with_backoff(100, 1_000);
+    /// assert_eq!(policy.backoff_for(1), 100);
+    /// assert_eq!(policy.backoff_for(3), 400);
+    /// assert_eq!(policy.backoff_for(10), 1_000);
+    /// ```
+    pub fn backoff_for(&self, failed_attempt: u32) -> u64 {
+        let exponent = failed_attempt.saturating_sub(1).min(20);
+        self.initial_backoff_ms
+            .saturating_mul(1u64 << exponent)
+            .min(self.max_backoff_ms)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn sleep_ms(ms: u64) {
+    gloo_timers::future::TimeoutFuture::new(ms.min(u32::MAX as u64) as u32).await;
+}
+
+// 原生環境（測試、CLI）沒有瀏覽器計時器，改用 tokio 的計時器
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn sleep_ms(ms: u64) {
+    tokio::time::sleep(std::time::Duration::from_millis(ms)).await;
+}
+
+/// Races `future` against a timer; resolves to [`ApiError::Timeout`] when the timer wins.
+#[cfg(target_arch = "wasm32")]
+pub async fn with_timeout<T, F>(timeout_ms: Option<u64>, future: F) -> ApiResult<T>
+where
+    F: Future<Output = ApiResult<T>>,
+{
+    use futures::future::{select, Either};
+
+    let Some(timeout_ms) = timeout_ms else {
+        return future.await;
+    };
+    match select(Box::pin(future), Box::pin(sleep_ms(timeout_ms))).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(ApiError::Timeout),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn with_timeout<T, F>(timeout_ms: Option<u64>, future: F) -> ApiResult<T>
+where
+    F: Future<Output = ApiResult<T>>,
+{
+    let Some(timeout_ms) = timeout_ms else {
+        return future.await;
+    };
+    tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), future)
+        .await
+        .unwrap_or(Err(ApiError::Timeout))
+}
+
+/// Runs `operation` until it succeeds, fails with a non-retryable error, or
+/// the policy runs out of attempts.
+pub async fn retry_with_policy<T, F, Fut>(policy: &RetryPolicy, mut operation: F) -> ApiResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ApiResult<T>>,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match with_timeout(policy.timeout_ms, operation()).await {
+            Ok(value) => return Ok(value),
+            Err(error) if error.is_retryable() && attempt < max_attempts => {
+                let delay_ms = policy.backoff_for(attempt);
+                tracing::warn!(
+                    attempt = attempt,
+                    max_attempts = max_attempts,
+                    delay_ms = delay_ms,
+                    error = %error,
+                    "API call failed, retrying"
+                );
+                sleep_ms(delay_ms).await;
+                attempt += 1;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// [`retry_with_policy`] for deletes: a retry that finds the record gone
+/// succeeds, since the earlier attempt may have deleted it before timing out.
+pub async fn retry_delete<F, Fut>(policy: &RetryPolicy, mut operation: F) -> ApiResult<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ApiResult<()>>,
+{
+    let mut attempts = 0;
+    retry_with_policy(policy, || {
+        attempts += 1;
+        let retried = attempts > 1;
+        let deleted = operation();
+        async move {
+            match deleted.await {
+                Err(ApiError::NotFound) if retried => Ok(()),
+                result => result,
+            }
+        }
+    })
+    .await
+}
+
+/// Sends a raw reqwest request under `policy`.
+///
+/// `build` is called once per attempt because a `RequestBuilder` is consumed by
+/// `send`. Retryable HTTP statuses are retried too, but when attempts run out the
+/// last response is returned as `Ok` so callers keep their own status handling.
+pub async fn send_with_retry<F>(policy: &RetryPolicy, mut build: F) -> ApiResult<reqwest::Response>
+where
+    F: FnMut() -> reqwest::RequestBuilder,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        let request = build();
+        let result = with_timeout(policy.timeout_ms, async move {
+            request
+                .send()
+                .await
+                .map_err(|e| ApiError::NetworkError(e.to_string()))
+        })
+        .await;
+
+        let retry_reason = match &result {
+            Ok(response) if !response.status().is_success() => {
+                let error = ApiError::ServerError(response.status().as_u16());
+                error.is_retryable().then_some(error)
+            }
+            Ok(_) => None,
+            Err(error) => error.is_retryable().then(|| error.clone()),
+        };
+
+        match retry_reason {
+            Some(error) if attempt < max_attempts => {
+                let delay_ms = policy.backoff_for(attempt);
+                tracing::warn!(
+                    attempt = attempt,
+                    max_attempts = max_attempts,
+                    delay_ms = delay_ms,
+                    error = %error,
+                    "HTTP request failed, retrying"
+                );
+                sleep_ms(delay_ms).await;
+                attempt += 1;
+            }
+            _ => return result,
+        }
+    }
+}
+
+/// [`ApiClient`] decorator that applies a [`RetryPolicy`] to every call of the
+/// wrapped client.
+#[allow(dead_code)]
+pub struct RetryingApiClient<C: ApiClient> {
+    inner: C,
+    policy: RetryPolicy,
+}
+
+#[allow(dead_code)]
+impl<C: ApiClient> RetryingApiClient<C> {
+    pub fn new(inner: C, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<C: ApiClient> ApiClient for RetryingApiClient<C> {
+    async fn get_paragraphs(&self) -> ApiResult<ParagraphData> {
+        retry_with_policy(&self.policy, || self.inner.get_paragraphs()).await
+    }
+
+    async fn get_chapters(&self) -> ApiResult<ChapterData> {
+        retry_with_policy(&self.policy, || self.inner.get_chapters()).await
+    }
+
+    async fn get_paragraph_by_id(&self, id: &str) -> ApiResult<Paragraph> {
+        retry_with_policy(&self.policy, || self.inner.get_paragraph_by_id(id)).await
+    }
+
+    async fn update_paragraph(&self, paragraph: &Paragraph) -> ApiResult<()> {
+        retry_with_policy(&self.policy, || self.inner.update_paragraph(paragraph)).await
+    }
+
+    async fn delete_paragraph(&self, id: &str) -> ApiResult<()> {
+        retry_delete(&self.policy, || self.inner.delete_paragraph(id)).await
+    }
+
+    async fn create_chapter(&self, chapter: &NewChapter) -> ApiResult<ChapterRecord> {
+        with_timeout(self.policy.timeout_ms, self.inner.create_chapter(chapter)).await
+    }
+
+    async fn update_chapter(&self, id: &str, update: &ChapterUpdate) -> ApiResult<ChapterRecord> {
+        retry_with_policy(&self.policy, || self.inner.update_chapter(id, update)).await
+    }
+
+    async fn delete_chapter(&self, id: &str) -> ApiResult<()> {
+        retry_delete(&self.policy, || self.inner.delete_chapter(id)).await
+    }
+
+    async fn batch(&self, requests: &[BatchRequest]) -> ApiResult<()> {
+        with_timeout(self.policy.timeout_ms, self.inner.batch(requests)).await
+    }
+
+    async fn auth_with_password(
+        &self,
+        credentials: &PasswordCredentials,
+    ) -> ApiResult<AuthSession> {
+        retry_with_policy(&self.policy, || self.inner.auth_with_password(credentials)).await
+    }
+
+    async fn create_user(&self, request: &SignUpRequest) -> ApiResult<AuthUser> {
+        // 建立帳號不是冪等操作，逾時後重送可能造成重複註冊
+        with_timeout(self.policy.timeout_ms, self.inner.create_user(request)).await
+    }
+
+    async fn refresh_auth(&self, token: &str) -> ApiResult<AuthSession> {
+        retry_with_policy(&self.policy, || self.inner.refresh_auth(token)).await
+    }
+
+    async fn create_invite(&self, invite: &NewInvite) -> ApiResult<Invite> {
+        with_timeout(self.policy.timeout_ms, self.inner.create_invite(invite)).await
+    }
+
+    async fn list_invites(&self) -> ApiResult<InviteData> {
+        retry_with_policy(&self.policy, || self.inner.list_invites()).await
+    }
+
+    async fn check_invite_code(
+        &self,
+        code: &str,
+        email: &str,
+    ) -> ApiResult<Result<(), InviteCodeError>> {
+        retry_with_policy(&self.policy, || self.inner.check_invite_code(code, email)).await
+    }
+
+    async fn update_invite(&self, id: &str, update: &InviteUpdate) -> ApiResult<Invite> {
+        retry_with_policy(&self.policy, || self.inner.update_invite(id, update)).await
+    }
+
+    async fn list_revisions(&self, paragraph_id: &str) -> ApiResult<RevisionData> {
+        retry_with_policy(&self.policy, || self.inner.list_revisions(paragraph_id)).await
+    }
+
+    async fn list_records(&self, endpoint: &str) -> ApiResult<Vec<serde_json::Value>> {
+        retry_with_policy(&self.policy, || self.inner.list_records(endpoint)).await
+    }
+}
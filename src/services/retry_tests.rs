@@ -0,0 +1,398 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::api::*;
+    use super::super::auth::{AuthSession, AuthUser, PasswordCredentials, SignUpRequest};
+    use super::super::chapters::{ChapterUpdate, NewChapter};
+    use super::super::invite::{Invite, InviteCodeError, InviteData, InviteUpdate, NewInvite};
+    use super::super::outbox::*;
+    use super::super::retry::*;
+    use super::super::revisions::RevisionData;
+    use crate::contexts::paragraph_context::ParagraphData;
+    use crate::models::chapter::Chapter as ChapterRecord;
+    use crate::models::paragraph::Paragraph;
+    use std::cell::{Cell, RefCell};
+
+    /// Helper client: fails with the queued errors first, then succeeds
+    struct FlakyClient {
+        errors: RefCell<Vec<ApiError>>,
+        calls: Cell<u32>,
+    }
+
+    impl FlakyClient {
+        fn new(errors: Vec<ApiError>) -> Self {
+            Self {
+                errors: RefCell::new(errors),
+                calls: Cell::new(0),
+            }
+        }
+
+        fn next(&self) -> ApiResult<()> {
+            self.calls.set(self.calls.get() + 1);
+            let mut errors = self.errors.borrow_mut();
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors.remove(0))
+            }
+        }
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl ApiClient for FlakyClient {
+        async fn get_paragraphs(&self) -> ApiResult<ParagraphData> {
+            self.next().map(|_| ParagraphData { items: Vec::new() })
+        }
+
+        async fn get_chapters(&self) -> ApiResult<ChapterData> {
+            self.next().map(|_| ChapterData { items: Vec::new() })
+        }
+
+        async fn get_paragraph_by_id(&self, _id: &str) -> ApiResult<Paragraph> {
+            self.next().and(Err(ApiError::NotFound))
+        }
+
+        async fn update_paragraph(&self, _paragraph: &Paragraph) -> ApiResult<()> {
+            self.next()
+        }
+
+        async fn delete_paragraph(&self, _id: &str) -> ApiResult<()> {
+            self.next()
+        }
+
+        async fn create_chapter(&self, _chapter: &NewChapter) -> ApiResult<ChapterRecord> {
+            self.next().and(Err(ApiError::NotFound))
+        }
+
+        async fn update_chapter(
+            &self,
+            _id: &str,
+            _update: &ChapterUpdate,
+        ) -> ApiResult<ChapterRecord> {
+            self.next().and(Err(ApiError::NotFound))
+        }
+
+        async fn delete_chapter(&self, _id: &str) -> ApiResult<()> {
+            self.next()
+        }
+
+        async fn batch(&self, _requests: &[BatchRequest]) -> ApiResult<()> {
+            self.next()
+        }
+
+        async fn auth_with_password(
+            &self,
+            _credentials: &PasswordCredentials,
+        ) -> ApiResult<AuthSession> {
+            self.next().and(Err(ApiError::ServerError(400)))
+        }
+
+        async fn create_user(&self, _request: &SignUpRequest) -> ApiResult<AuthUser> {
+            self.next().and(Err(ApiError::ServerError(400)))
+        }
+
+        async fn refresh_auth(&self, _token: &str) -> ApiResult<AuthSession> {
+            self.next().and(Err(ApiError::ServerError(401)))
+        }
+
+        async fn create_invite(&self, _invite: &NewInvite) -> ApiResult<Invite> {
+            self.next().and(Err(ApiError::ServerError(400)))
+        }
+
+        async fn list_invites(&self) -> ApiResult<InviteData> {
+            self.next().map(|_| InviteData { items: Vec::new() })
+        }
+
+        async fn check_invite_code(
+            &self,
+            _code: &str,
+            _email: &str,
+        ) -> ApiResult<Result<(), InviteCodeError>> {
+            self.next().map(|_| Err(InviteCodeError::Unknown))
+        }
+
+        async fn update_invite(&self, _id: &str, _update: &InviteUpdate) -> ApiResult<Invite> {
+            self.next().and(Err(ApiError::NotFound))
+        }
+
+        async fn list_revisions(&self, _paragraph_id: &str) -> ApiResult<RevisionData> {
+            self.next().map(|_| RevisionData { items: Vec::new() })
+        }
+
+        async fn list_records(&self, _endpoint: &str) -> ApiResult<Vec<serde_json::Value>> {
+            self.next().map(|_| Vec::new())
+        }
+    }
+
+    fn entry(method: OutboxMethod, url: &str, body: serde_json::Value) -> OutboxEntry {
+        OutboxEntry::new(method, url, Some(body))
+    }
+
+    #[test]
+    fn test_is_retryable_classification() {
+        assert!(ApiError::NetworkError("offline".to_string()).is_retryable());
+        assert!(ApiError::Timeout.is_retryable());
+        assert!(ApiError::ServerError(503).is_retryable());
+        assert!(ApiError::ServerError(429).is_retryable());
+        assert!(!ApiError::ServerError(400).is_retryable());
+        assert!(!ApiError::NotFound.is_retryable());
+        assert!(!ApiError::ParseError("bad json".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy::default().with_backoff(200, 1_000);
+        assert_eq!(policy.backoff_for(1), 200);
+        assert_eq!(policy.backoff_for(2), 400);
+        assert_eq!(policy.backoff_for(3), 800);
+        assert_eq!(policy.backoff_for(4), 1_000);
+        assert_eq!(policy.backoff_for(u32::MAX), 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_retrying_client_recovers_from_transient_errors() {
+        // Two transient failures, then success
+        let flaky = FlakyClient::new(vec![
+            ApiError::NetworkError("offline".to_string()),
+            ApiError::ServerError(502),
+        ]);
+        let policy = RetryPolicy::default()
+            .with_max_attempts(3)
+            .with_backoff(1, 1);
+        let client = RetryingApiClient::new(flaky, policy);
+
+        let result = client.get_chapters().await;
+
+        assert!(result.is_ok());
+        assert_eq!(client.inner().calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retrying_client_gives_up_after_max_attempts() {
+        let flaky = FlakyClient::new(vec![ApiError::Timeout; 5]);
+        let policy = RetryPolicy::default()
+            .with_max_attempts(2)
+            .with_backoff(1, 1);
+        let client = RetryingApiClient::new(flaky, policy);
+
+        let result = client.get_paragraphs().await;
+
+        assert!(matches!(result, Err(ApiError::Timeout)));
+        assert_eq!(client.inner().calls.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_backoff_and_timeout_apply_natively() {
+        let started = std::time::Instant::now();
+        sleep_ms(20).await;
+        assert!(started.elapsed() >= std::time::Duration::from_millis(20));
+
+        let slow = async {
+            sleep_ms(1_000).await;
+            Ok(())
+        };
+        assert!(matches!(
+            with_timeout(Some(10), slow).await,
+            Err(ApiError::Timeout)
+        ));
+        assert!(with_timeout(None, async { Ok(()) }).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_retried_delete_treats_not_found_as_deleted() {
+        // The first attempt timed out after the server deleted the record
+        let flaky = FlakyClient::new(vec![ApiError::Timeout, ApiError::NotFound]);
+        let policy = RetryPolicy::default()
+            .with_max_attempts(3)
+            .with_backoff(1, 1);
+        let client = RetryingApiClient::new(flaky, policy.clone());
+
+        assert!(client.delete_paragraph("p1").await.is_ok());
+        assert_eq!(client.inner().calls.get(), 2);
+
+        // Without a retry, a missing record is still an error
+        let flaky = FlakyClient::new(vec![ApiError::NotFound]);
+        let client = RetryingApiClient::new(flaky, policy);
+
+        assert!(matches!(
+            client.delete_chapter("c1").await,
+            Err(ApiError::NotFound)
+        ));
+        assert_eq!(client.inner().calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retrying_client_does_not_retry_client_errors() {
+        let flaky = FlakyClient::new(vec![ApiError::ServerError(403)]);
+        let client = RetryingApiClient::new(flaky, RetryPolicy::default());
+
+        let result = client.get_chapters().await;
+
+        assert!(matches!(result, Err(ApiError::ServerError(403))));
+        assert_eq!(client.inner().calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retrying_client_wraps_mock_client() {
+        let client =
+            RetryingApiClient::new(MockApiClient::new().with_failure(), RetryPolicy::no_retry());
+
+        let result = client.get_paragraphs().await;
+
+        assert!(matches!(result, Err(ApiError::NetworkError(_))));
+    }
+
+    #[test]
+    fn test_outbox_merges_updates_to_same_record() {
+        let mut outbox = Outbox::default();
+        outbox.enqueue(entry(
+            OutboxMethod::Patch,
+            "/p/1",
+            serde_json::json!({"v": 1}),
+        ));
+        outbox.enqueue(entry(OutboxMethod::Post, "/p", serde_json::json!({"v": 2})));
+        outbox.enqueue(entry(OutboxMethod::Post, "/p", serde_json::json!({"v": 3})));
+        outbox.enqueue(entry(
+            OutboxMethod::Patch,
+            "/p/1",
+            serde_json::json!({"v": 4}),
+        ));
+
+        // The later PATCH replaces the first in place; POSTs stay separate
+        assert_eq!(outbox.len(), 3);
+        assert_eq!(outbox.entries[0].body, Some(serde_json::json!({"v": 4})));
+        assert_eq!(outbox.entries[1].method, OutboxMethod::Post);
+    }
+
+    #[test]
+    fn test_outbox_json_round_trip() {
+        let mut outbox = Outbox::default();
+        outbox.enqueue(entry(
+            OutboxMethod::Put,
+            "/p/1",
+            serde_json::json!({"texts": []}),
+        ));
+
+        let restored = Outbox::from_json(&outbox.to_json());
+
+        assert_eq!(restored, outbox);
+        assert!(Outbox::from_json("not json").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_outbox_replay_stops_on_transient_failure() {
+        let mut outbox = Outbox::default();
+        outbox.enqueue(entry(OutboxMethod::Post, "/p", serde_json::json!({"v": 1})));
+        outbox.enqueue(entry(
+            OutboxMethod::Patch,
+            "/p/2",
+            serde_json::json!({"v": 2}),
+        ));
+        outbox.enqueue(entry(
+            OutboxMethod::Patch,
+            "/p/3",
+            serde_json::json!({"v": 3}),
+        ));
+        let results = RefCell::new(vec![
+            Ok(()),
+            Err(ApiError::NetworkError("offline".to_string())),
+        ]);
+
+        let report = outbox
+            .replay_with(|_| {
+                let result = results.borrow_mut().remove(0);
+                async move { result }
+            })
+            .await;
+
+        assert_eq!(report.sent, 1);
+        assert_eq!(report.remaining, 2);
+        assert_eq!(outbox.entries[0].url, "/p/2");
+        assert_eq!(outbox.entries[0].attempts, 1);
+        assert!(outbox.entries[0].last_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_outbox_replay_drops_rejected_entries() {
+        let mut outbox = Outbox::default();
+        outbox.enqueue(entry(
+            OutboxMethod::Patch,
+            "/p/1",
+            serde_json::json!({"v": 1}),
+        ));
+        outbox.enqueue(entry(
+            OutboxMethod::Patch,
+            "/p/2",
+            serde_json::json!({"v": 2}),
+        ));
+        let results = RefCell::new(vec![Err(ApiError::ServerError(400)), Ok(())]);
+
+        let report = outbox
+            .replay_with(|_| {
+                let result = results.borrow_mut().remove(0);
+                async move { result }
+            })
+            .await;
+
+        assert_eq!(report.sent, 1);
+        assert_eq!(report.dropped.len(), 1);
+        assert_eq!(report.dropped[0].0.url, "/p/1");
+        assert!(outbox.is_empty());
+    }
+
+    #[test]
+    fn test_outbox_only_queues_creates_that_never_reached_the_server() {
+        let offline = ApiError::NetworkError("connection refused".to_string());
+        for error in [
+            offline.clone(),
+            ApiError::Timeout,
+            ApiError::ServerError(503),
+        ] {
+            assert!(OutboxEntry::should_queue(OutboxMethod::Patch, &error));
+        }
+        assert!(OutboxEntry::should_queue(OutboxMethod::Post, &offline));
+        assert!(!OutboxEntry::should_queue(
+            OutboxMethod::Post,
+            &ApiError::Timeout
+        ));
+        assert!(!OutboxEntry::should_queue(
+            OutboxMethod::Post,
+            &ApiError::ServerError(503)
+        ));
+        assert!(!OutboxEntry::should_queue(
+            OutboxMethod::Patch,
+            &ApiError::ServerError(400)
+        ));
+    }
+
+    #[test]
+    fn test_outbox_creates_replay_once_with_their_own_id() {
+        let create = entry(
+            OutboxMethod::Post,
+            "/p",
+            serde_json::json!({"id": "abc", "v": 1}),
+        );
+        let update = entry(
+            OutboxMethod::Patch,
+            "/p/abc",
+            serde_json::json!({"id": "abc"}),
+        );
+        let batch = entry(
+            OutboxMethod::Post,
+            "https://example.com/db/api/batch",
+            serde_json::json!({"requests": [
+                {"method": "PATCH", "url": "/api/collections/paragraphs/records/p1", "body": {}},
+                {"method": "POST", "url": "/api/collections/paragraph_revisions/records", "body": {"id": "r1"}},
+            ]}),
+        );
+        let policy = RetryPolicy::default();
+
+        assert_eq!(create.created_record_url().as_deref(), Some("/p/abc"));
+        assert_eq!(update.created_record_url(), None);
+        assert_eq!(
+            batch.created_record_url().as_deref(),
+            Some("https://example.com/db/api/collections/paragraph_revisions/records/r1")
+        );
+        assert_eq!(create.replay_policy(&policy).max_attempts, 1);
+        assert_eq!(update.replay_policy(&policy), policy);
+    }
+}
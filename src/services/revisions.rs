@@ -0,0 +1,107 @@
+use crate::constants::config::{PARAGRAPHS, PARAGRAPH_REVISIONS};
+use crate::models::paragraph::{Paragraph, ParagraphChoice, Text};
+use crate::services::api::{ApiClient, ApiError, ApiResult, BatchRequest};
+use crate::services::auth::AuthUser;
+use crate::services::paragraphs::new_record_id;
+use serde::{Deserialize, Serialize};
+
+/// Record in the `paragraph_revisions` collection: the full content of a
+/// paragraph right after one save.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ParagraphRevision {
+    pub id: String,
+    pub paragraph: String,
+    /// Id of the user who saved; empty for saves made before sign-in existed.
+    #[serde(default)]
+    pub author: String,
+    /// Name or email of the author at the time of the save.
+    #[serde(default)]
+    pub author_name: String,
+    #[serde(default)]
+    pub texts: Vec<Text>,
+    #[serde(default)]
+    pub choices: Vec<ParagraphChoice>,
+    #[serde(default)]
+    pub created: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RevisionData {
+    pub items: Vec<ParagraphRevision>,
+}
+
+/// Body for a new revision.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NewRevision {
+    pub paragraph: String,
+    pub author: String,
+    pub author_name: String,
+    pub texts: Vec<Text>,
+    pub choices: Vec<ParagraphChoice>,
+}
+
+impl NewRevision {
+    pub fn snapshot(paragraph: &Paragraph, author: Option<&AuthUser>) -> Self {
+        let (author, author_name) = match author {
+            Some(user) if !user.name.is_empty() => (user.id.clone(), user.name.clone()),
+            Some(user) => (user.id.clone(), user.email.clone()),
+            None => (String::new(), String::new()),
+        };
+        Self {
+            paragraph: paragraph.id.clone(),
+            author,
+            author_name,
+            texts: paragraph.texts.clone(),
+            choices: paragraph.choices.clone(),
+        }
+    }
+}
+
+/// Create request for a revision of `paragraph`, to be sent in the same batch
+/// as the write it records.
+///
+/// The revision carries its own id, so a batch replayed after it already
+/// went through is rejected instead of recording the save twice.
+pub fn revision_request(
+    paragraph: &Paragraph,
+    author: Option<&AuthUser>,
+) -> ApiResult<BatchRequest> {
+    let mut body = serde_json::to_value(NewRevision::snapshot(paragraph, author))
+        .map_err(|e| ApiError::ParseError(e.to_string()))?;
+    if let Some(fields) = body.as_object_mut() {
+        fields.insert("id".to_string(), new_record_id().into());
+    }
+    Ok(BatchRequest::create(PARAGRAPH_REVISIONS, body))
+}
+
+/// Puts the texts and choices of `revision` back on `current`.
+///
+/// The paragraph update and the revision recording the restore are sent in
+/// one batch, so the history never misses a restore that took effect.
+pub async fn restore_revision<C: ApiClient + ?Sized>(
+    client: &C,
+    current: &Paragraph,
+    revision: &ParagraphRevision,
+    author: Option<&AuthUser>,
+) -> ApiResult<Paragraph> {
+    if revision.paragraph != current.id {
+        return Err(ApiError::NotFound);
+    }
+    let mut restored = current.clone();
+    restored.texts = revision.texts.clone();
+    restored.choices = revision.choices.clone();
+
+    let requests = vec![
+        BatchRequest::patch(
+            PARAGRAPHS,
+            &restored.id,
+            serde_json::json!({
+                "texts": restored.texts,
+                "choices": restored.choices,
+            }),
+        ),
+        revision_request(&restored, author)?,
+    ];
+    client.batch(&requests).await?;
+    Ok(restored)
+}
@@ -0,0 +1,98 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::super::api::*;
+    use super::super::auth::AuthUser;
+    use super::super::revisions::*;
+    use crate::models::paragraph::{Paragraph, ParagraphChoice, Text};
+
+    fn paragraph(body: &str, target: &str) -> Paragraph {
+        Paragraph {
+            id: "p1".to_string(),
+            chapter_id: "c1".to_string(),
+            texts: vec![Text {
+                lang: "en-US".to_string(),
+                paragraphs: body.to_string(),
+                choices: vec!["Go".to_string()],
+                ..Default::default()
+            }],
+            choices: vec![ParagraphChoice::goto(vec![target.to_string()])],
+            ..Default::default()
+        }
+    }
+
+    fn author() -> AuthUser {
+        AuthUser {
+            id: "user1".to_string(),
+            email: "writer@example.com".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Records a save of `paragraph` the way the editors do, in a batch.
+    async fn save(client: &MockApiClient, paragraph: &Paragraph, author: Option<&AuthUser>) {
+        let request = revision_request(paragraph, author).unwrap();
+        client.batch(&[request]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_each_save_is_listed_newest_first() {
+        // Prepare test data
+        let client = MockApiClient::new();
+
+        // Execute test
+        save(&client, &paragraph("First draft", "p2"), Some(&author())).await;
+        save(&client, &paragraph("Second draft", "p3"), None).await;
+        let listed = client.list_revisions("p1").await.unwrap().items;
+
+        // Verify result
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].texts[0].paragraphs, "Second draft");
+        assert_eq!(listed[0].author, "");
+        assert_eq!(listed[1].author, "user1");
+        assert_eq!(listed[1].author_name, "writer@example.com");
+        assert_ne!(listed[0].id, listed[1].id);
+        assert!(client.list_revisions("p9").await.unwrap().items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restore_patches_paragraph_and_records_revision_in_one_batch() {
+        // Prepare test data
+        let client = MockApiClient::new();
+        save(&client, &paragraph("First draft", "p2"), None).await;
+        let old = client.list_revisions("p1").await.unwrap().items.remove(0);
+        let current = paragraph("Second draft", "p3");
+
+        // Execute test
+        let restored = restore_revision(&client, &current, &old, Some(&author()))
+            .await
+            .unwrap();
+
+        // Verify result
+        assert_eq!(restored.texts, old.texts);
+        assert_eq!(restored.choices, old.choices);
+        assert_eq!(restored.chapter_id, "c1");
+        let batches = client.batches.borrow();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[1][0].method, "PATCH");
+        assert_eq!(batches[1][0].url, "/api/collections/paragraphs/records/p1");
+        assert_eq!(
+            batches[1][1].url,
+            "/api/collections/paragraph_revisions/records"
+        );
+        assert_eq!(batches[1][1].body.as_ref().unwrap()["author"], "user1");
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_revision_of_another_paragraph() {
+        let client = MockApiClient::new();
+        let mut other = paragraph("Elsewhere", "p2");
+        other.id = "p7".to_string();
+        save(&client, &other, None).await;
+        let revision = client.list_revisions("p7").await.unwrap().items.remove(0);
+
+        let result = restore_revision(&client, &paragraph("Draft", "p3"), &revision, None).await;
+
+        assert!(matches!(result, Err(ApiError::NotFound)));
+        assert_eq!(client.batches.borrow().len(), 1);
+    }
+}
@@ -0,0 +1,91 @@
+//! Thin wrapper over the browser Speech Synthesis API.
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{SpeechSynthesis, SpeechSynthesisUtterance, SpeechSynthesisVoice};
+
+fn synthesis() -> Option<SpeechSynthesis> {
+    // Native builds (tests, server rendering) have no browser to call into
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    web_sys::window()?.speech_synthesis().ok()
+}
+
+pub fn is_supported() -> bool {
+    synthesis().is_some()
+}
+
+fn language_matches(voice_lang: &str, lang: &str) -> bool {
+    let voice_lang = voice_lang.replace('_', "-").to_lowercase();
+    let lang = lang.to_lowercase();
+    voice_lang == lang || voice_lang.split('-').next() == lang.split('-').next()
+}
+
+fn voices() -> Vec<SpeechSynthesisVoice> {
+    let Some(synthesis) = synthesis() else {
+        return Vec::new();
+    };
+    synthesis
+        .get_voices()
+        .iter()
+        .filter_map(|voice| voice.dyn_into::<SpeechSynthesisVoice>().ok())
+        .collect()
+}
+
+/// Names of the installed voices that can read `lang`, exact region first.
+///
+/// Browsers load voices lazily, so the list may be empty the first time it
+/// is asked for.
+pub fn voice_names_for(lang: &str) -> Vec<String> {
+    let mut matching: Vec<SpeechSynthesisVoice> = voices()
+        .into_iter()
+        .filter(|voice| language_matches(&voice.lang(), lang))
+        .collect();
+    matching.sort_by_key(|voice| !voice.lang().eq_ignore_ascii_case(lang));
+    matching.iter().map(|voice| voice.name()).collect()
+}
+
+/// Speaks `text` and calls `on_end` once it has been read or was cut off.
+///
+/// Returns `false` when the browser cannot speak.
+pub fn speak(
+    text: &str,
+    lang: &str,
+    voice: Option<&str>,
+    rate: f32,
+    on_end: impl FnOnce() + 'static,
+) -> bool {
+    let Some(synthesis) = synthesis() else {
+        return false;
+    };
+    let Ok(utterance) = SpeechSynthesisUtterance::new_with_text(text) else {
+        return false;
+    };
+    utterance.set_lang(lang);
+    utterance.set_rate(rate);
+    if let Some(name) = voice {
+        if let Some(found) = voices().into_iter().find(|v| v.name() == name) {
+            utterance.set_voice(Some(&found));
+        }
+    }
+    // `end` and `error` share one callback, which only acts the first time
+    let mut pending = Some(on_end);
+    let callback = Closure::<dyn FnMut()>::new(move || {
+        if let Some(on_end) = pending.take() {
+            on_end();
+        }
+    })
+    .into_js_value();
+    utterance.set_onend(Some(callback.unchecked_ref()));
+    utterance.set_onerror(Some(callback.unchecked_ref()));
+    synthesis.speak(&utterance);
+    true
+}
+
+/// Stops the current sentence and drops anything queued.
+pub fn cancel() {
+    if let Some(synthesis) = synthesis() {
+        synthesis.cancel();
+    }
+}
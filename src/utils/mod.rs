@@ -25,8 +25,10 @@ mod tests {
                 lang: "en".to_string(),
                 paragraphs: "Sample".to_string(),
                 choices: vec![],
+                ..Default::default()
             }],
-            choices: vec![ParagraphChoice::Simple(vec!["next".to_string()])],
+            choices: vec![ParagraphChoice::goto(vec!["next".to_string()])],
+            ..Default::default()
         }
     }
 
@@ -0,0 +1,111 @@
+use ifecaro::models::achievements::{Achievement, AchievementText, UnlockedAchievements};
+use ifecaro::models::impacts::{AttributeField, CharacterStateSnapshot, Impact, NumericOp};
+use ifecaro::models::interpolation::StoryVariables;
+use ifecaro::models::progress::ReadingProgress;
+use serde_json::json;
+use std::collections::{BTreeSet, HashMap};
+
+fn achievement(id: &str, trigger: &str) -> Achievement {
+    Achievement {
+        id: id.to_string(),
+        texts: vec![
+            AchievementText {
+                lang: "en-US".to_string(),
+                title: format!("Title {id}"),
+                description: String::new(),
+            },
+            AchievementText {
+                lang: "zh-TW".to_string(),
+                title: format!("標題 {id}"),
+                description: String::new(),
+            },
+        ],
+        trigger: trigger.to_string(),
+        order: 0,
+    }
+}
+
+fn variables(impacts: &[Impact]) -> StoryVariables {
+    StoryVariables {
+        settings: HashMap::from([("player_character".to_string(), "hero".to_string())]),
+        state: CharacterStateSnapshot::default().apply_impacts(impacts),
+    }
+}
+
+fn progress(visited: &[&str], endings: &[&str]) -> ReadingProgress {
+    let set = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<BTreeSet<_>>();
+    ReadingProgress {
+        visited: set(visited),
+        endings: set(endings),
+    }
+}
+
+#[test]
+fn test_triggers_read_flags_progress_and_attributes() {
+    let state = variables(&[
+        Impact::Flag {
+            character_id: "hero".to_string(),
+            path: vec!["saved_the_boy".to_string()],
+            value: json!(true),
+        },
+        Impact::CharacterAttribute {
+            character_id: "hero".to_string(),
+            field: AttributeField::Courage,
+            op: NumericOp::Set,
+            value: 65,
+        },
+    ]);
+    let seen = progress(&["p1", "p2", "end_a"], &["end_a"]);
+
+    let met = |trigger: &str| achievement("a", trigger).is_met(&state, &seen);
+    assert!(met("flags.saved_the_boy"));
+    assert!(met("courage >= 60 && visited.p2"));
+    assert!(met("ending.end_a"));
+    assert!(!met("ending.end_b"));
+    assert!(met("endings_count == 1 && visited_count >= 3"));
+    assert!(!met("stress > 10"));
+    // A trigger with a syntax error never unlocks
+    assert!(!met("courage >="));
+}
+
+#[test]
+fn test_achievements_unlock_once_with_their_timestamp() {
+    let achievements = vec![
+        achievement("first_end", "endings_count > 0"),
+        achievement("explorer", "visited_count >= 3"),
+    ];
+    let state = variables(&[]);
+    let mut unlocked = UnlockedAchievements::default();
+
+    let newly = unlocked.unlock_met(&achievements, &state, &progress(&["p1"], &["p1"]), 1_000);
+    assert_eq!(
+        newly.iter().map(|a| a.id.as_str()).collect::<Vec<_>>(),
+        vec!["first_end"]
+    );
+
+    let seen = progress(&["p1", "p2", "p3"], &["p1"]);
+    let newly = unlocked.unlock_met(&achievements, &state, &seen, 2_000);
+    assert_eq!(newly.len(), 1);
+    assert_eq!(newly[0].title_for("zh-TW"), "標題 explorer");
+    assert!(unlocked
+        .unlock_met(&achievements, &state, &seen, 3_000)
+        .is_empty());
+
+    let restored = UnlockedAchievements::from_json(&unlocked.to_json()).unwrap();
+    assert_eq!(restored.unlocked_at("first_end"), Some(1_000));
+    assert_eq!(restored.unlocked_at("explorer"), Some(2_000));
+    assert!(UnlockedAchievements::from_json("oops").is_err());
+}
+
+#[test]
+fn test_text_falls_back_to_english_then_id() {
+    let localized = achievement("a", "true");
+    assert_eq!(localized.title_for("ja-JP"), "Title a");
+
+    let untitled = Achievement {
+        texts: Vec::new(),
+        ..localized
+    };
+    assert_eq!(untitled.title_for("en-US"), "a");
+    assert!(untitled.text_for("en-US").is_none());
+}
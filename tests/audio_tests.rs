@@ -0,0 +1,106 @@
+use ifecaro::models::audio::{
+    crossfade_gains, AudioCue, AudioCues, CueKind, Volume, AUDIO_MUTED_SETTING,
+    AUDIO_VOLUME_SETTING,
+};
+use ifecaro::models::multi_attr_check::EventOutcomeTier;
+use std::collections::HashMap;
+
+fn cue(
+    id: &str,
+    kind: CueKind,
+    chapter: &str,
+    paragraph: &str,
+    tier: Option<EventOutcomeTier>,
+) -> AudioCue {
+    AudioCue {
+        id: id.to_string(),
+        collection_id: "audio".to_string(),
+        kind,
+        chapter_id: chapter.to_string(),
+        paragraph_id: paragraph.to_string(),
+        tier,
+        file: format!("{id}.ogg"),
+    }
+}
+
+fn cues() -> AudioCues {
+    AudioCues::new(vec![
+        cue("rain", CueKind::Ambience, "c1", "", None),
+        cue("door", CueKind::Effect, "", "p1", None),
+        cue("bell", CueKind::Effect, "", "p1", None),
+        cue(
+            "crash",
+            CueKind::Effect,
+            "",
+            "",
+            Some(EventOutcomeTier::Disaster),
+        ),
+        cue(
+            "p2_crash",
+            CueKind::Effect,
+            "",
+            "p2",
+            Some(EventOutcomeTier::Disaster),
+        ),
+    ])
+}
+
+#[test]
+fn test_cues_are_found_by_chapter_paragraph_and_tier() {
+    let cues = cues();
+    let ids = |found: Vec<&AudioCue>| found.iter().map(|c| c.id.clone()).collect::<Vec<_>>();
+
+    assert_eq!(cues.ambience_for("c1").map(|c| c.id.as_str()), Some("rain"));
+    assert!(cues.ambience_for("c2").is_none());
+    assert!(cues.ambience_for("").is_none());
+    assert_eq!(ids(cues.effects_for("p1")), vec!["door", "bell"]);
+    // Tier cues only play for their outcome
+    assert!(cues.effects_for("p2").is_empty());
+    assert_eq!(
+        cues.tier_effect(Some("p2"), &EventOutcomeTier::Disaster)
+            .map(|c| c.id.as_str()),
+        Some("p2_crash")
+    );
+    assert_eq!(
+        cues.tier_effect(Some("p1"), &EventOutcomeTier::Disaster)
+            .map(|c| c.id.as_str()),
+        Some("crash")
+    );
+    assert!(cues
+        .tier_effect(None, &EventOutcomeTier::GreatSuccess)
+        .is_none());
+    assert_eq!(
+        cues.urls("/api/files/")[0],
+        "/api/files/audio/rain/rain.ogg"
+    );
+}
+
+#[test]
+fn test_volume_reads_settings() {
+    assert_eq!(Volume::from_settings(&HashMap::new()), Volume::default());
+
+    let settings = HashMap::from([
+        (AUDIO_VOLUME_SETTING.to_string(), "1.7".to_string()),
+        (AUDIO_MUTED_SETTING.to_string(), "false".to_string()),
+    ]);
+    let volume = Volume::from_settings(&settings);
+    assert_eq!(volume.gain(), 1.0);
+
+    let muted = Volume {
+        muted: true,
+        ..volume
+    };
+    assert_eq!(muted.gain(), 0.0);
+}
+
+#[test]
+fn test_crossfade_keeps_loudness_steady() {
+    assert_eq!(crossfade_gains(0.0), (1.0, 0.0));
+    let (out_gain, in_gain) = crossfade_gains(1.0);
+    assert!(out_gain.abs() < 1e-6 && (in_gain - 1.0).abs() < 1e-6);
+    for step in 0..=10 {
+        let (a, b) = crossfade_gains(step as f32 / 10.0);
+        assert!((a * a + b * b - 1.0).abs() < 1e-5);
+    }
+    assert_eq!(crossfade_gains(-3.0), (1.0, 0.0));
+}
@@ -0,0 +1,108 @@
+use ifecaro::models::bookmarks::{
+    excerpt, page_turn_mode, Bookmark, Bookmarks, CurrentPage, PagePosition, ReadingPosition,
+};
+use std::collections::HashMap;
+
+fn bookmark(id: &str, chapter_id: &str, paragraph_id: &str, index: u32) -> Bookmark {
+    Bookmark {
+        id: id.to_string(),
+        chapter_id: chapter_id.to_string(),
+        place: ReadingPosition {
+            paragraph_id: paragraph_id.to_string(),
+            reader_mode: false,
+            position: PagePosition::Page { index },
+        },
+        excerpt: format!("Passage {id}"),
+        note: String::new(),
+    }
+}
+
+#[test]
+fn test_positions_follow_the_page_turn_mode() {
+    let page = PagePosition::capture("horizontal", 1790.0, 600.0);
+    assert_eq!(page, PagePosition::Page { index: 3 });
+    // A page is whatever one turn scrolls on this screen
+    assert_eq!(page.scroll_top(500.0, 10_000.0), 1500.0);
+    assert_eq!(page.scroll_top(500.0, 1200.0), 1200.0);
+
+    let scroll = PagePosition::capture("scroll", 1790.4, 600.0);
+    assert_eq!(scroll, PagePosition::Scroll { offset: 1790.0 });
+    assert_eq!(scroll.scroll_top(500.0, 10_000.0), 1790.0);
+    assert_eq!(
+        PagePosition::capture("vertical", 100.0, 0.0),
+        PagePosition::Scroll { offset: 100.0 }
+    );
+
+    let mut settings = HashMap::new();
+    assert_eq!(page_turn_mode(&settings), "scroll");
+    settings.insert("theme_mode".to_string(), "paper".to_string());
+    assert_eq!(page_turn_mode(&settings), "horizontal");
+    settings.insert("page_turn_mode".to_string(), "vertical".to_string());
+    assert_eq!(page_turn_mode(&settings), "vertical");
+}
+
+#[test]
+fn test_positions_only_apply_to_their_page() {
+    let path = vec!["p1".to_string(), "p2".to_string(), "p3".to_string()];
+    let saved = ReadingPosition {
+        paragraph_id: "p2".to_string(),
+        reader_mode: true,
+        position: PagePosition::Scroll { offset: 420.0 },
+    };
+    let restored = ReadingPosition::from_json(&saved.to_json()).unwrap();
+    assert_eq!(restored, saved);
+
+    let same = CurrentPage {
+        paragraph_id: "p2",
+        reader_mode: true,
+        path: &path[..2],
+    };
+    let grown = CurrentPage {
+        paragraph_id: "p3",
+        reader_mode: true,
+        path: &path,
+    };
+    let game_mode = CurrentPage {
+        paragraph_id: "p2",
+        reader_mode: false,
+        path: &path[..2],
+    };
+    assert!(saved.is_current(&same));
+    assert!(!saved.is_current(&grown));
+    // Reader mode keeps earlier paragraphs above, so the offset still holds
+    assert!(saved.is_on(&grown));
+    assert!(!saved.is_on(&game_mode));
+    assert!(ReadingPosition::from_json("not json").is_none());
+}
+
+#[test]
+fn test_bookmarks_are_listed_by_chapter() {
+    let mut bookmarks = Bookmarks::default();
+    bookmarks.add(bookmark("b1", "c1", "p1", 0));
+    bookmarks.add(bookmark("b2", "c2", "p5", 2));
+    bookmarks.add(bookmark("b3", "c1", "p2", 1));
+    // Bookmarking the same place again only updates the note
+    let mut again = bookmark("b4", "c1", "p1", 0);
+    again.note = "Remember the key".to_string();
+    bookmarks.add(again);
+
+    let c1 = bookmarks.in_chapter("c1");
+    assert_eq!(
+        c1.iter().map(|b| b.id.as_str()).collect::<Vec<_>>(),
+        vec!["b1", "b3"]
+    );
+    assert_eq!(c1[0].note, "Remember the key");
+
+    bookmarks.remove("b1");
+    let restored = Bookmarks::from_json(&bookmarks.to_json()).unwrap();
+    assert_eq!(restored, bookmarks);
+    assert_eq!(restored.in_chapter("c1").len(), 1);
+    assert!(Bookmarks::from_json("{").is_err());
+}
+
+#[test]
+fn test_excerpts_are_one_short_line() {
+    assert_eq!(excerpt("  The door\n  creaked. ", 80), "The door creaked.");
+    assert_eq!(excerpt("風吹過空蕩蕩的街道", 5), "風吹過空…");
+    assert_eq!(excerpt("Far too long a sentence", 9), "Far too…");
+}
@@ -0,0 +1,195 @@
+use ifecaro::models::bundle::{
+    plan_import, BundleCollection, BundleError, ExistingRecords, IdStrategy, RecordAction,
+    StoryBundle, BUNDLE_VERSION,
+};
+use ifecaro::models::chapter::Chapter;
+use ifecaro::models::impacts::{Impact, NumericOp, RelationshipField};
+use ifecaro::models::paragraph::{Paragraph, ParagraphChoice};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+
+fn sample() -> StoryBundle {
+    let mut bundle = StoryBundle::new("2024-05-01T00:00:00Z");
+    bundle.chapters = vec![Chapter {
+        id: "c1".to_string(),
+        titles: vec![],
+        order: 1,
+    }];
+    bundle.paragraphs = vec![
+        Paragraph {
+            id: "p1".to_string(),
+            chapter_id: "c1".to_string(),
+            texts: vec![],
+            choices: vec![ParagraphChoice {
+                time_limit: Some(5),
+                timeout_to: Some("p2,outside".to_string()),
+                impacts: Some(vec![Impact::Relationship {
+                    from_id: "hero".to_string(),
+                    to_id: "rival".to_string(),
+                    field: RelationshipField::Trust,
+                    op: NumericOp::Add,
+                    value: 1,
+                }]),
+                ..ParagraphChoice::goto(vec!["p2".to_string()])
+            }],
+            ..Default::default()
+        },
+        Paragraph {
+            id: "p2".to_string(),
+            chapter_id: "c1".to_string(),
+            texts: vec![],
+            choices: vec![],
+            ..Default::default()
+        },
+    ];
+    bundle.characters = vec![
+        json!({"id": "hero", "char_id": "Hero"}),
+        json!({"id": "rival", "char_id": "Rival"}),
+    ];
+    bundle.relationships = vec![json!({"id": "r1", "from_id": "hero", "to_id": "rival"})];
+    bundle.attributes = vec![json!({"id": "a1", "character_id": "hero", "courage": 3})];
+    bundle.settings = vec![json!({"id": "s1", "key": "theme", "value": "dark"})];
+    bundle
+}
+
+fn counter() -> impl FnMut() -> String {
+    let mut next = 0;
+    move || {
+        next += 1;
+        format!("new{}", next)
+    }
+}
+
+#[test]
+fn test_parse_checks_format_and_version() {
+    assert!(matches!(
+        StoryBundle::parse(r#"{"format": "twine", "version": 1}"#),
+        Err(BundleError::WrongFormat(format)) if format == "twine"
+    ));
+    let newer = format!(
+        r#"{{"format": "ifecaro-story", "version": {}}}"#,
+        BUNDLE_VERSION + 1
+    );
+    assert_eq!(
+        StoryBundle::parse(&newer),
+        Err(BundleError::UnsupportedVersion(BUNDLE_VERSION + 1))
+    );
+    assert!(matches!(
+        StoryBundle::parse("{"),
+        Err(BundleError::Parse(_))
+    ));
+
+    // Collections missing from the file are empty
+    let minimal = StoryBundle::parse(r#"{"format": "ifecaro-story", "version": 1}"#).unwrap();
+    assert!(minimal.paragraphs.is_empty() && minimal.settings.is_empty());
+}
+
+#[test]
+fn test_keep_strategy_updates_existing_ids_and_matches_settings_by_key() {
+    let existing = ExistingRecords {
+        ids: HashMap::from([
+            (
+                BundleCollection::Paragraphs,
+                HashSet::from(["p1".to_string()]),
+            ),
+            (
+                BundleCollection::Characters,
+                HashSet::from(["hero".to_string()]),
+            ),
+        ]),
+        settings: HashMap::from([("theme".to_string(), "local".to_string())]),
+    };
+
+    let plan = plan_import(&sample(), &existing, IdStrategy::Keep, counter());
+
+    assert_eq!(
+        plan.count(BundleCollection::Paragraphs, RecordAction::Update),
+        1
+    );
+    assert_eq!(
+        plan.count(BundleCollection::Paragraphs, RecordAction::Create),
+        1
+    );
+    assert_eq!(
+        plan.count(BundleCollection::Characters, RecordAction::Update),
+        1
+    );
+    assert_eq!(
+        plan.count(BundleCollection::Chapters, RecordAction::Create),
+        1
+    );
+    let setting = plan
+        .records
+        .iter()
+        .find(|r| r.collection == BundleCollection::Settings)
+        .unwrap();
+    assert_eq!(
+        (setting.id.as_str(), setting.action),
+        ("local", RecordAction::Update)
+    );
+    assert!(setting.body.get("id").is_none());
+    assert!(plan.id_map().keys().all(|id| id == "s1"));
+
+    // Import order puts referenced collections first
+    let order: Vec<BundleCollection> = plan.records.iter().map(|r| r.collection).collect();
+    let first_paragraph = order
+        .iter()
+        .position(|c| *c == BundleCollection::Paragraphs);
+    let last_character = order
+        .iter()
+        .rposition(|c| *c == BundleCollection::Characters);
+    assert!(last_character < first_paragraph);
+}
+
+#[test]
+fn test_remap_strategy_rewrites_every_reference() {
+    let plan = plan_import(
+        &sample(),
+        &ExistingRecords::default(),
+        IdStrategy::Remap,
+        counter(),
+    );
+    let ids = plan.id_map();
+
+    assert!(plan
+        .records
+        .iter()
+        .all(|r| r.action == RecordAction::Create));
+    assert_eq!(ids.len(), plan.records.len());
+    let body = |source: &str| {
+        plan.records
+            .iter()
+            .find(|r| r.source_id == source)
+            .unwrap()
+            .body
+            .clone()
+    };
+    let p1: Paragraph = serde_json::from_value(json!({
+        "id": ids["p1"],
+        "chapter_id": body("p1")["chapter_id"],
+        "texts": [],
+        "choices": body("p1")["choices"],
+    }))
+    .unwrap();
+    assert_eq!(p1.chapter_id, ids["c1"]);
+    assert_eq!(p1.choices[0].to, vec![ids["p2"].clone()]);
+    // Links leaving the bundle are kept as they are
+    assert_eq!(
+        p1.choices[0].timeout_to,
+        Some(format!("{},outside", ids["p2"]))
+    );
+    assert_eq!(
+        p1.choices[0].impacts.as_ref().unwrap()[0],
+        Impact::Relationship {
+            from_id: ids["hero"].clone(),
+            to_id: ids["rival"].clone(),
+            field: RelationshipField::Trust,
+            op: NumericOp::Add,
+            value: 1,
+        }
+    );
+    assert_eq!(body("r1")["from_id"], json!(ids["hero"]));
+    assert_eq!(body("r1")["to_id"], json!(ids["rival"]));
+    assert_eq!(body("a1")["character_id"], json!(ids["hero"]));
+    assert_eq!(body("a1")["courage"], json!(3));
+}
@@ -11,12 +11,10 @@ pub fn create_test_paragraph(id: &str, chapter_id: &str, lang: &str, text: &str)
             lang: lang.to_string(),
             paragraphs: text.to_string(),
             choices: vec![],
+            ..Default::default()
         }],
         choices: vec![],
-        collection_id: "test_collection".to_string(),
-        collection_name: "Test Collection".to_string(),
-        created: "2024-01-01T00:00:00Z".to_string(),
-        updated: "2024-01-01T00:00:00Z".to_string(),
+        ..Default::default()
     }
 }
 
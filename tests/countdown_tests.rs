@@ -0,0 +1,102 @@
+use ifecaro::models::countdown::{
+    parse_factor, should_pause, RunningCountdowns, TimerOptions, RELAXED_TIMERS_SETTING,
+};
+use ifecaro::models::paragraph::ParagraphChoice;
+use std::collections::HashMap;
+
+fn settings(factor: &str) -> HashMap<String, String> {
+    HashMap::from([(RELAXED_TIMERS_SETTING.to_string(), factor.to_string())])
+}
+
+fn choice(time_limit: Option<u32>) -> ParagraphChoice {
+    ParagraphChoice {
+        to: vec!["next".to_string()],
+        time_limit,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_parse_factor_falls_back_and_clamps() {
+    assert_eq!(parse_factor(None), Some(1.0));
+    assert_eq!(parse_factor(Some("1.5")), Some(1.5));
+    assert_eq!(parse_factor(Some("abc")), Some(1.0));
+    assert_eq!(parse_factor(Some("NaN")), Some(1.0));
+    // Relaxed timers never shorten a limit
+    assert_eq!(parse_factor(Some("0.5")), Some(1.0));
+    assert_eq!(parse_factor(Some("60")), Some(5.0));
+    assert_eq!(parse_factor(Some(" off ")), None);
+}
+
+#[test]
+fn test_reader_options_stretch_or_remove_limits() {
+    let choices = [choice(Some(10)), choice(None), choice(Some(3))];
+
+    let default = TimerOptions::from_settings(&HashMap::new(), false);
+    assert_eq!(default.limits(&choices), vec![10, 0, 3]);
+
+    let relaxed = TimerOptions::from_settings(&settings("1.5"), false);
+    // Rounded up so a stretched limit is never shorter than asked for
+    assert_eq!(relaxed.limits(&choices), vec![15, 0, 5]);
+
+    let off = TimerOptions::from_settings(&settings("off"), false);
+    assert_eq!(off.limits(&choices), vec![0, 0, 0]);
+}
+
+#[test]
+fn test_mandatory_timers_ignore_reader_options() {
+    let choices = [choice(Some(10)), choice(None)];
+
+    for factor in ["2", "off"] {
+        let options = TimerOptions::from_settings(&settings(factor), true);
+        assert_eq!(options.limits(&choices), vec![10, 0]);
+    }
+}
+
+#[test]
+fn test_mandatory_countdown_is_not_reset_by_reader_options() {
+    let choices = [choice(Some(10)), choice(None)];
+    let mut running = RunningCountdowns::default();
+    let mandatory = |factor| TimerOptions::from_settings(&settings(factor), true);
+
+    assert!(running.update("p1", mandatory("1").limits(&choices)));
+    for factor in ["2", "off", "1.5"] {
+        assert!(!running.update("p1", mandatory(factor).limits(&choices)));
+    }
+    assert_eq!(running.durations, vec![10, 0]);
+    assert_eq!(running.rates, vec![1.0, 1.0]);
+}
+
+#[test]
+fn test_running_countdowns_scale_what_is_left() {
+    let choices = [choice(Some(10)), choice(Some(4))];
+    let mut running = RunningCountdowns::default();
+    let options = |factor| TimerOptions::from_settings(&settings(factor), false);
+
+    assert!(running.update("p1", options("1").limits(&choices)));
+    // Stretched limits keep the bars' length and slow them down
+    assert!(running.update("p1", options("2").limits(&choices)));
+    assert_eq!(running.durations, vec![10, 4]);
+    assert_eq!(running.rates, vec![0.5, 0.5]);
+    assert!(!running.update("p1", options("2").limits(&choices)));
+
+    // Turning timers off stops them; turning them back on starts them anew
+    assert!(running.update("p1", options("off").limits(&choices)));
+    assert_eq!(running.durations, vec![0, 0]);
+    assert!(running.update("p1", options("3").limits(&choices)));
+    assert_eq!(running.durations, vec![30, 12]);
+    assert_eq!(running.rates, vec![1.0, 1.0]);
+
+    // A new paragraph starts over at the current limits
+    assert!(running.update("p2", options("1.5").limits(&choices)));
+    assert_eq!(running.durations, vec![15, 6]);
+    assert_eq!(running.rates, vec![1.0, 1.0]);
+}
+
+#[test]
+fn test_countdown_pauses_for_any_reason() {
+    assert!(!should_pause(false, false, false));
+    assert!(should_pause(true, false, false));
+    assert!(should_pause(false, true, false));
+    assert!(should_pause(false, false, true));
+}
@@ -59,6 +59,7 @@ mod benchmark_tests {
                                 format!("選項三 - 段落{}", i),
                             ]
                         },
+                        ..Default::default()
                     },
                     Text {
                         lang: "en-US".to_string(),
@@ -72,6 +73,7 @@ mod benchmark_tests {
                                 format!("Option Three - Paragraph {}", i),
                             ]
                         },
+                        ..Default::default()
                     },
                     Text {
                         lang: "ja-JP".to_string(),
@@ -85,14 +87,15 @@ mod benchmark_tests {
                                 format!("選択肢三 - 段落{}", i),
                             ]
                         },
+                        ..Default::default()
                     },
                 ],
                 choices: if i % 3 == 0 {
                     vec![]
                 } else {
                     vec![
-                        ParagraphChoice::Simple(vec![format!("para{}", i + 1)]),
-                        ParagraphChoice::Complex {
+                        ParagraphChoice::goto(vec![format!("para{}", i + 1)]),
+                        ParagraphChoice {
                             to: vec![format!("para{}", i + 2), format!("para{}", i + 3)],
                             type_: "conditional".to_string(),
                             key: Some(format!("condition_{}", i)),
@@ -102,9 +105,10 @@ mod benchmark_tests {
                             timeout_to: None,
                             impacts: None,
                         },
-                        ParagraphChoice::Simple(vec![format!("para{}", i + 4)]),
+                        ParagraphChoice::goto(vec![format!("para{}", i + 4)]),
                     ]
                 },
+                ..Default::default()
             }
         }).collect();
 
@@ -231,17 +235,8 @@ mod benchmark_tests {
             }
 
             for choice in &paragraph.choices {
-                match choice {
-                    ParagraphChoice::Simple(targets) => {
-                        assert!(!targets.is_empty());
-                    }
-                    ParagraphChoice::Complex { to, type_, .. } => {
-                        assert!(!to.is_empty());
-                        assert!(!type_.is_empty());
-                        // Additional validation can be added here
-                    }
-                    _ => {}
-                }
+                assert!(!choice.to.is_empty());
+                assert!(!choice.type_.is_empty());
             }
         }
 
@@ -448,11 +443,12 @@ mod stress_tests {
                             format!("壓力測試選項2 - {}", i),
                             format!("壓力測試選項3 - {}", i),
                         ],
+                        ..Default::default()
                     },
                 ],
                 choices: vec![
-                    ParagraphChoice::Simple(vec![format!("stress_para{}", i + 1)]),
-                    ParagraphChoice::Complex {
+                    ParagraphChoice::goto(vec![format!("stress_para{}", i + 1)]),
+                    ParagraphChoice {
                         to: vec![format!("stress_para{}", i + 2)],
                         type_: "stress_test".to_string(),
                         key: Some(format!("stress_key_{}", i)),
@@ -463,6 +459,7 @@ mod stress_tests {
                         impacts: None,
                     },
                 ],
+                ..Default::default()
             });
         }
 
@@ -55,16 +55,18 @@ mod interaction_tests {
                             lang: "zh-TW".to_string(),
                             paragraphs: "這是測試段落一的內容。你站在森林的入口，看到兩條路。".to_string(),
                             choices: vec!["走左邊的路".to_string(), "走右邊的路".to_string()],
+                            ..Default::default()
                         },
                         Text {
                             lang: "en-US".to_string(),
                             paragraphs: "This is test paragraph one. You stand at the forest entrance, seeing two paths.".to_string(),
                             choices: vec!["Take the left path".to_string(), "Take the right path".to_string()],
+                            ..Default::default()
                         },
                     ],
                     choices: vec![
-                        ParagraphChoice::Simple(vec!["para2".to_string()]),
-                        ParagraphChoice::Complex {
+                        ParagraphChoice::goto(vec!["para2".to_string()]),
+                        ParagraphChoice {
                             to: vec!["para3".to_string()],
                             type_: "conditional".to_string(),
                             key: Some("forest_knowledge".to_string()),
@@ -75,6 +77,7 @@ mod interaction_tests {
                             impacts: None,
                         },
                     ],
+                    ..Default::default()
                 },
                 Paragraph {
                     id: "para2".to_string(),
@@ -84,17 +87,20 @@ mod interaction_tests {
                             lang: "zh-TW".to_string(),
                             paragraphs: "你沿著左邊的路走，來到一個清澈的湖邊。".to_string(),
                             choices: vec!["飲用湖水".to_string(), "繼續前進".to_string()],
+                            ..Default::default()
                         },
                         Text {
                             lang: "en-US".to_string(),
                             paragraphs: "You walk along the left path and arrive at a clear lake.".to_string(),
                             choices: vec!["Drink the lake water".to_string(), "Continue forward".to_string()],
+                            ..Default::default()
                         },
                     ],
                     choices: vec![
-                        ParagraphChoice::Simple(vec!["para4".to_string()]),
-                        ParagraphChoice::Simple(vec!["para5".to_string()]),
+                        ParagraphChoice::goto(vec!["para4".to_string()]),
+                        ParagraphChoice::goto(vec!["para5".to_string()]),
                     ],
+                    ..Default::default()
                 },
                 Paragraph {
                     id: "para3".to_string(),
@@ -104,15 +110,17 @@ mod interaction_tests {
                             lang: "zh-TW".to_string(),
                             paragraphs: "你選擇了右邊的路，發現了一個古老的遺跡。".to_string(),
                             choices: vec!["探索遺跡".to_string(), "繞過遺跡".to_string()],
+                            ..Default::default()
                         },
                         Text {
                             lang: "en-US".to_string(),
                             paragraphs: "You chose the right path and discovered an ancient ruin.".to_string(),
                             choices: vec!["Explore the ruin".to_string(), "Go around the ruin".to_string()],
+                            ..Default::default()
                         },
                     ],
                     choices: vec![
-                        ParagraphChoice::Complex {
+                        ParagraphChoice {
                             to: vec!["para6".to_string()],
                             type_: "gain_item".to_string(),
                             key: Some("ancient_key".to_string()),
@@ -122,8 +130,9 @@ mod interaction_tests {
                             timeout_to: None,
                             impacts: None,
                         },
-                        ParagraphChoice::Simple(vec!["para7".to_string()]),
+                        ParagraphChoice::goto(vec!["para7".to_string()]),
                     ],
+                    ..Default::default()
                 },
                 Paragraph {
                     id: "para4".to_string(),
@@ -133,16 +142,19 @@ mod interaction_tests {
                             lang: "zh-TW".to_string(),
                             paragraphs: "你喝了湖水，感到精神振奮。".to_string(),
                             choices: vec!["離開湖邊".to_string()],
+                            ..Default::default()
                         },
                         Text {
                             lang: "en-US".to_string(),
                             paragraphs: "You drank the lake water and feel refreshed.".to_string(),
                             choices: vec!["Leave the lake".to_string()],
+                            ..Default::default()
                         },
                     ],
                     choices: vec![
-                        ParagraphChoice::Simple(vec!["para8".to_string()]),
+                        ParagraphChoice::goto(vec!["para8".to_string()]),
                     ],
+                    ..Default::default()
                 },
             ],
             loaded: true,
@@ -402,42 +414,16 @@ mod interaction_tests {
                 // Load choices
                 for (i, choice_text) in text.choices.iter().enumerate() {
                     if let Some(paragraph_choice) = paragraph.choices.get(i) {
-                        match paragraph_choice {
-                            ParagraphChoice::Simple(targets) => {
-                                choices.push((
-                                    choice_text.clone(),
-                                    targets.clone(),
-                                    "goto".to_string(),
-                                    None,
-                                    None,
-                                    "chapter1".to_string(),
-                                    false,
-                                    None,
-                                ));
-                            }
-                            ParagraphChoice::Complex {
-                                to,
-                                type_,
-                                key,
-                                value,
-                                same_page,
-                                time_limit,
-                                timeout_to,
-                                ..
-                            } => {
-                                choices.push((
-                                    choice_text.clone(),
-                                    to.clone(),
-                                    type_.clone(),
-                                    key.clone(),
-                                    value.clone(),
-                                    "chapter1".to_string(),
-                                    same_page.unwrap_or(false),
-                                    *time_limit,
-                                ));
-                            }
-                            _ => {}
-                        }
+                        choices.push((
+                            choice_text.clone(),
+                            paragraph_choice.to.clone(),
+                            paragraph_choice.type_.clone(),
+                            paragraph_choice.key.clone(),
+                            paragraph_choice.value.clone(),
+                            "chapter1".to_string(),
+                            paragraph_choice.same_page.unwrap_or(false),
+                            paragraph_choice.time_limit,
+                        ));
                     }
                 }
             }
@@ -513,50 +499,42 @@ mod interaction_tests {
         let paragraph = &paragraph_state.paragraphs[0]; // para1
 
         // Test complex choice structure
-        match &paragraph.choices[1] {
-            ParagraphChoice::Complex {
-                to,
-                type_,
-                key,
-                value,
-                same_page,
-                time_limit,
-                timeout_to,
-                ..
-            } => {
-                assert_eq!(to, &vec!["para3".to_string()]);
-                assert_eq!(type_, "conditional");
-                assert_eq!(key.as_ref().unwrap(), "forest_knowledge");
-                assert_eq!(value.as_ref().unwrap(), &serde_json::json!(true));
-                assert_eq!(same_page.unwrap(), false);
-                assert_eq!(time_limit.unwrap(), 60);
-                assert!(timeout_to.is_none());
-            }
-            _ => panic!("Expected Complex choice"),
-        }
+        let ParagraphChoice {
+            to,
+            type_,
+            key,
+            value,
+            same_page,
+            time_limit,
+            timeout_to,
+            ..
+        } = &paragraph.choices[1];
+        assert_eq!(to, &vec!["para3".to_string()]);
+        assert_eq!(type_, "conditional");
+        assert_eq!(key.as_ref().unwrap(), "forest_knowledge");
+        assert_eq!(value.as_ref().unwrap(), &serde_json::json!(true));
+        assert_eq!(same_page.unwrap(), false);
+        assert_eq!(time_limit.unwrap(), 60);
+        assert!(timeout_to.is_none());
 
         let paragraph3 = &paragraph_state.paragraphs[2]; // para3
-        match &paragraph3.choices[0] {
-            ParagraphChoice::Complex {
-                to,
-                type_,
-                key,
-                value,
-                same_page,
-                time_limit,
-                timeout_to,
-                ..
-            } => {
-                assert_eq!(to, &vec!["para6".to_string()]);
-                assert_eq!(type_, "gain_item");
-                assert_eq!(key.as_ref().unwrap(), "ancient_key");
-                assert_eq!(value.as_ref().unwrap(), &serde_json::json!(1));
-                assert_eq!(same_page.unwrap(), false);
-                assert!(time_limit.is_none());
-                assert!(timeout_to.is_none());
-            }
-            _ => panic!("Expected Complex choice"),
-        }
+        let ParagraphChoice {
+            to,
+            type_,
+            key,
+            value,
+            same_page,
+            time_limit,
+            timeout_to,
+            ..
+        } = &paragraph3.choices[0];
+        assert_eq!(to, &vec!["para6".to_string()]);
+        assert_eq!(type_, "gain_item");
+        assert_eq!(key.as_ref().unwrap(), "ancient_key");
+        assert_eq!(value.as_ref().unwrap(), &serde_json::json!(1));
+        assert_eq!(same_page.unwrap(), false);
+        assert!(time_limit.is_none());
+        assert!(timeout_to.is_none());
     }
 
     #[test]
@@ -878,11 +856,7 @@ mod interaction_tests {
                         self.choice_targets = paragraph
                             .choices
                             .iter()
-                            .map(|choice| match choice {
-                                ParagraphChoice::Simple(targets) => targets.clone(),
-                                ParagraphChoice::Complex { to, .. } => to.clone(),
-                                _ => vec![],
-                            })
+                            .map(|choice| choice.to.clone())
                             .collect();
                     }
                 }
@@ -1095,8 +1069,10 @@ mod edge_case_tests {
                 lang: "zh-TW".to_string(),
                 paragraphs: "".to_string(),
                 choices: vec![],
+                ..Default::default()
             }],
             choices: vec![],
+            ..Default::default()
         };
 
         let text = &paragraph.texts[0];
@@ -1114,11 +1090,13 @@ mod edge_case_tests {
                 lang: "zh-TW".to_string(),
                 paragraphs: "Test content".to_string(),
                 choices: vec!["Choice 1".to_string(), "Choice 2".to_string()],
+                ..Default::default()
             }],
             choices: vec![
-                ParagraphChoice::Simple(vec!["para1".to_string()]),
+                ParagraphChoice::goto(vec!["para1".to_string()]),
                 // Missing second choice in paragraph.choices
             ],
+            ..Default::default()
         };
 
         let text_choices_count = paragraph.texts[0].choices.len();
@@ -1139,10 +1117,10 @@ mod edge_case_tests {
                     lang: "zh-TW".to_string(),
                     paragraphs: "Test content".to_string(),
                     choices: vec!["Go to nonexistent".to_string()],
+                    ..Default::default()
                 }],
-                choices: vec![ParagraphChoice::Simple(
-                    vec!["nonexistent_para".to_string()],
-                )],
+                choices: vec![ParagraphChoice::goto(vec!["nonexistent_para".to_string()])],
+                ..Default::default()
             }],
             loaded: true,
         };
@@ -1163,8 +1141,10 @@ mod edge_case_tests {
                         lang: "zh-TW".to_string(),
                         paragraphs: "Go to B".to_string(),
                         choices: vec!["To B".to_string()],
+                        ..Default::default()
                     }],
-                    choices: vec![ParagraphChoice::Simple(vec!["para_b".to_string()])],
+                    choices: vec![ParagraphChoice::goto(vec!["para_b".to_string()])],
+                    ..Default::default()
                 },
                 Paragraph {
                     id: "para_b".to_string(),
@@ -1173,8 +1153,10 @@ mod edge_case_tests {
                         lang: "zh-TW".to_string(),
                         paragraphs: "Go to A".to_string(),
                         choices: vec!["To A".to_string()],
+                        ..Default::default()
                     }],
-                    choices: vec![ParagraphChoice::Simple(vec!["para_a".to_string()])],
+                    choices: vec![ParagraphChoice::goto(vec!["para_a".to_string()])],
+                    ..Default::default()
                 },
             ],
             loaded: true,
@@ -1188,19 +1170,14 @@ mod edge_case_tests {
         assert!(para_b.is_some());
 
         // Check circular reference
-        if let ParagraphChoice::Simple(targets) = &para_a.unwrap().choices[0] {
-            assert_eq!(targets[0], "para_b");
-        }
-
-        if let ParagraphChoice::Simple(targets) = &para_b.unwrap().choices[0] {
-            assert_eq!(targets[0], "para_a");
-        }
+        assert_eq!(para_a.unwrap().choices[0].to[0], "para_b");
+        assert_eq!(para_b.unwrap().choices[0].to[0], "para_a");
     }
 
     #[test]
     fn test_malformed_json_values() {
         // Test with valid JSON value
-        let valid_choice = ParagraphChoice::Complex {
+        let valid_choice = ParagraphChoice {
             to: vec!["para1".to_string()],
             type_: "set_variable".to_string(),
             key: Some("player_health".to_string()),
@@ -1211,17 +1188,13 @@ mod edge_case_tests {
             impacts: None,
         };
 
-        match valid_choice {
-            ParagraphChoice::Complex { value, .. } => {
-                assert!(value.is_some());
-                let val = value.unwrap();
-                assert_eq!(val.as_i64().unwrap(), 100);
-            }
-            _ => panic!("Expected Complex choice"),
-        }
+        let value = valid_choice.value;
+        assert!(value.is_some());
+        let val = value.unwrap();
+        assert_eq!(val.as_i64().unwrap(), 100);
 
         // Test with complex JSON object
-        let complex_choice = ParagraphChoice::Complex {
+        let complex_choice = ParagraphChoice {
             to: vec!["para2".to_string()],
             type_: "complex_action".to_string(),
             key: Some("action_data".to_string()),
@@ -1237,15 +1210,11 @@ mod edge_case_tests {
             impacts: None,
         };
 
-        match complex_choice {
-            ParagraphChoice::Complex { value, .. } => {
-                assert!(value.is_some());
-                let val = value.unwrap();
-                assert_eq!(val["action"].as_str().unwrap(), "move");
-                assert_eq!(val["distance"].as_i64().unwrap(), 5);
-                assert!(val["items"].is_array());
-            }
-            _ => panic!("Expected Complex choice"),
-        }
+        let value = complex_choice.value;
+        assert!(value.is_some());
+        let val = value.unwrap();
+        assert_eq!(val["action"].as_str().unwrap(), "move");
+        assert_eq!(val["distance"].as_i64().unwrap(), 5);
+        assert!(val["items"].is_array());
     }
 }
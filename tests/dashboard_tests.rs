@@ -63,16 +63,18 @@ mod dashboard_test_helpers {
                             lang: "zh-TW".to_string(),
                             paragraphs: "這是第一個測試段落內容。".to_string(),
                             choices: vec!["選項一".to_string(), "選項二".to_string()],
+                            ..Default::default()
                         },
                         Text {
                             lang: "en-US".to_string(),
                             paragraphs: "This is the first test paragraph content.".to_string(),
                             choices: vec!["Option One".to_string(), "Option Two".to_string()],
+                            ..Default::default()
                         },
                     ],
                     choices: vec![
-                        ParagraphChoice::Simple(vec!["para2".to_string()]),
-                        ParagraphChoice::Complex {
+                        ParagraphChoice::goto(vec!["para2".to_string()]),
+                        ParagraphChoice {
                             to: vec!["para3".to_string()],
                             type_: "goto".to_string(),
                             key: Some("test_key".to_string()),
@@ -83,6 +85,7 @@ mod dashboard_test_helpers {
                             impacts: None,
                         },
                     ],
+                    ..Default::default()
                 },
                 Paragraph {
                     id: "para2".to_string(),
@@ -92,14 +95,17 @@ mod dashboard_test_helpers {
                             lang: "zh-TW".to_string(),
                             paragraphs: "這是第二個測試段落內容。".to_string(),
                             choices: vec!["繼續".to_string()],
+                            ..Default::default()
                         },
                         Text {
                             lang: "en-US".to_string(),
                             paragraphs: "This is the second test paragraph content.".to_string(),
                             choices: vec!["Continue".to_string()],
+                            ..Default::default()
                         },
                     ],
-                    choices: vec![ParagraphChoice::Simple(vec!["para3".to_string()])],
+                    choices: vec![ParagraphChoice::goto(vec!["para3".to_string()])],
+                    ..Default::default()
                 },
                 Paragraph {
                     id: "para3".to_string(),
@@ -109,14 +115,17 @@ mod dashboard_test_helpers {
                             lang: "zh-TW".to_string(),
                             paragraphs: "這是第三個測試段落內容。".to_string(),
                             choices: vec![],
+                            ..Default::default()
                         },
                         Text {
                             lang: "en-US".to_string(),
                             paragraphs: "This is the third test paragraph content.".to_string(),
                             choices: vec![],
+                            ..Default::default()
                         },
                     ],
                     choices: vec![],
+                    ..Default::default()
                 },
             ],
             loaded: true,
@@ -204,37 +213,30 @@ mod unit_tests {
         assert_eq!(paragraph.choices.len(), 2);
 
         // Test Simple choice
-        match &paragraph.choices[0] {
-            ParagraphChoice::Simple(targets) => {
-                assert_eq!(targets.len(), 1);
-                assert_eq!(targets[0], "para2");
-            }
-            _ => panic!("Expected Simple choice"),
-        }
+        let targets = &paragraph.choices[0].to;
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0], "para2");
+        assert_eq!(paragraph.choices[0].type_, "goto");
 
         // Test Complex choice
-        match &paragraph.choices[1] {
-            ParagraphChoice::Complex {
-                to,
-                type_,
-                key,
-                value,
-                same_page,
-                time_limit,
-                timeout_to,
-                ..
-            } => {
-                assert_eq!(to.len(), 1);
-                assert_eq!(to[0], "para3");
-                assert_eq!(type_, "goto");
-                assert_eq!(key.as_ref().unwrap(), "test_key");
-                assert_eq!(value.as_ref().unwrap(), &serde_json::json!("test_value"));
-                assert_eq!(same_page.unwrap(), false);
-                assert_eq!(time_limit.unwrap(), 30);
-                assert!(timeout_to.is_none());
-            }
-            _ => panic!("Expected Complex choice"),
-        }
+        let ParagraphChoice {
+            to,
+            type_,
+            key,
+            value,
+            same_page,
+            time_limit,
+            timeout_to,
+            ..
+        } = &paragraph.choices[1];
+        assert_eq!(to.len(), 1);
+        assert_eq!(to[0], "para3");
+        assert_eq!(type_, "goto");
+        assert_eq!(key.as_ref().unwrap(), "test_key");
+        assert_eq!(value.as_ref().unwrap(), &serde_json::json!("test_value"));
+        assert_eq!(same_page.unwrap(), false);
+        assert_eq!(time_limit.unwrap(), 30);
+        assert!(timeout_to.is_none());
     }
 
     #[test]
@@ -507,8 +509,10 @@ mod performance_tests {
                     lang: "zh-TW".to_string(),
                     paragraphs: format!("測試段落內容 {}", i),
                     choices: vec![format!("選項 {}", i)],
+                    ..Default::default()
                 }],
-                choices: vec![ParagraphChoice::Simple(vec![format!("para{}", i + 1)])],
+                choices: vec![ParagraphChoice::goto(vec![format!("para{}", i + 1)])],
+                ..Default::default()
             });
         }
 
@@ -0,0 +1,129 @@
+use ifecaro::models::paragraph::{Paragraph, Text};
+use ifecaro::models::replace::{apply_matches, find_matches, FindOptions};
+use ifecaro::models::search::MatchField;
+use std::collections::HashSet;
+
+fn text(lang: &str, body: &str, captions: &[&str]) -> Text {
+    Text {
+        lang: lang.to_string(),
+        paragraphs: body.to_string(),
+        choices: captions.iter().map(|c| c.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+fn sample() -> Vec<Paragraph> {
+    vec![
+        Paragraph {
+            id: "gate".to_string(),
+            chapter_id: "c1".to_string(),
+            texts: vec![
+                text("en-US", "Alice waits. alice sighs.", &["Greet Alice"]),
+                text("zh-TW", "愛麗絲在等待。", &["向愛麗絲打招呼"]),
+            ],
+            choices: vec![],
+            ..Default::default()
+        },
+        Paragraph {
+            id: "road".to_string(),
+            chapter_id: "c2".to_string(),
+            texts: vec![text("en-US", "Alice walks on.", &[])],
+            choices: vec![],
+            ..Default::default()
+        },
+    ]
+}
+
+fn options(pattern: &str) -> FindOptions {
+    FindOptions {
+        pattern: pattern.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_literal_find_covers_bodies_and_captions_in_context() {
+    // Prepare test data
+    let paragraphs = sample();
+
+    // Execute test
+    let matches = find_matches(&paragraphs, &options("alice"), "Beth").unwrap();
+
+    // Verify result
+    assert_eq!(matches.len(), 4);
+    assert_eq!(matches[1].before, "Alice waits. ");
+    assert_eq!(matches[1].matched, "alice");
+    assert_eq!(matches[1].after, " sighs.");
+    assert_eq!(matches[2].field, MatchField::Caption(0));
+    assert_eq!(matches[3].paragraph_id, "road");
+}
+
+#[test]
+fn test_scope_language_and_case_narrow_matches() {
+    let paragraphs = sample();
+    let scoped = FindOptions {
+        case_sensitive: true,
+        chapter_id: Some("c1".to_string()),
+        lang: Some("en-US".to_string()),
+        ..options("Alice")
+    };
+    let chinese = FindOptions {
+        lang: Some("zh-TW".to_string()),
+        ..options("愛麗絲")
+    };
+
+    assert_eq!(find_matches(&paragraphs, &scoped, "").unwrap().len(), 2);
+    assert_eq!(find_matches(&paragraphs, &chinese, "").unwrap().len(), 2);
+    assert!(find_matches(&paragraphs, &options(""), "x")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_regex_groups_expand_and_invalid_pattern_errors() {
+    let paragraphs = sample();
+    let regex = FindOptions {
+        regex: true,
+        case_sensitive: true,
+        ..options(r"(\w+) (waits|walks)")
+    };
+    let literal_dollar = options("Alice");
+
+    let matches = find_matches(&paragraphs, &regex, "$2 $1").unwrap();
+    let literal = find_matches(&paragraphs, &literal_dollar, "$1").unwrap();
+
+    assert_eq!(matches[0].replacement, "waits Alice");
+    assert_eq!(literal[0].replacement, "$1");
+    assert!(find_matches(
+        &paragraphs,
+        &FindOptions {
+            regex: true,
+            ..options("(")
+        },
+        ""
+    )
+    .is_err());
+    // Empty matches are skipped instead of inserting between every character
+    let empty = FindOptions {
+        regex: true,
+        ..options("x*")
+    };
+    assert!(find_matches(&paragraphs, &empty, "y").unwrap().is_empty());
+}
+
+#[test]
+fn test_apply_skips_excluded_matches() {
+    // Prepare test data
+    let paragraphs = sample();
+    let matches = find_matches(&paragraphs, &options("alice"), "Beth").unwrap();
+    let excluded: HashSet<String> = [matches[3].key(), matches[0].key()].into_iter().collect();
+
+    // Execute test
+    let edited = apply_matches(&paragraphs, &matches, &excluded);
+
+    // Verify result
+    assert_eq!(edited.len(), 1);
+    assert_eq!(edited[0].texts[0].paragraphs, "Alice waits. Beth sighs.");
+    assert_eq!(edited[0].texts[0].choices, vec!["Greet Beth".to_string()]);
+    assert_eq!(edited[0].texts[1], paragraphs[0].texts[1]);
+}
@@ -0,0 +1,116 @@
+use ifecaro::models::input_prompt::{InputPrompt, PromptConfigError, PromptError, PromptKind};
+use serde_json::json;
+
+#[test]
+fn test_text_answers_are_trimmed_and_checked() {
+    let prompt = InputPrompt {
+        kind: PromptKind::Text {
+            min_length: Some(2),
+            max_length: Some(5),
+            pattern: Some("[A-Za-z]+".to_string()),
+        },
+        required: true,
+    };
+
+    assert_eq!(prompt.validate("  Ada "), Ok("Ada".to_string()));
+    assert_eq!(prompt.validate("   "), Err(PromptError::Required));
+    assert_eq!(prompt.validate("A"), Err(PromptError::TooShort(2)));
+    assert_eq!(prompt.validate("Adelaide"), Err(PromptError::TooLong(5)));
+    // The pattern has to match the whole answer
+    assert_eq!(prompt.validate("Ada1"), Err(PromptError::PatternMismatch));
+    assert_eq!(
+        InputPrompt {
+            required: false,
+            ..prompt
+        }
+        .validate(""),
+        Ok(String::new())
+    );
+}
+
+#[test]
+fn test_numbers_and_options() {
+    let age = InputPrompt {
+        kind: PromptKind::Number {
+            min: Some(16.0),
+            max: Some(99.0),
+            integer: true,
+        },
+        required: true,
+    };
+    assert_eq!(age.validate("42.0"), Ok("42".to_string()));
+    assert_eq!(age.validate("forty"), Err(PromptError::NotANumber));
+    assert_eq!(age.validate("42.5"), Err(PromptError::NotAnInteger));
+    assert_eq!(age.validate("12"), Err(PromptError::BelowMin(16.0)));
+    assert_eq!(age.validate("120"), Err(PromptError::AboveMax(99.0)));
+
+    let house = InputPrompt {
+        kind: PromptKind::Select {
+            options: vec!["Heron".to_string(), "Stag".to_string()],
+        },
+        required: true,
+    };
+    assert_eq!(house.validate("Stag"), Ok("Stag".to_string()));
+    assert_eq!(house.validate("stag"), Err(PromptError::NotAnOption));
+}
+
+#[test]
+fn test_prompt_is_read_from_choice_value() {
+    let stored = json!({ "kind": "number", "min": 1, "integer": true });
+    let prompt = InputPrompt::from_value(&stored).unwrap();
+    assert_eq!(
+        prompt.kind,
+        PromptKind::Number {
+            min: Some(1.0),
+            max: None,
+            integer: true,
+        }
+    );
+    assert!(!prompt.required);
+
+    let as_string = json!(prompt.to_value().to_string());
+    assert_eq!(InputPrompt::from_value(&as_string), Some(prompt));
+    assert_eq!(InputPrompt::from_value(&json!("plain value")), None);
+}
+
+#[test]
+fn test_check_reports_authoring_mistakes() {
+    let broken = InputPrompt {
+        kind: PromptKind::Text {
+            min_length: Some(10),
+            max_length: Some(3),
+            pattern: Some("(".to_string()),
+        },
+        required: true,
+    };
+    let errors = broken.check(Some(" "));
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0], PromptConfigError::MissingKey);
+    assert_eq!(errors[1], PromptConfigError::EmptyRange);
+    assert!(matches!(errors[2], PromptConfigError::InvalidPattern(_)));
+
+    let empty_select = InputPrompt {
+        kind: PromptKind::Select {
+            options: Vec::new(),
+        },
+        required: true,
+    };
+    assert_eq!(
+        empty_select.check(Some("house")),
+        vec![PromptConfigError::NoOptions]
+    );
+    assert!(InputPrompt::default().check(Some("player_name")).is_empty());
+}
+
+#[test]
+fn test_check_rejects_keys_the_reader_settings_use() {
+    assert_eq!(
+        InputPrompt::default().check(Some(" reader_mode ")),
+        vec![PromptConfigError::ReservedKey("reader_mode".to_string())]
+    );
+    assert_eq!(
+        InputPrompt::default().check(Some("relaxed_timers")),
+        vec![PromptConfigError::ReservedKey("relaxed_timers".to_string())]
+    );
+}
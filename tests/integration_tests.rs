@@ -15,7 +15,7 @@ macro_rules! hs {
 #[cfg(test)]
 mod story_integration_tests {
     use super::*;
-    use ifecaro::pages::story::{merge_paragraphs_for_lang, Paragraph, Text};
+    use ifecaro::pages::story::{merge_paragraphs_for_lang, Paragraph, StoryVariables, Text};
 
     fn make_test_paragraph(id: &str, chapter_id: &str, lang: &str, text: &str) -> Paragraph {
         Paragraph {
@@ -25,12 +25,10 @@ mod story_integration_tests {
                 lang: lang.to_string(),
                 paragraphs: text.to_string(),
                 choices: vec![],
+                ..Default::default()
             }],
             choices: vec![],
-            collection_id: String::new(),
-            collection_name: String::new(),
-            created: String::new(),
-            updated: String::new(),
+            ..Default::default()
         }
     }
 
@@ -49,11 +47,40 @@ mod story_integration_tests {
             true,  // reader_mode
             false, // is_settings_chapter
             &choice_ids,
+            &StoryVariables::default(),
         );
 
         assert_eq!(result, "故事開始\n\n劇情發展\n\n故事結束");
     }
 
+    #[test]
+    fn test_illustration_heads_its_paragraph() {
+        let mut p1 = make_test_paragraph("p1", "c1", "zh", "故事開始");
+        p1.illustration = "fox_k2j4.webp".to_string();
+        p1.texts[0].illustration_alt = Some("暮色中的狐狸".to_string());
+        let p2 = make_test_paragraph("p2", "c1", "zh", "劇情發展");
+
+        let result = merge_paragraphs_for_lang(
+            &[p1, p2],
+            "zh",
+            true,
+            false,
+            &["p2".to_string()],
+            &StoryVariables::default(),
+        );
+
+        let blocks = ifecaro::models::markup::parse(&result).blocks;
+        assert_eq!(blocks.len(), 3);
+        match &blocks[0] {
+            ifecaro::models::markup::Block::Illustration { src, alt } => {
+                assert!(src.ends_with("/files/paragraphs/p1/fox_k2j4.webp"));
+                assert_eq!(alt, "暮色中的狐狸");
+            }
+            other => panic!("expected an illustration, got {:?}", other),
+        }
+        assert!(result.ends_with("故事開始\n\n劇情發展"));
+    }
+
     #[test]
     fn test_story_ui_integration() {
         use ifecaro::components::story_content::{
@@ -0,0 +1,183 @@
+use ifecaro::models::impacts::{CharacterAttributes, CharacterStateSnapshot, Impact};
+use ifecaro::models::interpolation::{
+    interpolate, interpolate_markup, lint_paragraphs, parse_expr, KnownKeys, LintIssue,
+    StoryVariables,
+};
+use ifecaro::models::markup::{parse, Block, Inline};
+use ifecaro::models::paragraph::{Paragraph, ParagraphChoice, Text};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn variables() -> StoryVariables {
+    let mut hero = CharacterAttributes {
+        stress: 72,
+        courage: 40,
+        ..Default::default()
+    };
+    hero.traits_flags = json!({ "saved_the_boy": true, "quest": { "stage": 2 } });
+    let rival = CharacterAttributes {
+        courage: 90,
+        ..Default::default()
+    };
+    StoryVariables {
+        settings: HashMap::from([
+            ("player_name".to_string(), "ada".to_string()),
+            ("player_character".to_string(), "hero".to_string()),
+        ]),
+        state: CharacterStateSnapshot {
+            characters: HashMap::from([("hero".to_string(), hero), ("rival".to_string(), rival)]),
+            relationships: Vec::new(),
+            answers: HashMap::from([("pet_name".to_string(), "*Rex* [^1]".to_string())]),
+        },
+    }
+}
+
+#[test]
+fn test_names_resolve_against_settings_attributes_and_flags() {
+    let variables = variables();
+
+    assert_eq!(
+        interpolate("Hello, {player_name | capitalize}.", &variables),
+        "Hello, Ada."
+    );
+    assert_eq!(
+        interpolate("You feel {stress > 60 ? 'shaken' : 'calm'}.", &variables),
+        "You feel shaken."
+    );
+    assert_eq!(
+        interpolate(
+            "{rival.courage > courage && flags.saved_the_boy ? \"outmatched\" : 'ready'}",
+            &variables
+        ),
+        "outmatched"
+    );
+    assert_eq!(interpolate("Stage {quest.stage}", &variables), "Stage 2");
+    assert_eq!(
+        interpolate("{nickname | default 'stranger' | upper}", &variables),
+        "STRANGER"
+    );
+}
+
+#[test]
+fn test_literal_braces_and_bad_expressions_are_left_alone() {
+    let variables = variables();
+
+    assert_eq!(
+        interpolate("\\{player_name\\}", &variables),
+        "{player_name}"
+    );
+    assert_eq!(interpolate("a { b", &variables), "a { b");
+    assert_eq!(interpolate("{stress >}", &variables), "{stress >}");
+    assert!(parse_expr("stress > ").is_err());
+    assert!(parse_expr("name | shout").is_err());
+    assert!(parse_expr("a ? 'b'").is_err());
+}
+
+#[test]
+fn test_answers_are_kept_with_the_state_and_read_as_typed() {
+    let variables = variables();
+
+    assert_eq!(
+        interpolate("Come, {pet_name}!", &variables),
+        "Come, *Rex* [^1]!"
+    );
+    let text = "**Come**, {pet_name}!\n\n[^1]: note";
+    let document = parse(&interpolate_markup(text, &variables));
+    assert_eq!(
+        document.blocks,
+        vec![Block::Paragraph(vec![
+            Inline::Strong(vec![Inline::Text("Come".to_string())]),
+            Inline::Text(", *Rex* [^1]!".to_string()),
+        ])]
+    );
+
+    let updated = variables.state.apply_impacts(&[Impact::Flag {
+        character_id: "hero".to_string(),
+        path: vec!["met_rex".to_string()],
+        value: json!(true),
+    }]);
+    assert_eq!(updated.answers, variables.state.answers);
+}
+
+#[test]
+fn test_without_state_names_are_empty() {
+    let variables = StoryVariables::default();
+
+    assert_eq!(
+        interpolate(
+            "[{player_name}] {stress > 60 ? 'shaken' : 'calm'}",
+            &variables
+        ),
+        "[] calm"
+    );
+}
+
+#[test]
+fn test_linter_reports_unknown_keys_and_syntax_errors() {
+    let mut setter = ParagraphChoice::goto(vec!["p2".to_string()]);
+    setter.type_ = "setting".to_string();
+    setter.key = Some("player_name".to_string());
+    setter.impacts = Some(vec![Impact::Flag {
+        character_id: "hero".to_string(),
+        path: vec!["saved_the_boy".to_string()],
+        value: json!(true),
+    }]);
+    let paragraphs = vec![
+        Paragraph {
+            id: "p1".to_string(),
+            chapter_id: "c1".to_string(),
+            texts: vec![Text {
+                lang: "en-US".to_string(),
+                paragraphs:
+                    "{player_name}, {stress > 60 ? 'shaken' : 'calm'}, {hero.flags.saved_the_boy}"
+                        .to_string(),
+                choices: vec!["Go, {player_nmae}".to_string()],
+                ..Default::default()
+            }],
+            choices: vec![setter],
+            ..Default::default()
+        },
+        Paragraph {
+            id: "p2".to_string(),
+            chapter_id: "c1".to_string(),
+            texts: vec![Text {
+                lang: "en-US".to_string(),
+                paragraphs: "{villain.courage} {mood ? 'x'} {mood}".to_string(),
+                choices: Vec::new(),
+                ..Default::default()
+            }],
+            choices: Vec::new(),
+            ..Default::default()
+        },
+    ];
+    let known = KnownKeys::from_story(&paragraphs, ["hero".to_string()]);
+
+    let issues = lint_paragraphs(&paragraphs, &known);
+
+    assert_eq!(
+        issues,
+        vec![
+            LintIssue::UnknownKey {
+                paragraph_id: "p1".to_string(),
+                lang: "en-US".to_string(),
+                key: "player_nmae".to_string(),
+            },
+            LintIssue::UnknownKey {
+                paragraph_id: "p2".to_string(),
+                lang: "en-US".to_string(),
+                key: "villain.courage".to_string(),
+            },
+            LintIssue::Syntax {
+                paragraph_id: "p2".to_string(),
+                lang: "en-US".to_string(),
+                expression: "mood ? 'x'".to_string(),
+                message: "expected \":\"".to_string(),
+            },
+            LintIssue::UnknownKey {
+                paragraph_id: "p2".to_string(),
+                lang: "en-US".to_string(),
+                key: "mood".to_string(),
+            },
+        ]
+    );
+}
@@ -84,7 +84,7 @@ mod main_code_usage_tests {
     #[test]
     fn test_using_main_business_logic() {
         // Directly use the main program's business logic
-        use ifecaro::pages::story::merge_paragraphs_for_lang;
+        use ifecaro::pages::story::{merge_paragraphs_for_lang, StoryVariables};
 
         let paragraphs = vec![
             create_test_paragraph("main_p1", "main_c1", "zh", "主要段落1"),
@@ -101,6 +101,7 @@ mod main_code_usage_tests {
             true, // reader_mode
             false,
             &selected_choice_ids,
+            &StoryVariables::default(),
         );
 
         let expected = "主要段落1\n\n主要段落2\n\n主要段落3";
@@ -113,6 +114,7 @@ mod main_code_usage_tests {
             false, // normal mode
             false,
             &selected_choice_ids,
+            &StoryVariables::default(),
         );
 
         let expected_normal = "主要段落1\n\n主要段落2\n\n主要段落3";
@@ -0,0 +1,140 @@
+use ifecaro::models::markup::{parse, Block, Footnote, Inline};
+
+fn text(s: &str) -> Inline {
+    Inline::Text(s.to_string())
+}
+
+#[test]
+fn test_emphasis_strong_and_nesting() {
+    let document = parse("She *knew*, **truly** *knew **it***.");
+
+    assert_eq!(
+        document.blocks,
+        vec![Block::Paragraph(vec![
+            text("She "),
+            Inline::Emphasis(vec![text("knew")]),
+            text(", "),
+            Inline::Strong(vec![text("truly")]),
+            text(" "),
+            Inline::Emphasis(vec![text("knew "), Inline::Strong(vec![text("it")])]),
+            text("."),
+        ])]
+    );
+}
+
+#[test]
+fn test_unclosed_and_escaped_markers_stay_literal() {
+    let document = parse("2 * 3 = 6, **almost\n\\*not emphasis\\*");
+
+    assert_eq!(
+        document.blocks,
+        vec![
+            Block::Paragraph(vec![text("2 * 3 = 6, **almost")]),
+            Block::Paragraph(vec![text("*not emphasis*")]),
+        ]
+    );
+}
+
+#[test]
+fn test_speaker_lines_scene_breaks_and_footnotes() {
+    let document = parse(
+        "@Mara: Keep *quiet*.\n\n* * *\nThe lamp[^lamp] went out.[^missing]\n---\n[^lamp]: An **oil** lamp.",
+    );
+
+    assert_eq!(
+        document.blocks,
+        vec![
+            Block::Speaker {
+                name: "Mara".to_string(),
+                content: vec![
+                    text("Keep "),
+                    Inline::Emphasis(vec![text("quiet")]),
+                    text("."),
+                ],
+            },
+            Block::SceneBreak,
+            Block::Paragraph(vec![
+                text("The lamp"),
+                Inline::FootnoteRef(1),
+                text(" went out.[^missing]"),
+            ]),
+            Block::SceneBreak,
+        ]
+    );
+    assert_eq!(
+        document.footnotes,
+        vec![Footnote {
+            label: "lamp".to_string(),
+            content: vec![
+                text("An "),
+                Inline::Strong(vec![text("oil")]),
+                text(" lamp."),
+            ],
+        }]
+    );
+    assert_eq!(
+        document.plain_text(),
+        "Mara: Keep quiet.\nThe lamp went out.[^missing]"
+    );
+}
+
+#[test]
+fn test_html_is_kept_as_text() {
+    let source =
+        "<script>alert(1)</script> *<img src=x onerror=alert(2)>*\n@<b>Eve</b>: \"hi\" & bye";
+    let document = parse(source);
+
+    assert_eq!(
+        document.blocks,
+        vec![
+            Block::Paragraph(vec![
+                text("<script>alert(1)</script> "),
+                Inline::Emphasis(vec![text("<img src=x onerror=alert(2)>")]),
+            ]),
+            Block::Speaker {
+                name: "<b>Eve</b>".to_string(),
+                content: vec![text("\"hi\" & bye")],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_many_unmatched_markers_parse_quickly() {
+    let source = "**a *b ".repeat(2000);
+    let started = std::time::Instant::now();
+    let document = parse(&source);
+
+    assert!(started.elapsed() < std::time::Duration::from_secs(1));
+    assert_eq!(document.plain_text(), source.trim());
+}
+
+#[test]
+fn test_illustrations_only_accept_web_urls() {
+    let document = parse(
+        "![A fox](https://example.com/api/files/paragraphs/p1/fox.webp)\n\
+         ![](/api/files/paragraphs/p2/map.png)\n\
+         ![x](javascript:alert(1))\n\
+         ![x](//elsewhere.example/a.png)\n\
+         Before ![inline](/a.png)",
+    );
+
+    assert_eq!(
+        document.blocks,
+        vec![
+            Block::Illustration {
+                src: "https://example.com/api/files/paragraphs/p1/fox.webp".to_string(),
+                alt: "A fox".to_string(),
+            },
+            Block::Illustration {
+                src: "/api/files/paragraphs/p2/map.png".to_string(),
+                alt: String::new(),
+            },
+            Block::Paragraph(vec![text("![x](javascript:alert(1))")]),
+            Block::Paragraph(vec![text("![x](//elsewhere.example/a.png)")]),
+            Block::Paragraph(vec![text("Before ![inline](/a.png)")]),
+        ]
+    );
+    // Narration reads the words only
+    assert!(!document.plain_text().contains("fox"));
+}
@@ -0,0 +1,76 @@
+use ifecaro::models::narration::{
+    parse_rate, resume_index, sentences_for_paragraph, split_sentences, Sentence,
+};
+
+fn texts(sentences: &[Sentence]) -> Vec<(&str, usize)> {
+    sentences
+        .iter()
+        .map(|s| (s.text.as_str(), s.block))
+        .collect()
+}
+
+#[test]
+fn test_sentences_split_on_latin_and_full_width_marks() {
+    let sentences = split_sentences(
+        "It was 3.14 miles. \"Run!\" she said... Why?\n\n雨停了。她說：「走吧！」然後離開",
+    );
+
+    assert_eq!(
+        texts(&sentences),
+        vec![
+            ("It was 3.14 miles.", 0),
+            ("\"Run!\" she said...", 0),
+            ("Why?", 0),
+            ("雨停了。", 1),
+            ("她說：「走吧！」", 1),
+            ("然後離開", 1),
+        ]
+    );
+}
+
+#[test]
+fn test_markup_is_read_as_plain_text() {
+    let sentences = sentences_for_paragraph(
+        "@Mara: Keep *quiet*. Now.\n* * *\nThe lamp[^n] went out.\n[^n]: Oil.",
+    );
+
+    assert_eq!(
+        texts(&sentences),
+        vec![
+            ("Mara: Keep quiet.", 0),
+            ("Now.", 0),
+            ("The lamp went out.", 1),
+        ]
+    );
+}
+
+#[test]
+fn test_sentences_know_where_they_are_in_their_line() {
+    let sentences = sentences_for_paragraph("@Mara: Keep *quiet*.  Now.\n\nGo.");
+
+    let spans: Vec<(usize, usize, usize)> = sentences
+        .iter()
+        .map(|s| (s.block, s.start, s.end))
+        .collect();
+    assert_eq!(spans, vec![(0, 0, 17), (0, 19, 23), (1, 0, 3)]);
+}
+
+#[test]
+fn test_narration_resumes_after_text_added_on_the_same_page() {
+    let first = split_sentences("One. Two.");
+    let extended = split_sentences("One. Two.\nThree.");
+    let replaced = split_sentences("Elsewhere.");
+
+    assert_eq!(resume_index(&[], &first), 0);
+    assert_eq!(resume_index(&first, &extended), 2);
+    assert_eq!(resume_index(&extended, &replaced), 0);
+}
+
+#[test]
+fn test_rate_setting_is_clamped() {
+    assert_eq!(parse_rate(None), 1.0);
+    assert_eq!(parse_rate(Some("1.25")), 1.25);
+    assert_eq!(parse_rate(Some("9")), 2.0);
+    assert_eq!(parse_rate(Some("fast")), 1.0);
+    assert_eq!(parse_rate(Some("NaN")), 1.0);
+}
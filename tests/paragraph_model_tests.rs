@@ -0,0 +1,82 @@
+use ifecaro::models::paragraph::{
+    migrate_choice, Paragraph, ParagraphChoice, CHOICE_SCHEMA_VERSION,
+};
+use serde_json::json;
+
+#[test]
+fn test_migrate_bare_string_choice() {
+    let (choice, version) = migrate_choice(json!("p2")).unwrap();
+
+    assert_eq!(version, 0);
+    assert_eq!(choice, ParagraphChoice::goto(vec!["p2".to_string()]));
+}
+
+#[test]
+fn test_migrate_target_list_choice() {
+    let (choice, version) = migrate_choice(json!(["p2", "p3"])).unwrap();
+
+    assert_eq!(version, 1);
+    assert_eq!(choice.to, vec!["p2".to_string(), "p3".to_string()]);
+    assert_eq!(choice.type_, "goto");
+}
+
+#[test]
+fn test_migrate_object_with_single_target() {
+    let (choice, version) = migrate_choice(json!({
+        "to": "p4",
+        "type": "set",
+        "key": "lamp",
+        "value": true,
+        "time_limit": 10,
+        "timeout_to": "p5"
+    }))
+    .unwrap();
+
+    assert_eq!(version, 1);
+    assert_eq!(choice.to, vec!["p4".to_string()]);
+    assert_eq!(choice.type_, "set");
+    assert_eq!(choice.key.as_deref(), Some("lamp"));
+    assert_eq!(choice.value, Some(json!(true)));
+    assert_eq!(choice.time_limit, Some(10));
+    assert_eq!(choice.timeout_targets(), vec!["p5".to_string()]);
+}
+
+#[test]
+fn test_migrate_current_object_and_missing_type() {
+    let (choice, version) = migrate_choice(json!({ "to": ["p6"] })).unwrap();
+
+    assert_eq!(version, CHOICE_SCHEMA_VERSION);
+    assert_eq!(choice.type_, "goto");
+    assert!(migrate_choice(json!(42)).is_err());
+}
+
+#[test]
+fn test_paragraph_with_mixed_choices_writes_current_shape() {
+    // Prepare test data
+    let stored = json!({
+        "id": "p1",
+        "chapter_id": "c1",
+        "texts": [{ "lang": "en-US", "paragraphs": "Hi", "choices": ["A", "B", "C"] }],
+        "choices": ["p2", ["p3"], { "to": "p4", "type": "goto", "same_page": true }],
+        "collectionId": "abc",
+        "created": "2024-01-01 00:00:00.000Z"
+    });
+
+    // Execute test
+    let paragraph: Paragraph = serde_json::from_value(stored).unwrap();
+    let written = serde_json::to_value(&paragraph).unwrap();
+
+    // Verify result
+    assert_eq!(paragraph.choices.len(), 3);
+    assert_eq!(paragraph.text_for("en-US").unwrap().choices[2], "C");
+    assert_eq!(
+        written["choices"],
+        json!([
+            { "to": ["p2"], "type": "goto" },
+            { "to": ["p3"], "type": "goto" },
+            { "to": ["p4"], "type": "goto", "same_page": true }
+        ])
+    );
+    let reread: Paragraph = serde_json::from_value(written).unwrap();
+    assert_eq!(reread, paragraph);
+}
@@ -11,8 +11,10 @@ fn find_paragraph_by_id_returns_updated_object() {
             lang: "zh-TW".to_string(),
             paragraphs: "更新後的段落內容".to_string(),
             choices: vec![],
+            ..Default::default()
         }],
-        choices: vec![ParagraphChoice::Simple(Vec::new())],
+        choices: vec![ParagraphChoice::goto(Vec::new())],
+        ..Default::default()
     };
 
     // The paragraph list now only contains the updated version
@@ -0,0 +1,74 @@
+//! Auth flow against a real PocketBase instance.
+//!
+//! Start the container from `pocketbase/docker-compose.yml`, then run:
+//!
+//! ```bash
+//! IFECARO_TEST_POCKETBASE_URL=http://127.0.0.1:8090/api \
+//!     cargo test --test pocketbase_auth_tests -- --ignored
+//! ```
+//!
+//! The `users` collection must allow public sign-up (the PocketBase default),
+//! and the invite hook must be relaxed with `IFECARO_OPEN_SIGNUP=true`.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod pocketbase_auth_tests {
+    use ifecaro::services::api::{ApiClient, ApiError, HttpApiClient};
+    use ifecaro::services::auth::{
+        current_session, login, logout, register, PasswordCredentials, SignUpRequest,
+    };
+
+    fn client() -> HttpApiClient {
+        let base_url = std::env::var("IFECARO_TEST_POCKETBASE_URL")
+            .unwrap_or_else(|_| "http://127.0.0.1:8090/api".to_string());
+        HttpApiClient::with_base_url(base_url)
+    }
+
+    fn unique_email() -> String {
+        format!(
+            "auth-test-{}@example.com",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a local PocketBase container"]
+    async fn test_register_login_refresh_logout() {
+        let client = client();
+        let email = unique_email();
+        let request = SignUpRequest {
+            email: email.clone(),
+            password: "integration-pass".to_string(),
+            password_confirm: "integration-pass".to_string(),
+            name: "Integration".to_string(),
+            invite_code: String::new(),
+        };
+
+        let session = register(&client, &request).await.expect("register");
+        assert_eq!(session.record.email, email);
+        assert!(!session.token.is_empty());
+
+        let refreshed = client.refresh_auth(&session.token).await.expect("refresh");
+        assert_eq!(refreshed.record.id, session.record.id);
+
+        logout();
+        assert!(current_session().is_none());
+
+        let again = login(&client, &email, "integration-pass")
+            .await
+            .expect("login");
+        assert_eq!(again.record.id, session.record.id);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a local PocketBase container"]
+    async fn test_wrong_password_is_rejected() {
+        let result = client()
+            .auth_with_password(&PasswordCredentials {
+                identity: unique_email(),
+                password: "nope".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ApiError::ServerError(400))));
+    }
+}
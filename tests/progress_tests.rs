@@ -0,0 +1,84 @@
+use ifecaro::models::paragraph::{Paragraph, ParagraphChoice, Text};
+use ifecaro::models::progress::{ChapterProgress, ReadingProgress};
+
+fn paragraph(id: &str, chapter_id: &str, targets: &[&str]) -> Paragraph {
+    let choices: Vec<ParagraphChoice> = targets
+        .iter()
+        .map(|target| ParagraphChoice::goto(vec![target.to_string()]))
+        .collect();
+    Paragraph {
+        id: id.to_string(),
+        chapter_id: chapter_id.to_string(),
+        texts: vec![Text {
+            lang: "en-US".to_string(),
+            paragraphs: format!("Paragraph {}", id),
+            choices: vec![String::new(); choices.len()],
+            ..Default::default()
+        }],
+        choices,
+        ..Default::default()
+    }
+}
+
+fn story() -> Vec<Paragraph> {
+    vec![
+        paragraph("start", "c1", &["left", "right"]),
+        paragraph("left", "c1", &["bad_end"]),
+        paragraph("right", "c1", &["next"]),
+        paragraph("bad_end", "c1", &[]),
+        paragraph("next", "c2", &["good_end"]),
+        paragraph("good_end", "c2", &[]),
+    ]
+}
+
+#[test]
+fn test_record_collects_visits_and_endings() {
+    let paragraphs = story();
+    let mut progress = ReadingProgress::default();
+
+    assert!(progress.record(["start", "left", "bad_end"], &paragraphs));
+    // Going back and taking the same path again adds nothing
+    assert!(!progress.record(["start", "left"], &paragraphs));
+    assert!(progress.record(["start", "right", ""], &paragraphs));
+
+    assert!(progress.has_visited("right"));
+    assert!(!progress.has_visited(""));
+    assert_eq!(progress.endings.iter().collect::<Vec<_>>(), vec!["bad_end"]);
+
+    let restored = ReadingProgress::from_json(&progress.to_json()).unwrap();
+    assert_eq!(restored, progress);
+    assert_eq!(
+        ReadingProgress::from_json("[]").unwrap(),
+        ReadingProgress::default()
+    );
+    assert!(ReadingProgress::from_json("oops").is_err());
+}
+
+#[test]
+fn test_chapter_progress_counts_only_that_chapter() {
+    let paragraphs = story();
+    let mut progress = ReadingProgress::default();
+    progress.record(["start", "left", "bad_end", "next"], &paragraphs);
+
+    assert_eq!(
+        progress.chapter(&paragraphs, "c1"),
+        ChapterProgress {
+            visited: 3,
+            total: 4,
+            endings_reached: 1,
+            endings: 1,
+        }
+    );
+    let percentages = progress.percentages(&paragraphs);
+    assert_eq!(percentages["c1"], 75);
+    assert_eq!(percentages["c2"], 50);
+    assert_eq!(progress.chapter(&paragraphs, "missing").percent(), 0);
+
+    // Never shows 100% while a paragraph is left
+    let almost = ChapterProgress {
+        visited: 199,
+        total: 200,
+        ..ChapterProgress::default()
+    };
+    assert_eq!(almost.percent(), 99);
+}
@@ -2,7 +2,9 @@ mod common;
 
 #[cfg(test)]
 mod reader_mode_integration_tests {
-    use ifecaro::pages::story::{merge_paragraphs_for_lang, ComplexChoice, Paragraph, Text};
+    use ifecaro::pages::story::{
+        merge_paragraphs_for_lang, Paragraph, ParagraphChoice, StoryVariables, Text,
+    };
     use std::collections::HashMap;
 
     fn create_story_network() -> Vec<Paragraph> {
@@ -71,11 +73,12 @@ mod reader_mode_integration_tests {
             lang: lang.to_string(),
             paragraphs: text.to_string(),
             choices: choice_texts.into_iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
         };
 
-        let complex_choices: Vec<ComplexChoice> = choice_targets
+        let complex_choices: Vec<ParagraphChoice> = choice_targets
             .into_iter()
-            .map(|targets| ComplexChoice {
+            .map(|targets| ParagraphChoice {
                 to: targets.into_iter().map(|s| s.to_string()).collect(),
                 type_: "goto".to_string(),
                 key: None,
@@ -92,10 +95,7 @@ mod reader_mode_integration_tests {
             chapter_id: chapter_id.to_string(),
             texts: vec![text_obj],
             choices: complex_choices,
-            collection_id: String::new(),
-            collection_name: String::new(),
-            created: String::new(),
-            updated: String::new(),
+            ..Default::default()
         }
     }
 
@@ -112,12 +112,10 @@ mod reader_mode_integration_tests {
                 lang: lang.to_string(),
                 paragraphs: text.to_string(),
                 choices: vec![],
+                ..Default::default()
             }],
             choices: vec![],
-            collection_id: String::new(),
-            collection_name: String::new(),
-            created: String::new(),
-            updated: String::new(),
+            ..Default::default()
         }
     }
 
@@ -202,6 +200,7 @@ mod reader_mode_integration_tests {
             true, // reader_mode
             false,
             &[],
+            &StoryVariables::default(),
         );
 
         // Should contain the start text
@@ -229,7 +228,14 @@ mod reader_mode_integration_tests {
                     ending_paragraph.clone(),
                 ];
 
-                let result = merge_paragraphs_for_lang(&path, "en", true, false, &[]);
+                let result = merge_paragraphs_for_lang(
+                    &path,
+                    "en",
+                    true,
+                    false,
+                    &[],
+                    &StoryVariables::default(),
+                );
 
                 // Should contain both start and ending texts
                 assert!(result.contains("You wake up in a mysterious forest."));
@@ -257,7 +263,14 @@ mod reader_mode_integration_tests {
         assert_eq!(expanded.len(), 1);
         assert_eq!(expanded[0].id, "empty_targets");
 
-        let result = merge_paragraphs_for_lang(&expanded, "en", true, false, &[]);
+        let result = merge_paragraphs_for_lang(
+            &expanded,
+            "en",
+            true,
+            false,
+            &[],
+            &StoryVariables::default(),
+        );
 
         assert_eq!(result, "This choice leads nowhere.");
     }
@@ -319,14 +332,16 @@ mod reader_mode_integration_tests {
                     lang: "en".to_string(),
                     paragraphs: "English story text.".to_string(),
                     choices: vec!["English choice".to_string()],
+                    ..Default::default()
                 },
                 Text {
                     lang: "zh".to_string(),
                     paragraphs: "中文故事內容。".to_string(),
                     choices: vec!["中文選擇".to_string()],
+                    ..Default::default()
                 },
             ],
-            choices: vec![ComplexChoice {
+            choices: vec![ParagraphChoice {
                 to: vec!["next".to_string()],
                 type_: "goto".to_string(),
                 key: None,
@@ -336,10 +351,7 @@ mod reader_mode_integration_tests {
                 timeout_to: None,
                 impacts: None,
             }],
-            collection_id: String::new(),
-            collection_name: String::new(),
-            created: String::new(),
-            updated: String::new(),
+            ..Default::default()
         };
 
         let next_paragraph = create_paragraph_with_no_choices(
@@ -352,13 +364,27 @@ mod reader_mode_integration_tests {
         let paragraphs = vec![multi_lang_paragraph, next_paragraph];
 
         // Test English expansion
-        let en_result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[]);
+        let en_result = merge_paragraphs_for_lang(
+            &paragraphs,
+            "en",
+            true,
+            false,
+            &[],
+            &StoryVariables::default(),
+        );
 
         assert!(en_result.contains("English story text."));
         assert!(en_result.contains("Next paragraph in English."));
 
         // Test Chinese expansion (should not include next paragraph since it has no Chinese text)
-        let zh_result = merge_paragraphs_for_lang(&paragraphs, "zh", true, false, &[]);
+        let zh_result = merge_paragraphs_for_lang(
+            &paragraphs,
+            "zh",
+            true,
+            false,
+            &[],
+            &StoryVariables::default(),
+        );
 
         assert!(zh_result.contains("中文故事內容。"));
         assert!(!zh_result.contains("Next paragraph in English."));
@@ -396,7 +422,14 @@ mod reader_mode_integration_tests {
         }
 
         // Test that merging works efficiently even with many paragraphs
-        let result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[]);
+        let result = merge_paragraphs_for_lang(
+            &paragraphs,
+            "en",
+            true,
+            false,
+            &[],
+            &StoryVariables::default(),
+        );
 
         // Should contain all paragraphs
         assert!(result.contains("This is paragraph number 0."));
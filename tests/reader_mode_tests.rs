@@ -9,7 +9,7 @@ wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 #[cfg(test)]
 mod reader_mode_tests {
     use super::*;
-    use ifecaro::pages::story::{merge_paragraphs_for_lang, ComplexChoice, Paragraph, Text};
+    use ifecaro::pages::story::{merge_paragraphs_for_lang, StoryVariables, Paragraph, ParagraphChoice, Text};
     use rand::seq::SliceRandom;
 
     fn create_test_paragraph_with_choices(
@@ -24,11 +24,12 @@ mod reader_mode_tests {
             lang: lang.to_string(),
             paragraphs: text.to_string(),
             choices: choice_texts.into_iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
         };
 
-        let complex_choices: Vec<ComplexChoice> = choice_targets
+        let complex_choices: Vec<ParagraphChoice> = choice_targets
             .into_iter()
-            .map(|targets| ComplexChoice {
+            .map(|targets| ParagraphChoice {
                 to: targets.into_iter().map(|s| s.to_string()).collect(),
                 type_: "goto".to_string(),
                 key: None,
@@ -45,10 +46,7 @@ mod reader_mode_tests {
             chapter_id: chapter_id.to_string(),
             texts: vec![choices_text],
             choices: complex_choices,
-            collection_id: String::new(),
-            collection_name: String::new(),
-            created: String::new(),
-            updated: String::new(),
+            ..Default::default()
         }
     }
 
@@ -70,6 +68,7 @@ mod reader_mode_tests {
             true,  // reader_mode = true
             false, // is_settings_chapter = false
             &[],   // choice_ids (not used in reader mode)
+            &StoryVariables::default(),
         );
 
         let expected =
@@ -93,6 +92,7 @@ mod reader_mode_tests {
             false, // reader_mode = false
             false,
             &[],
+            &StoryVariables::default(),
         );
 
         // Reader mode should also display all paragraphs
@@ -102,6 +102,7 @@ mod reader_mode_tests {
             true, // reader_mode = true
             false,
             &[],
+            &StoryVariables::default(),
         );
 
         let expected = "Paragraph 1\n\nParagraph 2\n\nParagraph 3";
@@ -124,6 +125,7 @@ mod reader_mode_tests {
             true, // reader_mode = true
             true, // is_settings_chapter = true
             &[],
+            &StoryVariables::default(),
         );
 
         let expected = "Settings paragraph 1\n\nSettings paragraph 2";
@@ -135,7 +137,7 @@ mod reader_mode_tests {
     fn test_reader_mode_empty_paragraphs() {
         let paragraphs = vec![];
 
-        let result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[]);
+        let result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[], &StoryVariables::default());
 
         assert_eq!(result, "");
     }
@@ -150,7 +152,7 @@ mod reader_mode_tests {
             "Only one paragraph.",
         )];
 
-        let result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[]);
+        let result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[], &StoryVariables::default());
 
         assert_eq!(result, "Only one paragraph.");
     }
@@ -166,32 +168,31 @@ mod reader_mode_tests {
                     lang: "en".to_string(),
                     paragraphs: "English text".to_string(),
                     choices: vec![],
+                    ..Default::default()
                 },
                 Text {
                     lang: "zh".to_string(),
                     paragraphs: "中文文本".to_string(),
                     choices: vec![],
+                    ..Default::default()
                 },
             ],
             choices: vec![],
-            collection_id: String::new(),
-            collection_name: String::new(),
-            created: String::new(),
-            updated: String::new(),
+            ..Default::default()
         };
 
         let paragraphs = vec![paragraph];
 
         // Test English
-        let en_result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[]);
+        let en_result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[], &StoryVariables::default());
         assert_eq!(en_result, "English text");
 
         // Test Chinese
-        let zh_result = merge_paragraphs_for_lang(&paragraphs, "zh", true, false, &[]);
+        let zh_result = merge_paragraphs_for_lang(&paragraphs, "zh", true, false, &[], &StoryVariables::default());
         assert_eq!(zh_result, "中文文本");
 
         // Test non-existent language
-        let missing_result = merge_paragraphs_for_lang(&paragraphs, "fr", true, false, &[]);
+        let missing_result = merge_paragraphs_for_lang(&paragraphs, "fr", true, false, &[], &StoryVariables::default());
         assert_eq!(missing_result, "");
     }
 
@@ -256,7 +257,7 @@ mod reader_mode_tests {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     fn test_complex_choice_structure() {
-        // Test the ComplexChoice structure used in reader mode
+        // Test the ParagraphChoice structure used in reader mode
         let paragraph = create_test_paragraph_with_choices(
             "test_choice",
             "chapter1",
@@ -324,7 +325,7 @@ mod reader_mode_tests {
             paragraphs[3].clone(), // end
         ];
 
-        let result = merge_paragraphs_for_lang(&expanded_path, "en", true, false, &[]);
+        let result = merge_paragraphs_for_lang(&expanded_path, "en", true, false, &[], &StoryVariables::default());
 
         let expected = "Beginning of story\n\nPath A continues\n\nStory conclusion";
         assert_eq!(result, expected);
@@ -345,7 +346,7 @@ mod reader_mode_tests {
 
         let paragraphs = vec![paragraph];
 
-        let result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[]);
+        let result = merge_paragraphs_for_lang(&paragraphs, "en", true, false, &[], &StoryVariables::default());
 
         assert_eq!(result, "This is the end of the story");
     }
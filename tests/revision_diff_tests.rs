@@ -0,0 +1,105 @@
+use ifecaro::models::diff::{describe_choice, diff_choices, diff_lines, diff_texts, DiffLine};
+use ifecaro::models::paragraph::{ParagraphChoice, Text};
+
+fn lines(text: &str) -> Vec<String> {
+    text.lines().map(str::to_string).collect()
+}
+
+fn text(lang: &str, body: &str, captions: &[&str]) -> Text {
+    Text {
+        lang: lang.to_string(),
+        paragraphs: body.to_string(),
+        choices: captions.iter().map(|c| c.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_diff_lines_keeps_common_lines_in_order() {
+    let diff = diff_lines(&lines("a\nb\nc\nd"), &lines("a\nc\nx\nd"));
+
+    assert_eq!(
+        diff,
+        vec![
+            DiffLine::Same("a".to_string()),
+            DiffLine::Removed("b".to_string()),
+            DiffLine::Same("c".to_string()),
+            DiffLine::Added("x".to_string()),
+            DiffLine::Same("d".to_string()),
+        ]
+    );
+    assert_eq!(
+        diff_lines(&[], &lines("new")),
+        vec![DiffLine::Added("new".to_string())]
+    );
+}
+
+#[test]
+fn test_diff_texts_reports_changed_languages_only() {
+    // Prepare test data
+    let old = vec![
+        text(
+            "en-US",
+            "The door is shut.\nIt is cold.",
+            &["Knock", "Leave"],
+        ),
+        text("zh-TW", "門關著。", &["敲門", "離開"]),
+    ];
+    let new = vec![
+        text(
+            "en-US",
+            "The door is open.\nIt is cold.",
+            &["Knock", "Leave"],
+        ),
+        text("zh-TW", "門關著。", &["敲門", "離開"]),
+        text("ja-JP", "扉は閉じている。", &["ノック"]),
+    ];
+
+    // Execute test
+    let diffs = diff_texts(&old, &new);
+
+    // Verify result
+    let langs: Vec<&str> = diffs.iter().map(|d| d.lang.as_str()).collect();
+    assert_eq!(langs, vec!["en-US", "ja-JP"]);
+    assert_eq!(
+        diffs[0].body[..2],
+        [
+            DiffLine::Removed("The door is shut.".to_string()),
+            DiffLine::Added("The door is open.".to_string()),
+        ]
+    );
+    assert!(diffs[0]
+        .captions
+        .iter()
+        .all(|l| matches!(l, DiffLine::Same(_))));
+    assert_eq!(
+        diffs[1].captions,
+        vec![DiffLine::Added("ノック".to_string())]
+    );
+}
+
+#[test]
+fn test_diff_choices_and_summary() {
+    let old = vec![
+        ParagraphChoice::goto(vec!["a".to_string()]),
+        ParagraphChoice::goto(vec!["b".to_string()]),
+    ];
+    let timed = ParagraphChoice {
+        time_limit: Some(10),
+        timeout_to: Some("c".to_string()),
+        ..ParagraphChoice::goto(vec!["b".to_string(), "d".to_string()])
+    };
+    let new = vec![
+        old[0].clone(),
+        timed.clone(),
+        ParagraphChoice::goto(vec!["e".to_string()]),
+    ];
+
+    let changes = diff_choices(&old, &new);
+
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].index, 1);
+    assert_eq!(changes[0].after.as_ref(), Some(&timed));
+    assert_eq!(changes[1].before, None);
+    assert_eq!(describe_choice(&timed), "goto → b, d · 10s → c");
+}
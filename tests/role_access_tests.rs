@@ -0,0 +1,67 @@
+use ifecaro::enums::route::Route;
+use ifecaro::models::role::{Capabilities, Role};
+use ifecaro::services::auth::AuthUser;
+
+fn translator(languages: &[&str]) -> Capabilities {
+    Capabilities::new(
+        Role::Translator,
+        languages.iter().map(|lang| lang.to_string()).collect(),
+    )
+}
+
+#[test]
+fn roles_are_ordered_by_privilege() {
+    assert!(Role::Reader < Role::Translator);
+    assert!(Role::Translator < Role::Editor);
+    assert!(Role::Editor < Role::Admin);
+}
+
+#[test]
+fn dashboard_route_requires_translator() {
+    let dashboard = Route::Dashboard {
+        lang: "zh-TW".to_string(),
+    };
+    let story = Route::Story {
+        lang: "zh-TW".to_string(),
+    };
+
+    assert_eq!(dashboard.required_role(), Some(Role::Translator));
+    assert_eq!(story.required_role(), None);
+    assert!(!Capabilities::default().can_access_dashboard());
+    assert!(translator(&[]).can_access_dashboard());
+}
+
+#[test]
+fn translators_edit_only_assigned_languages() {
+    let capabilities = translator(&["en-US", "ja-JP"]);
+
+    assert!(capabilities.can_edit_language("en-US"));
+    assert!(capabilities.can_edit_language("ja-JP"));
+    assert!(!capabilities.can_edit_language("zh-TW"));
+    assert!(!capabilities.can_edit_structure());
+    assert!(!capabilities.can_review_invites());
+}
+
+#[test]
+fn editors_and_admins_edit_everything() {
+    let editor = Capabilities::new(Role::Editor, Vec::new());
+    let admin = Capabilities::new(Role::Admin, Vec::new());
+
+    assert!(editor.can_edit_language("zh-TW"));
+    assert!(editor.can_edit_structure());
+    assert!(!editor.can_review_invites());
+    assert!(admin.can_edit_structure());
+    assert!(admin.can_review_invites());
+}
+
+#[test]
+fn users_without_role_field_default_to_reader() {
+    let user: AuthUser =
+        serde_json::from_str(r#"{"id":"u1","email":"reader@example.com"}"#).unwrap();
+    let translator: AuthUser =
+        serde_json::from_str(r#"{"id":"u2","role":"translator","languages":["en-US"]}"#).unwrap();
+
+    assert_eq!(user.role, Role::Reader);
+    assert!(!user.capabilities().can_access_dashboard());
+    assert!(translator.capabilities().can_edit_language("en-US"));
+}
@@ -0,0 +1,185 @@
+use ifecaro::models::impacts::Impact;
+use ifecaro::models::paragraph::{Paragraph, ParagraphChoice, Text};
+use ifecaro::models::search::{tokenize, MatchField, SearchFilters, SearchIndex};
+
+fn text(lang: &str, body: &str, captions: &[&str]) -> Text {
+    Text {
+        lang: lang.to_string(),
+        paragraphs: body.to_string(),
+        choices: captions.iter().map(|c| c.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+fn paragraph(
+    id: &str,
+    chapter_id: &str,
+    texts: Vec<Text>,
+    choices: Vec<ParagraphChoice>,
+) -> Paragraph {
+    Paragraph {
+        id: id.to_string(),
+        chapter_id: chapter_id.to_string(),
+        texts,
+        choices,
+        ..Default::default()
+    }
+}
+
+fn sample() -> Vec<Paragraph> {
+    let goto = |target: &str| ParagraphChoice::goto(vec![target.to_string()]);
+    vec![
+        paragraph(
+            "gate",
+            "c1",
+            vec![
+                text(
+                    "en-US",
+                    "Alice waits at the gate.",
+                    &["Greet Alice", "Walk away"],
+                ),
+                text("zh-TW", "愛麗絲在門口等待。", &["打招呼", "離開"]),
+            ],
+            vec![goto("yard"), goto("road")],
+        ),
+        paragraph(
+            "yard",
+            "c1",
+            vec![text(
+                "en-US",
+                "The yard is empty.",
+                &["Call out for Alicia"],
+            )],
+            vec![ParagraphChoice {
+                time_limit: Some(10),
+                timeout_to: Some("gate".to_string()),
+                ..goto("road")
+            }],
+        ),
+        paragraph(
+            "road",
+            "c2",
+            vec![text("zh-TW", "路上只有風聲，愛麗絲不見了。", &["回頭"])],
+            vec![ParagraphChoice {
+                impacts: Some(vec![Impact::Flag {
+                    character_id: "hero".to_string(),
+                    path: vec!["lost_alice".to_string()],
+                    value: serde_json::json!(true),
+                }]),
+                ..goto("gate")
+            }],
+        ),
+    ]
+}
+
+fn ids(hits: &[ifecaro::models::search::SearchHit]) -> Vec<&str> {
+    hits.iter().map(|h| h.paragraph_id.as_str()).collect()
+}
+
+#[test]
+fn test_tokenize_splits_words_and_unspaced_scripts() {
+    assert_eq!(
+        tokenize("Alice's GATE, 2nd"),
+        vec!["alice", "s", "gate", "2nd"]
+    );
+    assert_eq!(tokenize("見到Alice了"), vec!["見", "到", "alice", "了"]);
+}
+
+#[test]
+fn test_search_matches_every_language_and_caption() {
+    // Prepare test data
+    let index = SearchIndex::new(&sample());
+
+    // Execute test
+    let by_name = index.search("ali", &SearchFilters::default());
+    let in_chinese = index.search("愛麗絲", &SearchFilters::default());
+
+    // Verify result
+    assert_eq!(ids(&by_name), vec!["gate", "yard"]);
+    assert_eq!(by_name[0].matches[0].field, MatchField::Body);
+    assert_eq!(by_name[0].matches[1].field, MatchField::Caption(0));
+    assert_eq!(by_name[1].matches[0].field, MatchField::Caption(0));
+    assert_eq!(by_name[1].matches[0].snippet, "Call out for Alicia");
+    assert_eq!(ids(&in_chinese), vec!["gate", "road"]);
+    assert_eq!(in_chinese[0].matches[0].lang, "zh-TW");
+}
+
+#[test]
+fn test_search_requires_every_word() {
+    let index = SearchIndex::new(&sample());
+
+    assert_eq!(
+        ids(&index.search("alice gate", &SearchFilters::default())),
+        vec!["gate"]
+    );
+    assert!(index
+        .search("alice dragon", &SearchFilters::default())
+        .is_empty());
+    assert!(index.search("...", &SearchFilters::default()).is_empty());
+}
+
+#[test]
+fn test_unspaced_query_must_appear_unbroken() {
+    let index = SearchIndex::new(&sample());
+
+    // "road" has 麗, 絲 and 了, and "gate" has 口 and 門, but not in a row
+    assert!(index.search("麗絲了", &SearchFilters::default()).is_empty());
+    assert!(index.search("口門", &SearchFilters::default()).is_empty());
+    assert_eq!(
+        ids(&index.search("麗絲不見", &SearchFilters::default())),
+        vec!["road"]
+    );
+    assert_eq!(
+        ids(&index.search("門口 gate", &SearchFilters::default())),
+        vec!["gate"]
+    );
+    assert_eq!(
+        index.search("不見", &SearchFilters::default())[0].matches[0].snippet,
+        "路上只有風聲，愛麗絲不見了。"
+    );
+}
+
+#[test]
+fn test_filters_apply_with_and_without_query() {
+    let index = SearchIndex::new(&sample());
+    let chapter = SearchFilters {
+        chapter_id: Some("c2".to_string()),
+        ..Default::default()
+    };
+    let missing_en = SearchFilters {
+        missing_lang: Some("en-US".to_string()),
+        ..Default::default()
+    };
+    let timed = SearchFilters {
+        has_timer: true,
+        ..Default::default()
+    };
+    let with_impacts = SearchFilters {
+        has_impacts: true,
+        ..Default::default()
+    };
+
+    assert_eq!(ids(&index.search("愛麗絲", &chapter)), vec!["road"]);
+    assert_eq!(ids(&index.search("", &missing_en)), vec!["road"]);
+    assert!(index.search("", &missing_en)[0].matches.is_empty());
+    assert_eq!(ids(&index.search("", &timed)), vec!["yard"]);
+    assert_eq!(ids(&index.search("", &with_impacts)), vec!["road"]);
+    assert!(index.search("", &SearchFilters::default()).is_empty());
+}
+
+#[test]
+fn test_snippet_keeps_context_around_match() {
+    let long = format!("{} Alice {}", "word ".repeat(20), "tail ".repeat(20));
+    let index = SearchIndex::new(&[paragraph(
+        "long",
+        "c1",
+        vec![text("en-US", &long, &[])],
+        vec![],
+    )]);
+
+    let snippet = &index.search("alice", &SearchFilters::default())[0].matches[0].snippet;
+
+    assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+    assert!(snippet.contains("Alice"));
+    assert!(snippet.chars().count() < long.chars().count());
+}
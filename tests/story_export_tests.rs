@@ -0,0 +1,237 @@
+use ifecaro::models::bundle::StoryBundle;
+use ifecaro::models::chapter::{Chapter, ChapterTitle};
+use ifecaro::models::impacts::{AttributeField, Impact, NumericOp};
+use ifecaro::models::paragraph::{Paragraph, ParagraphChoice, Text};
+use ifecaro::models::story_export::{
+    export_choicescript, export_ink, illustration_issues, ExportIssue,
+};
+use serde_json::json;
+
+fn text(body: &str, captions: &[&str]) -> Text {
+    Text {
+        lang: "en-US".to_string(),
+        paragraphs: body.to_string(),
+        choices: captions.iter().map(|c| c.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+fn chapter(id: &str, order: i32, title: &str) -> Chapter {
+    Chapter {
+        id: id.to_string(),
+        titles: vec![ChapterTitle {
+            lang: "en-US".to_string(),
+            title: title.to_string(),
+        }],
+        order,
+    }
+}
+
+fn sample() -> StoryBundle {
+    let mut bundle = StoryBundle::new("");
+    // Listed out of order on purpose; export follows chapter order
+    bundle.chapters = vec![chapter("c2", 2, "Cellar"), chapter("c1", 1, "Gate")];
+    bundle.characters = vec![json!({"id": "x9", "char_id": "Hero"})];
+    bundle.paragraphs = vec![
+        Paragraph {
+            id: "down".to_string(),
+            chapter_id: "c2".to_string(),
+            texts: vec![text("Dark {here}.", &[])],
+            choices: vec![],
+            ..Default::default()
+        },
+        Paragraph {
+            id: "gate".to_string(),
+            chapter_id: "c1".to_string(),
+            texts: vec![text("A gate.", &["Enter", "Flip a coin"])],
+            choices: vec![
+                ParagraphChoice {
+                    impacts: Some(vec![
+                        Impact::CharacterAttribute {
+                            character_id: "x9".to_string(),
+                            field: AttributeField::Courage,
+                            op: NumericOp::Add,
+                            value: 2,
+                        },
+                        Impact::Flag {
+                            character_id: "x9".to_string(),
+                            path: vec!["entered".to_string()],
+                            value: json!(true),
+                        },
+                    ]),
+                    time_limit: Some(10),
+                    timeout_to: Some("yard".to_string()),
+                    ..ParagraphChoice::goto(vec!["down".to_string()])
+                },
+                ParagraphChoice::goto(vec!["down".to_string(), "yard".to_string()]),
+            ],
+            ..Default::default()
+        },
+        Paragraph {
+            id: "yard".to_string(),
+            chapter_id: "c1".to_string(),
+            texts: vec![Text {
+                lang: "ja-JP".to_string(),
+                paragraphs: "庭。".to_string(),
+                choices: vec![],
+                ..Default::default()
+            }],
+            choices: vec![],
+            ..Default::default()
+        },
+    ];
+    bundle
+}
+
+#[test]
+fn test_ink_export_uses_knots_diverts_and_assignments() {
+    let export = export_ink(&sample(), "en-US");
+
+    assert_eq!(export.files.len(), 1);
+    let ink = &export.files[0].content;
+    assert!(ink.starts_with("VAR flag_hero_entered = false\nVAR hero_courage = 0\n\n-> p_gate\n"));
+    assert!(ink.contains(
+        "=== p_gate ===\n// Chapter: Gate\nA gate.\n+ [Enter]\n    ~ hero_courage += 2\n    ~ flag_hero_entered = true\n    -> p_down\n+ [Flip a coin]\n    {~-> p_down|-> p_yard}\n"
+    ));
+    // Ink markup in prose is escaped; dead ends stop the story
+    assert!(ink.contains("=== p_down ===\n// Chapter: Cellar\nDark \\{here\\}.\n-> END"));
+}
+
+#[test]
+fn test_choicescript_export_splits_chapters_into_scenes() {
+    let export = export_choicescript(&sample(), "en-US");
+
+    let names: Vec<&str> = export.files.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["startup.txt", "ch_c1.txt", "ch_c2.txt"]);
+    assert_eq!(
+        export.files[0].content,
+        "*scene_list\n  startup\n  ch_c1\n  ch_c2\n\n*create flag_hero_entered false\n*create hero_courage 0\n*create random_roll 0\n\n*goto_scene ch_c1 p_gate\n"
+    );
+    let gate = &export.files[1].content;
+    assert!(gate.contains(
+        "*label p_gate\nA gate.\n\n*choice\n  #Enter\n    *set hero_courage +2\n    *set flag_hero_entered true\n    *goto_scene ch_c2 p_down\n"
+    ));
+    assert!(gate.contains(
+        "  #Flip a coin\n    *rand random_roll 1 2\n    *if random_roll = 1\n      *goto_scene ch_c2 p_down\n    *goto p_yard\n"
+    ));
+    assert!(gate.contains("*label p_yard\n庭。\n\n*ending"));
+}
+
+#[test]
+fn test_report_lists_what_does_not_round_trip() {
+    let issues = export_ink(&sample(), "en-US").issues;
+
+    assert_eq!(
+        issues,
+        vec![
+            ExportIssue::Timer {
+                paragraph_id: "gate".to_string(),
+                choice: 0
+            },
+            ExportIssue::RandomTargets {
+                paragraph_id: "gate".to_string(),
+                choice: 1,
+                targets: 2
+            },
+            ExportIssue::MissingTranslation {
+                paragraph_id: "yard".to_string()
+            },
+        ]
+    );
+    // Both formats lose the same things
+    assert_eq!(export_choicescript(&sample(), "en-US").issues, issues);
+}
+
+#[test]
+fn test_choicescript_export_keeps_command_like_prose_as_text() {
+    let mut bundle = sample();
+    bundle.paragraphs[0].texts = vec![text(
+        "*finale arrives.\n    Indented aside.\n  *both at once\n\nPlain.",
+        &[],
+    )];
+
+    let export = export_choicescript(&bundle, "en-US");
+
+    let cellar = &export.files[2].content;
+    assert!(cellar.contains(
+        "*label p_down\n\u{200B}*finale arrives.\nIndented aside.\n\u{200B}*both at once\n\nPlain.\n\n*ending"
+    ));
+    let rewritten: Vec<usize> = export
+        .issues
+        .iter()
+        .filter_map(|issue| match issue {
+            ExportIssue::RewrittenLine { paragraph_id, line } if paragraph_id == "down" => {
+                Some(*line)
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(rewritten, vec![0, 1, 2]);
+    // Ink escapes the same lines and reports nothing
+    let ink = export_ink(&bundle, "en-US");
+    assert!(ink.files[0]
+        .content
+        .contains("\\*finale arrives.\n    Indented aside.\n  \\*both at once\n"));
+    assert!(!ink
+        .issues
+        .iter()
+        .any(|issue| matches!(issue, ExportIssue::RewrittenLine { .. })));
+}
+
+#[test]
+fn test_illustration_files_are_reported_as_left_out() {
+    let mut bundle = sample();
+    bundle.paragraphs[2].illustration = "yard_1a2b.webp".to_string();
+    let expected = ExportIssue::Illustration {
+        paragraph_id: "yard".to_string(),
+    };
+
+    assert_eq!(
+        illustration_issues(&bundle.paragraphs),
+        vec![expected.clone()]
+    );
+    assert!(export_ink(&bundle, "en-US").issues.contains(&expected));
+    assert!(export_choicescript(&bundle, "en-US")
+        .issues
+        .contains(&expected));
+}
+
+#[test]
+fn test_colliding_identifiers_get_a_suffix() {
+    let mut bundle = sample();
+    // Names written only in CJK all come out as underscores
+    bundle.characters = vec![
+        json!({"id": "x9", "char_id": "愛麗絲"}),
+        json!({"id": "x8", "char_id": "鮑伯特"}),
+    ];
+    let flag = |character_id: &str, name: &str| Impact::Flag {
+        character_id: character_id.to_string(),
+        path: vec![name.to_string()],
+        value: json!(true),
+    };
+    bundle.paragraphs[1].choices[1].impacts = Some(vec![
+        flag("x9", "勇氣"),
+        flag("x9", "智慧"),
+        flag("x8", "勇氣"),
+    ]);
+
+    let export = export_ink(&bundle, "en-US");
+
+    let ink = &export.files[0].content;
+    assert!(ink.contains(
+        "    ~ flag_v______ = true\n    ~ flag_v_______2 = true\n    ~ flag_v____2___ = true\n"
+    ));
+    let renamed: Vec<String> = export
+        .issues
+        .iter()
+        .filter(|issue| matches!(issue, ExportIssue::RenamedVariable { .. }))
+        .map(|issue| issue.to_string())
+        .collect();
+    assert_eq!(
+        renamed,
+        vec![
+            "鮑伯特: identifier already taken, exported as v____2",
+            "v___.智慧: identifier already taken, exported as flag_v_______2",
+        ]
+    );
+}
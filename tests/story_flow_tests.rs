@@ -17,7 +17,51 @@ macro_rules! hs {
 mod story_flow_tests {
     use super::*;
     use ifecaro::components::story_content::{StoryContentUI, StoryContentUIProps};
-    use ifecaro::pages::story::merge_paragraphs_for_lang;
+    use ifecaro::models::impacts::{CharacterStateSnapshot, Impact};
+    use ifecaro::pages::story::{merge_paragraphs_for_lang, ParagraphChoice, StoryVariables};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_flag_set_by_choice_is_interpolated_in_next_paragraph() {
+        let mut start = create_test_paragraph("start", "chapter1", "en", "A boy is drowning.");
+        start.choices = vec![ParagraphChoice {
+            to: vec!["after".to_string()],
+            type_: "goto".to_string(),
+            impacts: Some(vec![Impact::Flag {
+                character_id: "hero".to_string(),
+                path: vec!["saved_the_boy".to_string()],
+                value: json!(true),
+            }]),
+            ..Default::default()
+        }];
+        let after = create_test_paragraph(
+            "after",
+            "chapter1",
+            "en",
+            "Saved: {flags.saved_the_boy}. Hero: {hero.flags.saved_the_boy}.",
+        );
+
+        // Clicking the choice applies its impacts to a fresh state, in which
+        // the character does not exist yet
+        let impacts = start.choices[0].impacts.clone().unwrap_or_default();
+        let state = CharacterStateSnapshot::default().apply_impacts(&impacts);
+        let variables = StoryVariables {
+            settings: HashMap::from([("player_character".to_string(), "hero".to_string())]),
+            state,
+        };
+
+        let result = merge_paragraphs_for_lang(
+            &[start, after],
+            "en",
+            false,
+            false,
+            &["after".to_string()],
+            &variables,
+        );
+
+        assert!(result.contains("Saved: true. Hero: true."), "{result}");
+    }
 
     #[test]
     fn test_multi_chapter_story_flow() {
@@ -34,8 +78,14 @@ mod story_flow_tests {
             "climax".to_string(),
         ];
 
-        let result =
-            merge_paragraphs_for_lang(&all_paragraphs, "zh", true, false, &selected_paragraphs);
+        let result = merge_paragraphs_for_lang(
+            &all_paragraphs,
+            "zh",
+            true,
+            false,
+            &selected_paragraphs,
+            &StoryVariables::default(),
+        );
 
         // NEW Reader Mode behavior: all paragraphs in expanded path are displayed
         let expected = "故事開場\n\n劇情發展\n\n高潮部分\n\n故事結局";
@@ -89,6 +139,7 @@ mod story_flow_tests {
             false, // normal mode
             false,
             &choice_ids,
+            &StoryVariables::default(),
         );
 
         // Test reader mode - NEW behavior: all paragraphs in expanded path are displayed
@@ -98,6 +149,7 @@ mod story_flow_tests {
             true, // reader mode
             false,
             &choice_ids,
+            &StoryVariables::default(),
         );
 
         // In NEW reader mode, all paragraphs should be displayed
@@ -126,6 +178,7 @@ mod story_flow_tests {
             true,  // reader_mode
             false, // is_settings_chapter
             &chapter1_ids,
+            &StoryVariables::default(),
         );
 
         // NEW Reader Mode behavior: all paragraphs in expanded path are displayed
@@ -0,0 +1,157 @@
+use ifecaro::models::paragraph::{Paragraph, ParagraphChoice, Text};
+use ifecaro::models::story_graph::{retarget_edge, EdgeKind, EdgeSlot, StoryGraph};
+
+fn paragraph(id: &str, chapter_id: &str, choices: Vec<ParagraphChoice>) -> Paragraph {
+    Paragraph {
+        id: id.to_string(),
+        chapter_id: chapter_id.to_string(),
+        texts: vec![Text {
+            lang: "en-US".to_string(),
+            paragraphs: format!("Paragraph {}\nsecond line", id),
+            choices: vec![String::new(); choices.len()],
+            ..Default::default()
+        }],
+        choices,
+        ..Default::default()
+    }
+}
+
+fn goto(target: &str) -> ParagraphChoice {
+    ParagraphChoice::goto(vec![target.to_string()])
+}
+
+fn sample_chapter() -> Vec<Paragraph> {
+    vec![
+        paragraph(
+            "start",
+            "c1",
+            vec![
+                goto("hall"),
+                ParagraphChoice::goto(vec!["hall".to_string(), "cellar".to_string()]),
+                ParagraphChoice {
+                    same_page: Some(true),
+                    ..goto("cellar")
+                },
+                ParagraphChoice {
+                    time_limit: Some(10),
+                    timeout_to: Some("ending".to_string()),
+                    ..goto("hall")
+                },
+            ],
+        ),
+        paragraph("hall", "c1", vec![goto("start")]),
+        paragraph("cellar", "c1", vec![]),
+        paragraph("ending", "c2", vec![]),
+    ]
+}
+
+#[test]
+fn test_edges_are_classified_by_choice_shape() {
+    let graph = StoryGraph::for_chapter(&sample_chapter(), "c1", "en-US");
+
+    let kinds: Vec<(EdgeKind, &str)> = graph
+        .edges
+        .iter()
+        .filter(|edge| edge.from == "start")
+        .map(|edge| (edge.kind, edge.to.as_str()))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (EdgeKind::Normal, "hall"),
+            (EdgeKind::Random, "hall"),
+            (EdgeKind::Random, "cellar"),
+            (EdgeKind::SamePage, "cellar"),
+            (EdgeKind::Normal, "hall"),
+            (EdgeKind::Timeout, "ending"),
+        ]
+    );
+}
+
+#[test]
+fn test_layout_places_targets_right_of_sources() {
+    let graph = StoryGraph::for_chapter(&sample_chapter(), "c1", "en-US");
+
+    let start = graph.node("start").unwrap();
+    let hall = graph.node("hall").unwrap();
+    let cellar = graph.node("cellar").unwrap();
+    assert!(hall.x > start.x);
+    assert_eq!(hall.x, cellar.x);
+    assert_ne!(hall.y, cellar.y);
+    assert_eq!(start.label, "Paragraph start");
+}
+
+#[test]
+fn test_targets_in_other_chapters_become_external_nodes() {
+    let graph = StoryGraph::for_chapter(&sample_chapter(), "c1", "en-US");
+
+    let ending = graph.node("ending").unwrap();
+    assert!(ending.external);
+    assert_eq!(graph.nodes.len(), 4);
+    assert!(graph.nodes.iter().filter(|n| n.external).count() == 1);
+    let (width, _) = graph.bounds();
+    assert!(ending.x < width);
+}
+
+#[test]
+fn test_cycle_without_entry_point_is_still_laid_out() {
+    let paragraphs = vec![
+        paragraph("a", "c1", vec![goto("b")]),
+        paragraph("b", "c1", vec![goto("a")]),
+    ];
+
+    let graph = StoryGraph::for_chapter(&paragraphs, "c1", "en-US");
+
+    assert_eq!(graph.nodes.len(), 2);
+    assert!(graph.node("b").unwrap().x > graph.node("a").unwrap().x);
+    let a = graph.node("a").unwrap();
+    assert_eq!(graph.node_at(a.x + 1.0, a.y + 1.0).unwrap().id, "a");
+}
+
+#[test]
+fn test_retarget_edge_updates_only_that_slot() {
+    let paragraphs = sample_chapter();
+    let graph = StoryGraph::for_chapter(&paragraphs, "c1", "en-US");
+    let random_edge = graph
+        .edges
+        .iter()
+        .find(|edge| edge.kind == EdgeKind::Random && edge.to == "cellar")
+        .unwrap();
+    let timeout_edge = graph
+        .edges
+        .iter()
+        .find(|edge| edge.slot == EdgeSlot::Timeout(0))
+        .unwrap();
+
+    let updated = retarget_edge(&paragraphs[0], random_edge, "hall").unwrap();
+    assert_eq!(
+        updated.choices[1].to,
+        vec!["hall".to_string(), "hall".to_string()]
+    );
+    assert_eq!(updated.choices[0], paragraphs[0].choices[0]);
+
+    let updated = retarget_edge(&updated, timeout_edge, "cellar").unwrap();
+    assert_eq!(updated.choices[3].timeout_to.as_deref(), Some("cellar"));
+
+    // Stale edge: the slot no longer points where the edge says
+    assert!(retarget_edge(&updated, timeout_edge, "hall").is_none());
+    assert!(retarget_edge(&paragraphs[1], random_edge, "hall").is_none());
+}
+
+#[test]
+fn test_discovered_graph_hides_unvisited_branches() {
+    let visited = ["start", "cellar", "ending"];
+    let graph =
+        StoryGraph::discovered(&sample_chapter(), "c1", "en-US", |id| visited.contains(&id));
+
+    let mut ids: Vec<&str> = graph.nodes.iter().map(|node| node.id.as_str()).collect();
+    ids.sort();
+    assert_eq!(ids, vec!["cellar", "ending", "start"]);
+    assert!(graph.node("ending").unwrap().external);
+    assert!(graph.edges.iter().all(|edge| edge.to != "hall"));
+    // No gap is left where the hidden paragraph would be
+    assert_eq!(graph.node("cellar").unwrap().y, 0.0);
+
+    let nothing = StoryGraph::discovered(&sample_chapter(), "c1", "en-US", |_| false);
+    assert!(nothing.nodes.is_empty());
+}
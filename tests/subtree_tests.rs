@@ -0,0 +1,124 @@
+use ifecaro::models::impacts::Impact;
+use ifecaro::models::paragraph::{Paragraph, ParagraphChoice, Text};
+use ifecaro::models::subtree::{clone_paragraphs, subtree_ids};
+
+fn paragraph(id: &str, chapter_id: &str, choices: Vec<ParagraphChoice>) -> Paragraph {
+    Paragraph {
+        id: id.to_string(),
+        chapter_id: chapter_id.to_string(),
+        texts: vec![
+            Text {
+                lang: "en-US".to_string(),
+                paragraphs: format!("Paragraph {}", id),
+                choices: vec![String::new(); choices.len()],
+                ..Default::default()
+            },
+            Text {
+                lang: "zh-TW".to_string(),
+                paragraphs: format!("段落 {}", id),
+                choices: vec![String::new(); choices.len()],
+                ..Default::default()
+            },
+        ],
+        choices,
+        ..Default::default()
+    }
+}
+
+fn goto(target: &str) -> ParagraphChoice {
+    ParagraphChoice::goto(vec![target.to_string()])
+}
+
+fn sample() -> Vec<Paragraph> {
+    vec![
+        paragraph(
+            "gate",
+            "c1",
+            vec![
+                goto("yard"),
+                ParagraphChoice {
+                    time_limit: Some(5),
+                    timeout_to: Some("tower, elsewhere".to_string()),
+                    ..goto("yard")
+                },
+            ],
+        ),
+        paragraph("yard", "c1", vec![goto("well"), goto("gate")]),
+        paragraph("tower", "c1", vec![]),
+        paragraph("well", "c1", vec![goto("next_chapter")]),
+        paragraph("next_chapter", "c2", vec![]),
+    ]
+}
+
+fn counter() -> impl FnMut() -> String {
+    let mut next = 0;
+    move || {
+        next += 1;
+        format!("new{}", next)
+    }
+}
+
+#[test]
+fn test_subtree_ids_respect_depth_and_chapter() {
+    let paragraphs = sample();
+
+    assert_eq!(subtree_ids(&paragraphs, "gate", 0), vec!["gate"]);
+    assert_eq!(
+        subtree_ids(&paragraphs, "gate", 1),
+        vec!["gate", "yard", "tower"]
+    );
+    assert_eq!(
+        subtree_ids(&paragraphs, "gate", 5),
+        vec!["gate", "yard", "tower", "well"]
+    );
+    assert!(subtree_ids(&paragraphs, "missing", 3).is_empty());
+}
+
+#[test]
+fn test_duplicate_keeps_links_texts_and_impacts() {
+    // Prepare test data
+    let mut paragraphs = sample();
+    paragraphs[0].choices[0].impacts = Some(vec![Impact::Flag {
+        character_id: "hero".to_string(),
+        path: vec!["opened_gate".to_string()],
+        value: serde_json::json!(true),
+    }]);
+    paragraphs[0].illustration = "gate_1a2b.webp".to_string();
+
+    // Execute test
+    let copies = clone_paragraphs(&paragraphs, &["gate".to_string()], counter());
+
+    // Verify result
+    assert_eq!(copies.len(), 1);
+    let copy = &copies[0];
+    assert_eq!(copy.id, "new1");
+    assert_eq!(copy.chapter_id, "c1");
+    assert_eq!(copy.texts, paragraphs[0].texts);
+    assert_eq!(copy.choices[0], paragraphs[0].choices[0]);
+    assert_eq!(
+        copy.choices[1].timeout_to.as_deref(),
+        Some("tower,elsewhere")
+    );
+    // The image file stays with the original record
+    assert!(copy.illustration.is_empty());
+}
+
+#[test]
+fn test_clone_subtree_remaps_internal_links_only() {
+    let paragraphs = sample();
+    let ids = subtree_ids(&paragraphs, "gate", 1);
+
+    let copies = clone_paragraphs(&paragraphs, &ids, counter());
+
+    let ids: Vec<&str> = copies.iter().map(|p| p.id.as_str()).collect();
+    assert_eq!(ids, vec!["new1", "new2", "new3"]);
+    // gate -> yard and the timeout to tower now point at the copies
+    assert_eq!(copies[0].choices[0].to, vec!["new2".to_string()]);
+    assert_eq!(
+        copies[0].choices[1].timeout_to.as_deref(),
+        Some("new3,elsewhere")
+    );
+    // yard -> well stays on the original, the loop back to gate follows the copy
+    assert_eq!(copies[1].choices[0].to, vec!["well".to_string()]);
+    assert_eq!(copies[1].choices[1].to, vec!["new1".to_string()]);
+}
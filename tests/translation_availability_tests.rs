@@ -10,12 +10,10 @@ fn test_paragraph_has_translation_exists() {
             lang: "en-US".to_string(),
             paragraphs: "Hello world".to_string(),
             choices: vec![],
+            ..Default::default()
         }],
         choices: vec![],
-        collection_id: String::new(),
-        collection_name: String::new(),
-        created: String::new(),
-        updated: String::new(),
+        ..Default::default()
     };
 
     let paragraphs = vec![paragraph];
@@ -32,12 +30,10 @@ fn test_paragraph_has_translation_missing() {
             lang: "en-US".to_string(),
             paragraphs: "Hello".to_string(),
             choices: vec![],
+            ..Default::default()
         }],
         choices: vec![],
-        collection_id: String::new(),
-        collection_name: String::new(),
-        created: String::new(),
-        updated: String::new(),
+        ..Default::default()
     };
 
     let paragraphs = vec![paragraph];
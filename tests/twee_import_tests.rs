@@ -0,0 +1,127 @@
+use ifecaro::models::twee::{parse_link, parse_passages, twee_to_bundle, TweeError, TweeWarning};
+
+const STORY: &str = r#":: StoryTitle
+The Cellar
+
+:: StoryData
+{
+  "ifid": "D674C58C-DEFA-4F70-B7A2-27742230C0FC",
+  "format": "Harlowe",
+  "start": "Door"
+}
+
+:: Hall [upstairs] {"position":"600,100"}
+A long hall.
+[[Back->Door]]
+
+:: Door [cellar intro]
+A heavy door. (set: $tried to true)
+You can [[open it->Cellar]] or [[Hall<-leave]].
+[[Wait|Nowhere]]
+
+:: Cellar
+Dark. <<if $tried>>Again?<</if>>
+[[Door]]
+
+:: Code [script]
+window.x = 1;
+
+:: Hall
+Second hall.
+"#;
+
+fn counter() -> impl FnMut() -> String {
+    let mut next = 0;
+    move || {
+        next += 1;
+        format!("id{}", next)
+    }
+}
+
+#[test]
+fn test_links_follow_twine_precedence() {
+    assert_eq!(parse_link("Go->Hall").target, "Hall");
+    assert_eq!(parse_link("a->b->c").caption, "a->b");
+    assert_eq!(parse_link("Hall<-Go").caption, "Go");
+    assert_eq!(parse_link("Go|Hall").target, "Hall");
+    let plain = parse_link("Hall");
+    assert_eq!(
+        (plain.caption.as_str(), plain.target.as_str()),
+        ("Hall", "Hall")
+    );
+}
+
+#[test]
+fn test_headers_carry_tags_and_escaped_names() {
+    let passages = parse_passages(":: A \\[1\\] [x y] {\"size\":\"wide\"}\nBody\n\n:: B\n");
+    assert_eq!(passages[0].name, "A [1]");
+    assert_eq!(passages[0].tags, vec!["x".to_string(), "y".to_string()]);
+    assert_eq!(passages[0].body, "Body");
+    assert_eq!(passages[1].body, "");
+}
+
+#[test]
+fn test_story_becomes_chapters_paragraphs_and_goto_choices() {
+    let import = twee_to_bundle(STORY, "en-US", "", counter()).unwrap();
+    let bundle = &import.bundle;
+
+    // The start passage comes first; chapters follow the first tag
+    let door = &bundle.paragraphs[0];
+    assert_eq!(
+        door.texts[0].paragraphs,
+        "A heavy door. (set: $tried to true)\nYou can  or ."
+    );
+    assert_eq!(door.texts[0].choices, vec!["open it", "leave"]);
+    let titles: Vec<&str> = bundle
+        .chapters
+        .iter()
+        .map(|c| c.title_for("en-US").unwrap())
+        .collect();
+    assert_eq!(titles, vec!["cellar", "upstairs", "The Cellar"]);
+    assert_eq!(bundle.paragraphs.len(), 3);
+
+    let id_of = |name: &str| {
+        bundle
+            .paragraphs
+            .iter()
+            .find(|p| p.texts[0].paragraphs.starts_with(name))
+            .unwrap()
+            .id
+            .clone()
+    };
+    assert_eq!(door.choices[0].to, vec![id_of("Dark")]);
+    assert_eq!(door.choices[1].to, vec![id_of("A long hall")]);
+    assert!(door.choices.iter().all(|c| c.type_ == "goto"));
+
+    assert_eq!(
+        import.warnings,
+        vec![
+            TweeWarning::CodePassage {
+                passage: "Code".to_string()
+            },
+            TweeWarning::DuplicatePassage {
+                passage: "Hall".to_string()
+            },
+            TweeWarning::UnsupportedMacro {
+                passage: "Door".to_string(),
+                name: "set".to_string()
+            },
+            TweeWarning::BrokenLink {
+                passage: "Door".to_string(),
+                target: "Nowhere".to_string()
+            },
+            TweeWarning::UnsupportedMacro {
+                passage: "Cellar".to_string(),
+                name: "if".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_source_without_passages_is_an_error() {
+    assert_eq!(
+        twee_to_bundle(":: StoryTitle\nEmpty\n", "en-US", "", counter()),
+        Err(TweeError::Empty)
+    );
+}